@@ -0,0 +1,8 @@
+//! The interactive layer of the mozzart application
+//!
+//! A `Session` evaluates the commands typed at the prompt against a
+//! `SessionState`, keeping the history needed to undo, redo and export them.
+
+mod session;
+
+pub use session::*;
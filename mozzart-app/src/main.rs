@@ -1,19 +1,38 @@
-use mozzart_std::constants::*;
-use mozzart_std::*;
+use mozzart_app::Session;
+use std::io::{self, BufRead, Write};
 
 fn main() {
-    // Create a C major scale
-    let c_major_scale = C4.into_major_scale();
-    {
-        // Result: [C4, D4, E4, F4, G4, A4, B4, C5]
-        let s = NamedSlice::new("C Major".to_string(), c_major_scale.notes());
-        println!("{:?}", s);
-    }
+    // A session exported with `export-session` can be given to continue from it
+    let mut session = match std::env::args().nth(1) {
+        Some(path) => match Session::load(&path) {
+            Ok(session) => session,
+            Err(error) => {
+                eprintln!("error: cannot load {path}: {error}");
+                std::process::exit(1);
+            }
+        },
+        None => Session::new(),
+    };
+    let stdin = io::stdin();
 
-    let c_major_triad = C4.major_triad_chord();
-    {
-        // Result: [C4, E4, G4]
-        let s = NamedSlice::new("C Major Triad".to_string(), c_major_triad.notes());
-        println!("{:?}", s);
+    print_prompt();
+    for line in stdin.lock().lines() {
+        let Ok(line) = line else { break };
+        match line.trim() {
+            "" => {}
+            "quit" | "exit" => break,
+            line => match session.execute(line) {
+                Ok(message) => println!("{message}"),
+                Err(error) => eprintln!("error: {error}"),
+            },
+        }
+        print_prompt();
     }
 }
+
+/// Writes the prompt, before reading a command
+fn print_prompt() {
+    print!("mozzart> ");
+    // A prompt which cannot be shown doesn't prevent reading commands
+    let _ = io::stdout().flush();
+}
@@ -0,0 +1,444 @@
+use mozzart_std::*;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Represents what the commands of a session work on: the current key and the saved presets
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct SessionState {
+    key: Option<DynScale>,
+    presets: PresetStore,
+}
+
+impl SessionState {
+    /// Returns the current key, if one was set
+    pub fn key(&self) -> Option<&DynScale> {
+        self.key.as_ref()
+    }
+
+    /// Returns the presets created during the session
+    pub fn presets(&self) -> &PresetStore {
+        &self.presets
+    }
+}
+
+/// Represents the errors reported by a session
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SessionError {
+    /// The line doesn't start with a known command
+    UnknownCommand(String),
+    /// A command was given without one of its arguments
+    MissingArgument {
+        command: &'static str,
+        argument: &'static str,
+    },
+    /// The command needs a key, and none was set
+    NoKey,
+    /// There is no command to undo
+    NothingToUndo,
+    /// There is no undone command to redo
+    NothingToRedo,
+    /// A session script could not be read or written
+    Io(String),
+    /// A command failed in the mozzart library
+    Mozzart(Error),
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionError::UnknownCommand(command) => write!(f, "unknown command '{command}'"),
+            SessionError::MissingArgument { command, argument } => {
+                write!(f, "'{command}' needs {argument}")
+            }
+            SessionError::NoKey => write!(f, "no key is set, use 'key <root> <quality>'"),
+            SessionError::NothingToUndo => write!(f, "nothing to undo"),
+            SessionError::NothingToRedo => write!(f, "nothing to redo"),
+            SessionError::Io(error) => write!(f, "{error}"),
+            SessionError::Mozzart(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+impl From<Error> for SessionError {
+    fn from(error: Error) -> Self {
+        SessionError::Mozzart(error)
+    }
+}
+
+/// Represents a command changing the state of a session
+///
+/// Commands are parsed from a line, such as `key C4 major` or
+/// `progression verse I vi7 ii7 V7/V`, and their lines are what an exported
+/// session replays.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Command {
+    /// Sets the current key
+    Key(DynScale),
+    /// Saves the current key as a scale preset
+    Scale { name: String },
+    /// Saves a progression written as Roman numerals in the current key, see `parse_progression`
+    Progression { name: String, numerals: String },
+}
+
+impl Command {
+    /// Applies the command to a state
+    ///
+    /// # Returns
+    /// The message to show, or the error which left the state unchanged
+    pub fn apply(&self, state: &mut SessionState) -> Result<String, SessionError> {
+        match self {
+            Command::Key(key) => {
+                state.key = Some(key.clone());
+                Ok(format!("key is {key}"))
+            }
+            Command::Scale { name } => {
+                let key = state.key.clone().ok_or(SessionError::NoKey)?;
+                let message = format!("saved scale '{name}' ({key})");
+                state.presets.insert_scale(name.clone(), key);
+                Ok(message)
+            }
+            Command::Progression { name, numerals } => {
+                let key = state.key.as_ref().ok_or(SessionError::NoKey)?;
+                let progression = progression_in(key, numerals)?;
+                let message = format!("saved progression '{name}' ({} chords)", progression.len());
+                state.presets.insert_progression(name.clone(), progression);
+                Ok(message)
+            }
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = SessionError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (command, rest) = split_command(line);
+        let missing = |command, argument| SessionError::MissingArgument { command, argument };
+
+        match command {
+            "key" => {
+                if rest.is_empty() {
+                    return Err(missing("key", "a root and a quality"));
+                }
+                Ok(Command::Key(rest.parse()?))
+            }
+            "scale" => {
+                let name = rest
+                    .split_whitespace()
+                    .next()
+                    .ok_or_else(|| missing("scale", "a name"))?;
+                Ok(Command::Scale {
+                    name: name.to_string(),
+                })
+            }
+            "progression" => {
+                let (name, numerals) = split_command(rest);
+                if name.is_empty() {
+                    return Err(missing("progression", "a name"));
+                }
+                if numerals.is_empty() {
+                    return Err(missing("progression", "roman numerals"));
+                }
+                Ok(Command::Progression {
+                    name: name.to_string(),
+                    numerals: numerals.to_string(),
+                })
+            }
+            _ => Err(SessionError::UnknownCommand(command.to_string())),
+        }
+    }
+}
+
+/// Splits a line into its first word and the rest of it, both trimmed
+fn split_command(line: &str) -> (&str, &str) {
+    let line = line.trim();
+    match line.split_once(char::is_whitespace) {
+        Some((command, rest)) => (command, rest.trim()),
+        None => (line, ""),
+    }
+}
+
+/// Returns the progression of Roman numerals in a key of any quality
+fn progression_in(key: &DynScale, numerals: &str) -> Result<Progression, Error> {
+    if let Ok(key) = key.try_into_fixed::<MajorScaleQuality, 8>() {
+        return parse_progression_in(&key, numerals);
+    }
+    if let Ok(key) = key.try_into_fixed::<MinorScaleQuality, 8>() {
+        return parse_progression_in(&key, numerals);
+    }
+    if let Ok(key) = key.try_into_fixed::<HarmonicMinorScaleQuality, 8>() {
+        return parse_progression_in(&key, numerals);
+    }
+    let key = key.try_into_fixed::<MelodicMinorScaleQuality, 8>()?;
+    parse_progression_in(&key, numerals)
+}
+
+/// Represents a session of commands, which can be undone, redone and exported
+///
+/// Each command changing the state is recorded with the state before it, so
+/// undoing it restores that snapshot. Besides the commands, a session
+/// understands:
+/// - `undo` and `redo`
+/// - `history`, listing the lines entered so far
+/// - `export-session <file>`, writing the commands in effect as a script
+///   and the presets next to it; the rest of the line is the path, spaces included
+#[derive(Debug, Default)]
+pub struct Session {
+    state: SessionState,
+    undo: Vec<(String, SessionState)>,
+    redo: Vec<(String, SessionState)>,
+    history: Vec<String>,
+}
+
+impl Session {
+    /// Creates a new `Session`, without a key nor presets
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a session by running the commands of a script, one per line
+    ///
+    /// Empty lines and lines starting with `#` are skipped.
+    ///
+    /// # Returns
+    /// The session, or the error of the first line which fails
+    pub fn replay(script: &str) -> Result<Self, SessionError> {
+        let mut session = Self::new();
+        for line in script.lines().map(str::trim) {
+            if !line.is_empty() && !line.starts_with('#') {
+                session.execute(line)?;
+            }
+        }
+        Ok(session)
+    }
+
+    /// Creates a session by running the script saved in a file, see `replay`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SessionError> {
+        let script =
+            std::fs::read_to_string(path).map_err(|error| SessionError::Io(error.to_string()))?;
+        Self::replay(&script)
+    }
+
+    /// Returns the current state of the session
+    pub fn state(&self) -> &SessionState {
+        &self.state
+    }
+
+    /// Returns the lines entered so far which succeeded, in order
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Returns the lines of the commands in effect, the ones not undone, in order
+    pub fn script(&self) -> String {
+        self.undo
+            .iter()
+            .map(|(line, _)| format!("{line}\n"))
+            .collect()
+    }
+
+    /// Runs a line
+    ///
+    /// # Returns
+    /// The message to show, or the error which left the session unchanged
+    pub fn execute(&mut self, line: &str) -> Result<String, SessionError> {
+        let line = line.trim();
+        let (command, rest) = split_command(line);
+        let message = match command {
+            "undo" => self.undo()?,
+            "redo" => self.redo()?,
+            "history" => self.list_history(),
+            "export-session" => {
+                if rest.is_empty() {
+                    return Err(SessionError::MissingArgument {
+                        command: "export-session",
+                        argument: "a file",
+                    });
+                }
+                self.export(rest)?
+            }
+            _ => {
+                let command: Command = line.parse()?;
+                let mut state = self.state.clone();
+                let message = command.apply(&mut state)?;
+                let before = std::mem::replace(&mut self.state, state);
+                self.undo.push((line.to_string(), before));
+                self.redo.clear();
+                message
+            }
+        };
+
+        self.history.push(line.to_string());
+        Ok(message)
+    }
+
+    /// Restores the state before the last command in effect
+    fn undo(&mut self) -> Result<String, SessionError> {
+        let (line, before) = self.undo.pop().ok_or(SessionError::NothingToUndo)?;
+        let after = std::mem::replace(&mut self.state, before);
+        let message = format!("undone '{line}'");
+        self.redo.push((line, after));
+        Ok(message)
+    }
+
+    /// Restores the state after the last undone command
+    fn redo(&mut self) -> Result<String, SessionError> {
+        let (line, after) = self.redo.pop().ok_or(SessionError::NothingToRedo)?;
+        let before = std::mem::replace(&mut self.state, after);
+        let message = format!("redone '{line}'");
+        self.undo.push((line, before));
+        Ok(message)
+    }
+
+    /// Returns the lines entered so far, numbered from 1
+    fn list_history(&self) -> String {
+        self.history
+            .iter()
+            .enumerate()
+            .map(|(index, line)| format!("{:>3}  {line}", index + 1))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Writes the script of the session to a file and its presets next to it
+    fn export(&self, path: &str) -> Result<String, SessionError> {
+        let path = Path::new(path);
+        let presets = presets_path(path);
+        std::fs::write(path, self.script()).map_err(|error| SessionError::Io(error.to_string()))?;
+        self.state.presets.save(&presets)?;
+        Ok(format!(
+            "exported {} commands to {} and the presets to {}",
+            self.undo.len(),
+            path.display(),
+            presets.display()
+        ))
+    }
+}
+
+/// Returns the path of the presets exported with a session script, such as `song.presets.json` for `song.txt`
+pub fn presets_path(script: &Path) -> PathBuf {
+    script.with_extension("presets.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_std::constants::*;
+
+    #[test]
+    fn test_undo_redo() {
+        let mut session = Session::new();
+        session.execute("key C4 major").unwrap();
+        session.execute("progression verse I vi IV V").unwrap();
+        let with_progression = session.state().clone();
+        assert_eq!(
+            with_progression
+                .presets()
+                .progression("verse")
+                .unwrap()
+                .chords()[0]
+                .notes(),
+            &[C4, E4, G4]
+        );
+
+        session.execute("undo").unwrap();
+        assert!(session.state().presets().progression("verse").is_none());
+        assert_eq!(
+            session.state().key(),
+            Some(&DynScale::from(major_scale(C4).unwrap()))
+        );
+
+        session.execute("redo").unwrap();
+        assert_eq!(session.state(), &with_progression);
+        assert_eq!(session.execute("redo"), Err(SessionError::NothingToRedo));
+
+        // A new command drops the undone ones
+        session.execute("undo").unwrap();
+        session.execute("key A3 minor").unwrap();
+        assert_eq!(session.execute("redo"), Err(SessionError::NothingToRedo));
+        session.execute("undo").unwrap();
+        session.execute("undo").unwrap();
+        assert_eq!(session.state(), &SessionState::default());
+        assert_eq!(session.execute("undo"), Err(SessionError::NothingToUndo));
+    }
+
+    #[test]
+    fn test_errors_leave_the_state_unchanged() {
+        let mut session = Session::new();
+        assert_eq!(
+            session.execute("progression verse I IV"),
+            Err(SessionError::NoKey)
+        );
+        assert_eq!(
+            session.execute("play"),
+            Err(SessionError::UnknownCommand("play".to_string()))
+        );
+        session.execute("key D4 harmonic").unwrap();
+        assert_eq!(
+            session.execute("progression bad I IX"),
+            Err(SessionError::Mozzart(Error::InvalidProgressionNumeral {
+                numeral: "IX".to_string(),
+                position: 2
+            }))
+        );
+        assert!(session.execute("progression").is_err());
+        assert!(session.state().presets().progression("bad").is_none());
+
+        assert_eq!(session.history(), &["key D4 harmonic"]);
+        assert_eq!(session.script(), "key D4 harmonic\n");
+    }
+
+    #[test]
+    fn test_progression_of_sevenths() {
+        let mut session = Session::new();
+        session.execute("key C4 major").unwrap();
+        session
+            .execute("progression turnaround | I vi7 | ii7 - V7/V |")
+            .unwrap();
+
+        let progression = session.state().presets().progression("turnaround").unwrap();
+        assert_eq!(progression.len(), 4);
+        assert_eq!(progression.chords()[0].notes(), &[C4, E4, G4]);
+        assert_eq!(progression.chords()[1].notes(), &[A4, C5, E5, G5]);
+        assert_eq!(progression.chords()[3].notes(), &[D5, FSHARP5, A5, C6]);
+        assert_eq!(
+            session.script(),
+            "key C4 major\nprogression turnaround | I vi7 | ii7 - V7/V |\n"
+        );
+    }
+
+    #[test]
+    fn test_export_and_replay() {
+        let path = std::env::temp_dir().join(format!("mozzart {} session.txt", std::process::id()));
+
+        let mut session = Session::new();
+        session.execute("key C4 major").unwrap();
+        session.execute("progression verse I vi IV V").unwrap();
+        session.execute("undo").unwrap();
+        session.execute("key G3 major").unwrap();
+        session.execute("progression chorus IV V I").unwrap();
+        session.execute("scale home").unwrap();
+        let exported = session
+            .execute(&format!("export-session {}", path.display()))
+            .unwrap();
+        assert!(exported.starts_with("exported 4 commands"));
+
+        let history = session.execute("history").unwrap();
+        assert!(history.starts_with("  1  key C4 major"));
+        assert_eq!(history.lines().count(), 7);
+
+        let replayed = Session::load(&path).unwrap();
+        assert_eq!(replayed.state(), session.state());
+        assert_eq!(replayed.script(), session.script());
+        assert!(replayed.state().presets().progression("verse").is_none());
+
+        let presets = PresetStore::load(presets_path(&path)).unwrap();
+        assert_eq!(&presets, session.state().presets());
+
+        std::fs::remove_file(presets_path(&path)).unwrap();
+        std::fs::remove_file(&path).unwrap();
+    }
+}
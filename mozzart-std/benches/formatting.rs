@@ -0,0 +1,64 @@
+//! Compares writing note names from the static table with the pitch class lookup it replaced
+//!
+//! Run with `cargo bench -p mozzart-std --bench formatting`.
+use mozzart_std::*;
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: u32 = 10_000;
+
+fn notes() -> Vec<Note> {
+    (0..128)
+        .filter_map(|midi| Note::try_from(midi).ok())
+        .collect()
+}
+
+fn measure(label: &str, mut run: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        run();
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{label:>10}: {:>8.1} ns per round of 128 notes",
+        elapsed.as_nanos() as f64 / f64::from(ROUNDS)
+    );
+    elapsed
+}
+
+fn main() {
+    let notes = notes();
+    let mut out = String::with_capacity(1024);
+
+    // Before the table, a name was written from the pitch class table of
+    // `Display` followed by the octave computed from the MIDI number
+    let lookup = measure("lookup", || {
+        out.clear();
+        for note in black_box(&notes) {
+            let octave = i16::from(note.midi_number() / 12) - 1;
+            write!(out, "{note}{octave}").unwrap();
+        }
+        black_box(&out);
+    });
+
+    let table = measure("name()", || {
+        out.clear();
+        for note in black_box(&notes) {
+            out.push_str(note.name());
+        }
+        black_box(&out);
+    });
+
+    let named = NamedSlice::new("all".to_string(), &notes);
+    measure("NamedSlice", || {
+        out.clear();
+        write!(out, "{named}").unwrap();
+        black_box(&out);
+    });
+
+    println!(
+        "name() is {:.1}x faster than the pitch class lookup",
+        lookup.as_secs_f64() / table.as_secs_f64()
+    );
+}
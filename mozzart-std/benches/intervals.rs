@@ -0,0 +1,91 @@
+//! Compares collecting intervals and pitches into vectors with consuming the lazy iterators
+//!
+//! Run with `cargo bench -p mozzart-std --bench intervals`.
+use mozzart_std::constants::*;
+use mozzart_std::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: u32 = 100_000;
+
+/// Chords and short phrases of three to eight notes, as found in a sequencer loop
+fn phrases() -> Vec<Vec<Note>> {
+    vec![
+        vec![C4, E4, G4],
+        vec![D4, F4, A4, C5],
+        vec![G3, B3, D4, F4, A4],
+        vec![C4, D4, E4, F4, G4, A4],
+        vec![E4, G4, B4, D5, FSHARP5, A5, C6],
+        vec![C4, D4, E4, F4, G4, A4, B4, C5],
+    ]
+}
+
+fn measure(label: &str, mut run: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        run();
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{label:>16}: {:>8.1} ns per round of 6 phrases",
+        elapsed.as_nanos() as f64 / f64::from(ROUNDS)
+    );
+    elapsed
+}
+
+fn main() {
+    let phrases = phrases();
+    let intervals: Vec<_> = phrases.iter().map(|notes| notes.intervals()).collect();
+
+    let collected = measure("intervals()", || {
+        for notes in black_box(&phrases) {
+            let total: u32 = notes
+                .intervals()
+                .iter()
+                .map(|interval| u32::from(interval.semitones()))
+                .sum();
+            black_box(total);
+        }
+    });
+
+    let lazy = measure("intervals_iter()", || {
+        for notes in black_box(&phrases) {
+            let total: u32 = notes
+                .intervals_iter()
+                .map(|interval| u32::from(interval.semitones()))
+                .sum();
+            black_box(total);
+        }
+    });
+
+    println!(
+        "intervals_iter() is {:.1}x faster than intervals()",
+        collected.as_secs_f64() / lazy.as_secs_f64()
+    );
+
+    let collected = measure("pitches()", || {
+        for steps in black_box(&intervals) {
+            let total: u32 = steps
+                .pitches(C3)
+                .iter()
+                .map(|note| u32::from(note.midi_number()))
+                .sum();
+            black_box(total);
+        }
+    });
+
+    let lazy = measure("pitches_iter()", || {
+        for steps in black_box(&intervals) {
+            let total: u32 = steps
+                .pitches_iter(C3)
+                .map(|note| u32::from(note.midi_number()))
+                .sum();
+            black_box(total);
+        }
+    });
+
+    println!(
+        "pitches_iter() is {:.1}x faster than pitches()",
+        collected.as_secs_f64() / lazy.as_secs_f64()
+    );
+}
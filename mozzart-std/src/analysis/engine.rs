@@ -0,0 +1,485 @@
+use crate::constants::*;
+use crate::{
+    major_scale, major_triad, minor_triad, natural_minor_scale, ChordQuality, Note,
+    PitchClassHistogram, PitchClassMask,
+};
+
+/// A note starting or stopping at a point in time
+///
+/// The time is given in seconds, from any origin, and must not go backwards.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum NoteEvent {
+    On { note: Note, time: f32 },
+    Off { note: Note, time: f32 },
+}
+
+impl NoteEvent {
+    /// Returns the time of the event, in seconds
+    pub const fn time(&self) -> f32 {
+        match self {
+            NoteEvent::On { time, .. } | NoteEvent::Off { time, .. } => *time,
+        }
+    }
+}
+
+/// The mode of an estimated key
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum KeyMode {
+    Major,
+    Minor,
+}
+
+/// The key the analysis engine believes is being played
+///
+/// The tonic is a pitch class, reported as the note of that class in the fourth octave.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct KeyEstimate {
+    pub tonic: Note,
+    pub mode: KeyMode,
+    /// How much the estimate can be trusted, from 0 to 1
+    pub confidence: f32,
+}
+
+/// The chord the analysis engine believes is sounding
+///
+/// The root is a pitch class, reported as the note of that class in the fourth octave.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ChordEstimate {
+    pub root: Note,
+    pub quality: ChordQuality,
+    /// How much the estimate can be trusted, from 0 to 1
+    pub confidence: f32,
+}
+
+/// The settings of an `AnalysisEngine`
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let config = AnalysisConfig {
+///     half_life: 4.0,
+///     ..AnalysisConfig::default()
+/// };
+/// let engine = AnalysisEngine::new(config);
+/// assert_eq!(engine.current_key_estimate(), None);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AnalysisConfig {
+    /// The time, in seconds, after which a played note weighs half as much
+    pub half_life: f32,
+    /// The confidence below which no key is reported
+    pub min_key_confidence: f32,
+    /// The confidence below which no chord is reported
+    pub min_chord_confidence: f32,
+}
+
+impl Default for AnalysisConfig {
+    fn default() -> Self {
+        Self {
+            half_life: 2.0,
+            min_key_confidence: 0.5,
+            min_chord_confidence: 0.75,
+        }
+    }
+}
+
+/// The amount of decayed note weight at which the key confidence is halved
+const EVIDENCE_SCALE: f32 = 1.0;
+
+/// The number of MIDI notes tracked by the engine
+const MIDI_NOTES: u32 = 128;
+
+/// Tracks the key and the chord of a live stream of notes
+///
+/// Each note that starts adds its pitch class to a histogram whose weights
+/// halve every half-life, so recent notes count more than old ones. The key
+/// is the major or natural minor key whose scale, and above all whose tonic
+/// triad, best covers the histogram. The chord is matched against the notes
+/// that are currently held.
+///
+/// Feeding an event only decays and updates the twelve bins of the histogram
+/// and the set of held notes, so it takes constant time whatever the length
+/// of the stream.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let mut engine = AnalysisEngine::default();
+/// for (i, note) in [C4, E4, G4].into_iter().enumerate() {
+///     engine.feed(NoteEvent::On { note, time: i as f32 * 0.1 });
+/// }
+///
+/// let chord = engine.current_chord_estimate().unwrap();
+/// assert_eq!((chord.root, chord.quality), (C4, ChordQuality::MajorTriad));
+///
+/// let key = engine.current_key_estimate().unwrap();
+/// assert_eq!((key.tonic, key.mode), (C4, KeyMode::Major));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnalysisEngine {
+    config: AnalysisConfig,
+    histogram: [f32; SEMITONES_IN_OCTAVE as usize],
+    held: u128,
+    time: Option<f32>,
+}
+
+impl AnalysisEngine {
+    /// Creates an engine that has not heard any note yet
+    ///
+    /// # Arguments
+    /// * `config` - The half-life and the confidence thresholds
+    ///
+    /// # Returns
+    /// A new `AnalysisEngine` instance
+    pub fn new(config: AnalysisConfig) -> Self {
+        Self {
+            config,
+            histogram: [0.0; SEMITONES_IN_OCTAVE as usize],
+            held: 0,
+            time: None,
+        }
+    }
+
+    /// Returns the settings of the engine
+    pub const fn config(&self) -> &AnalysisConfig {
+        &self.config
+    }
+
+    /// Updates the analysis with a note starting or stopping
+    ///
+    /// # Arguments
+    /// * `event` - The event; events are expected in chronological order
+    pub fn feed(&mut self, event: NoteEvent) {
+        self.advance_to(event.time());
+
+        match event {
+            NoteEvent::On { note, .. } => {
+                self.histogram[usize::from(note.pitch_class())] += 1.0;
+                self.held |= 1 << note.midi_number();
+            }
+            NoteEvent::Off { note, .. } => {
+                self.held &= !(1 << note.midi_number());
+            }
+        }
+    }
+
+    /// Lets time pass without any event, decaying the histogram
+    ///
+    /// Call this during silences so the confidence of the key estimate fades.
+    /// A time earlier than the last event is ignored.
+    ///
+    /// # Arguments
+    /// * `time` - The current time, in seconds
+    pub fn advance_to(&mut self, time: f32) {
+        if let Some(last) = self.time {
+            let elapsed = time - last;
+            if elapsed <= 0.0 {
+                return;
+            }
+
+            let decay = 0.5f32.powf(elapsed / self.config.half_life);
+            self.histogram
+                .iter_mut()
+                .for_each(|weight| *weight *= decay);
+        }
+        self.time = Some(time);
+    }
+
+    /// Returns the key that best explains the recent notes
+    ///
+    /// The confidence is the share of the histogram covered by the key, where
+    /// tonic triad notes count double, scaled down when few notes were heard
+    /// recently.
+    ///
+    /// # Returns
+    /// The estimate, or `None` if its confidence is below the configured threshold
+    pub fn current_key_estimate(&self) -> Option<KeyEstimate> {
+        let total: f32 = self.histogram.iter().sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let in_range = "the templates on C4 are in the MIDI range";
+        let templates = [
+            (
+                KeyMode::Major,
+                PitchClassMask::from_notes(major_scale(C4).expect(in_range).notes()),
+                PitchClassMask::from_notes(major_triad(C4).expect(in_range).notes()),
+            ),
+            (
+                KeyMode::Minor,
+                PitchClassMask::from_notes(natural_minor_scale(C4).expect(in_range).notes()),
+                PitchClassMask::from_notes(minor_triad(C4).expect(in_range).notes()),
+            ),
+        ];
+
+        let mut best: Option<(u8, KeyMode, f32)> = None;
+        for (mode, scale, triad) in templates {
+            for tonic in 0..SEMITONES_IN_OCTAVE {
+                let fit =
+                    self.weight_in(scale.transpose(tonic)) + self.weight_in(triad.transpose(tonic));
+                let better = match best {
+                    Some((_, _, best_fit)) => fit > best_fit,
+                    None => true,
+                };
+                if better {
+                    best = Some((tonic, mode, fit));
+                }
+            }
+        }
+
+        let (tonic, mode, fit) = best?;
+        let evidence = total / (total + EVIDENCE_SCALE);
+        let confidence = fit / (2.0 * total) * evidence;
+
+        (confidence >= self.config.min_key_confidence).then(|| KeyEstimate {
+            tonic: in_fourth_octave(tonic),
+            mode,
+            confidence,
+        })
+    }
+
+    /// Returns the chord that best matches the notes currently held
+    ///
+    /// The confidence is the number of pitch classes shared by the held notes
+    /// and the chord, over the number of pitch classes in either. When several
+    /// chords match equally well, the one rooted on the bass is preferred, then
+    /// the simplest one.
+    ///
+    /// # Returns
+    /// The estimate, or `None` if no note is held or the confidence is below the configured threshold
+    pub fn current_chord_estimate(&self) -> Option<ChordEstimate> {
+        if self.held == 0 {
+            return None;
+        }
+
+        let held: PitchClassMask = (0..MIDI_NOTES)
+            .filter(|midi| self.held & 1 << midi != 0)
+            .map(|midi| Note::new(midi as u8))
+            .collect();
+        let bass = (self.held.trailing_zeros() % u32::from(SEMITONES_IN_OCTAVE)) as u8;
+
+        let mut best: Option<(u8, ChordQuality, f32)> = None;
+        for quality in ChordQuality::ALL {
+            let shape: PitchClassMask = std::iter::once(C4)
+                .chain(
+                    quality
+                        .intervals()
+                        .iter()
+                        .map(|interval| Note::new(C4.midi_number() + interval.semitones())),
+                )
+                .collect();
+
+            for offset in 0..SEMITONES_IN_OCTAVE {
+                let root = (bass + offset) % SEMITONES_IN_OCTAVE;
+                let chord = shape.transpose(root);
+                let shared = (held.bits() & chord.bits()).count_ones();
+                let either = (held.bits() | chord.bits()).count_ones();
+                let score = shared as f32 / either as f32;
+
+                let better = match best {
+                    None => true,
+                    Some((best_root, _, best_score)) => {
+                        score > best_score
+                            || (score == best_score && root == bass && best_root != bass)
+                    }
+                };
+                if better {
+                    best = Some((root, quality, score));
+                }
+            }
+        }
+
+        let (root, quality, confidence) = best?;
+        (confidence >= self.config.min_chord_confidence).then(|| ChordEstimate {
+            root: in_fourth_octave(root),
+            quality,
+            confidence,
+        })
+    }
+
+    /// Returns the decayed weight of each pitch class heard so far
+    ///
+    /// The weights count note onsets rather than durations, which a live stream
+    /// doesn't know in advance. Use `PitchClassHistogram::best_keys` on it to rank
+    /// the keys with the Krumhansl-Schmuckler algorithm.
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let mut engine = AnalysisEngine::default();
+    /// engine.feed(NoteEvent::On { note: A4, time: 0.0 });
+    /// assert_eq!(engine.histogram().weights()[9], 1.0);
+    /// ```
+    pub fn histogram(&self) -> PitchClassHistogram {
+        PitchClassHistogram::new(self.histogram)
+    }
+
+    /// Returns the histogram weight of the pitch classes in the mask
+    fn weight_in(&self, mask: PitchClassMask) -> f32 {
+        self.histogram
+            .iter()
+            .enumerate()
+            .filter(|(class, _)| mask.bits() & 1 << class != 0)
+            .map(|(_, weight)| weight)
+            .sum()
+    }
+}
+
+impl Default for AnalysisEngine {
+    fn default() -> Self {
+        Self::new(AnalysisConfig::default())
+    }
+}
+
+/// Returns the note of a pitch class in the fourth octave
+pub(crate) fn in_fourth_octave(class: u8) -> Note {
+    Note::new(C4.midi_number() + class)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The time between two events of the scripted streams, in seconds
+    const TICK: f32 = 0.25;
+
+    /// Plays the notes one after the other, each released when the next starts
+    fn play(engine: &mut AnalysisEngine, notes: &[Note], start: f32) -> f32 {
+        let mut time = start;
+        for &note in notes {
+            engine.feed(NoteEvent::On { note, time });
+            time += TICK;
+            engine.feed(NoteEvent::Off { note, time });
+        }
+        time
+    }
+
+    /// Returns the notes of the scale going up and back down to the tonic
+    fn up_and_down(scale: &[Note]) -> Vec<Note> {
+        scale
+            .iter()
+            .chain(scale.iter().rev().skip(1))
+            .copied()
+            .collect()
+    }
+
+    fn key(engine: &AnalysisEngine) -> Option<(Note, KeyMode)> {
+        engine
+            .current_key_estimate()
+            .map(|estimate| (estimate.tonic, estimate.mode))
+    }
+
+    #[test]
+    fn test_modulation_from_c_major_to_g_major() {
+        let mut engine = AnalysisEngine::default();
+        let c_major = up_and_down(major_scale(C4).unwrap().notes());
+        let g_major = up_and_down(major_scale(G3).unwrap().notes());
+
+        let mut time = 0.0;
+        for _ in 0..4 {
+            time = play(&mut engine, &c_major, time);
+        }
+        assert_eq!(key(&engine), Some((C4, KeyMode::Major)));
+
+        let mut heard = 0;
+        'modulation: for _ in 0..4 {
+            for &note in &g_major {
+                time = play(&mut engine, &[note], time);
+                heard += 1;
+                if key(&engine) == Some((G4, KeyMode::Major)) {
+                    break 'modulation;
+                }
+            }
+        }
+        assert!(heard <= 12, "the key changed after {heard} notes");
+
+        // The estimate stays on the new key
+        time = play(&mut engine, &g_major, time);
+        play(&mut engine, &g_major, time);
+        assert_eq!(key(&engine), Some((G4, KeyMode::Major)));
+    }
+
+    #[test]
+    fn test_minor_key() {
+        let mut engine = AnalysisEngine::default();
+        let a_minor = [A3, C4, E4, A4, E4, C4, A3, B3, D4, E4, A3];
+        play(&mut engine, &a_minor, 0.0);
+        assert_eq!(key(&engine), Some((A4, KeyMode::Minor)));
+    }
+
+    #[test]
+    fn test_silence_decays_confidence() {
+        let mut engine = AnalysisEngine::default();
+        let time = play(&mut engine, major_scale(C4).unwrap().notes(), 0.0);
+        let heard = engine.current_key_estimate().unwrap();
+        assert!(heard.confidence >= engine.config().min_key_confidence);
+
+        engine.advance_to(time + engine.config().half_life);
+        let fading = engine.current_key_estimate().unwrap();
+        assert!(fading.confidence < heard.confidence);
+
+        engine.advance_to(time + 8.0 * engine.config().half_life);
+        assert_eq!(engine.current_key_estimate(), None);
+    }
+
+    #[test]
+    fn test_chord_follows_held_notes() {
+        let mut engine = AnalysisEngine::default();
+        for note in [E3, G3, C4] {
+            engine.feed(NoteEvent::On { note, time: 0.0 });
+        }
+        let chord = engine.current_chord_estimate().unwrap();
+        assert_eq!((chord.root, chord.quality), (C4, ChordQuality::MajorTriad));
+        assert_eq!(chord.confidence, 1.0);
+
+        engine.feed(NoteEvent::Off {
+            note: C4,
+            time: 1.0,
+        });
+        engine.feed(NoteEvent::On {
+            note: B3,
+            time: 1.0,
+        });
+        let chord = engine.current_chord_estimate().unwrap();
+        assert_eq!((chord.root, chord.quality), (E4, ChordQuality::MinorTriad));
+
+        for note in [E3, G3, B3] {
+            engine.feed(NoteEvent::Off { note, time: 2.0 });
+        }
+        assert_eq!(engine.current_chord_estimate(), None);
+    }
+
+    #[test]
+    fn test_chord_prefers_the_bass_as_root() {
+        let mut engine = AnalysisEngine::default();
+        for note in [C3, E4, G4, A4] {
+            engine.feed(NoteEvent::On { note, time: 0.0 });
+        }
+        let chord = engine.current_chord_estimate().unwrap();
+        assert_eq!((chord.root, chord.quality), (C4, ChordQuality::MajorSixth));
+
+        engine.feed(NoteEvent::Off {
+            note: C3,
+            time: 0.5,
+        });
+        engine.feed(NoteEvent::On {
+            note: A2,
+            time: 0.5,
+        });
+        engine.feed(NoteEvent::On {
+            note: C4,
+            time: 0.5,
+        });
+        let chord = engine.current_chord_estimate().unwrap();
+        assert_eq!(
+            (chord.root, chord.quality),
+            (A4, ChordQuality::MinorSeventh)
+        );
+    }
+}
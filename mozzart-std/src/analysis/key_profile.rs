@@ -0,0 +1,319 @@
+use super::engine::in_fourth_octave;
+use crate::constants::*;
+use crate::{Error, KeyMode, Note, TimedNote};
+
+/// The weights given to each pitch class in a key, from its tonic upwards
+///
+/// The Krumhansl-Kessler profiles of the major and minor keys are
+/// `KRUMHANSL_MAJOR_PROFILE` and `KRUMHANSL_MINOR_PROFILE`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct KeyProfile([f32; SEMITONES_IN_OCTAVE as usize]);
+
+impl KeyProfile {
+    /// Creates a new `KeyProfile`
+    ///
+    /// # Arguments
+    /// * `weights` - The weight of each pitch class, from the tonic to the major seventh above it
+    ///
+    /// # Returns
+    /// A new `KeyProfile` instance
+    pub const fn new(weights: [f32; SEMITONES_IN_OCTAVE as usize]) -> Self {
+        Self(weights)
+    }
+
+    /// Returns the weight of each pitch class, from the tonic to the major seventh above it
+    pub const fn weights(&self) -> &[f32; SEMITONES_IN_OCTAVE as usize] {
+        &self.0
+    }
+
+    /// Returns the profile of the key whose tonic is a number of semitones above C
+    ///
+    /// The profiles are written with their tonic first; this rotates them so the
+    /// weights are indexed by pitch class, from C to B, as in a histogram.
+    ///
+    /// # Arguments
+    /// * `tonic` - The pitch class of the tonic, from 0 for C to 11 for B
+    ///
+    /// # Returns
+    /// The weights indexed by pitch class
+    fn in_key_of(&self, tonic: u8) -> [f32; SEMITONES_IN_OCTAVE as usize] {
+        let mut weights = self.0;
+        weights.rotate_right(usize::from(tonic % SEMITONES_IN_OCTAVE));
+        weights
+    }
+}
+
+/// Represents how long each pitch class sounds in a piece
+///
+/// This is the input of the Krumhansl-Schmuckler key-finding algorithm: the
+/// histogram is correlated with the profile of every major and minor key, and
+/// the best correlations give the most likely keys.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let tune = [(C4, 1.0), (E4, 0.5), (G4, 0.5), (F4, 0.5), (D4, 0.5), (C4, 2.0)];
+/// let histogram = PitchClassHistogram::from_weighted(&tune);
+///
+/// let best = histogram.best_keys(1).unwrap();
+/// assert_eq!((best[0].0, best[0].1), (C4, KeyMode::Major));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct PitchClassHistogram([f32; SEMITONES_IN_OCTAVE as usize]);
+
+impl PitchClassHistogram {
+    /// Creates a histogram from the weight of each pitch class, from C to B
+    pub(crate) const fn new(weights: [f32; SEMITONES_IN_OCTAVE as usize]) -> Self {
+        Self(weights)
+    }
+
+    /// Creates a histogram from notes and their weights
+    ///
+    /// # Arguments
+    /// * `notes` - The notes, each with its weight, usually its duration
+    ///
+    /// # Returns
+    /// The sum of the weights of each pitch class
+    pub fn from_weighted(notes: &[(Note, f32)]) -> Self {
+        let mut weights = [0.0; SEMITONES_IN_OCTAVE as usize];
+        for &(note, weight) in notes {
+            weights[usize::from(note.pitch_class())] += weight;
+        }
+        Self::new(weights)
+    }
+
+    /// Creates a histogram weighted by the durations of timed notes
+    ///
+    /// # Arguments
+    /// * `notes` - The notes, such as the notes of a `Phrase`
+    ///
+    /// # Returns
+    /// The sum of the durations of each pitch class, in whole notes
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let melody = Melody::new([(A3, Duration::Half), (C4, Duration::Quarter)]);
+    /// let phrase = melody.realize(&major_scale(C4).unwrap(), OrnamentParams::default()).unwrap();
+    /// let histogram = PitchClassHistogram::from_timed_notes(phrase.notes());
+    /// assert_eq!(histogram.weights()[9], 0.5);
+    /// ```
+    pub fn from_timed_notes(notes: &[TimedNote]) -> Self {
+        let weighted: Vec<_> = notes
+            .iter()
+            .map(|timed| {
+                let duration = timed.duration;
+                let weight = duration.numerator() as f32 / duration.denominator() as f32;
+                (timed.note, weight)
+            })
+            .collect();
+        Self::from_weighted(&weighted)
+    }
+
+    /// Returns the weight of each pitch class, from C to B
+    pub const fn weights(&self) -> &[f32; SEMITONES_IN_OCTAVE as usize] {
+        &self.0
+    }
+
+    /// Returns the sum of the weights of all the pitch classes
+    pub fn total(&self) -> f32 {
+        self.0.iter().sum()
+    }
+
+    /// Returns the Pearson correlation of this histogram with a key profile
+    ///
+    /// The profile is taken with its tonic on C; see `best_keys` for the other tonics.
+    ///
+    /// # Arguments
+    /// * `profile` - The profile of the key
+    ///
+    /// # Returns
+    /// The correlation, from -1 to 1, or `Error::EmptyHistogram` if the
+    /// histogram has no weight. A histogram with the same weight on every pitch
+    /// class has no correlation with any key, 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let triad = PitchClassHistogram::from_weighted(&[(C4, 1.0), (E4, 1.0), (G4, 1.0)]);
+    /// let major = triad.correlate(&KRUMHANSL_MAJOR_PROFILE).unwrap();
+    /// let minor = triad.correlate(&KRUMHANSL_MINOR_PROFILE).unwrap();
+    /// assert!(major > minor);
+    ///
+    /// let empty = PitchClassHistogram::default();
+    /// assert_eq!(empty.correlate(&KRUMHANSL_MAJOR_PROFILE), Err(Error::EmptyHistogram));
+    /// ```
+    pub fn correlate(&self, profile: &KeyProfile) -> Result<f32, Error> {
+        self.correlate_in(profile, 0)
+    }
+
+    /// Returns the Pearson correlation of this histogram with a key profile on a tonic
+    fn correlate_in(&self, profile: &KeyProfile, tonic: u8) -> Result<f32, Error> {
+        if self.total() <= 0.0 {
+            return Err(Error::EmptyHistogram);
+        }
+
+        let profile = profile.in_key_of(tonic);
+        let count = f32::from(SEMITONES_IN_OCTAVE);
+        let mean = self.total() / count;
+        let profile_mean = profile.iter().sum::<f32>() / count;
+
+        let (mut covariance, mut variance, mut profile_variance) = (0.0, 0.0, 0.0);
+        for (weight, expected) in self.0.iter().zip(profile) {
+            let (deviation, profile_deviation) = (weight - mean, expected - profile_mean);
+            covariance += deviation * profile_deviation;
+            variance += deviation * deviation;
+            profile_variance += profile_deviation * profile_deviation;
+        }
+
+        if variance == 0.0 || profile_variance == 0.0 {
+            return Ok(0.0);
+        }
+        Ok(covariance / (variance * profile_variance).sqrt())
+    }
+
+    /// Returns the keys that best match this histogram, with the Krumhansl-Schmuckler algorithm
+    ///
+    /// The histogram is correlated with the Krumhansl-Kessler profiles of the 24
+    /// major and minor keys. Keys with the same correlation keep major keys
+    /// first, then the tonics from C to B.
+    ///
+    /// # Arguments
+    /// * `n` - The number of keys to return, at most 24
+    ///
+    /// # Returns
+    /// The tonic, reported as the note of its pitch class in the fourth octave,
+    /// the mode and the correlation of the best keys, the best first, or
+    /// `Error::EmptyHistogram` if the histogram has no weight
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let tune = [(A3, 2.0), (C4, 1.0), (E4, 1.0), (GSHARP3, 0.5), (A3, 2.0)];
+    /// let histogram = PitchClassHistogram::from_weighted(&tune);
+    /// let (tonic, mode, _) = histogram.best_keys(1).unwrap()[0];
+    /// assert_eq!((tonic, mode), (A4, KeyMode::Minor));
+    /// ```
+    pub fn best_keys(&self, n: usize) -> Result<Vec<(Note, KeyMode, f32)>, Error> {
+        let profiles = [
+            (KeyMode::Major, KRUMHANSL_MAJOR_PROFILE),
+            (KeyMode::Minor, KRUMHANSL_MINOR_PROFILE),
+        ];
+
+        let mut keys = Vec::with_capacity(2 * usize::from(SEMITONES_IN_OCTAVE));
+        for (mode, profile) in profiles {
+            for tonic in 0..SEMITONES_IN_OCTAVE {
+                let correlation = self.correlate_in(&profile, tonic)?;
+                keys.push((in_fourth_octave(tonic), mode, correlation));
+            }
+        }
+
+        keys.sort_by(|a, b| b.2.total_cmp(&a.2));
+        keys.truncate(n);
+        Ok(keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{major_scale, Duration, Melody, OrnamentParams};
+
+    /// A simple tune in C major, with its durations
+    fn c_major_tune() -> Vec<TimedNote> {
+        let melody = Melody::new([
+            (C4, Duration::Quarter),
+            (E4, Duration::Quarter),
+            (A4, Duration::Quarter),
+            (G4, Duration::Quarter),
+            (F4, Duration::Quarter),
+            (E4, Duration::Quarter),
+            (D4, Duration::Quarter),
+            (C4, Duration::Quarter),
+            (E4, Duration::Half),
+            (A3, Duration::Quarter),
+            (C4, Duration::Quarter),
+            (D4, Duration::Quarter),
+            (B3, Duration::Eighth),
+            (C4, Duration::Whole),
+        ]);
+        let phrase = melody
+            .realize(&major_scale(C4).unwrap(), OrnamentParams::default())
+            .unwrap();
+        phrase.notes().to_vec()
+    }
+
+    fn keys(histogram: &PitchClassHistogram, n: usize) -> Vec<(Note, KeyMode)> {
+        histogram
+            .best_keys(n)
+            .unwrap()
+            .into_iter()
+            .map(|(tonic, mode, _)| (tonic, mode))
+            .collect()
+    }
+
+    #[test]
+    fn test_c_major_tune() {
+        let histogram = PitchClassHistogram::from_timed_notes(&c_major_tune());
+        assert_eq!(
+            keys(&histogram, 2),
+            vec![(C4, KeyMode::Major), (A4, KeyMode::Minor)]
+        );
+
+        let best = histogram.best_keys(24).unwrap();
+        assert_eq!(best.len(), 24);
+        assert!(best.windows(2).all(|pair| pair[0].2 >= pair[1].2));
+        assert!(best.iter().all(|key| (-1.0..=1.0).contains(&key.2)));
+    }
+
+    #[test]
+    fn test_transposition() {
+        let tune = c_major_tune();
+        for semitones in 1..SEMITONES_IN_OCTAVE {
+            let transposed: Vec<_> = tune
+                .iter()
+                .map(|timed| TimedNote {
+                    note: timed.note.checked_add_semitones(semitones.into()).unwrap(),
+                    ..*timed
+                })
+                .collect();
+            let histogram = PitchClassHistogram::from_timed_notes(&transposed);
+
+            let tonic = |note: Note| Note::new(C4.midi_number() + note.pitch_class());
+            let expected = vec![
+                (
+                    tonic(C4.checked_add_semitones(semitones.into()).unwrap()),
+                    KeyMode::Major,
+                ),
+                (
+                    tonic(A4.checked_add_semitones(semitones.into()).unwrap()),
+                    KeyMode::Minor,
+                ),
+            ];
+            assert_eq!(keys(&histogram, 2), expected, "{semitones}");
+        }
+    }
+
+    #[test]
+    fn test_empty_histogram() {
+        let empty = PitchClassHistogram::from_weighted(&[]);
+        assert_eq!(empty.best_keys(3), Err(Error::EmptyHistogram));
+        assert_eq!(
+            empty.correlate(&KRUMHANSL_MINOR_PROFILE),
+            Err(Error::EmptyHistogram)
+        );
+
+        // Every pitch class equally present gives no preference rather than NaN
+        let chromatic: Vec<_> = (60..72).map(|midi| (Note::new(midi), 1.0)).collect();
+        let flat = PitchClassHistogram::from_weighted(&chromatic);
+        assert_eq!(flat.correlate(&KRUMHANSL_MAJOR_PROFILE), Ok(0.0));
+    }
+}
@@ -0,0 +1,5 @@
+mod engine;
+mod key_profile;
+
+pub use engine::*;
+pub use key_profile::*;
@@ -0,0 +1,3 @@
+mod pitch;
+
+pub use pitch::*;
@@ -0,0 +1,510 @@
+use crate::{Error, Note, Tuning};
+
+/// The lowest frequency detected by default, just below G1
+const DEFAULT_MIN_FREQUENCY: f32 = 50.0;
+
+/// The highest frequency detected by default, just above C7
+const DEFAULT_MAX_FREQUENCY: f32 = 2_200.0;
+
+/// The confidence below which a frame has no pitch by default
+const DEFAULT_MIN_CONFIDENCE: f32 = 0.85;
+
+/// Represents a pitch found in a buffer of samples
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let sample_rate = 44_100;
+/// let samples: Vec<f32> = (0..4096)
+///     .map(|i| (2.0 * std::f32::consts::PI * 440.0 * i as f32 / sample_rate as f32).sin())
+///     .collect();
+///
+/// let pitch = detect_pitch(&samples, sample_rate).unwrap();
+/// assert_eq!(pitch.note(), A4);
+/// assert!(pitch.cents().abs() < 5.0);
+/// assert!(pitch.confidence() > 0.9);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DetectedPitch {
+    note: Note,
+    frequency: f32,
+    cents: f32,
+    confidence: f32,
+}
+
+impl DetectedPitch {
+    /// Returns the note closest to the detected frequency
+    pub fn note(&self) -> Note {
+        self.note
+    }
+
+    /// Returns the detected frequency in hertz
+    pub fn frequency(&self) -> f32 {
+        self.frequency
+    }
+
+    /// Returns the distance from the note to the detected frequency in cents, from -50 to 50
+    pub fn cents(&self) -> f32 {
+        self.cents
+    }
+
+    /// Returns how periodic the samples are at the detected frequency, from 0 to 1
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+}
+
+/// Represents a monophonic pitch detector based on the YIN algorithm
+///
+/// The detector looks for the period over which the samples repeat best,
+/// between the periods of the highest and the lowest detectable frequencies.
+/// The shortest period repeating with enough confidence wins, which avoids
+/// reporting a note an octave too low. The frequency is then mapped to the
+/// closest note through the tuning.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let detector = PitchDetector::new()
+///     .with_frequency_range(80.0, 1_000.0)
+///     .unwrap()
+///     .with_min_confidence(0.9)
+///     .with_tuning(Tuning::baroque());
+///
+/// let sample_rate = 22_050;
+/// let samples: Vec<f32> = (0..2048)
+///     .map(|i| (2.0 * std::f32::consts::PI * 415.0 * i as f32 / sample_rate as f32).sin())
+///     .collect();
+/// assert_eq!(detector.detect(&samples, sample_rate).unwrap().note(), A4);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PitchDetector {
+    min_frequency: f32,
+    max_frequency: f32,
+    min_confidence: f32,
+    tuning: Tuning,
+}
+
+impl PitchDetector {
+    /// Creates a detector from 50 Hz to 2200 Hz, with a confidence of 0.85, in concert pitch
+    pub fn new() -> Self {
+        Self {
+            min_frequency: DEFAULT_MIN_FREQUENCY,
+            max_frequency: DEFAULT_MAX_FREQUENCY,
+            min_confidence: DEFAULT_MIN_CONFIDENCE,
+            tuning: Tuning::default(),
+        }
+    }
+
+    /// Returns the detector with another range of detectable frequencies
+    ///
+    /// A lower minimum frequency needs longer frames, see `frame_length`.
+    ///
+    /// # Arguments
+    /// * `min_frequency` - The lowest detectable frequency in hertz
+    /// * `max_frequency` - The highest detectable frequency in hertz
+    ///
+    /// # Returns
+    /// The detector, or `Error::InvalidFrequencyRange` unless both frequencies
+    /// are finite and `0 < min_frequency < max_frequency`
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// assert!(PitchDetector::new().with_frequency_range(80.0, 1_000.0).is_ok());
+    /// assert!(matches!(
+    ///     PitchDetector::new().with_frequency_range(0.0, 1_000.0),
+    ///     Err(Error::InvalidFrequencyRange(_))
+    /// ));
+    /// ```
+    pub fn with_frequency_range(
+        mut self,
+        min_frequency: f32,
+        max_frequency: f32,
+    ) -> Result<Self, Error> {
+        let invalid = |reason: &str| Err(Error::InvalidFrequencyRange(reason.to_string()));
+        if !min_frequency.is_finite() || !max_frequency.is_finite() {
+            return invalid("the frequencies must be finite");
+        }
+        if min_frequency <= 0.0 {
+            return invalid("the lowest frequency must be positive");
+        }
+        if min_frequency >= max_frequency {
+            return invalid("the lowest frequency must be below the highest");
+        }
+
+        self.min_frequency = min_frequency;
+        self.max_frequency = max_frequency;
+        Ok(self)
+    }
+
+    /// Returns the detector with another confidence below which a frame has no pitch
+    ///
+    /// # Arguments
+    /// * `min_confidence` - The confidence, from 0 to 1; higher values reject more noisy frames
+    pub fn with_min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+
+    /// Returns the detector with another tuning used to name the frequencies
+    pub fn with_tuning(mut self, tuning: Tuning) -> Self {
+        self.tuning = tuning;
+        self
+    }
+
+    /// Returns the lowest detectable frequency in hertz
+    pub fn min_frequency(&self) -> f32 {
+        self.min_frequency
+    }
+
+    /// Returns the highest detectable frequency in hertz
+    pub fn max_frequency(&self) -> f32 {
+        self.max_frequency
+    }
+
+    /// Returns the confidence below which a frame has no pitch
+    pub fn min_confidence(&self) -> f32 {
+        self.min_confidence
+    }
+
+    /// Returns the tuning used to name the frequencies
+    pub fn tuning(&self) -> Tuning {
+        self.tuning
+    }
+
+    /// Returns the number of samples needed to detect the lowest frequency
+    ///
+    /// A frame holds two periods of the lowest frequency, so every period can
+    /// be compared with the samples following it.
+    ///
+    /// # Arguments
+    /// * `sample_rate` - The number of samples per second
+    pub fn frame_length(&self, sample_rate: u32) -> usize {
+        self.max_period(sample_rate).saturating_mul(2)
+    }
+
+    /// Detects the pitch at the start of a buffer of samples
+    ///
+    /// Only the first `frame_length` samples are analysed. A shorter buffer is
+    /// analysed as a whole, down to the lowest frequency fitting twice in it.
+    ///
+    /// # Arguments
+    /// * `samples` - The mono samples, from -1 to 1
+    /// * `sample_rate` - The number of samples per second
+    ///
+    /// # Returns
+    /// The detected pitch, or `None` if the samples are silent, not periodic
+    /// enough, or their frequency is outside of the detectable range
+    pub fn detect(&self, samples: &[f32], sample_rate: u32) -> Option<DetectedPitch> {
+        let max_period = self.max_period(sample_rate).min(samples.len() / 2);
+        let min_period = ((sample_rate as f32 / self.max_frequency).floor() as usize).max(2);
+        if max_period <= min_period {
+            return None;
+        }
+
+        let differences = normalized_differences(&samples[..2 * max_period], max_period)?;
+        let threshold = f64::from(1.0 - self.min_confidence);
+        let mut period = (min_period..max_period).find(|&tau| differences[tau] < threshold)?;
+        // The dip continues below the threshold down to its minimum
+        while period + 1 < max_period && differences[period + 1] < differences[period] {
+            period += 1;
+        }
+
+        let (offset, difference) = parabolic_minimum(
+            differences[period - 1],
+            differences[period],
+            differences[period + 1],
+        );
+        let frequency = f64::from(sample_rate) / (period as f64 + offset);
+        if frequency < f64::from(self.min_frequency) || frequency > f64::from(self.max_frequency) {
+            return None;
+        }
+
+        let (note, cents) = self.tuning.nearest_note(frequency)?;
+        Some(DetectedPitch {
+            note,
+            frequency: frequency as f32,
+            cents: cents as f32,
+            confidence: (1.0 - difference).clamp(0.0, 1.0) as f32,
+        })
+    }
+
+    /// Detects the pitch of successive frames of a buffer of samples
+    ///
+    /// # Arguments
+    /// * `samples` - The mono samples, from -1 to 1
+    /// * `sample_rate` - The number of samples per second
+    /// * `hop` - The number of samples between the starts of two frames, at least 1
+    ///
+    /// # Returns
+    /// The pitch of each complete frame, see `detect`, in order
+    pub fn detect_track(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        hop: usize,
+    ) -> Vec<Option<DetectedPitch>> {
+        let length = self.frame_length(sample_rate);
+        if samples.len() < length {
+            return Vec::new();
+        }
+
+        (0..=samples.len() - length)
+            .step_by(hop.max(1))
+            .map(|start| self.detect(&samples[start..start + length], sample_rate))
+            .collect()
+    }
+
+    /// Returns the period of the lowest detectable frequency in samples
+    ///
+    /// A period too long for a `usize` saturates, and no buffer is long enough for it.
+    fn max_period(&self, sample_rate: u32) -> usize {
+        ((sample_rate as f32 / self.min_frequency).ceil() as usize).saturating_add(1)
+    }
+}
+
+impl Default for PitchDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Detects the pitch at the start of a buffer of samples with the default detector
+///
+/// # Arguments
+/// * `samples` - The mono samples, from -1 to 1
+/// * `sample_rate` - The number of samples per second
+///
+/// # Returns
+/// The detected pitch, see `PitchDetector::detect`
+pub fn detect_pitch(samples: &[f32], sample_rate: u32) -> Option<DetectedPitch> {
+    PitchDetector::new().detect(samples, sample_rate)
+}
+
+/// Detects the pitch of successive frames of a buffer of samples with the default detector
+///
+/// # Arguments
+/// * `samples` - The mono samples, from -1 to 1
+/// * `sample_rate` - The number of samples per second
+/// * `hop` - The number of samples between the starts of two frames
+///
+/// # Returns
+/// The pitch of each frame, see `PitchDetector::detect_track`
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let sample_rate = 8_000;
+/// let mut samples = vec![0.0; 4000];
+/// samples.extend((0..4000).map(|i| (2.0 * std::f32::consts::PI * 220.0 * i as f32 / 8000.0).sin()));
+///
+/// let notes: Vec<_> = detect_pitch_track(&samples, sample_rate, 1000)
+///     .iter()
+///     .map(|pitch| pitch.map(|pitch| pitch.note()))
+///     .collect();
+/// assert_eq!(notes[0], None);
+/// assert_eq!(notes.last(), Some(&Some(A3)));
+/// ```
+pub fn detect_pitch_track(
+    samples: &[f32],
+    sample_rate: u32,
+    hop: usize,
+) -> Vec<Option<DetectedPitch>> {
+    PitchDetector::new().detect_track(samples, sample_rate, hop)
+}
+
+/// Returns the cumulative mean normalized difference of a frame for each period
+///
+/// The difference at a period is the energy of the frame minus itself shifted
+/// by the period. It is divided by its mean over the shorter periods, so it is
+/// 1 on average and close to 0 at the periods of the signal.
+///
+/// # Arguments
+/// * `frame` - The samples, twice as many as the longest period
+/// * `max_period` - The longest period, in samples
+///
+/// # Returns
+/// The normalized differences for the periods from 0 to `max_period`, or `None` if the frame is silent
+fn normalized_differences(frame: &[f32], max_period: usize) -> Option<Vec<f64>> {
+    let window = &frame[..max_period];
+    if window.iter().all(|&sample| sample == 0.0) {
+        return None;
+    }
+
+    let mut differences = vec![1.0; max_period + 1];
+    let mut total = 0.0;
+    for (tau, normalized) in differences.iter_mut().enumerate().skip(1) {
+        let difference: f64 = window
+            .iter()
+            .zip(&frame[tau..])
+            .map(|(&a, &b)| f64::from(a - b).powi(2))
+            .sum();
+        total += difference;
+        *normalized = if total > 0.0 {
+            difference * tau as f64 / total
+        } else {
+            1.0
+        };
+    }
+
+    Some(differences)
+}
+
+/// Returns the offset and the value of the minimum of the parabola through three points
+///
+/// # Arguments
+/// * `before`, `at`, `after` - The values at -1, 0 and 1
+fn parabolic_minimum(before: f64, at: f64, after: f64) -> (f64, f64) {
+    let curvature = before - 2.0 * at + after;
+    if curvature <= 0.0 {
+        return (0.0, at);
+    }
+
+    let offset = (before - after) / (2.0 * curvature);
+    (offset, at - (before - after) * offset / 4.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use std::f64::consts::TAU;
+
+    const SAMPLE_RATE: u32 = 44_100;
+
+    /// Returns the samples of a sine wave playing a frequency
+    fn sine(frequency: f64, length: usize) -> Vec<f32> {
+        (0..length)
+            .map(|i| (TAU * frequency * i as f64 / f64::from(SAMPLE_RATE)).sin() as f32 * 0.8)
+            .collect()
+    }
+
+    #[test]
+    fn test_sine_waves() {
+        let tuning = Tuning::default();
+        for note in [A4, C3, E5] {
+            let samples = sine(tuning.frequency(note), 8192);
+            let pitch = detect_pitch(&samples, SAMPLE_RATE).unwrap();
+            assert_eq!(pitch.note(), note);
+            assert!(pitch.cents().abs() < 5.0, "{note:?}: {}", pitch.cents());
+            assert!(pitch.confidence() > 0.95);
+        }
+
+        // A detuned note keeps its offset
+        let samples = sine(tuning.frequency(A4) * 2f64.powf(0.3 / 12.0), 8192);
+        let pitch = detect_pitch(&samples, SAMPLE_RATE).unwrap();
+        assert_eq!(pitch.note(), A4);
+        assert!((pitch.cents() - 30.0).abs() < 5.0, "{}", pitch.cents());
+    }
+
+    #[test]
+    fn test_harmonics_keep_the_fundamental() {
+        let frequency = Tuning::default().frequency(G3);
+        let samples: Vec<f32> = (0..4096)
+            .map(|i| {
+                let t = TAU * frequency * i as f64 / f64::from(SAMPLE_RATE);
+                (0.3 * t.sin() + 0.5 * (2.0 * t).sin() + 0.3 * (3.0 * t).sin()) as f32
+            })
+            .collect();
+        assert_eq!(detect_pitch(&samples, SAMPLE_RATE).unwrap().note(), G3);
+    }
+
+    #[test]
+    fn test_noise_and_silence() {
+        // A linear congruential generator, for reproducible white noise
+        let mut state: u32 = 12345;
+        let noise: Vec<f32> = (0..8192)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 8) as f32 / (1 << 24) as f32 * 2.0 - 1.0
+            })
+            .collect();
+        let detected = detect_pitch(&noise, SAMPLE_RATE);
+        assert!(
+            detected.filter(|pitch| pitch.confidence() >= 0.9).is_none(),
+            "{detected:?}"
+        );
+
+        assert_eq!(detect_pitch(&[0.0; 4096], SAMPLE_RATE), None);
+        assert_eq!(detect_pitch(&[], SAMPLE_RATE), None);
+        assert_eq!(detect_pitch(&sine(440.0, 10), SAMPLE_RATE), None);
+    }
+
+    #[test]
+    fn test_frequency_range() {
+        let samples = sine(Tuning::default().frequency(A5), 4096);
+        let detector = PitchDetector::new()
+            .with_frequency_range(1_000.0, 2_000.0)
+            .unwrap();
+        assert_eq!(detector.detect(&samples, SAMPLE_RATE), None);
+        let detector = detector.with_frequency_range(500.0, 1_000.0).unwrap();
+        assert_eq!(detector.detect(&samples, SAMPLE_RATE).unwrap().note(), A5);
+        assert!(
+            detector.frame_length(SAMPLE_RATE) < PitchDetector::new().frame_length(SAMPLE_RATE)
+        );
+    }
+
+    #[test]
+    fn test_invalid_frequency_range() {
+        let detector = PitchDetector::new();
+        for (min, max) in [
+            (0.0, 1_000.0),
+            (-50.0, 1_000.0),
+            (1_000.0, 500.0),
+            (440.0, 440.0),
+            (f32::NAN, 1_000.0),
+            (50.0, f32::INFINITY),
+        ] {
+            assert!(
+                matches!(
+                    detector.with_frequency_range(min, max),
+                    Err(Error::InvalidFrequencyRange(_))
+                ),
+                "{min} {max}"
+            );
+        }
+
+        // A tiny lowest frequency saturates the frame length instead of overflowing
+        let detector = detector.with_frequency_range(1e-30, 1_000.0).unwrap();
+        assert_eq!(detector.frame_length(SAMPLE_RATE), usize::MAX);
+        let samples = sine(440.0, 4096);
+        assert_eq!(detector.detect_track(&samples, SAMPLE_RATE, 512), vec![]);
+        assert_eq!(detector.detect(&samples, SAMPLE_RATE).unwrap().note(), A4);
+    }
+
+    #[test]
+    fn test_glide_is_monotonic() {
+        // One second from A3 up to A4, the frequency rising exponentially
+        let (start, end) = (220.0, 440.0);
+        let length = SAMPLE_RATE as usize;
+        let rate = f64::ln(end / start) / length as f64;
+        let samples: Vec<f32> = (0..length)
+            .map(|i| {
+                // The phase is the integral of the frequency
+                let phase = start * ((rate * i as f64).exp() - 1.0) / rate;
+                (TAU * phase / f64::from(SAMPLE_RATE)).sin() as f32
+            })
+            .collect();
+
+        let track = detect_pitch_track(&samples, SAMPLE_RATE, 2048);
+        assert!(track.len() > 15);
+        let frequencies: Vec<f32> = track
+            .iter()
+            .map(|pitch| pitch.expect("every frame is pitched").frequency())
+            .collect();
+        assert!(
+            frequencies.windows(2).all(|pair| pair[0] < pair[1]),
+            "{frequencies:?}"
+        );
+        assert!(track[0].unwrap().note() <= ASHARP3);
+        assert!(track.last().unwrap().unwrap().note() >= GSHARP4);
+    }
+}
@@ -0,0 +1,466 @@
+use crate::constants::*;
+use crate::{Error, Note, Voicing};
+
+/// The chord degrees a builder keeps one tone for, from the third to the thirteenth
+const DEGREES: [u8; 6] = [3, 5, 7, 9, 11, 13];
+
+/// A tone of the chord being built, as set by a modifier or implied by another one
+#[derive(Debug, Clone, Copy)]
+struct Tone {
+    semitones: u8,
+    name: &'static str,
+    explicit: bool,
+}
+
+/// Builds chords of any shape from fluent modifiers
+///
+/// The builder starts from a major triad and every modifier changes one degree
+/// of the chord: `minor` lowers the third, `flat_five` lowers the fifth, `ninth`
+/// adds a ninth and the seventh below it, and so on. Tones implied by another
+/// modifier, like the seventh of a ninth chord, give way to explicit ones, but
+/// two modifiers setting the same degree differently, like `major` and `minor`,
+/// make `build` report the contradiction.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// // The "Hendrix chord"
+/// let c7_sharp9 = ChordBuilder::new(C4).seventh().sharp_nine().build().unwrap();
+/// assert_eq!(c7_sharp9.notes(), &[C4, E4, G4, ASHARP4, DSHARP5]);
+///
+/// let contradiction = ChordBuilder::new(C4).major().minor().build();
+/// assert!(matches!(contradiction, Err(Error::ContradictoryChord { .. })));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChordBuilder {
+    root: Note,
+    tones: [Option<Tone>; DEGREES.len()],
+    added: Vec<u8>,
+    omitted: Vec<u8>,
+    omit_root: bool,
+    bass: Option<Note>,
+    error: Option<Error>,
+}
+
+impl ChordBuilder {
+    /// Creates a new `ChordBuilder` for a major triad
+    ///
+    /// # Arguments
+    /// * `root` - The root note of the chord
+    ///
+    /// # Returns
+    /// A new `ChordBuilder` instance
+    pub fn new(root: Note) -> Self {
+        Self {
+            root,
+            tones: [None; DEGREES.len()],
+            added: Vec::new(),
+            omitted: Vec::new(),
+            omit_root: false,
+            bass: None,
+            error: None,
+        }
+        .imply(3, 4, "major third")
+        .imply(5, 7, "perfect fifth")
+    }
+
+    /// Makes the third major
+    pub fn major(self) -> Self {
+        self.set(3, 4, "major third")
+    }
+
+    /// Makes the third minor
+    pub fn minor(self) -> Self {
+        self.set(3, 3, "minor third")
+    }
+
+    /// Makes the third minor and the fifth diminished
+    pub fn diminished(self) -> Self {
+        self.minor().flat_five()
+    }
+
+    /// Makes the third major and the fifth augmented
+    pub fn augmented(self) -> Self {
+        self.major().sharp_five()
+    }
+
+    /// Replaces the third with a major second
+    pub fn sus2(self) -> Self {
+        self.set(3, 2, "suspended second")
+    }
+
+    /// Replaces the third with a perfect fourth
+    pub fn sus4(self) -> Self {
+        self.set(3, 5, "suspended fourth")
+    }
+
+    /// Lowers the fifth by a semitone
+    pub fn flat_five(self) -> Self {
+        self.set(5, 6, "flat fifth")
+    }
+
+    /// Raises the fifth by a semitone
+    pub fn sharp_five(self) -> Self {
+        self.set(5, 8, "sharp fifth")
+    }
+
+    /// Adds a minor seventh, the seventh of dominant and minor seventh chords
+    pub fn seventh(self) -> Self {
+        self.set(7, 10, "minor seventh")
+    }
+
+    /// Adds a major seventh
+    pub fn major_seventh(self) -> Self {
+        self.set(7, 11, "major seventh")
+    }
+
+    /// Adds a ninth, and a minor seventh unless a seventh is set
+    pub fn ninth(self) -> Self {
+        self.imply(7, 10, "minor seventh").set(9, 14, "ninth")
+    }
+
+    /// Adds a flat ninth, and a minor seventh unless a seventh is set
+    pub fn flat_nine(self) -> Self {
+        self.imply(7, 10, "minor seventh").set(9, 13, "flat ninth")
+    }
+
+    /// Adds a sharp ninth, and a minor seventh unless a seventh is set
+    pub fn sharp_nine(self) -> Self {
+        self.imply(7, 10, "minor seventh").set(9, 15, "sharp ninth")
+    }
+
+    /// Adds an eleventh, with the ninth and the seventh below it unless they are set
+    pub fn eleventh(self) -> Self {
+        self.imply(7, 10, "minor seventh")
+            .imply(9, 14, "ninth")
+            .set(11, 17, "eleventh")
+    }
+
+    /// Adds a sharp eleventh, with the ninth and the seventh below it unless they are set
+    pub fn sharp_eleven(self) -> Self {
+        self.imply(7, 10, "minor seventh")
+            .imply(9, 14, "ninth")
+            .set(11, 18, "sharp eleventh")
+    }
+
+    /// Adds a thirteenth, with the eleventh, the ninth and the seventh below it unless they are set
+    pub fn thirteenth(self) -> Self {
+        self.imply(7, 10, "minor seventh")
+            .imply(9, 14, "ninth")
+            .imply(11, 17, "eleventh")
+            .set(13, 21, "thirteenth")
+    }
+
+    /// Adds a flat thirteenth, with the eleventh, the ninth and the seventh below it unless they are set
+    pub fn flat_thirteen(self) -> Self {
+        self.imply(7, 10, "minor seventh")
+            .imply(9, 14, "ninth")
+            .imply(11, 17, "eleventh")
+            .set(13, 20, "flat thirteenth")
+    }
+
+    /// Adds a single tone without the tones below it, as in `add9` or `add6` chords
+    ///
+    /// # Arguments
+    /// * `degree` - The degree to add: 2, 4, 6, 9, 11 or 13
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, degree: u8) -> Self {
+        let semitones = match degree {
+            2 => 2,
+            4 => 5,
+            6 => 9,
+            9 => 14,
+            11 => 17,
+            13 => 21,
+            _ => return self.fail(Error::InvalidScaleDegree(degree)),
+        };
+        self.added.push(semitones);
+        self
+    }
+
+    /// Leaves a degree out of the chord, usually the fifth
+    ///
+    /// # Arguments
+    /// * `degree` - The degree to leave out: 1 for the root, or 3, 5, 7, 9, 11 or 13
+    pub fn omit(mut self, degree: u8) -> Self {
+        match degree {
+            1 => self.omit_root(),
+            d if DEGREES.contains(&d) => {
+                self.omitted.push(d);
+                self
+            }
+            _ => self.fail(Error::InvalidScaleDegree(degree)),
+        }
+    }
+
+    /// Leaves the root out of the chord, as in rootless voicings
+    pub fn omit_root(mut self) -> Self {
+        self.omit_root = true;
+        self
+    }
+
+    /// Plays a bass note below the chord, as in slash chords
+    ///
+    /// # Arguments
+    /// * `note` - The bass note; only its pitch class matters, it is placed below the lowest tone
+    pub fn bass(mut self, note: Note) -> Self {
+        self.bass = Some(note);
+        self
+    }
+
+    /// Builds the chord
+    ///
+    /// # Returns
+    /// The notes of the chord, from the lowest to the highest and without
+    /// duplicates, or an error if two modifiers contradict each other, a degree
+    /// is invalid or a note would be outside of the MIDI range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let rootless = ChordBuilder::new(C4)
+    ///     .minor()
+    ///     .seventh()
+    ///     .flat_five()
+    ///     .add(9)
+    ///     .omit_root()
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(rootless.notes(), &[DSHARP4, FSHARP4, ASHARP4, D5]);
+    /// ```
+    pub fn build(&self) -> Result<Voicing, Error> {
+        if let Some(error) = &self.error {
+            return Err(error.clone());
+        }
+
+        let mut intervals: Vec<u8> = DEGREES
+            .iter()
+            .zip(&self.tones)
+            .filter(|(degree, _)| !self.omitted.contains(degree))
+            .filter_map(|(_, tone)| tone.map(|tone| tone.semitones))
+            .chain(self.added.iter().copied())
+            .collect();
+        if !self.omit_root {
+            intervals.push(0);
+        }
+        intervals.sort();
+        intervals.dedup();
+
+        let mut notes = intervals
+            .into_iter()
+            .map(|semitones| {
+                self.root
+                    .checked_add_semitones(i16::from(semitones))
+                    .ok_or(Error::NoteOutOfRange(self.root))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(bass) = self.bass {
+            let lowest = notes.first().copied().unwrap_or(self.root);
+            let below = (i16::from(lowest.midi_number()) - i16::from(bass.midi_number()))
+                .rem_euclid(i16::from(SEMITONES_IN_OCTAVE));
+            let below = if below == 0 {
+                i16::from(SEMITONES_IN_OCTAVE)
+            } else {
+                below
+            };
+            let bass = lowest
+                .checked_add_semitones(-below)
+                .ok_or(Error::NoteOutOfRange(bass))?;
+            notes.push(bass);
+        }
+
+        Ok(Voicing::new(notes))
+    }
+
+    /// Sets a degree explicitly, recording a contradiction with another explicit tone
+    fn set(mut self, degree: u8, semitones: u8, name: &'static str) -> Self {
+        let slot = &mut self.tones[Self::slot(degree)];
+        match slot {
+            Some(tone) if tone.explicit && tone.semitones != semitones => {
+                let error = Error::ContradictoryChord {
+                    first: tone.name,
+                    second: name,
+                };
+                self.fail(error)
+            }
+            _ => {
+                *slot = Some(Tone {
+                    semitones,
+                    name,
+                    explicit: true,
+                });
+                self
+            }
+        }
+    }
+
+    /// Sets a degree implied by another modifier, unless it is already set
+    fn imply(mut self, degree: u8, semitones: u8, name: &'static str) -> Self {
+        let slot = &mut self.tones[Self::slot(degree)];
+        if slot.is_none() {
+            *slot = Some(Tone {
+                semitones,
+                name,
+                explicit: false,
+            });
+        }
+        self
+    }
+
+    /// Records the first error, reported by `build`
+    fn fail(mut self, error: Error) -> Self {
+        self.error.get_or_insert(error);
+        self
+    }
+
+    /// Returns the index of the tone of a degree
+    fn slot(degree: u8) -> usize {
+        DEGREES
+            .iter()
+            .position(|&d| d == degree)
+            .expect("the degree has a tone")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_hendrix_chord() {
+        let chord = ChordBuilder::new(E3)
+            .seventh()
+            .sharp_nine()
+            .build()
+            .unwrap();
+        assert_eq!(chord.notes(), &[E3, GSHARP3, B3, D4, G4]);
+    }
+
+    #[test]
+    fn test_half_diminished() {
+        let chord = ChordBuilder::new(C4)
+            .minor()
+            .seventh()
+            .flat_five()
+            .build()
+            .unwrap();
+        assert_eq!(chord.notes(), half_diminished_seventh(C4).unwrap().notes());
+
+        let chord = ChordBuilder::new(C4)
+            .diminished()
+            .seventh()
+            .build()
+            .unwrap();
+        assert_eq!(chord.notes(), &[C4, DSHARP4, FSHARP4, ASHARP4]);
+    }
+
+    #[test]
+    fn test_thirteenth_sus4() {
+        let chord = ChordBuilder::new(C4).sus4().thirteenth().build().unwrap();
+        assert_eq!(chord.notes(), &[C4, F4, G4, ASHARP4, D5, F5, A5]);
+    }
+
+    #[test]
+    fn test_matches_canonical_chords() {
+        let built = |builder: ChordBuilder| builder.build().unwrap().notes().to_vec();
+
+        assert_eq!(
+            built(ChordBuilder::new(C4)),
+            major_triad(C4).unwrap().notes()
+        );
+        assert_eq!(
+            built(ChordBuilder::new(C4).minor()),
+            minor_triad(C4).unwrap().notes()
+        );
+        assert_eq!(
+            built(ChordBuilder::new(G3).seventh()),
+            dominant_seventh(G3).unwrap().notes()
+        );
+        assert_eq!(
+            built(ChordBuilder::new(C4).major_seventh().ninth()),
+            major_ninth(C4).unwrap().notes()
+        );
+        // The order of the modifiers doesn't matter
+        assert_eq!(
+            built(ChordBuilder::new(C4).ninth().major_seventh()),
+            major_ninth(C4).unwrap().notes()
+        );
+        assert_eq!(
+            built(ChordBuilder::new(D4).minor().eleventh()),
+            minor_eleventh(D4).unwrap().notes()
+        );
+        assert_eq!(
+            built(ChordBuilder::new(C4).augmented()),
+            augmented_triad(C4).unwrap().notes()
+        );
+    }
+
+    #[test]
+    fn test_add_omit_and_bass() {
+        let add9 = ChordBuilder::new(C4).add(9).build().unwrap();
+        assert_eq!(add9.notes(), &[C4, E4, G4, D5]);
+
+        let no_fifth = ChordBuilder::new(C4).seventh().omit(5).build().unwrap();
+        assert_eq!(no_fifth.notes(), &[C4, E4, ASHARP4]);
+
+        let slash = ChordBuilder::new(C4).bass(E2).build().unwrap();
+        assert_eq!(slash.notes(), &[E3, C4, E4, G4]);
+        let slash = ChordBuilder::new(C4).bass(C4).build().unwrap();
+        assert_eq!(slash.notes(), &[C3, C4, E4, G4]);
+
+        // Adding a tone already in the chord doesn't duplicate it
+        let doubled = ChordBuilder::new(C4).ninth().add(9).build().unwrap();
+        assert_eq!(doubled.notes(), dominant_ninth(C4).unwrap().notes());
+    }
+
+    #[test]
+    fn test_contradictions() {
+        assert_eq!(
+            ChordBuilder::new(C4).major().minor().build(),
+            Err(Error::ContradictoryChord {
+                first: "major third",
+                second: "minor third"
+            })
+        );
+        assert_eq!(
+            ChordBuilder::new(C4).sharp_five().flat_five().build(),
+            Err(Error::ContradictoryChord {
+                first: "sharp fifth",
+                second: "flat fifth"
+            })
+        );
+        assert!(ChordBuilder::new(C4).sus4().minor().build().is_err());
+        assert!(ChordBuilder::new(C4)
+            .seventh()
+            .major_seventh()
+            .build()
+            .is_err());
+
+        // Setting the same tone twice is not a contradiction
+        assert!(ChordBuilder::new(C4).minor().minor().build().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_chords() {
+        assert_eq!(
+            ChordBuilder::new(C4).add(8).build(),
+            Err(Error::InvalidScaleDegree(8))
+        );
+        assert_eq!(
+            ChordBuilder::new(C4).omit(2).build(),
+            Err(Error::InvalidScaleDegree(2))
+        );
+        assert_eq!(
+            ChordBuilder::new(G9).build(),
+            Err(Error::NoteOutOfRange(G9))
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::constants::*;
-use crate::Note;
+use crate::{consonance_score, Error, Interval, NamedSlice, Note, NoteRow, PitchClassMask};
 use std::fmt;
 
 /// Represents the quality of a chord
@@ -14,10 +14,11 @@ use std::fmt;
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_major = major_triad(C4);
+/// let c_major = major_triad(C4).unwrap();
 /// assert_eq!(c_major.quality(), ChordQuality::MajorTriad);
 /// ```
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChordQuality {
     MajorTriad,
     MinorTriad,
@@ -49,6 +50,86 @@ pub enum ChordQuality {
     MajorThirteenth,
 }
 
+impl ChordQuality {
+    /// All the chord qualities, in declaration order
+    pub const ALL: [ChordQuality; 28] = [
+        ChordQuality::MajorTriad,
+        ChordQuality::MinorTriad,
+        ChordQuality::DominantSeventh,
+        ChordQuality::DominantSeventhNinth,
+        ChordQuality::MinorSeventh,
+        ChordQuality::MinorSeventhNinth,
+        ChordQuality::MajorSeventh,
+        ChordQuality::MinorMajorSeventh,
+        ChordQuality::MajorSixth,
+        ChordQuality::MinorSixth,
+        ChordQuality::MajorSixthNinth,
+        ChordQuality::MinorSixthNinth,
+        ChordQuality::Sus2,
+        ChordQuality::Sus4,
+        ChordQuality::DiminishedTriad,
+        ChordQuality::DiminishedSeventh,
+        ChordQuality::HalfDiminishedSeventh,
+        ChordQuality::AugmentedTriad,
+        ChordQuality::AugmentedSeventh,
+        ChordQuality::DominantNinth,
+        ChordQuality::MinorNinth,
+        ChordQuality::MajorNinth,
+        ChordQuality::DominantEleventh,
+        ChordQuality::MinorEleventh,
+        ChordQuality::MajorEleventh,
+        ChordQuality::DominantThirteenth,
+        ChordQuality::MinorThirteenth,
+        ChordQuality::MajorThirteenth,
+    ];
+
+    /// Returns the intervals above the root that build a chord of this quality
+    ///
+    /// # Returns
+    /// The intervals of the chord, without the root
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(ChordQuality::MajorTriad.intervals(), &[MAJOR_THIRD, PERFECT_FIFTH]);
+    /// ```
+    pub fn intervals(&self) -> &'static [Interval] {
+        match self {
+            ChordQuality::MajorTriad => &MAJOR_TRIAD_INTERVALS,
+            ChordQuality::MinorTriad => &MINOR_TRIAD_INTERVALS,
+            ChordQuality::DominantSeventh => &DOMINANT_SEVENTH_INTERVALS,
+            ChordQuality::DominantSeventhNinth => &DOMINANT_SEVENTH_NINTH_INTERVALS,
+            ChordQuality::MinorSeventh => &MINOR_SEVENTH_INTERVALS,
+            ChordQuality::MinorSeventhNinth => &MINOR_SEVENTH_NINTH_INTERVALS,
+            ChordQuality::MajorSeventh => &MAJOR_SEVENTH_INTERVALS,
+            ChordQuality::MinorMajorSeventh => &MINOR_MAJOR_SEVENTH_INTERVALS,
+            ChordQuality::MajorSixth => &MAJOR_SIXTH_INTERVALS,
+            ChordQuality::MinorSixth => &MINOR_SIXTH_INTERVALS,
+            ChordQuality::MajorSixthNinth => &MAJOR_SIXTH_NINTH_INTERVALS,
+            ChordQuality::MinorSixthNinth => &MINOR_SIXTH_NINTH_INTERVALS,
+            ChordQuality::Sus2 => &SUS2_INTERVALS,
+            ChordQuality::Sus4 => &SUS4_INTERVALS,
+            ChordQuality::DiminishedTriad => &DIMINISHED_TRIAD_INTERVALS,
+            ChordQuality::DiminishedSeventh => &DIMINISHED_SEVENTH_INTERVALS,
+            ChordQuality::HalfDiminishedSeventh => &HALF_DIMINISHED_SEVENTH_INTERVALS,
+            ChordQuality::AugmentedTriad => &AUGMENTED_TRIAD_INTERVALS,
+            ChordQuality::AugmentedSeventh => &AUGMENTED_SEVENTH_INTERVALS,
+            ChordQuality::DominantNinth => &DOMINANT_NINTH_INTERVALS,
+            ChordQuality::MinorNinth => &MINOR_NINTH_INTERVALS,
+            ChordQuality::MajorNinth => &MAJOR_NINTH_INTERVALS,
+            ChordQuality::DominantEleventh => &DOMINANT_ELEVENTH_INTERVALS,
+            ChordQuality::MinorEleventh => &MINOR_ELEVENTH_INTERVALS,
+            ChordQuality::MajorEleventh => &MAJOR_ELEVENTH_INTERVALS,
+            ChordQuality::DominantThirteenth => &DOMINANT_THIRTEENTH_INTERVALS,
+            ChordQuality::MinorThirteenth => &MINOR_THIRTEENTH_INTERVALS,
+            ChordQuality::MajorThirteenth => &MAJOR_THIRTEENTH_INTERVALS,
+        }
+    }
+}
+
 /// Represents a chord
 ///
 /// This struct defines a chord, which is a collection of notes with a specific quality.
@@ -61,9 +142,10 @@ pub enum ChordQuality {
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_major = major_triad(C4);
+/// let c_major = major_triad(C4).unwrap();
 /// assert_eq!(c_major.quality(), ChordQuality::MajorTriad);
 /// ```
+#[derive(PartialEq, Eq)]
 pub struct Chord<const N: usize> {
     quality: ChordQuality,
     notes: [Note; N],
@@ -92,6 +174,61 @@ impl<const N: usize> Chord<N> {
         Self { quality, notes: ns }
     }
 
+    /// Creates a chord from a root and a named interval pattern
+    ///
+    /// The pattern must be the intervals of one of the chord qualities, such as
+    /// `MAJOR_TRIAD_INTERVALS` or `DOMINANT_SEVENTH_INTERVALS`, so the quality
+    /// of the chord can be recognised.
+    ///
+    /// # Arguments
+    /// * `root` - The root note of the chord
+    /// * `intervals` - The intervals above the root, one fewer than the notes of the chord
+    ///
+    /// # Returns
+    /// The chord, or `None` if the pattern has the wrong length, matches no chord
+    /// quality or a note would be above MIDI 127
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let g7: Chord<4> = Chord::from_intervals(G3, &DOMINANT_SEVENTH_INTERVALS).unwrap();
+    /// assert_eq!(g7.quality(), ChordQuality::DominantSeventh);
+    /// assert_eq!(g7.notes(), &[G3, B3, D4, F4]);
+    ///
+    /// assert_eq!(Chord::<3>::from_intervals(C4, &[MAJOR_THIRD, MAJOR_SEVENTH]), None);
+    /// assert_eq!(Chord::<3>::from_intervals(G9, &MAJOR_TRIAD_INTERVALS), None);
+    /// ```
+    pub fn from_intervals(root: Note, intervals: &[Interval]) -> Option<Self> {
+        if intervals.len() + 1 != N {
+            return None;
+        }
+
+        let quality = ChordQuality::ALL
+            .into_iter()
+            .find(|quality| quality.intervals() == intervals)?;
+        Self::with_quality(quality, root).ok()
+    }
+
+    /// Creates the chord of a quality on a root note, stacking the intervals of the quality
+    ///
+    /// # Arguments
+    /// * `quality` - The quality of the chord, whose intervals have one fewer than `N` entries
+    /// * `root` - The root note of the chord
+    ///
+    /// # Returns
+    /// The chord, or `Error::NoteOutOfRange` with the root if a note would be above MIDI 127
+    pub(crate) fn with_quality(quality: ChordQuality, root: Note) -> Result<Self, Error> {
+        let intervals = quality.intervals();
+        let widest = intervals.iter().max().map_or(0, Interval::semitones);
+        root.checked_add_semitones(i16::from(widest))
+            .ok_or(Error::NoteOutOfRange(root))?;
+        let notes = root.into_notes_from_intervals(intervals.iter().copied());
+        Ok(Self::new(quality, notes))
+    }
+
     /// Returns the notes of the chord
     ///
     /// # Returns
@@ -103,7 +240,7 @@ impl<const N: usize> Chord<N> {
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_major = major_triad(C4);
+    /// let c_major = major_triad(C4).unwrap();
     /// assert_eq!(c_major.notes(), &[C4, E4, G4]);
     /// ```
     pub const fn notes(&self) -> &[Note; N] {
@@ -121,7 +258,7 @@ impl<const N: usize> Chord<N> {
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_major = major_triad(C4);
+    /// let c_major = major_triad(C4).unwrap();
     /// assert_eq!(c_major.quality(), ChordQuality::MajorTriad);
     /// ```
     pub const fn quality(&self) -> ChordQuality {
@@ -137,6 +274,100 @@ impl<const N: usize> Chord<N> {
     pub const fn root(&self) -> Note {
         self.notes[0]
     }
+
+    /// Returns the chord transposed by a number of semitones
+    ///
+    /// The quality of the chord is preserved; every note is moved by the same amount.
+    ///
+    /// # Arguments
+    /// * `semitones` - The number of semitones to move (negative values move down)
+    ///
+    /// # Returns
+    /// A new chord with the same quality and transposed notes, or `None` if a
+    /// note would leave the MIDI range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let d_major = major_triad(C4).unwrap().transpose(2).unwrap();
+    /// assert_eq!(d_major.notes(), &[D4, FSHARP4, A4]);
+    /// assert_eq!(major_triad(E8).unwrap().transpose(12), None);
+    /// ```
+    pub fn transpose(&self, semitones: i8) -> Option<Chord<N>> {
+        let notes = self
+            .notes
+            .iter()
+            .map(|note| note.checked_add_semitones(i16::from(semitones)))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Chord::new(self.quality, notes))
+    }
+
+    /// Returns the tension of the chord
+    ///
+    /// The tension is the complement of the consonance score of the chord notes,
+    /// so it goes from 0.0 (perfectly consonant) to 1.0 (maximally dissonant).
+    ///
+    /// # Returns
+    /// The tension of the chord
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert!(major_triad(C4).unwrap().tension() < dominant_seventh(C4).unwrap().tension());
+    /// ```
+    pub fn tension(&self) -> f32 {
+        1.0 - consonance_score(&self.notes)
+    }
+
+    /// Returns the notes of the chord in reverse order
+    ///
+    /// # Returns
+    /// The notes of the chord, from the highest to the root
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let c_major = major_triad(C4).unwrap();
+    /// assert_eq!(c_major.reverse(), [G4, E4, C4]);
+    /// ```
+    pub fn reverse(&self) -> [Note; N] {
+        let mut notes = self.notes;
+        notes.reverse();
+        notes
+    }
+
+    /// Returns `true` if both chords are built from the same pitch classes
+    ///
+    /// Inversions and voicings in other octaves share the pitch classes of the
+    /// root-position chord, so they are recognized as the same underlying harmony.
+    ///
+    /// # Arguments
+    /// * `other` - The chord to compare with
+    ///
+    /// # Returns
+    /// `true` if the chords have the same set of pitch classes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert!(major_triad(C4).unwrap().is_inversion_of(&major_triad(C5).unwrap()));
+    /// assert!(!major_triad(C4).unwrap().is_inversion_of(&minor_triad(C4).unwrap()));
+    /// ```
+    pub fn is_inversion_of<const M: usize>(&self, other: &Chord<M>) -> bool {
+        PitchClassMask::from_notes(&self.notes) == PitchClassMask::from_notes(&other.notes)
+    }
 }
 
 /// Creates a major triad chord
@@ -145,19 +376,20 @@ impl<const N: usize> Chord<N> {
 /// The major triad is a three-note chord consisting of the root note, a major third,
 /// and a perfect fifth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_major = major_triad(C4);
+/// let c_major = major_triad(C4).unwrap();
 /// assert_eq!(c_major.quality(), ChordQuality::MajorTriad);
 /// ```
-pub fn major_triad(root: Note) -> Chord<3> {
-    let intervals = MAJOR_TRIAD_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MajorTriad, notes)
+pub fn major_triad(root: Note) -> Result<Chord<3>, Error> {
+    Chord::with_quality(ChordQuality::MajorTriad, root)
 }
 
 /// Creates a minor triad chord
@@ -166,19 +398,20 @@ pub fn major_triad(root: Note) -> Chord<3> {
 /// The minor triad is a three-note chord consisting of the root note, a minor third,
 /// and a perfect fifth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let a_minor = minor_triad(A4);
+/// let a_minor = minor_triad(A4).unwrap();
 /// assert_eq!(a_minor.quality(), ChordQuality::MinorTriad);
 /// ```
-pub fn minor_triad(root: Note) -> Chord<3> {
-    let intervals = MINOR_TRIAD_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MinorTriad, notes)
+pub fn minor_triad(root: Note) -> Result<Chord<3>, Error> {
+    Chord::with_quality(ChordQuality::MinorTriad, root)
 }
 
 /// Creates a dominant seventh chord
@@ -187,19 +420,20 @@ pub fn minor_triad(root: Note) -> Chord<3> {
 /// The dominant seventh chord is a four-note chord consisting of the root note, a major third,
 /// a perfect fifth, and a minor seventh.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_dominant_seventh = dominant_seventh(C4);
+/// let c_dominant_seventh = dominant_seventh(C4).unwrap();
 /// assert_eq!(c_dominant_seventh.quality(), ChordQuality::DominantSeventh);
 /// ```
-pub fn dominant_seventh(root: Note) -> Chord<4> {
-    let intervals = DOMINANT_SEVENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::DominantSeventh, notes)
+pub fn dominant_seventh(root: Note) -> Result<Chord<4>, Error> {
+    Chord::with_quality(ChordQuality::DominantSeventh, root)
 }
 
 /// Creates a dominant seventh ninth chord
@@ -208,19 +442,20 @@ pub fn dominant_seventh(root: Note) -> Chord<4> {
 /// The dominant seventh ninth chord is a five-note chord consisting of the root note, a major third,
 /// a perfect fifth, a minor seventh, and a major ninth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_dominant_seventh_ninth = dominant_seventh_ninth(C4);
+/// let c_dominant_seventh_ninth = dominant_seventh_ninth(C4).unwrap();
 /// assert_eq!(c_dominant_seventh_ninth.quality(), ChordQuality::DominantSeventhNinth);
 /// ```
-pub fn dominant_seventh_ninth(root: Note) -> Chord<5> {
-    let intervals = DOMINANT_SEVENTH_NINTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::DominantSeventhNinth, notes)
+pub fn dominant_seventh_ninth(root: Note) -> Result<Chord<5>, Error> {
+    Chord::with_quality(ChordQuality::DominantSeventhNinth, root)
 }
 
 /// Creates a major seventh chord
@@ -229,19 +464,20 @@ pub fn dominant_seventh_ninth(root: Note) -> Chord<5> {
 /// The major seventh chord is a four-note chord consisting of the root note, a major third,
 /// a perfect fifth, and a major seventh.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_major_seventh = major_seventh(C4);
+/// let c_major_seventh = major_seventh(C4).unwrap();
 /// assert_eq!(c_major_seventh.quality(), ChordQuality::MajorSeventh);
 /// ```
-pub fn major_seventh(root: Note) -> Chord<4> {
-    let intervals = MAJOR_SEVENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MajorSeventh, notes)
+pub fn major_seventh(root: Note) -> Result<Chord<4>, Error> {
+    Chord::with_quality(ChordQuality::MajorSeventh, root)
 }
 
 /// Creates a minor seventh chord
@@ -250,19 +486,20 @@ pub fn major_seventh(root: Note) -> Chord<4> {
 /// The minor seventh chord is a four-note chord consisting of the root note, a minor third,
 /// a perfect fifth, and a minor seventh.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_minor_seventh = minor_seventh(C4);
+/// let c_minor_seventh = minor_seventh(C4).unwrap();
 /// assert_eq!(c_minor_seventh.quality(), ChordQuality::MinorSeventh);
 /// ```
-pub fn minor_seventh(root: Note) -> Chord<4> {
-    let intervals = MINOR_SEVENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MinorSeventh, notes)
+pub fn minor_seventh(root: Note) -> Result<Chord<4>, Error> {
+    Chord::with_quality(ChordQuality::MinorSeventh, root)
 }
 
 /// Creates a minor seventh ninth chord
@@ -271,19 +508,20 @@ pub fn minor_seventh(root: Note) -> Chord<4> {
 /// The minor seventh ninth chord is a five-note chord consisting of the root note, a minor third,
 /// a perfect fifth, a minor seventh, and a major ninth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_minor_seventh_ninth = minor_seventh_ninth(C4);
+/// let c_minor_seventh_ninth = minor_seventh_ninth(C4).unwrap();
 /// assert_eq!(c_minor_seventh_ninth.quality(), ChordQuality::MinorSeventhNinth);
 /// ```
-pub fn minor_seventh_ninth(root: Note) -> Chord<5> {
-    let intervals = MINOR_SEVENTH_NINTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MinorSeventhNinth, notes)
+pub fn minor_seventh_ninth(root: Note) -> Result<Chord<5>, Error> {
+    Chord::with_quality(ChordQuality::MinorSeventhNinth, root)
 }
 
 /// Creates a minor major seventh chord
@@ -292,19 +530,20 @@ pub fn minor_seventh_ninth(root: Note) -> Chord<5> {
 /// The minor major seventh chord is a four-note chord consisting of the root note, a minor third,
 /// a perfect fifth, and a major seventh.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_minor_major_seventh = minor_major_seventh(C4);
+/// let c_minor_major_seventh = minor_major_seventh(C4).unwrap();
 /// assert_eq!(c_minor_major_seventh.quality(), ChordQuality::MinorMajorSeventh);
 /// ```
-pub fn minor_major_seventh(root: Note) -> Chord<4> {
-    let intervals = MINOR_MAJOR_SEVENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MinorMajorSeventh, notes)
+pub fn minor_major_seventh(root: Note) -> Result<Chord<4>, Error> {
+    Chord::with_quality(ChordQuality::MinorMajorSeventh, root)
 }
 
 /// Creates a major sixth chord
@@ -313,19 +552,20 @@ pub fn minor_major_seventh(root: Note) -> Chord<4> {
 /// The major sixth chord is a three-note chord consisting of the root note, a major third,
 /// and a major sixth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_major_sixth = major_sixth(C4);
+/// let c_major_sixth = major_sixth(C4).unwrap();
 /// assert_eq!(c_major_sixth.quality(), ChordQuality::MajorSixth);
 /// ```
-pub fn major_sixth(root: Note) -> Chord<4> {
-    let intervals = MAJOR_SIXTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MajorSixth, notes)
+pub fn major_sixth(root: Note) -> Result<Chord<4>, Error> {
+    Chord::with_quality(ChordQuality::MajorSixth, root)
 }
 
 /// Creates a minor sixth chord
@@ -334,19 +574,20 @@ pub fn major_sixth(root: Note) -> Chord<4> {
 /// The minor sixth chord is a three-note chord consisting of the root note, a minor third,
 /// and a major sixth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_minor_sixth = minor_sixth(C4);
+/// let c_minor_sixth = minor_sixth(C4).unwrap();
 /// assert_eq!(c_minor_sixth.quality(), ChordQuality::MinorSixth);
 /// ```
-pub fn minor_sixth(root: Note) -> Chord<4> {
-    let intervals = MINOR_SIXTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MinorSixth, notes)
+pub fn minor_sixth(root: Note) -> Result<Chord<4>, Error> {
+    Chord::with_quality(ChordQuality::MinorSixth, root)
 }
 
 /// Creates a major sixth ninth chord
@@ -355,18 +596,19 @@ pub fn minor_sixth(root: Note) -> Chord<4> {
 /// The major sixth ninth chord is a four-note chord consisting of the root note, a major third,
 /// a major sixth, and a major ninth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
-/// let c_major_sixth_ninth = major_sixth_ninth(C4);
+/// let c_major_sixth_ninth = major_sixth_ninth(C4).unwrap();
 /// assert_eq!(c_major_sixth_ninth.quality(), ChordQuality::MajorSixthNinth);
 /// ```
-pub fn major_sixth_ninth(root: Note) -> Chord<5> {
-    let intervals = MAJOR_SIXTH_NINTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MajorSixthNinth, notes)
+pub fn major_sixth_ninth(root: Note) -> Result<Chord<5>, Error> {
+    Chord::with_quality(ChordQuality::MajorSixthNinth, root)
 }
 
 /// Creates a minor sixth ninth chord
@@ -375,19 +617,20 @@ pub fn major_sixth_ninth(root: Note) -> Chord<5> {
 /// The minor sixth ninth chord is a four-note chord consisting of the root note, a minor third,
 /// a major sixth, and a major ninth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_minor_sixth_ninth = minor_sixth_ninth(C4);
+/// let c_minor_sixth_ninth = minor_sixth_ninth(C4).unwrap();
 /// assert_eq!(c_minor_sixth_ninth.quality(), ChordQuality::MinorSixthNinth);
 /// ```
-pub fn minor_sixth_ninth(root: Note) -> Chord<5> {
-    let intervals = MINOR_SIXTH_NINTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MinorSixthNinth, notes)
+pub fn minor_sixth_ninth(root: Note) -> Result<Chord<5>, Error> {
+    Chord::with_quality(ChordQuality::MinorSixthNinth, root)
 }
 
 /// Creates a suspended 2 chord
@@ -396,19 +639,20 @@ pub fn minor_sixth_ninth(root: Note) -> Chord<5> {
 /// The suspended 2 chord is a three-note chord consisting of the root note, a major second,
 /// and a perfect fifth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_suspended_2 = sus2(C4);
+/// let c_suspended_2 = sus2(C4).unwrap();
 /// assert_eq!(c_suspended_2.quality(), ChordQuality::Sus2);
 /// ```
-pub fn sus2(root: Note) -> Chord<3> {
-    let intervals = SUS2_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::Sus2, notes)
+pub fn sus2(root: Note) -> Result<Chord<3>, Error> {
+    Chord::with_quality(ChordQuality::Sus2, root)
 }
 
 /// Creates a suspended 4 chord
@@ -417,19 +661,20 @@ pub fn sus2(root: Note) -> Chord<3> {
 /// The suspended 4 chord is a three-note chord consisting of the root note, a perfect fourth,
 /// and a perfect fifth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_suspended_4 = sus4(C4);
+/// let c_suspended_4 = sus4(C4).unwrap();
 /// assert_eq!(c_suspended_4.quality(), ChordQuality::Sus4);
 /// ```
-pub fn sus4(root: Note) -> Chord<3> {
-    let intervals = SUS4_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::Sus4, notes)
+pub fn sus4(root: Note) -> Result<Chord<3>, Error> {
+    Chord::with_quality(ChordQuality::Sus4, root)
 }
 
 /// Creates a diminished triad chord
@@ -438,19 +683,20 @@ pub fn sus4(root: Note) -> Chord<3> {
 /// The diminished triad is a three-note chord consisting of the root note, a minor third,
 /// and a minor third.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let b_diminished = diminished_triad(B4);
+/// let b_diminished = diminished_triad(B4).unwrap();
 /// assert_eq!(b_diminished.quality(), ChordQuality::DiminishedTriad);
 /// ```
-pub fn diminished_triad(root: Note) -> Chord<3> {
-    let intervals = DIMINISHED_TRIAD_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::DiminishedTriad, notes)
+pub fn diminished_triad(root: Note) -> Result<Chord<3>, Error> {
+    Chord::with_quality(ChordQuality::DiminishedTriad, root)
 }
 
 /// Creates a diminished seventh chord
@@ -459,19 +705,20 @@ pub fn diminished_triad(root: Note) -> Chord<3> {
 /// The diminished seventh chord is a four-note chord consisting of the root note, a minor third,
 /// a diminished fifth, and a major sixth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_diminished_seventh = diminished_seventh(C4);
+/// let c_diminished_seventh = diminished_seventh(C4).unwrap();
 /// assert_eq!(c_diminished_seventh.quality(), ChordQuality::DiminishedSeventh);
 /// ```
-pub fn diminished_seventh(root: Note) -> Chord<4> {
-    let intervals = DIMINISHED_SEVENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::DiminishedSeventh, notes)
+pub fn diminished_seventh(root: Note) -> Result<Chord<4>, Error> {
+    Chord::with_quality(ChordQuality::DiminishedSeventh, root)
 }
 
 /// Creates a half-diminished seventh chord
@@ -480,19 +727,20 @@ pub fn diminished_seventh(root: Note) -> Chord<4> {
 /// The half-diminished seventh chord is a four-note chord consisting of the root note, a minor third,
 /// a diminished fifth, and a minor seventh.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_half_diminished_seventh = half_diminished_seventh(C4);
+/// let c_half_diminished_seventh = half_diminished_seventh(C4).unwrap();
 /// assert_eq!(c_half_diminished_seventh.quality(), ChordQuality::HalfDiminishedSeventh);
 /// ```
-pub fn half_diminished_seventh(root: Note) -> Chord<4> {
-    let intervals = HALF_DIMINISHED_SEVENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::HalfDiminishedSeventh, notes)
+pub fn half_diminished_seventh(root: Note) -> Result<Chord<4>, Error> {
+    Chord::with_quality(ChordQuality::HalfDiminishedSeventh, root)
 }
 
 /// Creates an augmented triad chord
@@ -501,19 +749,20 @@ pub fn half_diminished_seventh(root: Note) -> Chord<4> {
 /// The augmented triad is a three-note chord consisting of the root note, a major third,
 /// and a major third.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_augmented = augmented_triad(C4);
+/// let c_augmented = augmented_triad(C4).unwrap();
 /// assert_eq!(c_augmented.quality(), ChordQuality::AugmentedTriad);
 /// ```
-pub fn augmented_triad(root: Note) -> Chord<3> {
-    let intervals = AUGMENTED_TRIAD_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::AugmentedTriad, notes)
+pub fn augmented_triad(root: Note) -> Result<Chord<3>, Error> {
+    Chord::with_quality(ChordQuality::AugmentedTriad, root)
 }
 
 /// Creates an augmented seventh chord
@@ -522,16 +771,17 @@ pub fn augmented_triad(root: Note) -> Chord<3> {
 /// The augmented seventh chord is a four-note chord consisting of the root note, a major third,
 /// an augmented fifth, and an augmented seventh.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 /// ```
-pub fn augmented_seventh(root: Note) -> Chord<4> {
-    let intervals = AUGMENTED_SEVENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::AugmentedSeventh, notes)
+pub fn augmented_seventh(root: Note) -> Result<Chord<4>, Error> {
+    Chord::with_quality(ChordQuality::AugmentedSeventh, root)
 }
 
 /// Creates a dominant ninth chord
@@ -540,19 +790,20 @@ pub fn augmented_seventh(root: Note) -> Chord<4> {
 /// The dominant ninth chord is a five-note chord consisting of the root note, a major third,
 /// a perfect fifth, a minor seventh, and a major ninth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_dominant_ninth = dominant_ninth(C4);
+/// let c_dominant_ninth = dominant_ninth(C4).unwrap();
 /// assert_eq!(c_dominant_ninth.quality(), ChordQuality::DominantNinth);
 /// ```
-pub fn dominant_ninth(root: Note) -> Chord<5> {
-    let intervals = DOMINANT_NINTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::DominantNinth, notes)
+pub fn dominant_ninth(root: Note) -> Result<Chord<5>, Error> {
+    Chord::with_quality(ChordQuality::DominantNinth, root)
 }
 
 /// Creates a minor ninth chord
@@ -561,19 +812,20 @@ pub fn dominant_ninth(root: Note) -> Chord<5> {
 /// The minor ninth chord is a five-note chord consisting of the root note, a minor third,
 /// a perfect fifth, a minor seventh, and a major ninth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_minor_ninth = minor_ninth(C4);
+/// let c_minor_ninth = minor_ninth(C4).unwrap();
 /// assert_eq!(c_minor_ninth.quality(), ChordQuality::MinorNinth);
 /// ```
-pub fn minor_ninth(root: Note) -> Chord<5> {
-    let intervals = MINOR_NINTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MinorNinth, notes)
+pub fn minor_ninth(root: Note) -> Result<Chord<5>, Error> {
+    Chord::with_quality(ChordQuality::MinorNinth, root)
 }
 
 /// Creates a major ninth chord
@@ -582,19 +834,20 @@ pub fn minor_ninth(root: Note) -> Chord<5> {
 /// The major ninth chord is a five-note chord consisting of the root note, a major third,
 /// a perfect fifth, a major seventh, and a major ninth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_major_ninth = major_ninth(C4);
+/// let c_major_ninth = major_ninth(C4).unwrap();
 /// assert_eq!(c_major_ninth.quality(), ChordQuality::MajorNinth);
 /// ```
-pub fn major_ninth(root: Note) -> Chord<5> {
-    let intervals = MAJOR_NINTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MajorNinth, notes)
+pub fn major_ninth(root: Note) -> Result<Chord<5>, Error> {
+    Chord::with_quality(ChordQuality::MajorNinth, root)
 }
 
 /// Creates a dominant eleventh chord
@@ -603,19 +856,20 @@ pub fn major_ninth(root: Note) -> Chord<5> {
 /// The dominant eleventh chord is a six-note chord consisting of the root note, a major third,
 /// a perfect fifth, a minor seventh, a major ninth, and a major eleventh.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_dominant_eleventh = dominant_eleventh(C4);
+/// let c_dominant_eleventh = dominant_eleventh(C4).unwrap();
 /// assert_eq!(c_dominant_eleventh.quality(), ChordQuality::DominantEleventh);
 /// ```
-pub fn dominant_eleventh(root: Note) -> Chord<6> {
-    let intervals = DOMINANT_ELEVENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::DominantEleventh, notes)
+pub fn dominant_eleventh(root: Note) -> Result<Chord<6>, Error> {
+    Chord::with_quality(ChordQuality::DominantEleventh, root)
 }
 
 /// Creates a minor eleventh chord
@@ -624,19 +878,20 @@ pub fn dominant_eleventh(root: Note) -> Chord<6> {
 /// The minor eleventh chord is a six-note chord consisting of the root note, a minor third,
 /// a perfect fifth, a minor seventh, a major ninth, and a perfect eleventh.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_minor_eleventh = minor_eleventh(C4);
+/// let c_minor_eleventh = minor_eleventh(C4).unwrap();
 /// assert_eq!(c_minor_eleventh.quality(), ChordQuality::MinorEleventh);
 /// ```
-pub fn minor_eleventh(root: Note) -> Chord<6> {
-    let intervals = MINOR_ELEVENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MinorEleventh, notes)
+pub fn minor_eleventh(root: Note) -> Result<Chord<6>, Error> {
+    Chord::with_quality(ChordQuality::MinorEleventh, root)
 }
 
 /// Creates a major eleventh chord
@@ -645,19 +900,20 @@ pub fn minor_eleventh(root: Note) -> Chord<6> {
 /// The major eleventh chord is a six-note chord consisting of the root note, a major third,
 /// a perfect fifth, a major seventh, a major ninth, and a perfect eleventh.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_major_eleventh = major_eleventh(C4);
+/// let c_major_eleventh = major_eleventh(C4).unwrap();
 /// assert_eq!(c_major_eleventh.quality(), ChordQuality::MajorEleventh);
 /// ```
-pub fn major_eleventh(root: Note) -> Chord<6> {
-    let intervals = MAJOR_ELEVENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MajorEleventh, notes)
+pub fn major_eleventh(root: Note) -> Result<Chord<6>, Error> {
+    Chord::with_quality(ChordQuality::MajorEleventh, root)
 }
 
 /// Creates a dominant thirteenth chord
@@ -666,19 +922,20 @@ pub fn major_eleventh(root: Note) -> Chord<6> {
 /// The dominant thirteenth chord is a seven-note chord consisting of the root note, a major third,
 /// a perfect fifth, a minor seventh, a major ninth, a perfect eleventh, and a major thirteenth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_dominant_thirteenth = dominant_thirteenth(C4);
+/// let c_dominant_thirteenth = dominant_thirteenth(C4).unwrap();
 /// assert_eq!(c_dominant_thirteenth.quality(), ChordQuality::DominantThirteenth);
 /// ```
-pub fn dominant_thirteenth(root: Note) -> Chord<7> {
-    let intervals = DOMINANT_THIRTEENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::DominantThirteenth, notes)
+pub fn dominant_thirteenth(root: Note) -> Result<Chord<7>, Error> {
+    Chord::with_quality(ChordQuality::DominantThirteenth, root)
 }
 
 /// Creates a minor thirteenth chord
@@ -687,19 +944,20 @@ pub fn dominant_thirteenth(root: Note) -> Chord<7> {
 /// The minor thirteenth chord is a seven-note chord consisting of the root note, a minor third,
 /// a perfect fifth, a minor seventh, a major ninth, a perfect eleventh, and a minor thirteenth.    
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_minor_thirteenth = minor_thirteenth(C4);
+/// let c_minor_thirteenth = minor_thirteenth(C4).unwrap();
 /// assert_eq!(c_minor_thirteenth.quality(), ChordQuality::MinorThirteenth);
 /// ```
-pub fn minor_thirteenth(root: Note) -> Chord<7> {
-    let intervals = MINOR_THIRTEENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MinorThirteenth, notes)
+pub fn minor_thirteenth(root: Note) -> Result<Chord<7>, Error> {
+    Chord::with_quality(ChordQuality::MinorThirteenth, root)
 }
 
 /// Creates a major thirteenth chord
@@ -708,19 +966,20 @@ pub fn minor_thirteenth(root: Note) -> Chord<7> {
 /// The major thirteenth chord is a seven-note chord consisting of the root note, a major third,
 /// a perfect fifth, a major seventh, a major ninth, a perfect eleventh, and a major thirteenth.
 ///
+/// # Returns
+/// The chord, or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
 /// # Examples
 ///
 /// ```rust
 /// use mozzart_std::*;
 /// use mozzart_std::constants::*;
 ///
-/// let c_major_thirteenth = major_thirteenth(C4);
+/// let c_major_thirteenth = major_thirteenth(C4).unwrap();
 /// assert_eq!(c_major_thirteenth.quality(), ChordQuality::MajorThirteenth);
 /// ```
-pub fn major_thirteenth(root: Note) -> Chord<7> {
-    let intervals = MAJOR_THIRTEENTH_INTERVALS;
-    let notes = root.into_notes_from_intervals(intervals);
-    Chord::new(ChordQuality::MajorThirteenth, notes)
+pub fn major_thirteenth(root: Note) -> Result<Chord<7>, Error> {
+    Chord::with_quality(ChordQuality::MajorThirteenth, root)
 }
 
 /// Returns the suffix for a chord
@@ -749,7 +1008,7 @@ pub fn major_thirteenth(root: Note) -> Chord<7> {
 /// assert_eq!(chord_suffix(ChordQuality::MajorSixthNinth), "6/9");
 /// assert_eq!(chord_suffix(ChordQuality::MinorSixthNinth), "m6/9");
 /// ```
-fn chord_suffix(quality: ChordQuality) -> &'static str {
+pub(crate) fn chord_suffix(quality: ChordQuality) -> &'static str {
     match quality {
         ChordQuality::MajorTriad => "",
         ChordQuality::MinorTriad => "m",
@@ -782,6 +1041,68 @@ fn chord_suffix(quality: ChordQuality) -> &'static str {
     }
 }
 
+/// Writes the notes of a chord pattern above a root into a buffer
+///
+/// Unlike the chord constructors, the pattern doesn't have to match a chord
+/// quality and its length isn't fixed, and the notes are written into a buffer
+/// which is reused without allocating once it is large enough.
+///
+/// # Arguments
+/// * `root` - The root note of the chord
+/// * `pattern` - The intervals above the root, such as `MAJOR_TRIAD_INTERVALS`
+/// * `out` - The buffer, cleared then filled with the root and one note per interval
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let mut notes = Vec::with_capacity(8);
+/// build_chord_into(G3, &DOMINANT_SEVENTH_INTERVALS, &mut notes);
+/// assert_eq!(dominant_seventh(G3).unwrap(), notes);
+/// // Notes above MIDI 127 are left out
+/// build_chord_into(G9, &MAJOR_TRIAD_INTERVALS, &mut notes);
+/// assert_eq!(notes, vec![G9]);
+/// ```
+pub fn build_chord_into(root: Note, pattern: &[Interval], out: &mut Vec<Note>) {
+    out.clear();
+    out.push(root);
+    out.extend(
+        pattern
+            .iter()
+            .map_while(|interval| root.checked_add_semitones(i16::from(interval.semitones()))),
+    );
+}
+
+/// A chord equals a sequence of notes when it has exactly these notes, in the same order
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(C4.major_triad_chord().unwrap(), [C4, E4, G4]);
+/// assert_ne!(C4.major_triad_chord().unwrap(), vec![E4, G4, C5]);
+/// ```
+impl<const N: usize> PartialEq<[Note]> for Chord<N> {
+    fn eq(&self, other: &[Note]) -> bool {
+        self.notes[..] == *other
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<[Note; M]> for Chord<N> {
+    fn eq(&self, other: &[Note; M]) -> bool {
+        self.notes[..] == other[..]
+    }
+}
+
+impl<const N: usize> PartialEq<Vec<Note>> for Chord<N> {
+    fn eq(&self, other: &Vec<Note>) -> bool {
+        self.notes[..] == other[..]
+    }
+}
+
 impl<const N: usize> fmt::UpperHex for Chord<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         let root = self.root();
@@ -807,7 +1128,13 @@ impl<const N: usize> fmt::Display for Chord<N> {
 }
 
 impl<const N: usize> fmt::Debug for Chord<N> {
+    /// Writes the chord symbol, or with the alternate flag (`{:#?}`) a table of its notes
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        if f.alternate() {
+            let rows: Vec<_> = self.notes.iter().copied().map(NoteRow).collect();
+            return fmt::Debug::fmt(&NamedSlice::new(self.to_string(), &rows), f);
+        }
+
         let root = self.root();
         let suffix = chord_suffix(self.quality());
         write!(f, "{root:?}{suffix}")
@@ -817,10 +1144,106 @@ impl<const N: usize> fmt::Debug for Chord<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::SpellingPreference;
+
+    #[test]
+    fn test_debug_table() {
+        assert_eq!(format!("{:?}", major_triad(C4).unwrap()), "C[60]");
+        assert_eq!(
+            format!("{:#?}", major_triad(C4).unwrap()),
+            "C:
+    0  C4     60    261.63 Hz
+    1  E4     64    329.63 Hz
+    2  G4     67    392.00 Hz"
+        );
+    }
+
+    #[test]
+    fn test_eq_notes() {
+        let chord = C4.major_triad_chord().unwrap();
+        assert_eq!(chord, [C4, E4, G4]);
+        assert_eq!(chord, vec![C4, E4, G4]);
+        assert_eq!(chord, *[C4, E4, G4].as_slice());
+        assert_ne!(chord, [C4, G4, E4]);
+        assert_ne!(chord, [C4, E4]);
+        assert_ne!(chord, [C5, E5, G5]);
+    }
+
+    #[test]
+    fn test_from_intervals() {
+        assert_eq!(
+            Chord::from_intervals(C4, &MAJOR_TRIAD_INTERVALS),
+            Some(major_triad(C4).unwrap())
+        );
+        assert_eq!(
+            Chord::from_intervals(A3, &MINOR_SEVENTH_INTERVALS),
+            Some(minor_seventh(A3).unwrap())
+        );
+        assert_eq!(Chord::<4>::from_intervals(C4, &MAJOR_TRIAD_INTERVALS), None);
+        assert_eq!(Chord::<3>::from_intervals(G9, &MAJOR_TRIAD_INTERVALS), None);
+
+        // The highest note of the chord may be the top of the MIDI range
+        let top = G9.checked_add_semitones(-7).unwrap();
+        assert_eq!(
+            Chord::<3>::from_intervals(top, &MAJOR_TRIAD_INTERVALS).map(|chord| chord.notes()[2]),
+            Some(G9)
+        );
+        let above = top.checked_add_semitones(1).unwrap();
+        assert_eq!(
+            Chord::<3>::from_intervals(above, &MAJOR_TRIAD_INTERVALS),
+            None
+        );
+    }
+
+    #[test]
+    fn test_constructors_out_of_range() {
+        assert_eq!(major_triad(G9), Err(Error::NoteOutOfRange(G9)));
+        assert_eq!(major_thirteenth(C8), Err(Error::NoteOutOfRange(C8)));
+        assert_eq!(C9.dominant_seventh_chord(), Err(Error::NoteOutOfRange(C9)));
+        assert!(major_thirteenth(C7).is_ok());
+
+        let top = G9.checked_add_semitones(-7).unwrap();
+        assert_eq!(major_triad(top).unwrap().notes()[2], G9);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let c_major = major_triad(C4).unwrap();
+        assert_eq!(
+            c_major.transpose(-1).unwrap().notes(),
+            &[B3, DSHARP4, FSHARP4]
+        );
+        assert!(c_major.transpose(0) == Some(major_triad(C4).unwrap()));
+        assert_eq!(c_major.transpose(-61), None);
+        assert_eq!(major_triad(E8).unwrap().transpose(12), None);
+    }
+
+    #[test]
+    fn test_symbol() {
+        let sharps = SpellingPreference::Sharps;
+        assert_eq!(
+            major_triad(C4).unwrap().symbol(sharps),
+            Some("C".to_string())
+        );
+        assert_eq!(
+            minor_seventh(D4).unwrap().symbol(sharps),
+            Some("Dm7".to_string())
+        );
+        assert_eq!(minor_seventh(D4).unwrap().to_string(), "Dm7");
+        assert_eq!(
+            half_diminished_seventh(B3).unwrap().symbol(sharps),
+            Some("Bm7b5".to_string())
+        );
+        // The octave of the root doesn't appear in the symbol
+        assert_eq!(
+            dominant_seventh(G2).unwrap().symbol(sharps),
+            dominant_seventh(G5).unwrap().symbol(sharps)
+        );
+    }
 
     #[test]
     fn test_major_triad() {
-        let scale = major_triad(C4);
+        let scale = major_triad(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MajorTriad);
         assert_eq!(scale.notes().len(), 3);
         assert_eq!(scale.notes(), &[C4, E4, G4]);
@@ -829,7 +1252,7 @@ mod tests {
 
     #[test]
     fn test_minor_triad() {
-        let scale = minor_triad(C4);
+        let scale = minor_triad(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MinorTriad);
         assert_eq!(scale.notes().len(), 3);
         assert_eq!(scale.notes(), &[C4, DSHARP4, G4]);
@@ -838,7 +1261,7 @@ mod tests {
 
     #[test]
     fn test_dominant_seventh() {
-        let scale = dominant_seventh(C4);
+        let scale = dominant_seventh(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::DominantSeventh);
         assert_eq!(scale.notes().len(), 4);
         assert_eq!(scale.notes(), &[C4, E4, G4, BFLAT4]);
@@ -847,7 +1270,7 @@ mod tests {
 
     #[test]
     fn test_dominant_seventh_ninth() {
-        let scale = dominant_seventh_ninth(C4);
+        let scale = dominant_seventh_ninth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::DominantSeventhNinth);
         assert_eq!(scale.notes().len(), 5);
         assert_eq!(scale.notes(), &[C4, E4, G4, BFLAT4, D5]);
@@ -856,7 +1279,7 @@ mod tests {
 
     #[test]
     fn test_minor_seventh() {
-        let scale = minor_seventh(C4);
+        let scale = minor_seventh(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MinorSeventh);
         assert_eq!(scale.notes().len(), 4);
         assert_eq!(scale.notes(), &[C4, EFLAT4, G4, BFLAT4]);
@@ -865,7 +1288,7 @@ mod tests {
 
     #[test]
     fn test_minor_seventh_ninth() {
-        let scale = minor_seventh_ninth(C4);
+        let scale = minor_seventh_ninth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MinorSeventhNinth);
         assert_eq!(scale.notes().len(), 5);
         assert_eq!(scale.notes(), &[C4, EFLAT4, G4, BFLAT4, D5]);
@@ -874,7 +1297,7 @@ mod tests {
 
     #[test]
     fn test_major_seventh() {
-        let scale = major_seventh(C4);
+        let scale = major_seventh(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MajorSeventh);
         assert_eq!(scale.notes().len(), 4);
         assert_eq!(scale.notes(), &[C4, E4, G4, B4]);
@@ -883,7 +1306,7 @@ mod tests {
 
     #[test]
     fn test_minor_major_seventh() {
-        let scale = minor_major_seventh(C4);
+        let scale = minor_major_seventh(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MinorMajorSeventh);
         assert_eq!(scale.notes().len(), 4);
         assert_eq!(scale.notes(), &[C4, EFLAT4, G4, B4]);
@@ -892,7 +1315,7 @@ mod tests {
 
     #[test]
     fn test_major_sixth() {
-        let scale = major_sixth(C4);
+        let scale = major_sixth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MajorSixth);
         assert_eq!(scale.notes().len(), 4);
         assert_eq!(scale.notes(), &[C4, E4, G4, A4]);
@@ -901,7 +1324,7 @@ mod tests {
 
     #[test]
     fn test_minor_sixth() {
-        let scale = minor_sixth(C4);
+        let scale = minor_sixth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MinorSixth);
         assert_eq!(scale.notes().len(), 4);
         assert_eq!(scale.notes(), &[C4, EFLAT4, G4, A4]);
@@ -910,7 +1333,7 @@ mod tests {
 
     #[test]
     fn test_major_sixth_ninth() {
-        let scale = major_sixth_ninth(C4);
+        let scale = major_sixth_ninth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MajorSixthNinth);
         assert_eq!(scale.notes().len(), 5);
         assert_eq!(scale.notes(), &[C4, E4, G4, A4, D5]);
@@ -919,7 +1342,7 @@ mod tests {
 
     #[test]
     fn test_minor_sixth_ninth() {
-        let scale = minor_sixth_ninth(C4);
+        let scale = minor_sixth_ninth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MinorSixthNinth);
         assert_eq!(scale.notes().len(), 5);
         assert_eq!(scale.notes(), &[C4, EFLAT4, G4, A4, D5]);
@@ -928,7 +1351,7 @@ mod tests {
 
     #[test]
     fn test_sus2() {
-        let scale = sus2(C4);
+        let scale = sus2(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::Sus2);
         assert_eq!(scale.notes().len(), 3);
         assert_eq!(scale.notes(), &[C4, D4, G4]);
@@ -937,7 +1360,7 @@ mod tests {
 
     #[test]
     fn test_sus4() {
-        let scale = sus4(C4);
+        let scale = sus4(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::Sus4);
         assert_eq!(scale.notes().len(), 3);
         assert_eq!(scale.notes(), &[C4, F4, G4]);
@@ -946,7 +1369,7 @@ mod tests {
 
     #[test]
     fn test_diminished_triad() {
-        let scale = diminished_triad(C4);
+        let scale = diminished_triad(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::DiminishedTriad);
         assert_eq!(scale.notes().len(), 3);
         assert_eq!(scale.notes(), &[C4, EFLAT4, GFLAT4]);
@@ -955,7 +1378,7 @@ mod tests {
 
     #[test]
     fn test_diminished_seventh() {
-        let scale = diminished_seventh(C4);
+        let scale = diminished_seventh(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::DiminishedSeventh);
         assert_eq!(scale.notes().len(), 4);
         assert_eq!(scale.notes(), &[C4, EFLAT4, GFLAT4, A4]);
@@ -964,7 +1387,7 @@ mod tests {
 
     #[test]
     fn test_half_diminished_seventh() {
-        let scale = half_diminished_seventh(C4);
+        let scale = half_diminished_seventh(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::HalfDiminishedSeventh);
         assert_eq!(scale.notes().len(), 4);
         assert_eq!(scale.notes(), &[C4, EFLAT4, GFLAT4, BFLAT4]);
@@ -973,7 +1396,7 @@ mod tests {
 
     #[test]
     fn test_augmented_triad() {
-        let scale = augmented_triad(C4);
+        let scale = augmented_triad(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::AugmentedTriad);
         assert_eq!(scale.notes().len(), 3);
         assert_eq!(scale.notes(), &[C4, E4, GSHARP4]);
@@ -982,7 +1405,7 @@ mod tests {
 
     #[test]
     fn test_augmented_seventh() {
-        let scale = augmented_seventh(C4);
+        let scale = augmented_seventh(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::AugmentedSeventh);
         assert_eq!(scale.notes().len(), 4);
         assert_eq!(scale.notes(), &[C4, E4, GSHARP4, BFLAT4]);
@@ -991,7 +1414,7 @@ mod tests {
 
     #[test]
     fn test_dominant_ninth() {
-        let scale = dominant_ninth(C4);
+        let scale = dominant_ninth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::DominantNinth);
         assert_eq!(scale.notes().len(), 5);
         assert_eq!(scale.notes(), &[C4, E4, G4, BFLAT4, D5]);
@@ -1000,7 +1423,7 @@ mod tests {
 
     #[test]
     fn test_minor_ninth() {
-        let scale = minor_ninth(C4);
+        let scale = minor_ninth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MinorNinth);
         assert_eq!(scale.notes().len(), 5);
         assert_eq!(scale.notes(), &[C4, EFLAT4, G4, BFLAT4, D5]);
@@ -1009,7 +1432,7 @@ mod tests {
 
     #[test]
     fn test_major_ninth() {
-        let scale = major_ninth(C4);
+        let scale = major_ninth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MajorNinth);
         assert_eq!(scale.notes().len(), 5);
         assert_eq!(scale.notes(), &[C4, E4, G4, B4, D5]);
@@ -1018,7 +1441,7 @@ mod tests {
 
     #[test]
     fn test_dominant_eleventh() {
-        let scale = dominant_eleventh(C4);
+        let scale = dominant_eleventh(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::DominantEleventh);
         assert_eq!(scale.notes().len(), 6);
         assert_eq!(scale.notes(), &[C4, E4, G4, BFLAT4, D5, F5]);
@@ -1027,7 +1450,7 @@ mod tests {
 
     #[test]
     fn test_minor_eleventh() {
-        let scale = minor_eleventh(C4);
+        let scale = minor_eleventh(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MinorEleventh);
         assert_eq!(scale.notes().len(), 6);
         assert_eq!(scale.notes(), &[C4, EFLAT4, G4, BFLAT4, D5, F5]);
@@ -1036,7 +1459,7 @@ mod tests {
 
     #[test]
     fn test_major_eleventh() {
-        let scale = major_eleventh(C4);
+        let scale = major_eleventh(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MajorEleventh);
         assert_eq!(scale.notes().len(), 6);
         assert_eq!(scale.notes(), &[C4, E4, G4, B4, D5, F5]);
@@ -1045,7 +1468,7 @@ mod tests {
 
     #[test]
     fn test_dominant_thirteenth() {
-        let scale = dominant_thirteenth(C4);
+        let scale = dominant_thirteenth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::DominantThirteenth);
         assert_eq!(scale.notes().len(), 7);
         assert_eq!(scale.notes(), &[C4, E4, G4, BFLAT4, D5, F5, A5]);
@@ -1054,7 +1477,7 @@ mod tests {
 
     #[test]
     fn test_minor_thirteenth() {
-        let scale = minor_thirteenth(C4);
+        let scale = minor_thirteenth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MinorThirteenth);
         assert_eq!(scale.notes().len(), 7);
         assert_eq!(scale.notes(), &[C4, EFLAT4, G4, BFLAT4, D5, F5, A5]);
@@ -1063,10 +1486,34 @@ mod tests {
 
     #[test]
     fn test_major_thirteenth() {
-        let scale = major_thirteenth(C4);
+        let scale = major_thirteenth(C4).unwrap();
         assert_eq!(scale.quality(), ChordQuality::MajorThirteenth);
         assert_eq!(scale.notes().len(), 7);
         assert_eq!(scale.notes(), &[C4, E4, G4, B4, D5, F5, A5]);
         assert_eq!(format!("{}", scale), "Cmaj13");
     }
+
+    #[test]
+    fn test_is_inversion_of() {
+        let root_position = major_triad(C4).unwrap();
+        let first_inversion = Chord::<3>::new(ChordQuality::MajorTriad, [E4, G4, C5]);
+        let second_inversion = Chord::<3>::new(ChordQuality::MajorTriad, [G3, C4, E4]);
+
+        assert!(root_position.is_inversion_of(&first_inversion));
+        assert!(first_inversion.is_inversion_of(&root_position));
+        assert!(second_inversion.is_inversion_of(&first_inversion));
+    }
+
+    #[test]
+    fn test_is_not_inversion_of() {
+        assert!(!major_triad(C4)
+            .unwrap()
+            .is_inversion_of(&minor_triad(C4).unwrap()));
+        assert!(!major_triad(C4)
+            .unwrap()
+            .is_inversion_of(&major_triad(D4).unwrap()));
+        assert!(!major_triad(C4)
+            .unwrap()
+            .is_inversion_of(&major_seventh(C4).unwrap()));
+    }
 }
@@ -0,0 +1,256 @@
+use crate::constants::*;
+use crate::{Chord, Interval, Note};
+
+/// Returns the consonance score of a group of notes, using the default weights
+///
+/// See [`consonance_score_with`] for how the score is computed.
+///
+/// # Arguments
+/// * `notes` - The notes sounding together
+///
+/// # Returns
+/// A score between 0.0 (maximally dissonant) and 1.0 (perfectly consonant)
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert!(consonance_score(&[C4, E4, G4]) > consonance_score(&[C4, CSHARP4, D4]));
+/// ```
+pub fn consonance_score(notes: &[Note]) -> f32 {
+    consonance_score_with(notes, &CONSONANCE_WEIGHTS)
+}
+
+/// Returns the consonance score of a group of notes, using custom interval weights
+///
+/// Only the lowest occurrence of each pitch class is kept, so octave doublings
+/// don't change the score. Every pair of the remaining notes contributes the weight
+/// of the interval between them, measured upwards and reduced to an octave. Pairs
+/// involving the bass count twice, since the bass colours the whole sonority; this
+/// is what tells a major triad apart from a minor one. The total is normalized by
+/// the number of (weighted) pairs, and a single note scores 1.0.
+///
+/// # Arguments
+/// * `notes` - The notes sounding together
+/// * `weights` - The weight of each interval, indexed by its size in semitones
+///
+/// # Returns
+/// The weighted average of the interval weights
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// // Treat every interval as equally consonant
+/// assert_eq!(consonance_score_with(&[C4, CSHARP4], &[0.5; 12]), 0.5);
+/// ```
+pub fn consonance_score_with(notes: &[Note], weights: &[f32; 12]) -> f32 {
+    let mut sorted = notes.to_vec();
+    sorted.sort();
+
+    let mut distinct: Vec<Note> = Vec::with_capacity(sorted.len());
+    for note in sorted {
+        if distinct
+            .iter()
+            .all(|n| n.pitch_class() != note.pitch_class())
+        {
+            distinct.push(note);
+        }
+    }
+
+    let mut total = 0.0;
+    let mut pairs = 0.0;
+    for (i, low) in distinct.iter().enumerate() {
+        for high in &distinct[i + 1..] {
+            let interval = (high.midi_number() - low.midi_number()) % SEMITONES_IN_OCTAVE;
+            let factor = if i == 0 { 2.0 } else { 1.0 };
+            total += weights[interval as usize] * factor;
+            pairs += factor;
+        }
+    }
+
+    if pairs == 0.0 {
+        1.0
+    } else {
+        total / pairs
+    }
+}
+
+/// Returns the chords sorted from the most consonant to the least consonant
+///
+/// Chords with the same score keep their original order.
+///
+/// # Arguments
+/// * `chords` - The chords to rank
+///
+/// # Returns
+/// References to the chords, ordered by decreasing consonance score
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let chords = [diminished_triad(C4).unwrap(), major_triad(C4).unwrap(), minor_triad(C4).unwrap()];
+/// let ranked = rank_by_consonance(&chords);
+/// assert_eq!(ranked[0].quality(), ChordQuality::MajorTriad);
+/// assert_eq!(ranked[2].quality(), ChordQuality::DiminishedTriad);
+/// ```
+pub fn rank_by_consonance<const N: usize>(chords: &[Chord<N>]) -> Vec<&Chord<N>> {
+    let mut ranked: Vec<_> = chords.iter().collect();
+    ranked.sort_by(|a, b| {
+        let (a, b) = (consonance_score(a.notes()), consonance_score(b.notes()));
+        b.total_cmp(&a)
+    });
+    ranked
+}
+
+/// The classes of intervals of common-practice harmony
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Consonance {
+    /// The unison, the octave, the fifth and the fourth
+    Perfect,
+    /// The thirds and the sixths
+    Imperfect,
+    /// The seconds, the sevenths and the tritone
+    Dissonant,
+}
+
+impl Interval {
+    /// Returns the consonance class of this interval
+    ///
+    /// Compound intervals are classified as their simple form. The fourth is
+    /// counted as a perfect consonance, as it is between the upper voices of a chord.
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(PERFECT_FIFTH.consonance(), Consonance::Perfect);
+    /// assert_eq!(MINOR_THIRD.consonance(), Consonance::Imperfect);
+    /// assert_eq!(MAJOR_NINTH.consonance(), Consonance::Dissonant);
+    /// ```
+    pub fn consonance(&self) -> Consonance {
+        match self.semitones() % SEMITONES_IN_OCTAVE {
+            0 | 5 | 7 => Consonance::Perfect,
+            3 | 4 | 8 | 9 => Consonance::Imperfect,
+            _ => Consonance::Dissonant,
+        }
+    }
+}
+
+/// Counts of the intervals between all the pairs of notes of a sonority, by consonance class
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct IntervalReport {
+    /// The number of perfect consonances
+    pub perfect: usize,
+    /// The number of imperfect consonances
+    pub imperfect: usize,
+    /// The number of dissonances
+    pub dissonant: usize,
+}
+
+impl IntervalReport {
+    /// Returns the number of pairs of notes
+    pub fn total(&self) -> usize {
+        self.perfect + self.imperfect + self.dissonant
+    }
+}
+
+/// Classifies the intervals between every pair of notes
+///
+/// Unlike `consonance_score`, every note counts, doublings included, and every
+/// pair weighs the same. The number of dissonances is a quick measure of how
+/// tense a chord is.
+///
+/// # Arguments
+/// * `notes` - The notes sounding together
+///
+/// # Returns
+/// The number of pairs of notes in each consonance class
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let report = analyze_intervals(dominant_seventh(G3).unwrap().notes());
+/// assert_eq!(report.dissonant, 2);
+/// assert_eq!(report.total(), 6);
+/// ```
+pub fn analyze_intervals(notes: &[Note]) -> IntervalReport {
+    let mut report = IntervalReport::default();
+    for (i, &low) in notes.iter().enumerate() {
+        for &high in &notes[i + 1..] {
+            match Interval::from_pitches(low, high).consonance() {
+                Consonance::Perfect => report.perfect += 1,
+                Consonance::Imperfect => report.imperfect += 1,
+                Consonance::Dissonant => report.dissonant += 1,
+            }
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_relative_ordering() {
+        let major = consonance_score(major_triad(C4).unwrap().notes());
+        let minor = consonance_score(minor_triad(C4).unwrap().notes());
+        let dominant = consonance_score(dominant_seventh(C4).unwrap().notes());
+        let diminished = consonance_score(diminished_seventh(C4).unwrap().notes());
+        let cluster = consonance_score(&[C4, CSHARP4, D4, DSHARP4]);
+
+        assert!(major > minor);
+        assert!(minor > dominant);
+        assert!(dominant > diminished);
+        assert!(diminished > cluster);
+    }
+
+    #[test]
+    fn test_octave_doublings() {
+        let triad = consonance_score(&[C4, E4, G4]);
+        assert_eq!(consonance_score(&[C4, E4, G4, C5]), triad);
+        assert_eq!(consonance_score(&[C3, C4, E4, G4, G5]), triad);
+    }
+
+    #[test]
+    fn test_analyze_intervals() {
+        assert_eq!(
+            analyze_intervals(major_triad(C4).unwrap().notes()),
+            IntervalReport {
+                perfect: 1,
+                imperfect: 2,
+                dissonant: 0
+            }
+        );
+        assert_eq!(analyze_intervals(&[C4, CSHARP4, D4]).dissonant, 3);
+        assert_eq!(analyze_intervals(&[C3, C4, G4]).perfect, 3);
+        assert_eq!(analyze_intervals(&[C4]).total(), 0);
+    }
+
+    #[test]
+    fn test_single_note() {
+        assert_eq!(consonance_score(&[C4]), 1.0);
+        assert_eq!(consonance_score(&[]), 1.0);
+    }
+
+    #[test]
+    fn test_tension() {
+        assert!(major_triad(C4).unwrap().tension() < diminished_triad(C4).unwrap().tension());
+        assert_eq!(
+            major_triad(C4).unwrap().tension(),
+            1.0 - consonance_score(&[C4, E4, G4])
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use crate::constants::*;
+use crate::{Error, Interval, Note, Voicing};
+
+/// Returns the notes obtained by stacking the same interval again and again
+///
+/// Stacking perfect fourths gives quartal harmony, minor thirds the diminished
+/// cycle and major seconds the whole-tone scale. The notes keep going up, so a
+/// cycle that returns to its starting pitch class, see `cycle_length`, carries
+/// on an octave higher.
+///
+/// # Arguments
+/// * `start` - The first note of the cycle
+/// * `interval` - The interval between consecutive notes
+/// * `count` - The number of notes, including the first one
+///
+/// # Returns
+/// The notes from the lowest to the highest, or `Error::CycleOutOfRange` with the
+/// number of notes that fit if the cycle would go above MIDI 127
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let fourths = interval_cycle(C3, PERFECT_FOURTH, 5).unwrap();
+/// assert_eq!(fourths, vec![C3, F3, ASHARP3, DSHARP4, GSHARP4]);
+///
+/// assert_eq!(
+///     interval_cycle(C9, MAJOR_THIRD, 4),
+///     Err(Error::CycleOutOfRange { requested: 4, fits: 2 })
+/// );
+/// ```
+pub fn interval_cycle(start: Note, interval: Interval, count: usize) -> Result<Vec<Note>, Error> {
+    let step = i16::from(interval.semitones());
+    let mut notes = Vec::with_capacity(count);
+    let mut next = Some(start);
+
+    while notes.len() < count {
+        let Some(note) = next else {
+            return Err(Error::CycleOutOfRange {
+                requested: count,
+                fits: notes.len(),
+            });
+        };
+        notes.push(note);
+        next = note.checked_add_semitones(step);
+    }
+
+    Ok(notes)
+}
+
+/// Returns a chord built by stacking the same interval above a root
+///
+/// Such chords are symmetric: the diminished seventh stacks minor thirds, the
+/// augmented triad major thirds and the quartal chords perfect fourths. As most
+/// of them have no chord quality, the chord is returned as a voicing.
+///
+/// # Arguments
+/// * `root` - The lowest note of the chord
+/// * `interval` - The interval between consecutive notes
+/// * `size` - The number of notes of the chord
+///
+/// # Returns
+/// The voicing, or `Error::CycleOutOfRange` if the chord would go above MIDI 127
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let chord = symmetric_chord(B3, MINOR_THIRD, 4).unwrap();
+/// assert_eq!(chord, Voicing::from(&diminished_seventh(B3).unwrap()));
+/// ```
+pub fn symmetric_chord(root: Note, interval: Interval, size: usize) -> Result<Voicing, Error> {
+    interval_cycle(root, interval, size).map(Voicing::new)
+}
+
+/// Returns the number of distinct pitch classes of an interval cycle
+///
+/// This is the number of notes after which the cycle comes back to its starting
+/// pitch class: 12 for the cycle of fourths, 3 for major thirds and 2 for the
+/// tritone. Octaves and unisons never leave the starting pitch class.
+///
+/// # Arguments
+/// * `interval` - The interval between consecutive notes
+///
+/// # Returns
+/// The length of the cycle, from 1 to 12
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(cycle_length(PERFECT_FOURTH), 12);
+/// assert_eq!(cycle_length(MAJOR_THIRD), 3);
+/// assert_eq!(cycle_length(AUGMENTED_FOURTH), 2);
+/// ```
+pub fn cycle_length(interval: Interval) -> u8 {
+    let mut a = SEMITONES_IN_OCTAVE;
+    let mut b = interval.semitones() % SEMITONES_IN_OCTAVE;
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    SEMITONES_IN_OCTAVE / a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    /// Returns the number of distinct pitch classes among some notes
+    fn distinct_pitch_classes(notes: &[Note]) -> usize {
+        notes.unique_pitch_classes().len()
+    }
+
+    #[test]
+    fn test_minor_third_cycle() {
+        let cycle = interval_cycle(C4, MINOR_THIRD, 5).unwrap();
+        assert_eq!(cycle, vec![C4, DSHARP4, FSHARP4, A4, C5]);
+        // The fifth note is back on C
+        assert_eq!(distinct_pitch_classes(&cycle[..4]), 4);
+        assert_eq!(distinct_pitch_classes(&cycle), 4);
+        assert_eq!(cycle_length(MINOR_THIRD), 4);
+    }
+
+    #[test]
+    fn test_whole_tone_cycle() {
+        let cycle = interval_cycle(C4, MAJOR_SECOND, 12).unwrap();
+        assert_eq!(distinct_pitch_classes(&cycle), 6);
+        assert_eq!(cycle_length(MAJOR_SECOND), 6);
+    }
+
+    #[test]
+    fn test_quartal_stack() {
+        assert_eq!(
+            symmetric_chord(C3, PERFECT_FOURTH, 5).unwrap().notes(),
+            &[C3, F3, ASHARP3, DSHARP4, GSHARP4]
+        );
+        assert_eq!(
+            symmetric_chord(C4, MAJOR_THIRD, 3).unwrap(),
+            Voicing::from(&augmented_triad(C4).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_cycle_out_of_range() {
+        assert_eq!(
+            interval_cycle(C9, PERFECT_FOURTH, 4),
+            Err(Error::CycleOutOfRange {
+                requested: 4,
+                fits: 2
+            })
+        );
+        assert_eq!(interval_cycle(G9, PERFECT_OCTAVE, 1), Ok(vec![G9]));
+        assert_eq!(interval_cycle(C4, PERFECT_FIFTH, 0), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_cycle_length_matches_pitch_classes() {
+        let lowest = Note::new(0);
+        for semitones in 0..=SEMITONES_IN_OCTAVE {
+            let interval = Interval::new(semitones);
+            let length = cycle_length(interval);
+            let cycle = interval_cycle(lowest, interval, usize::from(length)).unwrap();
+
+            // The notes before the cycle closes have different pitch classes
+            assert_eq!(distinct_pitch_classes(&cycle), cycle.len(), "{semitones}");
+            // and the next one is back on the starting pitch class
+            assert_eq!((semitones * length) % SEMITONES_IN_OCTAVE, 0, "{semitones}");
+        }
+    }
+}
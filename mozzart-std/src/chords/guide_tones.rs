@@ -0,0 +1,216 @@
+use crate::constants::*;
+use crate::{pitch_range, Chord, Note, PitchClassMask};
+use std::ops::RangeInclusive;
+
+impl<const N: usize> Chord<N> {
+    /// Returns the guide tones of the chord, the notes that define its quality
+    ///
+    /// The guide tones are the third and the seventh, or the third and the sixth
+    /// for sixth chords. Triads only have their third, and in suspended chords
+    /// the suspended second or fourth takes the place of the third.
+    ///
+    /// # Returns
+    /// The guide tones, in the octave the chord is built in
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(dominant_seventh(G3).unwrap().guide_tones(), vec![B3, F4]);
+    /// assert_eq!(major_sixth(C4).unwrap().guide_tones(), vec![E4, A4]);
+    /// assert_eq!(minor_triad(A3).unwrap().guide_tones(), vec![C4]);
+    /// ```
+    pub fn guide_tones(&self) -> Vec<Note> {
+        // The intervals of every quality start with the third, the fifth and then the seventh or sixth
+        [1, 3]
+            .into_iter()
+            .filter_map(|index| self.notes().get(index).copied())
+            .collect()
+    }
+
+    /// Returns the color tones of the chord, its ninth, eleventh and thirteenth
+    ///
+    /// # Returns
+    /// The extensions above the octave, from the lowest to the highest
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(dominant_thirteenth(C4).unwrap().color_tones(), vec![D5, F5, A5]);
+    /// assert!(dominant_seventh(C4).unwrap().color_tones().is_empty());
+    /// ```
+    pub fn color_tones(&self) -> Vec<Note> {
+        let root = self.root().midi_number();
+        self.notes()
+            .iter()
+            .filter(|note| note.midi_number() - root > SEMITONES_IN_OCTAVE)
+            .copied()
+            .collect()
+    }
+
+    /// Returns every note of a range that belongs to the chord, in any octave
+    ///
+    /// # Arguments
+    /// * `range` - The lowest and highest notes that may be played
+    ///
+    /// # Returns
+    /// The notes of the range whose pitch class is in the chord, from the lowest to the highest
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(major_triad(C4).unwrap().chord_tones_in_range(G3..=E4), vec![G3, C4, E4]);
+    /// ```
+    pub fn chord_tones_in_range(&self, range: RangeInclusive<Note>) -> Vec<Note> {
+        tones_in_range(PitchClassMask::from_notes(self.notes()), range).collect()
+    }
+
+    /// Writes every note of a range that belongs to the chord into a buffer
+    ///
+    /// This is `chord_tones_in_range` without allocating once the buffer is
+    /// large enough, for callers that reuse the buffer.
+    ///
+    /// # Arguments
+    /// * `range` - The lowest and highest notes that may be played
+    /// * `out` - The buffer, cleared then filled with the notes from the lowest to the highest
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let mut tones = Vec::with_capacity(16);
+    /// major_triad(C4).unwrap().chord_tones_in_range_into(G3..=E4, &mut tones);
+    /// assert_eq!(tones, vec![G3, C4, E4]);
+    /// ```
+    pub fn chord_tones_in_range_into(&self, range: RangeInclusive<Note>, out: &mut Vec<Note>) {
+        out.clear();
+        out.extend(tones_in_range(
+            PitchClassMask::from_notes(self.notes()),
+            range,
+        ));
+    }
+}
+
+/// Returns a guide-tone line through a progression
+///
+/// For each chord, the guide tone closest to the previous note of the line is
+/// picked, in whichever octave, so the line moves as little as possible. This is
+/// the classic exercise of voice leading the thirds and sevenths of a ii-V-I.
+/// When two guide tones are equally close, the lower one is picked.
+///
+/// # Arguments
+/// * `progression` - The chords, in playing order
+/// * `start` - The note the line starts from; the first guide tone is the one closest to it
+///
+/// # Returns
+/// One guide tone per chord
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let ii_v_i = [minor_seventh(D4).unwrap(), dominant_seventh(G3).unwrap(), major_seventh(C4).unwrap()];
+/// assert_eq!(guide_tone_line(&ii_v_i, C5), vec![C5, B4, B4]);
+/// assert_eq!(guide_tone_line(&ii_v_i, F4), vec![F4, F4, E4]);
+/// ```
+pub fn guide_tone_line<const N: usize>(progression: &[Chord<N>], start: Note) -> Vec<Note> {
+    let mut previous = start;
+    let mut line = Vec::with_capacity(progression.len());
+
+    for chord in progression {
+        let guide_tones = PitchClassMask::from_notes(&chord.guide_tones());
+        let low = Note::new(previous.midi_number().saturating_sub(SEMITONES_IN_OCTAVE));
+        let high = Note::new((previous.midi_number() + SEMITONES_IN_OCTAVE).min(G9.midi_number()));
+
+        let closest = tones_in_range(guide_tones, low..=high)
+            .min_by_key(|note| note.midi_number().abs_diff(previous.midi_number()));
+        if let Some(note) = closest {
+            line.push(note);
+            previous = note;
+        }
+    }
+
+    line
+}
+
+/// Returns the notes of a range whose pitch class is in the set
+fn tones_in_range(
+    pitch_classes: PitchClassMask,
+    range: RangeInclusive<Note>,
+) -> impl Iterator<Item = Note> {
+    pitch_range(*range.start(), *range.end()).filter(move |&note| pitch_classes.contains(note))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_guide_tones() {
+        assert_eq!(minor_seventh(D4).unwrap().guide_tones(), vec![F4, C5]);
+        assert_eq!(dominant_seventh(G3).unwrap().guide_tones(), vec![B3, F4]);
+        assert_eq!(major_seventh(C4).unwrap().guide_tones(), vec![E4, B4]);
+        assert_eq!(minor_sixth(A3).unwrap().guide_tones(), vec![C4, FSHARP4]);
+        assert_eq!(dominant_ninth(C4).unwrap().guide_tones(), vec![E4, ASHARP4]);
+        assert_eq!(sus4(C4).unwrap().guide_tones(), vec![F4]);
+        assert_eq!(major_triad(C4).unwrap().guide_tones(), vec![E4]);
+    }
+
+    #[test]
+    fn test_color_tones() {
+        assert_eq!(major_ninth(C4).unwrap().color_tones(), vec![D5]);
+        assert_eq!(minor_eleventh(A3).unwrap().color_tones(), vec![B4, D5]);
+        assert_eq!(major_sixth_ninth(F3).unwrap().color_tones(), vec![G4]);
+        assert!(major_triad(C4).unwrap().color_tones().is_empty());
+    }
+
+    #[test]
+    fn test_chord_tones_in_range() {
+        let g7 = dominant_seventh(G3).unwrap();
+        assert_eq!(g7.chord_tones_in_range(C4..=C5), vec![D4, F4, G4, B4]);
+        assert!(g7.chord_tones_in_range(C4..=CSHARP4).is_empty());
+
+        let mut tones = vec![C0; 8];
+        g7.chord_tones_in_range_into(C4..=C5, &mut tones);
+        assert_eq!(tones, g7.chord_tones_in_range(C4..=C5));
+        g7.chord_tones_in_range_into(C4..=CSHARP4, &mut tones);
+        assert!(tones.is_empty());
+    }
+
+    #[test]
+    fn test_ii_v_i_guide_tone_lines() {
+        let ii_v_i = [
+            minor_seventh(D4).unwrap(),
+            dominant_seventh(G3).unwrap(),
+            major_seventh(C4).unwrap(),
+        ];
+
+        // Starting on the seventh of Dm7: C -> B -> B
+        assert_eq!(guide_tone_line(&ii_v_i, C5), vec![C5, B4, B4]);
+        // Starting on the third of Dm7: F -> F -> E
+        assert_eq!(guide_tone_line(&ii_v_i, F4), vec![F4, F4, E4]);
+
+        // Each line only ever holds or falls by a half step
+        for start in [C5, F4] {
+            let line = guide_tone_line(&ii_v_i, start);
+            assert!(line
+                .windows(2)
+                .all(|pair| matches!(pair[0].midi_number() - pair[1].midi_number(), 0 | 1)));
+        }
+
+        assert!(guide_tone_line::<4>(&[], C4).is_empty());
+    }
+}
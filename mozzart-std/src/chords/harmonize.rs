@@ -0,0 +1,333 @@
+use crate::constants::*;
+use crate::{
+    validate_satb, Chord, ChordQuality, Error, Note, PitchClassMask, Scale, ScaleLike,
+    ScaleQuality, Voice,
+};
+use std::ops::RangeInclusive;
+
+/// The number of chords the search may try before giving up
+const MAX_ATTEMPTS: usize = 10_000;
+
+/// The degrees tried for each melody note, from the most to the least common harmony
+const DEGREE_PREFERENCE: [usize; 7] = [1, 5, 4, 6, 2, 3, 7];
+
+/// Represents the preferences of `harmonize_melody` when choosing chords
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum HarmonizationStyle {
+    /// Every chord has its root in the bass
+    RootPosition,
+    /// Chords may have their third in the bass, root position being preferred
+    FirstInversions,
+    /// As `FirstInversions`, but chords sharing tones with the previous one come first
+    CommonTones,
+}
+
+impl HarmonizationStyle {
+    /// Returns `true` if the third of a chord may be in the bass
+    const fn allows_first_inversions(&self) -> bool {
+        !matches!(self, HarmonizationStyle::RootPosition)
+    }
+}
+
+/// Harmonizes a melody into a four-part chorale
+///
+/// The melody is the soprano. For each of its notes, a diatonic triad of the key
+/// containing the note is chosen, the tonic being preferred at the start and the
+/// end, and the alto, tenor and bass are voiced within their ranges with as
+/// little movement as possible. Every choice is checked with `validate_satb`; when
+/// no chord fits a note, the search backtracks over the previous choices.
+///
+/// Violations that only involve the soprano, such as a leading tone of the
+/// melody that doesn't resolve to the tonic, belong to the melody and are accepted.
+///
+/// # Arguments
+/// * `melody` - The notes of the soprano
+/// * `key` - The key giving the diatonic triads
+/// * `style` - The preferences when choosing and voicing the chords
+///
+/// # Returns
+/// The chords, each from the soprano down to the bass, or an error if a melody
+/// note is outside of the key or no harmonization is found within the allowed attempts
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let c_major = major_scale(C4).unwrap();
+/// let chorale =
+///     harmonize_melody(&[E4, D4, C4], &c_major, HarmonizationStyle::RootPosition).unwrap();
+/// assert_eq!(chorale.len(), 3);
+/// assert!(validate_satb(&chorale, &c_major).is_empty());
+/// ```
+pub fn harmonize_melody<Q>(
+    melody: &[Note],
+    key: &Scale<Q, 8>,
+    style: HarmonizationStyle,
+) -> Result<Vec<[Note; 4]>, Error>
+where
+    Q: ScaleQuality,
+{
+    if let Some(&note) = melody.iter().find(|&&note| !key.contains_pitch_class(note)) {
+        return Err(Error::NoteOutsideKey(note));
+    }
+
+    let triads = DEGREE_PREFERENCE
+        .iter()
+        .filter_map(|&degree| key.triad_on(degree).map(|triad| (degree, triad)))
+        .collect();
+    let mut search = Search {
+        melody,
+        key,
+        style,
+        triads,
+        attempts: 0,
+        chorale: Vec::with_capacity(melody.len()),
+        harmonies: Vec::with_capacity(melody.len()),
+    };
+
+    if search.extend() {
+        Ok(search.chorale)
+    } else {
+        Err(Error::HarmonizationNotFound {
+            attempts: search.attempts,
+        })
+    }
+}
+
+/// The state of the backtracking search of `harmonize_melody`
+struct Search<'a, Q: ScaleQuality> {
+    melody: &'a [Note],
+    key: &'a Scale<Q, 8>,
+    style: HarmonizationStyle,
+    triads: Vec<(usize, Chord<3>)>,
+    attempts: usize,
+    chorale: Vec<[Note; 4]>,
+    harmonies: Vec<PitchClassMask>,
+}
+
+impl<Q: ScaleQuality> Search<'_, Q> {
+    /// Harmonizes the next melody note, and the following ones, backtracking on failure
+    fn extend(&mut self) -> bool {
+        let index = self.chorale.len();
+        if index == self.melody.len() {
+            return true;
+        }
+
+        for (harmony, chord) in self.candidates(index) {
+            self.attempts += 1;
+            if self.attempts > MAX_ATTEMPTS {
+                return false;
+            }
+
+            self.chorale.push(chord);
+            self.harmonies.push(harmony);
+            if self.extend() {
+                return true;
+            }
+            self.chorale.pop();
+            self.harmonies.pop();
+        }
+
+        false
+    }
+
+    /// Returns the chords that may harmonize a melody note, the best first
+    fn candidates(&self, index: usize) -> Vec<(PitchClassMask, [Note; 4])> {
+        let soprano = self.melody[index];
+        let previous = self.chorale.last();
+        let mut candidates = Vec::new();
+
+        for (rank, (degree, triad)) in self.triads.iter().enumerate() {
+            let harmony = PitchClassMask::from_notes(triad.notes());
+            if !harmony.contains(soprano) {
+                continue;
+            }
+
+            let penalty = self.chord_penalty(index, rank, *degree, harmony);
+            for chord in voicings(soprano, triad, self.style) {
+                if !self.fits(&chord) {
+                    continue;
+                }
+                let cost = penalty + voicing_penalty(triad, &chord, previous);
+                candidates.push((cost, harmony, chord));
+            }
+        }
+
+        candidates.sort_by_key(|(cost, ..)| *cost);
+        candidates
+            .into_iter()
+            .map(|(_, harmony, chord)| (harmony, chord))
+            .collect()
+    }
+
+    /// Returns how much less a triad is preferred for a melody note
+    fn chord_penalty(
+        &self,
+        index: usize,
+        rank: usize,
+        degree: usize,
+        harmony: PitchClassMask,
+    ) -> u32 {
+        let mut penalty = 2 * rank as u32;
+
+        let is_boundary = index == 0 || index == self.melody.len() - 1;
+        if is_boundary && degree != 1 {
+            penalty += 24;
+        }
+
+        if self.style == HarmonizationStyle::CommonTones {
+            if let Some(previous) = self.harmonies.last() {
+                let common = (previous.bits() & harmony.bits()).count_ones();
+                penalty += 4 * (3 - common.min(3));
+            }
+        }
+
+        penalty
+    }
+
+    /// Returns `true` if a chord breaks no part-writing rule after the previous one
+    fn fits(&self, chord: &[Note; 4]) -> bool {
+        let chords: Vec<_> = self
+            .chorale
+            .last()
+            .into_iter()
+            .chain([chord])
+            .copied()
+            .collect();
+        validate_satb(&chords, self.key)
+            .iter()
+            .all(|violation| violation.voices == [Voice::Soprano])
+    }
+}
+
+/// Returns every complete voicing of a triad under a soprano note
+fn voicings(soprano: Note, triad: &Chord<3>, style: HarmonizationStyle) -> Vec<[Note; 4]> {
+    let harmony = PitchClassMask::from_notes(triad.notes());
+    let [root, third, _] = *triad.notes();
+    // A diminished triad is only used in first inversion
+    let root_position = triad.quality() != ChordQuality::DiminishedTriad;
+    let is_bass = |note: Note| {
+        (root_position && same_pitch_class(note, root))
+            || (style.allows_first_inversions() && same_pitch_class(note, third))
+    };
+
+    let mut voicings = Vec::new();
+    for bass in notes_in(BASS_RANGE, is_bass) {
+        for tenor in notes_in(TENOR_RANGE, |note| harmony.contains(note)) {
+            for alto in notes_in(ALTO_RANGE, |note| harmony.contains(note)) {
+                let chord = [soprano, alto, tenor, bass];
+                let ordered = soprano >= alto && alto >= tenor && tenor >= bass;
+                let spaced = alto.distance_to(soprano) <= SEMITONES_IN_OCTAVE
+                    && tenor.distance_to(alto) <= SEMITONES_IN_OCTAVE;
+                if ordered && spaced && PitchClassMask::from_notes(&chord) == harmony {
+                    voicings.push(chord);
+                }
+            }
+        }
+    }
+
+    voicings
+}
+
+/// Returns how much less a voicing is preferred, mostly from the movement of the lower voices
+fn voicing_penalty(triad: &Chord<3>, chord: &[Note; 4], previous: Option<&[Note; 4]>) -> u32 {
+    let movement: u32 = previous.map_or(0, |previous| {
+        previous[1..]
+            .iter()
+            .zip(&chord[1..])
+            .map(|(&from, &to)| u32::from(from.distance_to(to)))
+            .sum()
+    });
+
+    let root = triad.root();
+    let inverted = !same_pitch_class(chord[3], root);
+    let doubled_root = chord
+        .iter()
+        .filter(|&&note| same_pitch_class(note, root))
+        .count()
+        == 2;
+
+    movement + 3 * u32::from(inverted) + 2 * u32::from(!doubled_root)
+}
+
+/// Returns the notes of a range that satisfy a predicate, from the lowest
+fn notes_in(range: RangeInclusive<Note>, predicate: impl Fn(Note) -> bool) -> Vec<Note> {
+    (range.start().midi_number()..=range.end().midi_number())
+        .map(Note::new)
+        .filter(|&note| predicate(note))
+        .collect()
+}
+
+/// Returns `true` if both notes have the same pitch class
+fn same_pitch_class(a: Note, b: Note) -> bool {
+    a.pitch_class() == b.pitch_class()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    /// A simple eight-note melody in C major
+    const MELODY: [Note; 8] = [E4, F4, G4, E4, D4, F4, D4, C4];
+
+    #[test]
+    fn test_harmonize_without_violations() {
+        let c_major = major_scale(C4).unwrap();
+        for style in [
+            HarmonizationStyle::RootPosition,
+            HarmonizationStyle::FirstInversions,
+            HarmonizationStyle::CommonTones,
+        ] {
+            let chorale = harmonize_melody(&MELODY, &c_major, style).unwrap();
+            assert_eq!(chorale.len(), MELODY.len());
+            assert_eq!(validate_satb(&chorale, &c_major), vec![], "{style:?}");
+
+            let soprano: Vec<_> = chorale.iter().map(|chord| chord[0]).collect();
+            assert_eq!(soprano, MELODY);
+        }
+    }
+
+    #[test]
+    fn test_root_position() {
+        let c_major = major_scale(C4).unwrap();
+        let chorale =
+            harmonize_melody(&MELODY, &c_major, HarmonizationStyle::RootPosition).unwrap();
+
+        // Every bass is the root of a diatonic triad other than the diminished one
+        for chord in &chorale {
+            let triad = (1..=6)
+                .filter_map(|degree| c_major.triad_on(degree))
+                .find(|triad| same_pitch_class(triad.root(), chord[3]))
+                .unwrap();
+            let harmony = PitchClassMask::from_notes(triad.notes());
+            assert_eq!(PitchClassMask::from_notes(chord), harmony);
+        }
+
+        // The phrase starts and ends on the tonic
+        assert!(same_pitch_class(chorale[0][3], C4));
+        assert!(same_pitch_class(chorale[7][3], C4));
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let c_major = major_scale(C4).unwrap();
+        let style = HarmonizationStyle::CommonTones;
+        assert_eq!(
+            harmonize_melody(&MELODY, &c_major, style),
+            harmonize_melody(&MELODY, &c_major, style)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let c_major = major_scale(C4).unwrap();
+        let style = HarmonizationStyle::FirstInversions;
+        assert_eq!(
+            harmonize_melody(&[C4, FSHARP4, G4], &c_major, style),
+            Err(Error::NoteOutsideKey(FSHARP4))
+        );
+        assert_eq!(harmonize_melody(&[], &c_major, style), Ok(vec![]));
+    }
+}
@@ -1,3 +1,32 @@
+mod builder;
 mod chord;
+mod consonance;
+mod cycles;
+mod guide_tones;
+mod harmonize;
+mod nashville;
+mod negative_harmony;
+mod progression;
+mod progression_model;
+mod roman;
+mod satb;
+mod substitution;
+mod symbol;
+mod tensions;
+mod voicing;
 
+pub use builder::*;
 pub use chord::*;
+pub use consonance::*;
+pub use cycles::*;
+pub use guide_tones::*;
+pub use harmonize::*;
+pub use nashville::*;
+pub use negative_harmony::*;
+pub use progression::*;
+pub use progression_model::*;
+pub use roman::*;
+pub use satb::*;
+pub use substitution::*;
+pub use tensions::*;
+pub use voicing::*;
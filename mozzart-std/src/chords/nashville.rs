@@ -0,0 +1,236 @@
+use super::roman::split_tokens;
+use crate::constants::*;
+use crate::{Chord, Error, Interval, Note, Scale, ScaleQuality, Voicing};
+
+/// The characters separating the numbers of a chart, besides whitespace
+const SEPARATORS: [char; 1] = ['|'];
+
+/// Parses a chord chart written in the Nashville Number System and builds its chords in a key
+///
+/// Each number is a degree of the key, from 1 to 7, and plays the chord the
+/// key builds on that degree, see `Scale::triad_on` and `Scale::seventh_on`.
+/// A suffix chooses the chord instead:
+/// - none: the triad of the key on the degree, such as the minor triad on 2 in a major key
+/// - `7`: the seventh chord of the key on the degree, such as the dominant seventh on 5
+/// - `m` or `-`: a minor triad
+/// - `m7` or `-7`: a minor seventh chord
+/// - `maj7`: a major seventh chord
+///
+/// A leading `b` or `#` lowers or raises the root by a semitone, out of the key,
+/// so the chord is then a major triad, or a dominant seventh chord with `7`.
+/// The numbers are separated by whitespace or bar lines (`|`).
+///
+/// # Arguments
+/// * `key` - The scale providing the root of each degree
+/// * `input` - The numbers of the chart, in playing order
+///
+/// # Returns
+/// The chords in close position from their root, in playing order,
+/// `Error::InvalidNashvilleNumber` with the first malformed number and the
+/// index of its first character, or `Error::NoteOutOfRange` if a chord would
+/// be above the MIDI range
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let chords = nashville_to_chords(&major_scale(C4).unwrap(), "1 6m | 4 57").unwrap();
+/// assert_eq!(chords[0].notes(), major_triad(C4).unwrap().notes());
+/// assert_eq!(chords[1].notes(), minor_triad(A4).unwrap().notes());
+/// assert_eq!(chords[2].notes(), major_triad(F4).unwrap().notes());
+/// assert_eq!(chords[3].notes(), dominant_seventh(G4).unwrap().notes());
+///
+/// let error = nashville_to_chords(&major_scale(C4).unwrap(), "1 8").unwrap_err();
+/// assert_eq!(error, Error::InvalidNashvilleNumber { number: "8".to_string(), position: 2 });
+/// ```
+pub fn nashville_to_chords<Q: ScaleQuality>(
+    key: &Scale<Q, 8>,
+    input: &str,
+) -> Result<Vec<Voicing>, Error> {
+    split_tokens(input, &SEPARATORS)
+        .into_iter()
+        .map(|(position, token)| {
+            let invalid = || Error::InvalidNashvilleNumber {
+                number: token.to_string(),
+                position,
+            };
+            let number = parse_number(token).ok_or_else(invalid)?;
+            number.voicing_in(key)
+        })
+        .collect()
+}
+
+/// Represents the chord asked for by the suffix of a Nashville number
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum NashvilleChord {
+    /// The triad of the key on the degree
+    Triad,
+    /// The seventh chord of the key on the degree
+    Seventh,
+    /// A minor triad
+    Minor,
+    /// A minor seventh chord
+    MinorSeventh,
+    /// A major seventh chord
+    MajorSeventh,
+}
+
+/// Represents a parsed Nashville number, such as `b7` or `6m`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct NashvilleNumber {
+    accidental: i16,
+    degree: usize,
+    chord: NashvilleChord,
+}
+
+impl NashvilleNumber {
+    /// Returns the chord of the number in a key
+    fn voicing_in<Q: ScaleQuality>(&self, key: &Scale<Q, 8>) -> Result<Voicing, Error> {
+        let natural = key.notes()[self.degree - 1];
+        let root = natural
+            .checked_add_semitones(self.accidental)
+            .ok_or(Error::NoteOutOfRange(natural))?;
+        let out_of_range = || Error::NoteOutOfRange(root);
+
+        let diatonic = self.accidental == 0;
+        let voicing = match self.chord {
+            NashvilleChord::Triad if diatonic => {
+                key.triad_on(self.degree).map(|chord| Voicing::from(&chord))
+            }
+            NashvilleChord::Seventh if diatonic => key
+                .seventh_on(self.degree)
+                .map(|chord| Voicing::from(&chord)),
+            NashvilleChord::Triad => chord_on::<3>(root, &MAJOR_TRIAD_INTERVALS),
+            NashvilleChord::Seventh => chord_on::<4>(root, &DOMINANT_SEVENTH_INTERVALS),
+            NashvilleChord::Minor => chord_on::<3>(root, &MINOR_TRIAD_INTERVALS),
+            NashvilleChord::MinorSeventh => chord_on::<4>(root, &MINOR_SEVENTH_INTERVALS),
+            NashvilleChord::MajorSeventh => chord_on::<4>(root, &MAJOR_SEVENTH_INTERVALS),
+        };
+        voicing.ok_or_else(out_of_range)
+    }
+}
+
+/// Returns the voicing of the chord stacking intervals above a root, if it fits in the MIDI range
+fn chord_on<const N: usize>(root: Note, intervals: &[Interval]) -> Option<Voicing> {
+    Chord::<N>::from_intervals(root, intervals).map(|chord| Voicing::from(&chord))
+}
+
+/// Parses a Nashville number, returning `None` if it is malformed
+fn parse_number(token: &str) -> Option<NashvilleNumber> {
+    let (accidental, rest) = match token.strip_prefix('b') {
+        Some(rest) => (-1, rest),
+        None => match token.strip_prefix('#') {
+            Some(rest) => (1, rest),
+            None => (0, token),
+        },
+    };
+
+    let mut chars = rest.chars();
+    let degree = chars.next()?.to_digit(10)? as usize;
+    if !(1..=7).contains(&degree) {
+        return None;
+    }
+    let chord = match chars.as_str() {
+        "" => NashvilleChord::Triad,
+        "7" => NashvilleChord::Seventh,
+        "m" | "-" => NashvilleChord::Minor,
+        "m7" | "-7" => NashvilleChord::MinorSeventh,
+        "maj7" => NashvilleChord::MajorSeventh,
+        _ => return None,
+    };
+
+    Some(NashvilleNumber {
+        accidental,
+        degree,
+        chord,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dominant_seventh, half_diminished_seventh, harmonic_minor_scale, major_scale,
+        major_seventh, major_triad, minor_seventh, minor_triad,
+    };
+
+    fn notes(voicings: &[Voicing]) -> Vec<Vec<Note>> {
+        voicings
+            .iter()
+            .map(|voicing| voicing.notes().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_one_four_five_one() {
+        let chords = nashville_to_chords(&major_scale(C4).unwrap(), "1 4 5 1").unwrap();
+        let expected = [
+            major_triad(C4).unwrap(),
+            major_triad(F4).unwrap(),
+            major_triad(G4).unwrap(),
+            major_triad(C4).unwrap(),
+        ];
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|chord| chord.notes().to_vec())
+            .collect();
+        assert_eq!(notes(&chords), expected);
+    }
+
+    #[test]
+    fn test_markers() {
+        let key = major_scale(G3).unwrap();
+        let chords =
+            nashville_to_chords(&key, "1maj7 | 6m7 | 2-7 | 57 | 7 | 1m | b7 | #4-").unwrap();
+        assert_eq!(chords[0].notes(), major_seventh(G3).unwrap().notes());
+        assert_eq!(chords[1].notes(), minor_seventh(E4).unwrap().notes());
+        assert_eq!(chords[2].notes(), minor_seventh(A3).unwrap().notes());
+        assert_eq!(chords[3].notes(), dominant_seventh(D4).unwrap().notes());
+        // The triad of the key on the seventh degree is diminished
+        assert_eq!(chords[4].notes(), &[FSHARP4, A4, C5]);
+        assert_eq!(chords[5].notes(), minor_triad(G3).unwrap().notes());
+        assert_eq!(chords[6].notes(), major_triad(F4).unwrap().notes());
+        assert_eq!(chords[7].notes(), minor_triad(CSHARP4).unwrap().notes());
+
+        // Without a suffix the chords follow the key
+        let chords = nashville_to_chords(&harmonic_minor_scale(A3).unwrap(), "1 27 5").unwrap();
+        assert_eq!(chords[0].notes(), minor_triad(A3).unwrap().notes());
+        assert_eq!(
+            chords[1].notes(),
+            half_diminished_seventh(B3).unwrap().notes()
+        );
+        assert_eq!(chords[2].notes(), major_triad(E4).unwrap().notes());
+        assert!(nashville_to_chords(&major_scale(C4).unwrap(), "")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_errors() {
+        let key = major_scale(C4).unwrap();
+        for (input, number, position) in [
+            ("1 4 0", "0", 4),
+            ("1 | 9", "9", 4),
+            ("1 IV", "IV", 2),
+            ("1 5sus", "5sus", 2),
+            ("bb3", "bb3", 0),
+            ("1 -", "-", 2),
+        ] {
+            assert_eq!(
+                nashville_to_chords(&key, input),
+                Err(Error::InvalidNashvilleNumber {
+                    number: number.to_string(),
+                    position
+                }),
+                "{input}"
+            );
+        }
+
+        let high = major_scale(C9.checked_add_semitones(-5).unwrap()).unwrap();
+        assert!(matches!(
+            nashville_to_chords(&high, "1 5m"),
+            Err(Error::NoteOutOfRange(_))
+        ));
+    }
+}
@@ -0,0 +1,339 @@
+use crate::constants::*;
+use crate::{Chord, Error, Interval, Melody, Note, Progression, Scale, ScaleQuality, Voicing};
+
+/// Returns a note reflected around the negative-harmony axis of a key
+///
+/// The axis lies midway between the tonic and the dominant, so in C the tonic
+/// is exchanged with the dominant, E with Eb and B with Ab. The reflection
+/// gives a pitch class; the note is placed in the octave closest to the input.
+/// The reflected pitch class is always an odd number of semitones away, so the
+/// closest octave is never ambiguous: the result is at most 5 semitones above
+/// or below the input, and reflecting it again gives back the input note.
+///
+/// This is the canonical policy of the crate: every `negative_harmony` method
+/// takes the tonic of the key and reflects each note with this function.
+/// `Chord::negative` is the exception; it takes the note below the axis and
+/// mirrors the whole chord into root position.
+///
+/// # Arguments
+/// * `note` - The note to reflect
+/// * `key_center` - The tonic of the key, in any octave
+///
+/// # Returns
+/// The reflected note, or `None` if it would be outside of the MIDI range
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(negative_harmony(G4, C4), Some(C5));
+/// assert_eq!(negative_harmony(E4, C2), Some(DSHARP4));
+/// assert_eq!(negative_harmony(DSHARP4, C2), Some(E4));
+/// ```
+pub fn negative_harmony(note: Note, key_center: Note) -> Option<Note> {
+    let octave = i16::from(SEMITONES_IN_OCTAVE);
+    let tonic = i16::from(key_center.pitch_class());
+    let pitch_class = i16::from(note.pitch_class());
+    let reflected = (2 * tonic + 7 - pitch_class).rem_euclid(octave);
+
+    // The reflected pitch class is an odd distance away, in -5..=5 once wrapped
+    let mut semitones = (reflected - pitch_class).rem_euclid(octave);
+    if semitones > octave / 2 {
+        semitones -= octave;
+    }
+    note.checked_add_semitones(semitones)
+}
+
+impl<const N: usize> Chord<N> {
+    /// Returns the chord reflected around a tonal axis, as in negative harmony
+    ///
+    /// The axis lies halfway between `axis` and the semitone above it, so with
+    /// the axis of C (between Eb and E) C is exchanged with G, E with Eb and B with Ab.
+    /// The reflection turns the chord upside down: the highest note becomes the
+    /// root, and a major triad becomes a minor triad. Use `negative_harmony`,
+    /// which takes the tonic instead of the axis, to keep each note close to
+    /// where it was.
+    ///
+    /// # Arguments
+    /// * `axis` - The note just below the axis, see `Scale::negative_axis`
+    ///
+    /// # Returns
+    /// The reflected chord, or `None` if a note would be outside of the MIDI range
+    /// or the reflected notes match no chord quality
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let axis = major_scale(C4).unwrap().negative_axis();
+    /// assert_eq!(major_triad(C4).unwrap().negative(axis), Some(minor_triad(C4).unwrap()));
+    /// assert_eq!(dominant_seventh(G3).unwrap().negative(axis), Some(half_diminished_seventh(D4).unwrap()));
+    /// ```
+    pub fn negative(&self, axis: Note) -> Option<Chord<N>> {
+        let notes = self
+            .notes()
+            .iter()
+            .rev()
+            .map(|note| {
+                let semitones = i16::from(axis.midi_number()) + 1 - i16::from(note.midi_number());
+                axis.checked_add_semitones(semitones)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let (&root, rest) = notes.split_first()?;
+        let intervals: Vec<_> = rest
+            .iter()
+            .map(|note| Interval::new(note.midi_number() - root.midi_number()))
+            .collect();
+        Chord::from_intervals(root, &intervals)
+    }
+
+    /// Returns the notes of the chord, each reflected with `negative_harmony`
+    ///
+    /// Unlike `negative`, each note stays within 5 semitones of where it was,
+    /// so the result is a voicing close to the chord rather than a chord in
+    /// root position. The reflected notes seldom stack into a chord quality:
+    /// in C, G7 gives Ab, C, D and F, a D half-diminished seventh or an F minor
+    /// sixth chord.
+    ///
+    /// # Arguments
+    /// * `key_center` - The tonic of the key, in any octave
+    ///
+    /// # Returns
+    /// The reflected notes, or `None` if a note would be outside of the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let negative = major_triad(C4).unwrap().negative_harmony(C4).unwrap();
+    /// assert_eq!(negative.notes(), &[G3, DSHARP4, C5]);
+    /// ```
+    pub fn negative_harmony(&self, key_center: Note) -> Option<Voicing> {
+        self.notes()
+            .iter()
+            .map(|&note| negative_harmony(note, key_center))
+            .collect::<Option<Vec<_>>>()
+            .map(Voicing::new)
+    }
+}
+
+impl Progression {
+    /// Returns the progression with each note reflected with `negative_harmony`
+    ///
+    /// # Arguments
+    /// * `key_center` - The tonic of the key, in any octave
+    ///
+    /// # Returns
+    /// The reflected progression, or `None` if a note would be outside of the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let progression = Progression::new([major_triad(F3).unwrap(), major_triad(G3).unwrap()]);
+    /// let negative = progression.negative_harmony(C4).unwrap();
+    /// assert_eq!(negative.chords()[0].notes(), &[D3, G3, ASHARP3]);
+    /// assert_eq!(negative.chords()[1].notes(), &[GSHARP3, C4, F4]);
+    /// ```
+    pub fn negative_harmony(&self, key_center: Note) -> Option<Progression> {
+        self.chords()
+            .iter()
+            .map(|chord| {
+                chord
+                    .notes()
+                    .iter()
+                    .map(|&note| negative_harmony(note, key_center))
+                    .collect::<Option<Vec<_>>>()
+                    .map(Voicing::new)
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(Progression::new)
+    }
+}
+
+impl Melody {
+    /// Returns the melody with each note reflected with `negative_harmony`
+    ///
+    /// The durations and the ornaments are kept.
+    ///
+    /// # Arguments
+    /// * `key_center` - The tonic of the key, in any octave
+    ///
+    /// # Returns
+    /// The reflected melody, or `Error::NoteOutOfRange` if a note would be
+    /// outside of the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let melody = Melody::new([(E4, Duration::Half), (G4, Duration::Half)]);
+    /// let negative = melody.negative_harmony(C4).unwrap();
+    /// assert_eq!(negative.notes().collect::<Vec<_>>(), vec![DSHARP4, C5]);
+    /// ```
+    pub fn negative_harmony(&self, key_center: Note) -> Result<Melody, Error> {
+        self.try_map_notes(|note| {
+            negative_harmony(note, key_center).ok_or(Error::NoteOutOfRange(note))
+        })
+    }
+}
+
+impl<Q, const N: usize> Scale<Q, N>
+where
+    Q: ScaleQuality,
+{
+    /// Returns the conventional axis of negative harmony for this scale
+    ///
+    /// The axis lies halfway between the tonic and the dominant, between the
+    /// minor and the major third above the tonic.
+    ///
+    /// # Returns
+    /// The note just below the axis, the minor third above the tonic
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(major_scale(C4).unwrap().negative_axis(), DSHARP4);
+    /// ```
+    pub fn negative_axis(&self) -> Note {
+        self.root() + MINOR_THIRD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::*;
+    use crate::*;
+
+    #[test]
+    fn test_major_triad_becomes_minor() {
+        let axis = major_scale(C4).unwrap().negative_axis();
+        assert_eq!(
+            major_triad(C4).unwrap().negative(axis),
+            Some(minor_triad(C4).unwrap())
+        );
+        // The dominant becomes the minor subdominant
+        assert_eq!(
+            major_triad(G3).unwrap().negative(axis),
+            Some(minor_triad(F4).unwrap())
+        );
+        assert_eq!(
+            major_triad(F3).unwrap().negative(axis),
+            Some(minor_triad(G4).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_reflection_is_an_involution() {
+        let axis = major_scale(D4).unwrap().negative_axis();
+        for chord in [
+            major_triad(D4).unwrap(),
+            minor_triad(B3).unwrap(),
+            diminished_triad(CSHARP4).unwrap(),
+        ] {
+            let negative = chord.negative(axis).unwrap();
+            assert_eq!(negative.negative(axis), Some(chord));
+        }
+        for chord in [dominant_seventh(A3).unwrap(), major_seventh(G3).unwrap()] {
+            let negative = chord.negative(axis).unwrap();
+            assert_eq!(negative.negative(axis), Some(chord));
+        }
+    }
+
+    fn pitch_classes(notes: impl IntoIterator<Item = Note>) -> Vec<u8> {
+        let mut classes: Vec<_> = notes.into_iter().map(|note| note.pitch_class()).collect();
+        classes.sort();
+        classes.dedup();
+        classes
+    }
+
+    #[test]
+    fn test_negative_harmony_of_notes() {
+        for key_center in [C4, FSHARP2, A5] {
+            for midi in 12..=115 {
+                let note = C0.checked_add_semitones(midi - 12).unwrap();
+                let negative = negative_harmony(note, key_center).unwrap();
+                let distance = negative.midi_number().abs_diff(note.midi_number());
+                assert!(distance % 2 == 1 && distance <= 5, "{note:?}");
+                // Reflecting twice gives back the note, not only its pitch class
+                assert_eq!(negative_harmony(negative, key_center), Some(note));
+            }
+        }
+        // The tonic and the dominant are exchanged in the nearest octave
+        assert_eq!(negative_harmony(C4, C4), Some(G3));
+        assert_eq!(negative_harmony(D4, D7), Some(A3));
+        assert_eq!(negative_harmony(G9, C4), None);
+    }
+
+    #[test]
+    fn test_negative_harmony_of_chords() {
+        // G7 becomes a D half-diminished seventh, or an F minor sixth chord
+        let negative = dominant_seventh(G3).unwrap().negative_harmony(C4).unwrap();
+        assert_eq!(negative.notes(), &[GSHARP3, C4, D4, F4]);
+        assert_eq!(
+            pitch_classes(negative.notes().iter().copied()),
+            pitch_classes(half_diminished_seventh(D4).unwrap().notes().iter().copied())
+        );
+        assert_eq!(
+            pitch_classes(negative.notes().iter().copied()),
+            pitch_classes(minor_sixth(F4).unwrap().notes().iter().copied())
+        );
+
+        let progression =
+            Progression::new([dominant_seventh(G3).unwrap(), major_seventh(C4).unwrap()]);
+        let negative = progression.negative_harmony(C4).unwrap();
+        assert_eq!(negative.len(), 2);
+        assert_eq!(
+            negative.chords()[0],
+            dominant_seventh(G3).unwrap().negative_harmony(C4).unwrap()
+        );
+        assert_eq!(
+            Progression::new([major_triad(C9).unwrap()]).negative_harmony(C4),
+            None
+        );
+    }
+
+    #[test]
+    fn test_negative_harmony_of_melodies() {
+        let c_major = major_scale(C4).unwrap();
+        let c_minor = natural_minor_scale(C4).unwrap();
+        let melody = Melody::new(
+            c_major
+                .notes()
+                .iter()
+                .map(|&note| (note, Duration::Quarter)),
+        );
+
+        let negative = melody.negative_harmony(C4).unwrap();
+        assert_eq!(
+            pitch_classes(negative.notes()),
+            pitch_classes(c_minor.notes().iter().copied())
+        );
+        assert!(negative
+            .durations()
+            .all(|duration| duration == Duration::Quarter));
+
+        let twice = negative.negative_harmony(C4).unwrap();
+        assert_eq!(twice, melody);
+
+        let high = Melody::new([(G9, Duration::Whole)]);
+        assert_eq!(high.negative_harmony(C4), Err(Error::NoteOutOfRange(G9)));
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let axis = major_scale(C1).unwrap().negative_axis();
+        assert_eq!(major_triad(C5).unwrap().negative(axis), None);
+        let axis = major_scale(C4).unwrap().negative_axis();
+        // A sixth chord reflects into a minor seventh in first inversion, which has no quality
+        assert_eq!(major_sixth(C4).unwrap().negative(axis), None);
+    }
+}
@@ -0,0 +1,354 @@
+use crate::constants::*;
+use crate::{ChordQuality, Error, Note, RomanNumeral, Scale, ScaleQuality, Voicing};
+
+/// The Roman numerals for the seven degrees of a diatonic scale
+pub(crate) const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+/// Represents a chord progression
+///
+/// A progression is an ordered sequence of chords, such as the ubiquitous
+/// "I-V-vi-IV". Each chord is kept as a voicing, so triads, seventh chords and
+/// inversions can follow each other. Progressions can be transposed, analysed
+/// against a key as Roman numerals, or built from Roman numerals in a given key.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let c_major = major_scale(C4).unwrap();
+/// let progression = Progression::from_roman(&c_major, &["I", "V", "vi", "IV"]).unwrap();
+/// assert_eq!(progression.chords()[2].notes(), &[A4, C5, E5]);
+/// assert_eq!(progression.roman_numerals(&c_major), vec!["I", "V", "vi", "IV"]);
+///
+/// let cadence = Progression::from_roman(&c_major, &["ii7", "V7", "I"]).unwrap();
+/// assert_eq!(cadence.chords()[1].notes(), &[G4, B4, D5, F5]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Progression {
+    chords: Vec<Voicing>,
+}
+
+impl Progression {
+    /// Creates a new `Progression` from a sequence of chords
+    ///
+    /// # Arguments
+    /// * `chords` - The chords of the progression, in playing order, as chords or voicings
+    ///
+    /// # Returns
+    /// A new `Progression` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let progression = Progression::new([
+    ///     Voicing::from(&dominant_seventh(G3).unwrap()),
+    ///     Voicing::from(&major_triad(C4).unwrap()),
+    /// ]);
+    /// assert_eq!(progression.chords()[0].notes(), &[G3, B3, D4, F4]);
+    /// ```
+    pub fn new<C>(chords: impl IntoIterator<Item = C>) -> Self
+    where
+        C: Into<Voicing>,
+    {
+        Self {
+            chords: chords.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns the chords of the progression
+    ///
+    /// # Returns
+    /// A slice of the chords, in playing order
+    pub fn chords(&self) -> &[Voicing] {
+        &self.chords
+    }
+
+    /// Returns the number of chords in the progression
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.chords.len()
+    }
+
+    /// Returns `true` if the progression has no chords
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.chords.is_empty()
+    }
+
+    /// Returns the progression transposed by a number of semitones
+    ///
+    /// # Arguments
+    /// * `semitones` - The number of semitones to move (negative values move down)
+    ///
+    /// # Returns
+    /// A new progression where every chord has been transposed, or `None` if a
+    /// note would leave the MIDI range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let progression = Progression::new([major_triad(C4).unwrap(), major_triad(G4).unwrap()]);
+    /// let transposed = progression.transpose(-2).unwrap();
+    /// assert_eq!(transposed.chords()[0].notes(), &[ASHARP3, D4, F4]);
+    /// assert_eq!(transposed.chords()[1].notes(), &[F4, A4, C5]);
+    /// assert!(progression.transpose(-62).is_none());
+    /// ```
+    pub fn transpose(&self, semitones: i8) -> Option<Self> {
+        let chords = self
+            .chords
+            .iter()
+            .map(|chord| chord.transpose(semitones))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self::new(chords))
+    }
+
+    /// Analyses the progression in a key, returning one Roman numeral per chord
+    ///
+    /// The root and quality of each chord are recognized from its pitch classes,
+    /// as for `Voicing::symbol`, so an inversion is written with the numeral of
+    /// its root. Major and augmented chords are written in uppercase, minor and
+    /// diminished chords in lowercase. Diminished chords are marked with `°`,
+    /// augmented ones with `+`, and seventh chords carry a `7` or `maj7` suffix.
+    /// Chords whose root is not in the key are written relative to the degree
+    /// above with a `b` prefix. A chord matching no chord quality is written `?`.
+    ///
+    /// # Arguments
+    /// * `key` - The scale used as the tonal center of the analysis
+    ///
+    /// # Returns
+    /// The Roman numerals of the chords, in playing order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let c_major = major_scale(C4).unwrap();
+    /// let progression = Progression::new([major_triad(C4).unwrap(), major_triad(ASHARP4).unwrap(), diminished_triad(B4).unwrap()]);
+    /// assert_eq!(progression.roman_numerals(&c_major), vec!["I", "bVII", "vii°"]);
+    /// ```
+    pub fn roman_numerals<Q: ScaleQuality>(&self, key: &Scale<Q, 8>) -> Vec<String> {
+        self.chords
+            .iter()
+            .map(|chord| match chord.chord() {
+                Some((root, quality)) => roman_numeral(key, root, quality),
+                None => "?".to_string(),
+            })
+            .collect()
+    }
+
+    /// Builds a progression from Roman numerals in a key
+    ///
+    /// Each numeral is parsed as a `RomanNumeral`, so its case and suffix give the
+    /// quality of the chord: `ii7`, `V7` or `vii°` are accepted, as are secondary
+    /// chords such as `V/V`. The chords are in close position from their root.
+    ///
+    /// # Arguments
+    /// * `key` - The scale providing the root of each degree
+    /// * `numerals` - The Roman numerals, in playing order
+    ///
+    /// # Returns
+    /// The progression, `Error::InvalidRomanNumeral` if a numeral cannot be parsed,
+    /// or `Error::NoteOutOfRange` if a chord would leave the MIDI range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let g_major = major_scale(G4).unwrap();
+    /// let progression = Progression::from_roman(&g_major, &["ii", "V", "I"]).unwrap();
+    /// assert_eq!(progression.chords()[0].notes(), &[A4, C5, E5]);
+    /// assert_eq!(progression.chords()[1].notes(), &[D5, FSHARP5, A5]);
+    /// assert_eq!(progression.chords()[2].notes(), &[G4, B4, D5]);
+    /// ```
+    pub fn from_roman<Q: ScaleQuality>(
+        key: &Scale<Q, 8>,
+        numerals: &[&str],
+    ) -> Result<Self, Error> {
+        numerals
+            .iter()
+            .map(|numeral| numeral.parse::<RomanNumeral>()?.voicing_in(key))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+}
+
+/// Returns the Roman numeral of a chord analysed in a key
+fn roman_numeral<Q: ScaleQuality>(key: &Scale<Q, 8>, root: Note, quality: ChordQuality) -> String {
+    let offset = pitch_class_offset(key, root.midi_number());
+    let degrees = degree_offsets(key);
+
+    let (accidental, degree) = match degrees.iter().position(|&d| d == offset) {
+        Some(degree) => ("", degree),
+        None => match degrees
+            .iter()
+            .position(|&d| d == (offset + 1) % SEMITONES_IN_OCTAVE)
+        {
+            Some(degree) => ("b", degree),
+            None => {
+                let below = (offset + SEMITONES_IN_OCTAVE - 1) % SEMITONES_IN_OCTAVE;
+                let degree = degrees.iter().position(|&d| d == below).unwrap_or_default();
+                ("#", degree)
+            }
+        },
+    };
+
+    let (minor, suffix) = numeral_quality(quality);
+    let numeral = if minor {
+        NUMERALS[degree].to_lowercase()
+    } else {
+        NUMERALS[degree].to_string()
+    };
+
+    format!("{accidental}{numeral}{suffix}")
+}
+
+/// Returns whether a chord quality is written in lowercase, and its numeral suffix
+pub(crate) fn numeral_quality(quality: ChordQuality) -> (bool, &'static str) {
+    match quality {
+        ChordQuality::MajorTriad => (false, ""),
+        ChordQuality::MinorTriad => (true, ""),
+        ChordQuality::DiminishedTriad => (true, "°"),
+        ChordQuality::AugmentedTriad => (false, "+"),
+        ChordQuality::DominantSeventh => (false, "7"),
+        ChordQuality::MajorSeventh => (false, "maj7"),
+        ChordQuality::MinorSeventh => (true, "7"),
+        ChordQuality::MinorMajorSeventh => (true, "maj7"),
+        ChordQuality::DiminishedSeventh => (true, "°7"),
+        ChordQuality::HalfDiminishedSeventh => (true, "ø7"),
+        ChordQuality::AugmentedSeventh => (false, "+7"),
+        ChordQuality::MajorSixth => (false, "6"),
+        ChordQuality::MinorSixth => (true, "6"),
+        ChordQuality::MajorSixthNinth => (false, "6/9"),
+        ChordQuality::MinorSixthNinth => (true, "6/9"),
+        ChordQuality::DominantSeventhNinth => (false, "7/9"),
+        ChordQuality::MinorSeventhNinth => (true, "7/9"),
+        ChordQuality::Sus2 => (false, "sus2"),
+        ChordQuality::Sus4 => (false, "sus4"),
+        ChordQuality::DominantNinth => (false, "9"),
+        ChordQuality::MinorNinth => (true, "9"),
+        ChordQuality::MajorNinth => (false, "maj9"),
+        ChordQuality::DominantEleventh => (false, "11"),
+        ChordQuality::MinorEleventh => (true, "11"),
+        ChordQuality::MajorEleventh => (false, "maj11"),
+        ChordQuality::DominantThirteenth => (false, "13"),
+        ChordQuality::MinorThirteenth => (true, "13"),
+        ChordQuality::MajorThirteenth => (false, "maj13"),
+    }
+}
+
+/// Returns the distance in semitones, within an octave, between the key root and a note
+pub(crate) fn pitch_class_offset<Q: ScaleQuality>(key: &Scale<Q, 8>, midi: u8) -> u8 {
+    let root = key.root().pitch_class();
+    (midi % SEMITONES_IN_OCTAVE + SEMITONES_IN_OCTAVE - root) % SEMITONES_IN_OCTAVE
+}
+
+/// Returns the offsets of the seven scale degrees from the key root
+pub(crate) fn degree_offsets<Q: ScaleQuality>(key: &Scale<Q, 8>) -> [u8; 7] {
+    let mut offsets = [0; 7];
+    for (offset, note) in offsets.iter_mut().zip(key.notes()) {
+        *offset = pitch_class_offset(key, note.midi_number());
+    }
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_roman_round_trip() {
+        let c_major = major_scale(C4).unwrap();
+        let progression = Progression::from_roman(&c_major, &["I", "IV", "V", "I"]).unwrap();
+
+        assert_eq!(progression.len(), 4);
+        assert_eq!(progression.chords()[0].notes(), &[C4, E4, G4]);
+        assert_eq!(progression.chords()[1].notes(), &[F4, A4, C5]);
+        assert_eq!(progression.chords()[2].notes(), &[G4, B4, D5]);
+        assert_eq!(progression.chords()[3].notes(), &[C4, E4, G4]);
+        assert_eq!(
+            progression.roman_numerals(&c_major),
+            vec!["I", "IV", "V", "I"]
+        );
+    }
+
+    #[test]
+    fn test_roman_numerals_in_minor_key() {
+        let a_minor = natural_minor_scale(A4).unwrap();
+        let progression = Progression::from_roman(&a_minor, &["i", "ii°", "III", "bII"]).unwrap();
+
+        assert_eq!(
+            progression.chords()[1].chord(),
+            Some((B4, ChordQuality::DiminishedTriad))
+        );
+        assert_eq!(progression.chords()[3].notes(), &[ASHARP4, D5, F5]);
+        assert_eq!(
+            progression.roman_numerals(&a_minor),
+            vec!["i", "ii°", "III", "bII"]
+        );
+    }
+
+    #[test]
+    fn test_transpose() {
+        let c_major = major_scale(C4).unwrap();
+        let progression = Progression::from_roman(&c_major, &["I", "V", "vi", "IV"]).unwrap();
+        let d_progression = progression.transpose(2).unwrap();
+
+        assert_eq!(d_progression.chords()[0].notes(), &[D4, FSHARP4, A4]);
+        assert_eq!(
+            d_progression.roman_numerals(&major_scale(D4).unwrap()),
+            vec!["I", "V", "vi", "IV"]
+        );
+        assert!(progression.transpose(70).is_none());
+    }
+
+    #[test]
+    fn test_invalid_roman_numeral() {
+        let c_major = major_scale(C4).unwrap();
+        for numeral in ["IX", "Iv", "", "bb", "V8"] {
+            let error = Progression::from_roman(&c_major, &["I", numeral]).unwrap_err();
+            assert_eq!(error, Error::InvalidRomanNumeral(numeral.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_triads_and_sevenths() {
+        let c_major = major_scale(C4).unwrap();
+        let progression = Progression::from_roman(&c_major, &["ii7", "V7", "I"]).unwrap();
+
+        assert_eq!(progression.chords()[0].notes(), &[D4, F4, A4, C5]);
+        assert_eq!(progression.chords()[1].notes(), &[G4, B4, D5, F5]);
+        assert_eq!(progression.chords()[2].notes(), &[C4, E4, G4]);
+        assert_eq!(progression.roman_numerals(&c_major), vec!["ii7", "V7", "I"]);
+        assert_eq!(
+            progression
+                .transpose(2)
+                .unwrap()
+                .roman_numerals(&major_scale(D4).unwrap()),
+            vec!["ii7", "V7", "I"]
+        );
+
+        // An inversion is analysed from its root, a cluster matches no quality
+        let first_inversion = major_triad(F4).unwrap().inversions().unwrap().remove(1);
+        let progression = Progression::new([first_inversion, Voicing::new([C4, CSHARP4, D4])]);
+        assert_eq!(progression.roman_numerals(&c_major), vec!["IV", "?"]);
+        assert_eq!(progression.clone(), progression);
+    }
+}
@@ -0,0 +1,351 @@
+#[cfg(feature = "rand")]
+use crate::Error;
+use crate::{Progression, Scale, ScaleQuality};
+#[cfg(feature = "rand")]
+use rand::{Rng, RngExt};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A first-order Markov model of chord progressions
+///
+/// The model counts how often each Roman numeral follows another in a corpus
+/// of progressions. It scores new progressions by their log-likelihood and,
+/// with the `rand` feature, generates progressions that follow the learned
+/// transitions. The numerals are relative to the key, so progressions in
+/// different keys can be learned together.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let c_major = major_scale(C4).unwrap();
+/// let corpus = [
+///     Progression::from_roman(&c_major, &["I", "IV", "V", "I"]).unwrap(),
+///     Progression::from_roman(&c_major, &["I", "vi", "ii", "V", "I"]).unwrap(),
+/// ];
+/// let model = ProgressionModel::train(&corpus, &c_major);
+///
+/// assert_eq!(model.count("V", "I"), 2);
+/// let cadence = Progression::from_roman(&c_major, &["ii", "V", "I"]).unwrap();
+/// let odd = Progression::from_roman(&c_major, &["V", "IV", "ii"]).unwrap();
+/// assert!(model.log_likelihood(&cadence, &c_major) > model.log_likelihood(&odd, &c_major));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProgressionModel {
+    /// The number of times each numeral is followed by each other one
+    transitions: BTreeMap<String, BTreeMap<String, u32>>,
+    /// Every numeral of the corpus
+    vocabulary: BTreeSet<String>,
+}
+
+impl ProgressionModel {
+    /// Learns the transitions between the chords of a corpus of progressions
+    ///
+    /// # Arguments
+    /// * `progressions` - The corpus, each progression analysed in the key
+    /// * `key` - The key giving the Roman numerals of the chords
+    ///
+    /// # Returns
+    /// The trained model
+    pub fn train<Q>(progressions: &[Progression], key: &Scale<Q, 8>) -> Self
+    where
+        Q: ScaleQuality,
+    {
+        let mut model = Self::default();
+        for progression in progressions {
+            let numerals = progression.roman_numerals(key);
+            model.vocabulary.extend(numerals.iter().cloned());
+            for pair in numerals.windows(2) {
+                *model
+                    .transitions
+                    .entry(pair[0].clone())
+                    .or_default()
+                    .entry(pair[1].clone())
+                    .or_default() += 1;
+            }
+        }
+        model
+    }
+
+    /// Returns the Roman numerals seen in the corpus, in alphabetical order
+    pub fn numerals(&self) -> impl Iterator<Item = &str> + '_ {
+        self.vocabulary.iter().map(String::as_str)
+    }
+
+    /// Returns the number of times a numeral was followed by another one in the corpus
+    ///
+    /// # Arguments
+    /// * `from` - The numeral of the first chord
+    /// * `to` - The numeral of the chord that follows it
+    pub fn count(&self, from: &str, to: &str) -> u32 {
+        self.transitions
+            .get(from)
+            .and_then(|next| next.get(to))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns the probability that a numeral is followed by another one
+    ///
+    /// The counts are smoothed with Laplace smoothing: every transition counts
+    /// one more time than it was seen, over the numerals of the corpus and one
+    /// more for any numeral the corpus doesn't have. Unseen transitions, and
+    /// unseen numerals, get a small probability rather than none.
+    ///
+    /// # Arguments
+    /// * `from` - The numeral of the first chord
+    /// * `to` - The numeral of the chord that follows it
+    ///
+    /// # Returns
+    /// The probability, above 0 and at most 1
+    pub fn probability(&self, from: &str, to: &str) -> f64 {
+        let total: u32 = self
+            .transitions
+            .get(from)
+            .map_or(0, |next| next.values().sum());
+        let outcomes = self.vocabulary.len() + 1;
+        f64::from(self.count(from, to) + 1) / (f64::from(total) + outcomes as f64)
+    }
+
+    /// Returns how likely a progression is under the model
+    ///
+    /// # Arguments
+    /// * `progression` - The progression to score
+    /// * `key` - The key giving the Roman numerals of the chords
+    ///
+    /// # Returns
+    /// The sum of the natural logarithms of the smoothed probabilities of the
+    /// transitions, always finite, 0 for a progression of fewer than two chords
+    pub fn log_likelihood<Q>(&self, progression: &Progression, key: &Scale<Q, 8>) -> f64
+    where
+        Q: ScaleQuality,
+    {
+        progression
+            .roman_numerals(key)
+            .windows(2)
+            .map(|pair| self.probability(&pair[0], &pair[1]).ln())
+            .sum()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl ProgressionModel {
+    /// Generates a progression following the learned transitions
+    ///
+    /// Each chord is drawn among the numerals that followed the previous one in
+    /// the corpus, weighted by their counts, so transitions that were never seen
+    /// are never generated. When a numeral was never followed by anything, the
+    /// next one is drawn uniformly among the numerals of the corpus, as the
+    /// smoothed distribution would.
+    ///
+    /// # Arguments
+    /// * `len` - The number of chords of the progression
+    /// * `start` - The Roman numeral of the first chord
+    /// * `key` - The key the numerals are built in
+    /// * `rng` - The source of randomness
+    ///
+    /// # Returns
+    /// The progression, or the error of `Progression::from_roman` if `start` or
+    /// a learned numeral cannot be built in the key
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// let c_major = major_scale(C4).unwrap();
+    /// let corpus = [Progression::from_roman(&c_major, &["I", "IV", "V", "I"]).unwrap()];
+    /// let model = ProgressionModel::train(&corpus, &c_major);
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let progression = model.generate(4, "IV", &c_major, &mut rng).unwrap();
+    /// assert_eq!(progression.roman_numerals(&c_major), vec!["IV", "V", "I", "IV"]);
+    /// ```
+    pub fn generate<Q, R>(
+        &self,
+        len: usize,
+        start: &str,
+        key: &Scale<Q, 8>,
+        rng: &mut R,
+    ) -> Result<Progression, Error>
+    where
+        Q: ScaleQuality,
+        R: Rng + ?Sized,
+    {
+        let mut numerals: Vec<&str> = Vec::with_capacity(len);
+        let mut current = start;
+        for _ in 0..len {
+            numerals.push(current);
+            current = self.next_numeral(current, rng);
+        }
+        Progression::from_roman(key, &numerals)
+    }
+
+    /// Draws the numeral following another one
+    fn next_numeral<'a, R>(&'a self, from: &'a str, rng: &mut R) -> &'a str
+    where
+        R: Rng + ?Sized,
+    {
+        match self.transitions.get(from) {
+            Some(next) => {
+                let total: u32 = next.values().sum();
+                let mut pick = rng.random_range(0..total);
+                next.iter()
+                    .find(|(_, &count)| {
+                        if pick < count {
+                            true
+                        } else {
+                            pick -= count;
+                            false
+                        }
+                    })
+                    .map(|(numeral, _)| numeral.as_str())
+                    .expect("the pick is below the total of the counts")
+            }
+            None if self.vocabulary.is_empty() => from,
+            None => {
+                let index = rng.random_range(0..self.vocabulary.len());
+                self.numerals().nth(index).unwrap_or(from)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::major_scale;
+
+    fn corpus() -> Vec<Progression> {
+        let c_major = major_scale(C4).unwrap();
+        [
+            &["I", "IV", "V", "I"][..],
+            &["I", "vi", "IV", "V", "I"],
+            &["I", "vi", "ii", "V", "I"],
+            &["ii", "V", "I", "IV", "I"],
+            &["I", "iii", "vi", "IV", "V", "I"],
+        ]
+        .iter()
+        .map(|numerals| Progression::from_roman(&c_major, numerals).unwrap())
+        .collect()
+    }
+
+    fn progression(numerals: &[&str]) -> Progression {
+        Progression::from_roman(&major_scale(C4).unwrap(), numerals).unwrap()
+    }
+
+    #[test]
+    fn test_train() {
+        let model = ProgressionModel::train(&corpus(), &major_scale(C4).unwrap());
+        assert_eq!(model.count("V", "I"), 5);
+        assert_eq!(model.count("V", "vi"), 0);
+        assert_eq!(model.count("I", "vi"), 2);
+        assert_eq!(
+            model.numerals().collect::<Vec<_>>(),
+            ["I", "IV", "V", "ii", "iii", "vi"]
+        );
+
+        // The numerals are relative to the key
+        let g_major = major_scale(G3).unwrap();
+        let transposed: Vec<_> = corpus()
+            .iter()
+            .map(|progression| progression.transpose(-5).unwrap())
+            .collect();
+        assert_eq!(ProgressionModel::train(&transposed, &g_major), model);
+    }
+
+    #[test]
+    fn test_likelihood() {
+        let c_major = major_scale(C4).unwrap();
+        let model = ProgressionModel::train(&corpus(), &c_major);
+
+        let known = model.log_likelihood(&progression(&["I", "vi", "IV", "V", "I"]), &c_major);
+        let unusual = model.log_likelihood(&progression(&["V", "vi", "iii", "ii", "IV"]), &c_major);
+        assert!(known > unusual);
+        assert!(known < 0.0);
+        assert_eq!(model.log_likelihood(&progression(&["I"]), &c_major), 0.0);
+    }
+
+    #[test]
+    fn test_smoothing() {
+        let c_major = major_scale(C4).unwrap();
+        let model = ProgressionModel::train(&corpus(), &c_major);
+
+        // Unseen transitions and numerals are unlikely but possible
+        let unseen = model.log_likelihood(&progression(&["V", "vi"]), &c_major);
+        assert!(unseen.is_finite());
+        assert!(unseen < model.log_likelihood(&progression(&["V", "I"]), &c_major));
+        assert!(model.probability("bVII", "vii°") > 0.0);
+        assert!(ProgressionModel::default().probability("I", "V") > 0.0);
+
+        // The smoothed probabilities of what follows a numeral add up to one
+        let total: f64 = model
+            .numerals()
+            .map(|to| model.probability("I", to))
+            .sum::<f64>()
+            + model.probability("I", "bVII");
+        assert!((total - 1.0).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_generate_follows_the_corpus() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let c_major = major_scale(C4).unwrap();
+        let model = ProgressionModel::train(&corpus(), &c_major);
+        let mut rng = SmallRng::seed_from_u64(42);
+
+        for _ in 0..20 {
+            let generated = model.generate(16, "I", &c_major, &mut rng).unwrap();
+            let numerals = generated.roman_numerals(&c_major);
+            assert_eq!(numerals.len(), 16);
+            assert_eq!(numerals[0], "I");
+            for pair in numerals.windows(2) {
+                assert!(model.count(&pair[0], &pair[1]) > 0, "{pair:?}");
+            }
+        }
+
+        assert_eq!(
+            model.generate(3, "X", &c_major, &mut rng).unwrap_err(),
+            Error::InvalidRomanNumeral("X".to_string())
+        );
+        assert!(model
+            .generate(0, "I", &c_major, &mut rng)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_generate_from_an_unseen_numeral() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let c_major = major_scale(C4).unwrap();
+        let model = ProgressionModel::train(&corpus(), &c_major);
+        let mut rng = SmallRng::seed_from_u64(1);
+        let generated = model.generate(2, "bVII", &c_major, &mut rng).unwrap();
+        let numerals = generated.roman_numerals(&c_major);
+        assert!(model.numerals().any(|numeral| numeral == numerals[1]));
+
+        // An empty model repeats the first chord
+        let generated = ProgressionModel::default()
+            .generate(3, "IV", &c_major, &mut rng)
+            .unwrap();
+        assert_eq!(generated.roman_numerals(&c_major), ["IV", "IV", "IV"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let model = ProgressionModel::train(&corpus(), &major_scale(C4).unwrap());
+        let json = serde_json::to_string(&model).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ProgressionModel>(&json).unwrap(),
+            model
+        );
+    }
+}
@@ -0,0 +1,505 @@
+use super::progression::{numeral_quality, NUMERALS};
+use crate::{ChordQuality, Error, Note, Progression, Scale, ScaleQuality, Voicing};
+use std::fmt;
+use std::str::FromStr;
+
+/// The offsets in semitones of the degrees of a major scale, used for the degrees of secondary chords
+const MAJOR_DEGREE_OFFSETS: [i16; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// The characters separating the numerals of a progression, besides whitespace
+const SEPARATORS: [char; 3] = ['-', ',', '|'];
+
+/// Represents a chord written as a Roman numeral, such as `V7`, `bVII` or `V7/ii`
+///
+/// The numeral gives the degree of the root in the key, a `b` or `#` prefix
+/// lowers or raises it, and the case and suffix give the quality of the chord:
+/// uppercase for major chords, lowercase for minor ones, `°` (or `o`) for
+/// diminished, `ø` for half-diminished, `+` for augmented, followed by the
+/// suffixes of `Progression::roman_numerals` such as `7` or `maj7`.
+///
+/// A secondary chord names its target after a slash: `V/V` is the dominant of
+/// the dominant, its root being the fifth degree of the major scale on the
+/// root of the target.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let numeral: RomanNumeral = "V7/ii".parse().unwrap();
+/// assert_eq!(numeral.degree(), 5);
+/// assert_eq!(numeral.quality(), ChordQuality::DominantSeventh);
+/// assert_eq!(numeral.target().unwrap().degree(), 2);
+///
+/// let chord = numeral.voicing_in(&major_scale(C4).unwrap()).unwrap();
+/// assert_eq!(chord.notes(), &[A4, CSHARP5, E5, G5]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RomanNumeral {
+    accidental: i8,
+    degree: u8,
+    quality: ChordQuality,
+    target: Option<Box<RomanNumeral>>,
+}
+
+impl RomanNumeral {
+    /// Creates a new `RomanNumeral` on a degree of the key
+    ///
+    /// # Arguments
+    /// * `degree` - The degree of the root, from 1 to 7
+    /// * `quality` - The quality of the chord
+    ///
+    /// # Returns
+    /// The numeral, or `Error::InvalidScaleDegree` if the degree is not between 1 and 7
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// let numeral = RomanNumeral::new(7, ChordQuality::MajorTriad).unwrap().with_accidental(-1);
+    /// assert_eq!(numeral.to_string(), "bVII");
+    /// assert!(RomanNumeral::new(8, ChordQuality::MajorTriad).is_err());
+    /// ```
+    pub fn new(degree: u8, quality: ChordQuality) -> Result<Self, Error> {
+        if !(1..=7).contains(&degree) {
+            return Err(Error::InvalidScaleDegree(degree));
+        }
+
+        Ok(Self {
+            accidental: 0,
+            degree,
+            quality,
+            target: None,
+        })
+    }
+
+    /// Returns the numeral with its root lowered (negative) or raised (positive) by semitones
+    pub fn with_accidental(mut self, accidental: i8) -> Self {
+        self.accidental = accidental;
+        self
+    }
+
+    /// Returns the numeral as a secondary chord of a target, such as the `V` of `V/V`
+    pub fn of(mut self, target: RomanNumeral) -> Self {
+        self.target = Some(Box::new(target));
+        self
+    }
+
+    /// Returns the degree of the root, from 1 to 7
+    pub fn degree(&self) -> u8 {
+        self.degree
+    }
+
+    /// Returns the number of semitones the root is raised (positive) or lowered (negative) by
+    pub fn accidental(&self) -> i8 {
+        self.accidental
+    }
+
+    /// Returns the quality of the chord
+    pub fn quality(&self) -> ChordQuality {
+        self.quality
+    }
+
+    /// Returns the chord this chord is a secondary chord of, if any
+    pub fn target(&self) -> Option<&RomanNumeral> {
+        self.target.as_deref()
+    }
+
+    /// Returns the root of the chord in a key
+    ///
+    /// # Arguments
+    /// * `key` - The scale providing the root of each degree
+    ///
+    /// # Returns
+    /// The root, or `Error::NoteOutOfRange` if it would be outside of the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let key = major_scale(C4).unwrap();
+    /// assert_eq!("bVI".parse::<RomanNumeral>().unwrap().root_in(&key).unwrap(), GSHARP4);
+    /// assert_eq!("V/V".parse::<RomanNumeral>().unwrap().root_in(&key).unwrap(), D5);
+    /// ```
+    pub fn root_in<Q: ScaleQuality>(&self, key: &Scale<Q, 8>) -> Result<Note, Error> {
+        let degree = usize::from(self.degree - 1);
+        let root = match &self.target {
+            Some(target) => {
+                let tonic = target.root_in(key)?;
+                tonic
+                    .checked_add_semitones(MAJOR_DEGREE_OFFSETS[degree])
+                    .ok_or(Error::NoteOutOfRange(tonic))?
+            }
+            None => key.notes()[degree],
+        };
+
+        root.checked_add_semitones(i16::from(self.accidental))
+            .ok_or(Error::NoteOutOfRange(root))
+    }
+
+    /// Returns the notes of the chord in a key, in close position from the root
+    ///
+    /// # Arguments
+    /// * `key` - The scale providing the root of each degree
+    ///
+    /// # Returns
+    /// The chord, or `Error::NoteOutOfRange` if a note would be outside of the MIDI range
+    pub fn voicing_in<Q: ScaleQuality>(&self, key: &Scale<Q, 8>) -> Result<Voicing, Error> {
+        let root = self.root_in(key)?;
+        let notes = self
+            .quality
+            .intervals()
+            .iter()
+            .map(|interval| {
+                root.checked_add_semitones(i16::from(interval.semitones()))
+                    .ok_or(Error::NoteOutOfRange(root))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Voicing::new(std::iter::once(root).chain(notes)))
+    }
+}
+
+impl fmt::Display for RomanNumeral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let accidental = if self.accidental < 0 { "b" } else { "#" };
+        let accidental = accidental.repeat(usize::from(self.accidental.unsigned_abs()));
+        let (minor, suffix) = numeral_quality(self.quality);
+        let numeral = NUMERALS[usize::from(self.degree - 1)];
+        let numeral = if minor {
+            numeral.to_lowercase()
+        } else {
+            numeral.to_string()
+        };
+
+        write!(f, "{accidental}{numeral}{suffix}")?;
+        if let Some(target) = &self.target {
+            write!(f, "/{target}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RomanNumeral {
+    type Err = Error;
+
+    /// Parses a Roman numeral, such as `I`, `bVII`, `#iv°`, `V7` or `V7/ii`
+    fn from_str(numeral: &str) -> Result<Self, Self::Err> {
+        parse_numeral(numeral).ok_or_else(|| Error::InvalidRomanNumeral(numeral.to_string()))
+    }
+}
+
+/// Parses a progression of Roman numerals
+///
+/// The numerals are separated by spaces, dashes, commas or bar lines, in any
+/// combination, so `"I - V - vi - IV"`, `"I, V, vi, IV"` and `"| I V | vi IV |"`
+/// are the same progression.
+///
+/// # Arguments
+/// * `progression` - The numerals of the progression, see `RomanNumeral`
+///
+/// # Returns
+/// The numerals in playing order, or `Error::InvalidProgressionNumeral` with the
+/// first malformed numeral and the index of its first character
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let numerals = parse_progression("I - V - vi - IV").unwrap();
+/// let names: Vec<_> = numerals.iter().map(|numeral| numeral.to_string()).collect();
+/// assert_eq!(names, vec!["I", "V", "vi", "IV"]);
+///
+/// let error = parse_progression("I | IX | V").unwrap_err();
+/// assert_eq!(error, Error::InvalidProgressionNumeral { numeral: "IX".to_string(), position: 4 });
+/// ```
+pub fn parse_progression(progression: &str) -> Result<Vec<RomanNumeral>, Error> {
+    tokens(progression)
+        .map(|(position, token)| {
+            parse_numeral(token).ok_or_else(|| Error::InvalidProgressionNumeral {
+                numeral: token.to_string(),
+                position,
+            })
+        })
+        .collect()
+}
+
+/// Parses a progression of Roman numerals and builds its chords in a key
+///
+/// # Arguments
+/// * `key` - The scale providing the root of each degree
+/// * `progression` - The numerals of the progression, see `parse_progression`
+///
+/// # Returns
+/// The progression, its chords in close position from their root, or the
+/// error of `parse_progression` or `RomanNumeral::voicing_in`
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let progression = parse_progression_in(&natural_minor_scale(A3).unwrap(), "i iv V7").unwrap();
+/// assert_eq!(progression.chords()[0].notes(), &[A3, C4, E4]);
+/// assert_eq!(progression.chords()[1].notes(), &[D4, F4, A4]);
+/// assert_eq!(progression.chords()[2].notes(), &[E4, GSHARP4, B4, D5]);
+/// ```
+pub fn parse_progression_in<Q: ScaleQuality>(
+    key: &Scale<Q, 8>,
+    progression: &str,
+) -> Result<Progression, Error> {
+    parse_progression(progression)?
+        .iter()
+        .map(|numeral| numeral.voicing_in(key))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Progression::new)
+}
+
+/// Returns the numerals of a progression with the index of their first character
+///
+/// A slash is a separator only between spaces, since it also introduces the
+/// target of a secondary chord and the ninth of a `6/9` chord.
+fn tokens(progression: &str) -> impl Iterator<Item = (usize, &str)> {
+    split_tokens(progression, &SEPARATORS)
+        .into_iter()
+        .filter(|&(_, token)| token != "/")
+}
+
+/// Splits a text on whitespace and separators, returning each token with the index of its first character
+///
+/// The index counts characters rather than bytes, so it can be shown to the user.
+pub(crate) fn split_tokens<'a>(text: &'a str, separators: &[char]) -> Vec<(usize, &'a str)> {
+    let mut start = None;
+    let mut tokens = Vec::new();
+    for (position, (index, c)) in text.char_indices().enumerate() {
+        let separator = c.is_whitespace() || separators.contains(&c);
+        match (separator, start) {
+            (true, Some((first, begin))) => {
+                tokens.push((first, &text[begin..index]));
+                start = None;
+            }
+            (false, None) => start = Some((position, index)),
+            _ => {}
+        }
+    }
+    if let Some((first, begin)) = start {
+        tokens.push((first, &text[begin..]));
+    }
+
+    tokens
+}
+
+/// Parses a Roman numeral, returning `None` if it is malformed
+fn parse_numeral(numeral: &str) -> Option<RomanNumeral> {
+    // The slash introducing a target is followed by a numeral, the one of `6/9` by a digit
+    let (chord, target) = match numeral.char_indices().find(|&(index, c)| {
+        c == '/' && numeral[index + 1..].starts_with(|next: char| "b#IViv".contains(next))
+    }) {
+        Some((index, _)) => (
+            &numeral[..index],
+            Some(parse_numeral(&numeral[index + 1..])?),
+        ),
+        None => (numeral, None),
+    };
+
+    let body = chord.trim_start_matches(['b', '#']);
+    let prefix = &chord[..chord.len() - body.len()];
+    // At most a double flat or a double sharp
+    let accidental = match prefix {
+        "" => 0,
+        "b" => -1,
+        "bb" => -2,
+        "#" => 1,
+        "##" => 2,
+        _ => return None,
+    };
+
+    let length = body
+        .find(|c: char| !"IViv".contains(c))
+        .unwrap_or(body.len());
+    let (letters, suffix) = body.split_at(length);
+    let minor = if !letters.is_empty() && letters.chars().all(|c| c.is_ascii_lowercase()) {
+        true
+    } else if letters.chars().all(|c| c.is_ascii_uppercase()) {
+        false
+    } else {
+        return None;
+    };
+    let degree = NUMERALS
+        .iter()
+        .position(|name| *name == letters.to_ascii_uppercase())?;
+
+    let suffix = match suffix.replace('o', "°").as_str() {
+        "ø" => "ø7".to_string(),
+        suffix => suffix.to_string(),
+    };
+    // Diminished, half-diminished and augmented chords are named by their suffix in either case
+    let marked = suffix.starts_with(['°', 'ø', '+']);
+    let quality = ChordQuality::ALL.into_iter().find(|&quality| {
+        let (lowercase, name) = numeral_quality(quality);
+        name == suffix && (marked || lowercase == minor)
+    })?;
+
+    let mut numeral = RomanNumeral::new(degree as u8 + 1, quality)
+        .ok()?
+        .with_accidental(accidental);
+    numeral.target = target.map(Box::new);
+    Some(numeral)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::{major_scale, natural_minor_scale};
+
+    fn numeral(degree: u8, quality: ChordQuality) -> RomanNumeral {
+        RomanNumeral::new(degree, quality).unwrap()
+    }
+
+    #[test]
+    fn test_parse_numerals() {
+        use ChordQuality::*;
+
+        let table = [
+            ("I", numeral(1, MajorTriad)),
+            ("ii", numeral(2, MinorTriad)),
+            ("vii°", numeral(7, DiminishedTriad)),
+            ("viio", numeral(7, DiminishedTriad)),
+            ("VII°", numeral(7, DiminishedTriad)),
+            ("III+", numeral(3, AugmentedTriad)),
+            ("V7", numeral(5, DominantSeventh)),
+            ("ii7", numeral(2, MinorSeventh)),
+            ("Imaj7", numeral(1, MajorSeventh)),
+            ("imaj7", numeral(1, MinorMajorSeventh)),
+            ("viiø", numeral(7, HalfDiminishedSeventh)),
+            ("viiø7", numeral(7, HalfDiminishedSeventh)),
+            ("vii°7", numeral(7, DiminishedSeventh)),
+            ("I6/9", numeral(1, MajorSixthNinth)),
+            ("Vsus4", numeral(5, Sus4)),
+            ("bVII", numeral(7, MajorTriad).with_accidental(-1)),
+            ("#iv°", numeral(4, DiminishedTriad).with_accidental(1)),
+            ("bbVII", numeral(7, MajorTriad).with_accidental(-2)),
+            ("V/V", numeral(5, MajorTriad).of(numeral(5, MajorTriad))),
+            (
+                "V7/ii",
+                numeral(5, DominantSeventh).of(numeral(2, MinorTriad)),
+            ),
+            (
+                "vii°7/V",
+                numeral(7, DiminishedSeventh).of(numeral(5, MajorTriad)),
+            ),
+            (
+                "V/V/V",
+                numeral(5, MajorTriad).of(numeral(5, MajorTriad).of(numeral(5, MajorTriad))),
+            ),
+            (
+                "V7/bIII",
+                numeral(5, DominantSeventh).of(numeral(3, MajorTriad).with_accidental(-1)),
+            ),
+        ];
+
+        for (text, expected) in table {
+            assert_eq!(text.parse::<RomanNumeral>(), Ok(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for text in ["I", "bVII", "#iv°", "V7/ii", "viiø7", "I6/9", "V/V/V"] {
+            let numeral: RomanNumeral = text.parse().unwrap();
+            assert_eq!(numeral.to_string(), text);
+        }
+        let numeral: RomanNumeral = "viio".parse().unwrap();
+        assert_eq!(numeral.to_string(), "vii°");
+    }
+
+    #[test]
+    fn test_invalid_numerals() {
+        for text in [
+            "", "IX", "Iv", "bb", "b", "V/", "V/IX", "I7x", "v+7x", "b#V", "bbbV", "ii°x", "VV",
+            "/V",
+        ] {
+            assert_eq!(
+                text.parse::<RomanNumeral>(),
+                Err(Error::InvalidRomanNumeral(text.to_string())),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_separators() {
+        let expected: Vec<_> = ["I", "V", "vi", "IV"]
+            .iter()
+            .map(|text| text.parse::<RomanNumeral>().unwrap())
+            .collect();
+
+        for progression in [
+            "I V vi IV",
+            "I - V - vi - IV",
+            "I-V-vi-IV",
+            "I, V, vi, IV",
+            "I,V,vi,IV",
+            "| I | V | vi | IV |",
+            "|I|V|vi|IV|",
+            "I / V / vi / IV",
+            "  I\tV\nvi  IV  ",
+            "I -, V | vi,IV",
+        ] {
+            assert_eq!(
+                parse_progression(progression),
+                Ok(expected.clone()),
+                "{progression}"
+            );
+        }
+        assert_eq!(parse_progression(""), Ok(vec![]));
+        assert_eq!(parse_progression(" | - "), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_error_positions() {
+        let table = [
+            ("I V X", "X", 4),
+            ("IX", "IX", 0),
+            ("I - V - vi - IIII", "IIII", 13),
+            ("| I | V7/ix |", "V7/ix", 6),
+            // Positions count characters, not bytes
+            ("vii° ° I", "°", 5),
+            ("I,V,vi,Iv", "Iv", 7),
+        ];
+
+        for (progression, numeral, position) in table {
+            assert_eq!(
+                parse_progression(progression),
+                Err(Error::InvalidProgressionNumeral {
+                    numeral: numeral.to_string(),
+                    position
+                }),
+                "{progression}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_progression_in() {
+        let progression =
+            parse_progression_in(&major_scale(C4).unwrap(), "I - V/V - V7 - I").unwrap();
+        let chords = progression.chords();
+        assert_eq!(chords[0].notes(), &[C4, E4, G4]);
+        assert_eq!(chords[1].notes(), &[D5, FSHARP5, A5]);
+        assert_eq!(chords[2].notes(), &[G4, B4, D5, F5]);
+
+        // Accidentals are relative to the degrees of the key
+        let progression =
+            parse_progression_in(&natural_minor_scale(C4).unwrap(), "i VII #iv° vii°7/V").unwrap();
+        let chords = progression.chords();
+        assert_eq!(chords[1].notes(), &[ASHARP4, D5, F5]);
+        assert_eq!(chords[2].notes(), &[FSHARP4, A4, C5]);
+        assert_eq!(chords[3].notes(), &[FSHARP5, A5, C6, DSHARP6]);
+
+        let error = parse_progression_in(&major_scale(G8).unwrap(), "I V/V").unwrap_err();
+        assert_eq!(error, Error::NoteOutOfRange(D9));
+    }
+}
@@ -0,0 +1,328 @@
+use crate::constants::*;
+use crate::{Note, Scale, ScaleQuality};
+use std::ops::RangeInclusive;
+
+/// Represents one of the four voices of a chorale
+///
+/// The voices are listed from the highest to the lowest, which is also the order
+/// of the notes of a chord given to `validate_satb`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Voice {
+    Soprano,
+    Alto,
+    Tenor,
+    Bass,
+}
+
+impl Voice {
+    /// All the voices, from the highest to the lowest
+    pub const ALL: [Voice; 4] = [Voice::Soprano, Voice::Alto, Voice::Tenor, Voice::Bass];
+
+    /// Returns the comfortable range of the voice
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(Voice::Tenor.range(), TENOR_RANGE);
+    /// ```
+    pub const fn range(&self) -> RangeInclusive<Note> {
+        match self {
+            Voice::Soprano => SOPRANO_RANGE,
+            Voice::Alto => ALTO_RANGE,
+            Voice::Tenor => TENOR_RANGE,
+            Voice::Bass => BASS_RANGE,
+        }
+    }
+
+    /// Returns `true` for the soprano and the bass
+    const fn is_outer(&self) -> bool {
+        matches!(self, Voice::Soprano | Voice::Bass)
+    }
+}
+
+/// The part-writing rules checked by `validate_satb`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum PartWritingRule {
+    /// A voice is outside of its range
+    Range,
+    /// A voice is below the next lower voice
+    VoiceCrossing,
+    /// Two adjacent upper voices are more than an octave apart
+    Spacing,
+    /// Two voices move in the same direction from a perfect fifth to a perfect fifth
+    ParallelFifths,
+    /// Two voices move in the same direction from an octave or unison to an octave or unison
+    ParallelOctaves,
+    /// The leading tone of the key does not move up to the tonic
+    UnresolvedLeadingTone,
+}
+
+/// A breach of a part-writing rule
+///
+/// The chord index is the position of the offending chord; for the rules that
+/// involve two consecutive chords, it is the position of the second one.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Violation {
+    pub chord: usize,
+    pub voices: Vec<Voice>,
+    pub rule: PartWritingRule,
+}
+
+impl Violation {
+    fn new(chord: usize, voices: &[Voice], rule: PartWritingRule) -> Self {
+        Self {
+            chord,
+            voices: voices.to_vec(),
+            rule,
+        }
+    }
+}
+
+/// Checks a four-part chorale against the common part-writing rules
+///
+/// Each chord lists the notes of the soprano, alto, tenor and bass, in this order.
+/// The following rules are checked:
+/// - every voice stays within its range (see `Voice::range`)
+/// - no voice is below the next lower voice
+/// - the soprano and the alto, and the alto and the tenor, are at most an octave apart
+/// - no two voices move in the same direction in consecutive perfect fifths or octaves
+/// - the leading tone of the key moves up to the tonic; in the alto and the tenor
+///   it may instead fall to the fifth of the key
+///
+/// # Arguments
+/// * `chords` - The chords of the chorale, each from the soprano down to the bass
+/// * `key` - The key giving the leading tone and the tonic
+///
+/// # Returns
+/// The violations, ordered by chord
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let c_major = major_scale(C4).unwrap();
+/// let phrase = [[E4, C4, G3, C3], [G4, D4, B3, G2], [G4, E4, C4, C3]];
+/// assert!(validate_satb(&phrase, &c_major).is_empty());
+///
+/// let fifths = [[G4, E4, C4, C3], [A4, F4, C4, D3]];
+/// let violations = validate_satb(&fifths, &c_major);
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(violations[0].rule, PartWritingRule::ParallelFifths);
+/// assert_eq!(violations[0].voices, vec![Voice::Soprano, Voice::Bass]);
+/// ```
+pub fn validate_satb<Q>(chords: &[[Note; 4]], key: &Scale<Q, 8>) -> Vec<Violation>
+where
+    Q: ScaleQuality,
+{
+    let mut violations = Vec::new();
+
+    for (index, chord) in chords.iter().enumerate() {
+        check_chord(index, chord, &mut violations);
+        if index > 0 {
+            check_motion(index, &chords[index - 1], chord, key, &mut violations);
+        }
+    }
+
+    violations
+}
+
+/// Checks the rules that apply to a single chord
+fn check_chord(index: usize, chord: &[Note; 4], violations: &mut Vec<Violation>) {
+    for (voice, note) in Voice::ALL.iter().zip(chord) {
+        if !voice.range().contains(note) {
+            violations.push(Violation::new(index, &[*voice], PartWritingRule::Range));
+        }
+    }
+
+    for upper in 0..3 {
+        let voices = [Voice::ALL[upper], Voice::ALL[upper + 1]];
+        let (high, low) = (chord[upper], chord[upper + 1]);
+
+        if high < low {
+            violations.push(Violation::new(
+                index,
+                &voices,
+                PartWritingRule::VoiceCrossing,
+            ));
+        }
+        if voices[1] != Voice::Bass && low.distance_to(high) > SEMITONES_IN_OCTAVE {
+            violations.push(Violation::new(index, &voices, PartWritingRule::Spacing));
+        }
+    }
+}
+
+/// Checks the rules that apply to the motion from one chord to the next
+fn check_motion<Q>(
+    index: usize,
+    previous: &[Note; 4],
+    chord: &[Note; 4],
+    key: &Scale<Q, 8>,
+    violations: &mut Vec<Violation>,
+) where
+    Q: ScaleQuality,
+{
+    for upper in 0..4 {
+        for lower in upper + 1..4 {
+            let rule = match parallel_interval(
+                (previous[upper], previous[lower]),
+                (chord[upper], chord[lower]),
+            ) {
+                Some(0) => PartWritingRule::ParallelOctaves,
+                Some(7) => PartWritingRule::ParallelFifths,
+                _ => continue,
+            };
+            let voices = [Voice::ALL[upper], Voice::ALL[lower]];
+            violations.push(Violation::new(index, &voices, rule));
+        }
+    }
+
+    let tonic = key.root().pitch_class();
+    let leading_tone = (tonic + SEMITONES_IN_OCTAVE - 1) % SEMITONES_IN_OCTAVE;
+    let dominant = (tonic + PERFECT_FIFTH.semitones()) % SEMITONES_IN_OCTAVE;
+
+    for (voice, (&from, &to)) in Voice::ALL.iter().zip(previous.iter().zip(chord)) {
+        if from.pitch_class() != leading_tone || from == to {
+            continue;
+        }
+
+        let resolved = to.midi_number() == from.midi_number() + 1;
+        let frustrated = !voice.is_outer() && to < from && to.pitch_class() == dominant;
+        if !resolved && !frustrated {
+            violations.push(Violation::new(
+                index,
+                &[*voice],
+                PartWritingRule::UnresolvedLeadingTone,
+            ));
+        }
+    }
+}
+
+/// Returns the interval, reduced to an octave, that two voices keep while moving in the same direction
+fn parallel_interval(previous: (Note, Note), next: (Note, Note)) -> Option<u8> {
+    let interval = |(high, low): (Note, Note)| low.distance_to(high) % SEMITONES_IN_OCTAVE;
+    let same_direction = previous.0.cmp(&next.0) == previous.1.cmp(&next.1);
+    let moving = previous.0 != next.0;
+
+    (moving && same_direction && interval(previous) == interval(next)).then(|| interval(next))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::major_scale;
+
+    /// A textbook I - IV - V - I phrase in C major
+    const PHRASE: [[Note; 4]; 4] = [
+        [C5, G4, E4, C3],
+        [C5, A4, F4, F3],
+        [B4, G4, D4, G3],
+        [C5, G4, E4, C3],
+    ];
+
+    fn check(chords: &[[Note; 4]]) -> Vec<Violation> {
+        validate_satb(chords, &major_scale(C4).unwrap())
+    }
+
+    fn violation(chord: usize, voices: &[Voice], rule: PartWritingRule) -> Vec<Violation> {
+        vec![Violation::new(chord, voices, rule)]
+    }
+
+    #[test]
+    fn test_correct_phrase() {
+        assert_eq!(check(&PHRASE), vec![]);
+    }
+
+    #[test]
+    fn test_range() {
+        let mut chords = PHRASE;
+        chords[0][3] = C2;
+        assert_eq!(
+            check(&chords),
+            violation(0, &[Voice::Bass], PartWritingRule::Range)
+        );
+    }
+
+    #[test]
+    fn test_voice_crossing() {
+        let mut chords = PHRASE;
+        chords[0] = [C5, E4, G4, C3];
+        assert_eq!(
+            check(&chords),
+            violation(
+                0,
+                &[Voice::Alto, Voice::Tenor],
+                PartWritingRule::VoiceCrossing
+            )
+        );
+    }
+
+    #[test]
+    fn test_spacing() {
+        let mut chords = PHRASE;
+        chords[0] = [E5, C4, G3, C3];
+        assert_eq!(
+            check(&chords),
+            violation(0, &[Voice::Soprano, Voice::Alto], PartWritingRule::Spacing)
+        );
+    }
+
+    #[test]
+    fn test_parallel_fifths() {
+        let mut chords = PHRASE;
+        chords[1] = [C5, A4, C4, F3];
+        assert_eq!(
+            check(&chords),
+            violation(
+                2,
+                &[Voice::Tenor, Voice::Bass],
+                PartWritingRule::ParallelFifths
+            )
+        );
+    }
+
+    #[test]
+    fn test_parallel_octaves() {
+        let chords = [
+            [E4, C4, G3, C3],
+            [F4, C4, A3, F3],
+            [G4, B3, G3, G3],
+            [E4, C4, G3, C3],
+        ];
+        assert_eq!(
+            check(&chords),
+            violation(
+                2,
+                &[Voice::Soprano, Voice::Bass],
+                PartWritingRule::ParallelOctaves
+            )
+        );
+    }
+
+    #[test]
+    fn test_unresolved_leading_tone() {
+        let mut chords = PHRASE;
+        chords[3] = [G4, E4, C4, C3];
+        assert_eq!(
+            check(&chords),
+            violation(3, &[Voice::Soprano], PartWritingRule::UnresolvedLeadingTone)
+        );
+    }
+
+    #[test]
+    fn test_leading_tone_may_fall_in_inner_voices() {
+        // The alto leading tone falls to the fifth of the key
+        let chords = [[D5, B4, G4, G3], [C5, G4, E4, C3]];
+        assert_eq!(check(&chords), vec![]);
+    }
+
+    #[test]
+    fn test_contrary_and_oblique_motion_are_allowed() {
+        // Fifths by contrary motion between the outer voices, and an octave
+        // becoming a fifth over a held note
+        let chords = [[G4, C4, E3, C3], [C5, C4, A3, F2]];
+        assert_eq!(check(&chords), vec![]);
+    }
+}
@@ -0,0 +1,256 @@
+use crate::chords::{degree_offsets, pitch_class_offset};
+use crate::constants::SEMITONES_IN_OCTAVE;
+use crate::{Chord, ChordQuality, Note, PitchClassMask, Scale, ScaleQuality};
+
+/// The reharmonization techniques suggested by `substitutions_for`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum SubstitutionKind {
+    /// A dominant chord replaced by the dominant a tritone away, which shares its third and seventh
+    TritoneSub,
+    /// A major chord replaced by the minor chord a minor third below
+    RelativeMinor,
+    /// A minor chord replaced by the major chord a minor third above
+    RelativeMajor,
+    /// A chord preceded, or replaced, by its own dominant
+    SecondaryDominant,
+    /// A dominant chord replaced by the diminished chord built on its third
+    DiminishedPassing,
+}
+
+/// A chord suggested in place of another one
+#[derive(Debug)]
+pub struct Substitution<const N: usize> {
+    pub kind: SubstitutionKind,
+    pub chord: Chord<N>,
+    /// The notes of the suggested chord whose pitch classes are also in the original chord
+    pub shared_tones: Vec<Note>,
+}
+
+/// Suggests chords that can replace a chord in a key
+///
+/// The suggestions depend on the function of the chord in the key:
+/// - dominant chords (a dominant seventh, or the major triad on the fifth degree)
+///   get a tritone substitution and a diminished chord on their third
+/// - major and minor chords on a degree of the key get their relative minor or major,
+///   as long as it is also on a degree of the key
+/// - chords on a degree other than the tonic, that are not diminished, get their
+///   secondary dominant
+///
+/// The suggested chords have as many notes as the original one, so triads are
+/// replaced by triads and seventh chords by seventh chords. Chords of other
+/// sizes, or whose root is outside of the key, get few or no suggestions.
+///
+/// # Arguments
+/// * `chord` - The chord to replace
+/// * `key` - The key giving the function of the chord
+///
+/// # Returns
+/// The suggestions, each with the tones it shares with the original chord
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let c_major = major_scale(C4).unwrap();
+/// let subs = substitutions_for(&dominant_seventh(G3).unwrap(), &c_major);
+///
+/// let tritone = subs.iter().find(|s| s.kind == SubstitutionKind::TritoneSub).unwrap();
+/// assert_eq!(tritone.chord, dominant_seventh(CSHARP3).unwrap());
+/// assert_eq!(tritone.shared_tones, vec![F3, B3]);
+/// ```
+pub fn substitutions_for<Q, const N: usize>(
+    chord: &Chord<N>,
+    key: &Scale<Q, 8>,
+) -> Vec<Substitution<N>>
+where
+    Q: ScaleQuality,
+{
+    let degrees = degree_offsets(key);
+    let offset = pitch_class_offset(key, chord.root().midi_number());
+    let degree = degrees.iter().position(|&d| d == offset);
+    let in_key = |semitones: i16| {
+        let offset =
+            (i16::from(offset) + semitones).rem_euclid(i16::from(SEMITONES_IN_OCTAVE)) as u8;
+        degrees.contains(&offset)
+    };
+
+    let quality = chord.quality();
+    let dominant = quality == ChordQuality::DominantSeventh
+        || (quality == ChordQuality::MajorTriad && degree == Some(4));
+
+    let mut candidates: Vec<(SubstitutionKind, i16, &[ChordQuality])> = Vec::new();
+    if dominant {
+        candidates.push((SubstitutionKind::TritoneSub, -6, &DOMINANT));
+        candidates.push((SubstitutionKind::DiminishedPassing, 4, &PASSING));
+    }
+    if degree.is_some() {
+        if !dominant && MAJOR.contains(&quality) && in_key(-3) {
+            candidates.push((SubstitutionKind::RelativeMinor, -3, &MINOR));
+        }
+        if MINOR.contains(&quality) && in_key(3) {
+            candidates.push((SubstitutionKind::RelativeMajor, 3, &MAJOR));
+        }
+    }
+    if degree.is_some_and(|degree| degree != 0) && !DIMINISHED.contains(&quality) {
+        candidates.push((SubstitutionKind::SecondaryDominant, 7, &DOMINANT));
+    }
+
+    let original = PitchClassMask::from_notes(chord.notes());
+    candidates
+        .into_iter()
+        .flat_map(|(kind, semitones, qualities)| {
+            let root = chord.root().checked_add_semitones(semitones);
+            qualities.iter().filter_map(move |quality| {
+                let chord = Chord::<N>::from_intervals(root?, quality.intervals())?;
+                let shared_tones = chord
+                    .notes()
+                    .iter()
+                    .copied()
+                    .filter(|note| original.contains(*note))
+                    .collect();
+                Some(Substitution {
+                    kind,
+                    chord,
+                    shared_tones,
+                })
+            })
+        })
+        .collect()
+}
+
+/// The dominant chords, as a triad and as a seventh chord
+const DOMINANT: [ChordQuality; 2] = [ChordQuality::MajorTriad, ChordQuality::DominantSeventh];
+
+/// The passing diminished chords, as a triad and as a seventh chord
+const PASSING: [ChordQuality; 2] = [
+    ChordQuality::DiminishedTriad,
+    ChordQuality::DiminishedSeventh,
+];
+
+/// The diminished chords, which have no dominant of their own
+const DIMINISHED: [ChordQuality; 3] = [
+    ChordQuality::DiminishedTriad,
+    ChordQuality::DiminishedSeventh,
+    ChordQuality::HalfDiminishedSeventh,
+];
+
+/// The major chords that have a relative minor, and that a minor chord may become
+const MAJOR: [ChordQuality; 3] = [
+    ChordQuality::MajorTriad,
+    ChordQuality::MajorSixth,
+    ChordQuality::MajorSeventh,
+];
+
+/// The minor chords that have a relative major, and that a major chord may become
+const MINOR: [ChordQuality; 2] = [ChordQuality::MinorTriad, ChordQuality::MinorSeventh];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::*;
+
+    fn find<const N: usize>(
+        subs: &[Substitution<N>],
+        kind: SubstitutionKind,
+        chord: Chord<N>,
+    ) -> &Substitution<N> {
+        subs.iter()
+            .find(|sub| sub.kind == kind && sub.chord == chord)
+            .unwrap_or_else(|| panic!("missing {kind:?} substitution {chord}"))
+    }
+
+    #[test]
+    fn test_dominant_substitutions() {
+        let subs = substitutions_for(&dominant_seventh(G3).unwrap(), &major_scale(C4).unwrap());
+
+        let tritone = find(
+            &subs,
+            SubstitutionKind::TritoneSub,
+            dominant_seventh(CSHARP3).unwrap(),
+        );
+        assert_eq!(tritone.shared_tones.len(), 2);
+
+        let passing = find(
+            &subs,
+            SubstitutionKind::DiminishedPassing,
+            diminished_seventh(B3).unwrap(),
+        );
+        assert_eq!(passing.shared_tones, vec![B3, D4, F4]);
+
+        find(
+            &subs,
+            SubstitutionKind::SecondaryDominant,
+            dominant_seventh(D4).unwrap(),
+        );
+        assert_eq!(subs.len(), 3);
+    }
+
+    #[test]
+    fn test_supertonic_substitutions() {
+        let subs = substitutions_for(&minor_seventh(D4).unwrap(), &major_scale(C4).unwrap());
+
+        let sixth = find(
+            &subs,
+            SubstitutionKind::RelativeMajor,
+            major_sixth(F4).unwrap(),
+        );
+        assert_eq!(sixth.shared_tones.len(), 4);
+        let seventh = find(
+            &subs,
+            SubstitutionKind::RelativeMajor,
+            major_seventh(F4).unwrap(),
+        );
+        assert_eq!(seventh.shared_tones, vec![F4, A4, C5]);
+
+        let secondary = find(
+            &subs,
+            SubstitutionKind::SecondaryDominant,
+            dominant_seventh(A4).unwrap(),
+        );
+        assert_eq!(secondary.shared_tones, vec![A4]);
+    }
+
+    #[test]
+    fn test_tonic_substitutions() {
+        let subs = substitutions_for(&major_seventh(C4).unwrap(), &major_scale(C4).unwrap());
+
+        let relative = find(
+            &subs,
+            SubstitutionKind::RelativeMinor,
+            minor_seventh(A3).unwrap(),
+        );
+        assert_eq!(relative.shared_tones, vec![C4, E4, G4]);
+        assert_eq!(subs.len(), 1);
+    }
+
+    #[test]
+    fn test_triads_get_triads() {
+        let subs = substitutions_for(&minor_triad(D4).unwrap(), &major_scale(C4).unwrap());
+        let kinds: Vec<_> = subs
+            .iter()
+            .map(|sub| (sub.kind, sub.chord.quality()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (SubstitutionKind::RelativeMajor, ChordQuality::MajorTriad),
+                (
+                    SubstitutionKind::SecondaryDominant,
+                    ChordQuality::MajorTriad
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chords_outside_the_key() {
+        assert!(
+            substitutions_for(&minor_triad(DSHARP4).unwrap(), &major_scale(C4).unwrap()).is_empty()
+        );
+        assert!(
+            substitutions_for(&diminished_triad(B3).unwrap(), &major_scale(C4).unwrap()).is_empty()
+        );
+    }
+}
@@ -0,0 +1,517 @@
+use super::chord::chord_suffix;
+use crate::constants::*;
+use crate::{
+    Chord, ChordQuality, Error, Interval, NamedInterval, Note, NoteSlice, PitchClassMask,
+    SpelledNote, SpellingPreference, Voicing,
+};
+use std::cmp::Reverse;
+use std::str::FromStr;
+
+impl<const N: usize> Chord<N> {
+    /// Returns the chord symbol, the name of the root followed by the suffix of the quality
+    ///
+    /// The root is spelled without its octave, with sharps or flats when it is
+    /// on a black key. The symbol names the quality the chord was built with, so
+    /// unlike `Voicing::symbol` it is always found; it is an `Option` for the two
+    /// to be used alike.
+    ///
+    /// # Arguments
+    /// * `spelling` - The accidental used if the root is a black key
+    ///
+    /// # Returns
+    /// The chord symbol, such as `"Cmaj7"` or `"Dbm"`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let chord = major_seventh(CSHARP4).unwrap();
+    /// assert_eq!(chord.symbol(SpellingPreference::Flats), Some("Dbmaj7".to_string()));
+    /// assert_eq!(chord.symbol(SpellingPreference::Sharps), Some("C#maj7".to_string()));
+    /// ```
+    pub fn symbol(&self, spelling: SpellingPreference) -> Option<String> {
+        let root = SpelledNote::spell(self.root(), spelling);
+        Some(format!("{root}{}", symbol_suffix(self.quality())))
+    }
+
+    /// Returns the names of the notes of the chord, spelled as chord tones
+    ///
+    /// Each note is named on the letter of its degree above the root, so the
+    /// third of the chord is a third above the root, the fifth a fifth above,
+    /// and so on: an Eb major triad is Eb, G and Bb, not D#, G and A#. Of the
+    /// spellings of the root, the one giving the fewest accidentals is chosen,
+    /// sharps winning ties.
+    ///
+    /// # Returns
+    /// The names of the notes of the chord, without the octave, from the root
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(major_triad(DSHARP4).unwrap().spell(), vec!["Eb", "G", "Bb"]);
+    /// assert_eq!(diminished_seventh(B3).unwrap().spell(), vec!["B", "D", "F", "Ab"]);
+    /// ```
+    pub fn spell(&self) -> Vec<String> {
+        SpelledNote::simple_spellings(self.root())
+            .into_iter()
+            .filter_map(|root| self.spell_from(root))
+            .min_by_key(|names| {
+                names
+                    .iter()
+                    .map(|note| note.alteration().unsigned_abs())
+                    .sum::<u8>()
+            })
+            .unwrap_or_default()
+            .iter()
+            .map(SpelledNote::to_string)
+            .collect()
+    }
+
+    /// Returns the names of the notes of the chord from a spelling of the root
+    ///
+    /// # Returns
+    /// The names of the notes, or `None` if a note would need more than a double accidental
+    fn spell_from(&self, root: SpelledNote) -> Option<Vec<SpelledNote>> {
+        self.notes()
+            .iter()
+            .map(|&note| {
+                let semitones =
+                    (note.midi_number() - self.root().midi_number()) % SEMITONES_IN_OCTAVE;
+                let number = letter_steps(self.quality(), semitones) + 1;
+                let interval = NamedInterval::with_semitones(number, semitones)?;
+                root.at_interval(interval).ok()
+            })
+            .collect()
+    }
+}
+
+/// The suffixes accepted when parsing a chord symbol besides those of `symbol_suffix`
+const SUFFIX_ALIASES: [(&str, ChordQuality); 3] = [
+    ("hdim7", ChordQuality::HalfDiminishedSeventh),
+    ("ø7", ChordQuality::HalfDiminishedSeventh),
+    ("ø", ChordQuality::HalfDiminishedSeventh),
+];
+
+/// The altered tensions that may follow the suffix of a chord symbol, with their interval from the root
+const ALTERATIONS: [(&str, Interval); 4] = [
+    ("b9", MINOR_NINTH),
+    ("#9", MINOR_TENTH),
+    ("#11", AUGMENTED_ELEVENTH),
+    ("b13", MINOR_THIRTEENTH),
+];
+
+/// Returns the suffix of a quality in a chord symbol
+///
+/// It is the suffix of `chord_suffix`, except for the half-diminished seventh,
+/// written `m7b5` as on lead sheets.
+fn symbol_suffix(quality: ChordQuality) -> &'static str {
+    match quality {
+        ChordQuality::HalfDiminishedSeventh => "m7b5",
+        _ => chord_suffix(quality),
+    }
+}
+
+/// Returns the number of letters between the root of a chord and one of its notes
+///
+/// # Arguments
+/// * `quality` - The quality of the chord
+/// * `semitones` - The distance from the root to the note, within an octave
+fn letter_steps(quality: ChordQuality, semitones: u8) -> u8 {
+    match semitones {
+        0 => 0,
+        1 | 2 => 1,
+        3 | 4 => 2,
+        5 => 3,
+        // The diminished and augmented fifths
+        6..=8 => 4,
+        // The diminished seventh of a diminished seventh chord
+        9 if quality == ChordQuality::DiminishedSeventh => 6,
+        9 => 5,
+        _ => 6,
+    }
+}
+
+impl Voicing {
+    /// Returns the chord symbol naming the notes of the voicing
+    ///
+    /// The notes are compared as pitch classes. Each note is tried as the root,
+    /// from the bass up, and the first one whose pitch classes are exactly those
+    /// of a chord quality names the chord. When two qualities have the same
+    /// pitch classes, such as `9` and `7/9`, the shorter suffix is used.
+    ///
+    /// A bass other than the root is written after a slash: as an inversion when
+    /// the bass is a chord tone, as in `C/E`, or as a slash chord over a foreign
+    /// bass, as in `C/D`, when the notes above the bass form a chord on their own.
+    ///
+    /// # Arguments
+    /// * `spelling` - The accidental used for the roots and basses on black keys
+    ///
+    /// # Returns
+    /// The chord symbol, or `None` if the notes match no chord quality
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let sharps = SpellingPreference::Sharps;
+    /// assert_eq!(Voicing::new([G3, B3, D4, F4]).symbol(sharps), Some("G7".to_string()));
+    /// assert_eq!(Voicing::new([E3, G3, C4]).symbol(sharps), Some("C/E".to_string()));
+    /// assert_eq!(Voicing::new([D3, C4, E4, G4]).symbol(sharps), Some("C/D".to_string()));
+    /// assert_eq!(Voicing::new([C4, CSHARP4, D4]).symbol(sharps), None);
+    /// ```
+    pub fn symbol(&self, spelling: SpellingPreference) -> Option<String> {
+        let bass = *self.notes().first()?;
+        let (root, quality) = self.chord()?;
+
+        let mut symbol = SpelledNote::spell(root, spelling).to_string();
+        symbol.push_str(symbol_suffix(quality));
+        if root.pitch_class() != bass.pitch_class() {
+            symbol.push('/');
+            symbol.push_str(&SpelledNote::spell(bass, spelling).to_string());
+        }
+        Some(symbol)
+    }
+
+    /// Returns the root and the quality of the chord played by the voicing
+    ///
+    /// The notes are matched as for `symbol`: all of them first, then those
+    /// above the bass when the bass is foreign to the chord.
+    ///
+    /// # Returns
+    /// The root, at its lowest place in the voicing, and the quality, or `None`
+    /// if the notes match no chord quality
+    pub(crate) fn chord(&self) -> Option<(Note, ChordQuality)> {
+        let bass = *self.notes().first()?;
+        let roots = self.notes().unique_pitch_classes();
+        identify(&roots, PitchClassMask::from_notes(self.notes())).or_else(|| {
+            let upper: Vec<_> = roots
+                .into_iter()
+                .filter(|&note| note.pitch_class() != bass.pitch_class())
+                .collect();
+            identify(&upper, PitchClassMask::from_notes(&upper))
+        })
+    }
+
+    /// Returns the chord symbol, or the notes when they match no chord quality
+    ///
+    /// # Arguments
+    /// * `spelling` - The accidental used for the notes on black keys
+    ///
+    /// # Returns
+    /// The chord symbol, see `symbol`, or the spelled notes with their octave, from the lowest
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let cluster = Voicing::new([C4, CSHARP4, D4]);
+    /// assert_eq!(cluster.symbol_or_pitches(SpellingPreference::Flats), "C4 Db4 D4");
+    /// ```
+    pub fn symbol_or_pitches(&self, spelling: SpellingPreference) -> String {
+        self.symbol(spelling).unwrap_or_else(|| {
+            self.notes()
+                .iter()
+                .map(|&note| {
+                    let octave = i16::from(note.midi_number() / SEMITONES_IN_OCTAVE) - 1;
+                    format!("{}{octave}", SpelledNote::spell(note, spelling))
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    }
+}
+
+/// Parses a chord symbol, the inverse of `Voicing::symbol`
+///
+/// The root is a letter with any sharps (`#`) or flats (`b`), followed by the
+/// suffix of a chord quality, such as `m7` or `6/9`, then by any altered
+/// tensions (`b9`, `#9`, `#11` and `b13`), and optionally by a slash and a bass
+/// note. The half-diminished seventh is written `m7b5`, `hdim7`, `ø7` or `ø`.
+/// The root is placed in the fourth octave with the chord above it, and the
+/// bass, if any, just below the root.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let voicing: Voicing = "Ebmaj7".parse().unwrap();
+/// assert_eq!(voicing.notes(), &[DSHARP4, G4, ASHARP4, D5]);
+/// let voicing: Voicing = "C/E".parse().unwrap();
+/// assert_eq!(voicing.notes(), &[E3, C4, E4, G4]);
+/// let voicing: Voicing = "C7#9".parse().unwrap();
+/// assert_eq!(voicing.notes(), &[C4, E4, G4, ASHARP4, DSHARP5]);
+/// assert_eq!(
+///     "Cmaj8".parse::<Voicing>(),
+///     Err(Error::InvalidChordSymbol("Cmaj8".to_string()))
+/// );
+/// ```
+impl FromStr for Voicing {
+    type Err = Error;
+
+    fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidChordSymbol(symbol.to_string());
+
+        let accidentals = symbol
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c != '#' && c != 'b')
+            .map_or(symbol.len(), |(index, _)| index);
+        let (root, rest) = symbol.split_at(accidentals);
+        let root: SpelledNote = root.parse().map_err(|_| invalid())?;
+        let root = Note::new(C4.midi_number() + root.pitch_class());
+
+        // The longest suffixes first, so that `6/9` is not read as `6` over a bass
+        let mut suffixes: Vec<_> = ChordQuality::ALL
+            .into_iter()
+            .map(|quality| (symbol_suffix(quality), quality))
+            .chain(SUFFIX_ALIASES)
+            .collect();
+        suffixes.sort_by_key(|&(suffix, _)| Reverse(suffix.len()));
+
+        let (quality, alterations, bass) = suffixes
+            .into_iter()
+            .find_map(|(suffix, quality)| {
+                let mut rest = rest.strip_prefix(suffix)?;
+                let mut alterations = Vec::new();
+                while let Some((tension, interval)) = ALTERATIONS
+                    .into_iter()
+                    .find_map(|(name, interval)| Some((rest.strip_prefix(name)?, interval)))
+                {
+                    alterations.push(interval);
+                    rest = tension;
+                }
+                if rest.is_empty() {
+                    return Some((quality, alterations, None));
+                }
+                let bass: SpelledNote = rest.strip_prefix('/')?.parse().ok()?;
+                Some((quality, alterations, Some(bass)))
+            })
+            .ok_or_else(invalid)?;
+
+        let mut notes: Vec<_> = root
+            .into_notes_from_intervals(quality.intervals().iter().copied())
+            .collect();
+        for interval in alterations {
+            let tension = root + interval;
+            if !notes.contains(&tension) {
+                notes.push(tension);
+            }
+        }
+        if let Some(bass) = bass {
+            let below = (root.pitch_class() + SEMITONES_IN_OCTAVE - bass.pitch_class())
+                % SEMITONES_IN_OCTAVE;
+            if below != 0 {
+                notes.push(Note::new(root.midi_number() - below));
+            }
+        }
+
+        Ok(Voicing::new(notes))
+    }
+}
+
+/// Returns the first root, with its quality, whose chord has exactly the pitch classes of a mask
+///
+/// When several qualities match on the same root, the one with the shortest suffix wins.
+fn identify(roots: &[Note], mask: PitchClassMask) -> Option<(Note, ChordQuality)> {
+    roots.iter().find_map(|&root| {
+        ChordQuality::ALL
+            .into_iter()
+            .filter(|quality| {
+                // Built in the lowest octave, as a chord on a high root would leave the MIDI range
+                let lowest = Note::new(root.pitch_class());
+                let notes = lowest.into_notes_from_intervals(quality.intervals().iter().copied());
+                notes.collect::<PitchClassMask>() == mask
+            })
+            .min_by_key(|&quality| chord_suffix(quality).len())
+            .map(|quality| (root, quality))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        augmented_triad, diminished_seventh, diminished_triad, dominant_seventh,
+        dominant_seventh_ninth, half_diminished_seventh, major_sixth, major_triad, minor_triad,
+        pitch_range, sus4,
+    };
+
+    const SHARPS: SpellingPreference = SpellingPreference::Sharps;
+    const FLATS: SpellingPreference = SpellingPreference::Flats;
+
+    /// Returns the close-position voicing of a quality on a root
+    fn chord(root: Note, quality: ChordQuality) -> Voicing {
+        Voicing::new(root.into_notes_from_intervals(quality.intervals().iter().copied()))
+    }
+
+    #[test]
+    fn test_every_quality_on_every_root() {
+        for quality in ChordQuality::ALL {
+            let expected = match quality {
+                ChordQuality::DominantSeventhNinth => ChordQuality::DominantNinth,
+                ChordQuality::MinorSeventhNinth => ChordQuality::MinorNinth,
+                _ => quality,
+            };
+            for root in pitch_range(C4, B4) {
+                let voicing = chord(root, quality);
+                for spelling in [SHARPS, FLATS] {
+                    let name = SpelledNote::spell(root, spelling);
+                    assert_eq!(
+                        voicing.symbol(spelling),
+                        Some(format!("{name}{}", symbol_suffix(expected))),
+                        "{quality:?} on {root:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for quality in ChordQuality::ALL {
+            for root in pitch_range(C4, B4) {
+                let mut voicing = chord(root, quality);
+                for _ in 0..voicing.notes().len() {
+                    for spelling in [SHARPS, FLATS] {
+                        let symbol = voicing.symbol(spelling).unwrap();
+                        let parsed: Voicing = symbol.parse().unwrap();
+                        assert_eq!(
+                            PitchClassMask::from_notes(parsed.notes()),
+                            PitchClassMask::from_notes(voicing.notes()),
+                            "{symbol}"
+                        );
+                        assert_eq!(
+                            parsed.notes()[0].pitch_class(),
+                            voicing.notes()[0].pitch_class(),
+                            "{symbol}"
+                        );
+                    }
+                    voicing = voicing.invert().unwrap();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_inversions_and_slash_chords() {
+        assert_eq!(
+            Voicing::new([F3, A3, C4, D4]).symbol(SHARPS),
+            Some("F6".to_string())
+        );
+        assert_eq!(
+            Voicing::new([D3, F3, A3, C4]).symbol(SHARPS),
+            Some("Dm7".to_string())
+        );
+        // The same notes as F6, but D comes before F from the bass up
+        assert_eq!(
+            Voicing::new([A3, C4, D4, F4]).symbol(SHARPS),
+            Some("Dm7/A".to_string())
+        );
+        assert_eq!(
+            Voicing::new([GSHARP2, C4, E4, G4]).symbol(FLATS),
+            Some("C/Ab".to_string())
+        );
+        assert_eq!(
+            Voicing::new([CSHARP4, F4, GSHARP4]).symbol(FLATS),
+            Some("Db".to_string())
+        );
+        assert_eq!(
+            Voicing::from(&half_diminished_seventh(FSHARP3).unwrap()).symbol(SHARPS),
+            Some("F#m7b5".to_string())
+        );
+        assert_eq!(
+            Voicing::new([G3, C4, D4]).symbol(SHARPS),
+            Some("Gsus4".to_string())
+        );
+        // Chords are recognized up to the top of the MIDI range
+        assert_eq!(
+            Voicing::new([C9, E9, G9]).symbol(SHARPS),
+            Some("C".to_string())
+        );
+    }
+
+    #[test]
+    fn test_spell() {
+        assert_eq!(major_triad(DSHARP4).unwrap().spell(), vec!["Eb", "G", "Bb"]);
+        assert_eq!(minor_triad(FSHARP3).unwrap().spell(), vec!["F#", "A", "C#"]);
+        assert_eq!(minor_triad(GFLAT3).unwrap().spell(), vec!["F#", "A", "C#"]);
+        assert_eq!(major_triad(CSHARP4).unwrap().spell(), vec!["Db", "F", "Ab"]);
+        assert_eq!(major_triad(B3).unwrap().spell(), vec!["B", "D#", "F#"]);
+        assert_eq!(minor_triad(GSHARP4).unwrap().spell(), vec!["G#", "B", "D#"]);
+        assert_eq!(augmented_triad(C4).unwrap().spell(), vec!["C", "E", "G#"]);
+        assert_eq!(diminished_triad(E4).unwrap().spell(), vec!["E", "G", "Bb"]);
+        assert_eq!(
+            dominant_seventh(AFLAT3).unwrap().spell(),
+            vec!["Ab", "C", "Eb", "Gb"]
+        );
+        assert_eq!(
+            diminished_seventh(CSHARP4).unwrap().spell(),
+            vec!["C#", "E", "G", "Bb"]
+        );
+        assert_eq!(major_sixth(F4).unwrap().spell(), vec!["F", "A", "C", "D"]);
+        assert_eq!(sus4(BFLAT3).unwrap().spell(), vec!["Bb", "Eb", "F"]);
+        assert_eq!(
+            dominant_seventh_ninth(EFLAT4).unwrap().spell(),
+            vec!["Eb", "G", "Bb", "Db", "F"]
+        );
+    }
+
+    #[test]
+    fn test_unclassifiable() {
+        assert_eq!(Voicing::new(Vec::new()).symbol(SHARPS), None);
+        assert_eq!(Voicing::new([C4, G4]).symbol(SHARPS), None);
+        assert_eq!(Voicing::new([C4]).symbol_or_pitches(SHARPS), "C4");
+        assert_eq!(
+            Voicing::new([C4, CSHARP4, D4, DSHARP4]).symbol_or_pitches(FLATS),
+            "C4 Db4 D4 Eb4"
+        );
+        assert_eq!(
+            Voicing::new([C4, E4, G4]).symbol_or_pitches(FLATS),
+            "C".to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |symbol: &str| {
+            symbol
+                .parse::<Voicing>()
+                .map(|voicing| voicing.notes().to_vec())
+        };
+        assert_eq!(parse("C"), Ok(vec![C4, E4, G4]));
+        assert_eq!(parse("C6/9"), Ok(vec![C4, E4, G4, A4, D5]));
+        assert_eq!(parse("C6/E"), Ok(vec![E3, C4, E4, G4, A4]));
+        assert_eq!(parse("Bbm7"), Ok(vec![ASHARP4, CSHARP5, F5, GSHARP5]));
+        assert_eq!(parse("C/C"), parse("C"));
+        assert_eq!(parse("Cm7b5"), Ok(vec![C4, DSHARP4, FSHARP4, ASHARP4]));
+        for alias in ["Chdim7", "Cø7", "Cø"] {
+            assert_eq!(parse(alias), parse("Cm7b5"), "{alias}");
+        }
+        assert_eq!(parse("C7#9"), Ok(vec![C4, E4, G4, ASHARP4, DSHARP5]));
+        assert_eq!(parse("C7b9/E"), Ok(vec![E3, C4, E4, G4, ASHARP4, CSHARP5]));
+        assert_eq!(
+            parse("Cm7b9b13"),
+            Ok(vec![C4, DSHARP4, G4, ASHARP4, CSHARP5, GSHARP5])
+        );
+
+        for symbol in ["", "H7", "C/", "C/H", "Cmaj", "C7/9/", "c", "C7#", "C7b9#"] {
+            assert_eq!(
+                symbol.parse::<Voicing>(),
+                Err(Error::InvalidChordSymbol(symbol.to_string())),
+                "{symbol}"
+            );
+        }
+        assert_eq!(parse("C7/9/D").unwrap()[0], D3);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{Chord, Note, NoteSlice, PitchClassMask, ScaleLike};
+
+/// Returns the avoid notes of a chord in the context of a scale
+///
+/// An avoid note is a scale tone, not in the chord, a minor ninth above a chord
+/// tone, such as F over a C major seventh in C major. Its clash with the chord
+/// tone a semitone below makes it unsuitable as a sustained extension. Only the
+/// pitch classes are compared, so the octave of the chord and the scale doesn't matter.
+///
+/// # Arguments
+/// * `chord` - The chord being played
+/// * `scale` - The scale or mode the chord is heard in
+///
+/// # Returns
+/// The avoid notes, as notes of the scale in the order of the scale
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(avoid_notes(&major_seventh(C4).unwrap(), &major_scale(C4).unwrap()), vec![F4]);
+/// assert_eq!(avoid_notes(&dominant_seventh(G3).unwrap(), &major_scale(C4).unwrap()), vec![C4]);
+/// ```
+pub fn avoid_notes<const N: usize, S>(chord: &Chord<N>, scale: &S) -> Vec<Note>
+where
+    S: ScaleLike + ?Sized,
+{
+    avoid_notes_over(chord.notes(), scale)
+}
+
+/// Returns the avoid notes of a scale over any set of chord tones, see `avoid_notes`
+pub(crate) fn avoid_notes_over<S>(chord_notes: &[Note], scale: &S) -> Vec<Note>
+where
+    S: ScaleLike + ?Sized,
+{
+    let chord_tones = PitchClassMask::from_notes(chord_notes);
+    let half_step_above = chord_tones.transpose(1);
+
+    scale
+        .pitches()
+        .unique_pitch_classes()
+        .into_iter()
+        .filter(|&note| !chord_tones.contains(note) && half_step_above.contains(note))
+        .collect()
+}
+
+/// Returns the tensions available over a chord in the context of a scale
+///
+/// The available tensions are the scale tones that are neither chord tones nor
+/// avoid notes, so they can be added to the chord as extensions.
+///
+/// # Arguments
+/// * `chord` - The chord being played
+/// * `scale` - The scale or mode the chord is heard in
+///
+/// # Returns
+/// The available tensions, as notes of the scale in the order of the scale
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(available_tensions(&major_seventh(C4).unwrap(), &major_scale(C4).unwrap()), vec![D4, A4]);
+/// ```
+pub fn available_tensions<const N: usize, S>(chord: &Chord<N>, scale: &S) -> Vec<Note>
+where
+    S: ScaleLike + ?Sized,
+{
+    let chord_tones = PitchClassMask::from_notes(chord.notes());
+    let avoid = PitchClassMask::from_notes(&avoid_notes(chord, scale));
+
+    scale
+        .pitches()
+        .unique_pitch_classes()
+        .into_iter()
+        .filter(|&note| !chord_tones.contains(note) && !avoid.contains(note))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::*;
+
+    #[test]
+    fn test_major_seventh_in_ionian() {
+        let cmaj7 = major_seventh(C4).unwrap();
+        let ionian = major_scale(C4).unwrap();
+
+        assert_eq!(avoid_notes(&cmaj7, &ionian), vec![F4]);
+        assert_eq!(available_tensions(&cmaj7, &ionian), vec![D4, A4]);
+    }
+
+    #[test]
+    fn test_major_seventh_in_lydian() {
+        let cmaj7 = major_seventh(C4).unwrap();
+        let lydian = DynScale::from_steps(
+            "lydian",
+            C4,
+            &[WHOLE, WHOLE, WHOLE, HALF, WHOLE, WHOLE, HALF],
+        )
+        .unwrap();
+
+        assert!(avoid_notes(&cmaj7, &lydian).is_empty());
+        assert_eq!(available_tensions(&cmaj7, &lydian), vec![D4, FSHARP4, A4]);
+    }
+
+    #[test]
+    fn test_dominant_seventh_in_major() {
+        let g7 = dominant_seventh(G3).unwrap();
+        let c_major = major_scale(C4).unwrap();
+
+        assert_eq!(avoid_notes(&g7, &c_major), vec![C4]);
+        assert_eq!(available_tensions(&g7, &c_major), vec![E4, A4]);
+        // The octave of the chord doesn't change the result
+        assert_eq!(
+            avoid_notes(&dominant_seventh(G5).unwrap(), &c_major),
+            vec![C4]
+        );
+    }
+
+    #[test]
+    fn test_minor_seventh_in_dorian_and_aeolian() {
+        let dm7 = minor_seventh(D4).unwrap();
+
+        // D dorian has no avoid notes: its E, G and B are all tensions
+        assert!(avoid_notes(&dm7, &major_scale(C4).unwrap()).is_empty());
+        // D aeolian: B flat is a half step above A
+        let aeolian = natural_minor_scale(D4).unwrap();
+        assert_eq!(avoid_notes(&dm7, &aeolian), vec![ASHARP4]);
+        assert_eq!(available_tensions(&dm7, &aeolian), vec![E4, G4]);
+    }
+}
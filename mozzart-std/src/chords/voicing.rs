@@ -0,0 +1,602 @@
+use crate::constants::*;
+use crate::{Chord, Error, Interval, Note};
+use std::ops::RangeInclusive;
+
+/// The drop voicings, named after the voices lowered by an octave, counted from the top
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum DropType {
+    /// The second-highest voice is lowered
+    Drop2,
+    /// The third-highest voice is lowered
+    Drop3,
+    /// The second- and fourth-highest voices are lowered
+    Drop2And4,
+}
+
+impl DropType {
+    /// Returns the positions of the lowered voices, counted from the top (1 is the highest)
+    const fn positions(&self) -> &'static [usize] {
+        match self {
+            DropType::Drop2 => &[2],
+            DropType::Drop3 => &[3],
+            DropType::Drop2And4 => &[2, 4],
+        }
+    }
+}
+
+/// Represents a voicing, the concrete notes used to play a chord
+///
+/// The notes of a voicing are always kept sorted from the lowest to the highest,
+/// so "the second note from the top" is well defined after any transformation.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let voicing = Voicing::from(&major_seventh(C4).unwrap());
+/// assert_eq!(voicing.drop2().unwrap().notes(), &[G3, C4, E4, B4]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<Note>", into = "Vec<Note>")
+)]
+pub struct Voicing {
+    notes: Vec<Note>,
+}
+
+impl Voicing {
+    /// Creates a new `Voicing` from a collection of notes
+    ///
+    /// # Arguments
+    /// * `notes` - The notes of the voicing, in any order
+    ///
+    /// # Returns
+    /// A new `Voicing` with the notes sorted from the lowest to the highest
+    pub fn new(notes: impl IntoIterator<Item = Note>) -> Self {
+        let mut notes: Vec<_> = notes.into_iter().collect();
+        notes.sort();
+        Self { notes }
+    }
+
+    /// Returns the notes of the voicing, from the lowest to the highest
+    pub fn notes(&self) -> &[Note] {
+        &self.notes
+    }
+
+    /// Returns the voicing transposed by a number of semitones
+    ///
+    /// # Arguments
+    /// * `semitones` - The number of semitones to move (negative values move down)
+    ///
+    /// # Returns
+    /// The transposed voicing, or `None` if a note would leave the MIDI range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let voicing = Voicing::new([E3, C4, G4]);
+    /// assert_eq!(voicing.transpose(2).unwrap().notes(), &[FSHARP3, D4, A4]);
+    /// assert_eq!(voicing.transpose(-60), None);
+    /// ```
+    pub fn transpose(&self, semitones: i8) -> Option<Self> {
+        self.notes
+            .iter()
+            .map(|note| note.checked_add_semitones(i16::from(semitones)))
+            .collect::<Option<Vec<_>>>()
+            .map(Self::new)
+    }
+
+    /// Returns the next inversion of the voicing
+    ///
+    /// The lowest note is moved up an octave.
+    ///
+    /// # Returns
+    /// The inverted voicing, or `Error::NoteOutOfRange` if the moved note would go above MIDI 127
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let voicing = Voicing::from(&major_triad(C4).unwrap()).invert().unwrap();
+    /// assert_eq!(voicing.notes(), &[E4, G4, C5]);
+    /// ```
+    pub fn invert(&self) -> Result<Self, Error> {
+        let mut notes = self.notes.clone();
+        if let Some(lowest) = notes.first_mut() {
+            *lowest = octave_up(*lowest)?;
+        }
+
+        Ok(Self::new(notes))
+    }
+
+    /// Returns the drop-2 voicing, lowering the second-highest note by an octave
+    ///
+    /// # Returns
+    /// The new voicing, or an error if the voicing has fewer than two notes or the
+    /// dropped note would go below MIDI 0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let voicing = Voicing::new([C4, E4, G4, B4]);
+    /// assert_eq!(voicing.drop2().unwrap().notes(), &[G3, C4, E4, B4]);
+    ///
+    /// // Dropping again lowers the new second-highest note
+    /// assert_eq!(voicing.drop2().unwrap().drop2().unwrap().notes(), &[E3, G3, C4, B4]);
+    /// ```
+    pub fn drop2(&self) -> Result<Self, Error> {
+        self.drop(DropType::Drop2.positions())
+    }
+
+    /// Returns the drop-3 voicing, lowering the third-highest note by an octave
+    ///
+    /// # Returns
+    /// The new voicing, or an error if the voicing has fewer than three notes or the
+    /// dropped note would go below MIDI 0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let voicing = Voicing::new([C4, E4, G4, B4]);
+    /// assert_eq!(voicing.drop3().unwrap().notes(), &[E3, C4, G4, B4]);
+    /// ```
+    pub fn drop3(&self) -> Result<Self, Error> {
+        self.drop(DropType::Drop3.positions())
+    }
+
+    /// Returns the drop-2-and-4 voicing, lowering the second- and fourth-highest notes by an octave
+    ///
+    /// # Returns
+    /// The new voicing, or an error if the voicing has fewer than four notes or a
+    /// dropped note would go below MIDI 0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let voicing = Voicing::new([C4, E4, G4, B4]);
+    /// assert_eq!(voicing.drop2and4().unwrap().notes(), &[C3, G3, E4, B4]);
+    /// ```
+    pub fn drop2and4(&self) -> Result<Self, Error> {
+        self.drop(DropType::Drop2And4.positions())
+    }
+
+    /// Lowers by an octave the notes at the given positions, counted from the top (1 is the highest)
+    fn drop(&self, positions: &[usize]) -> Result<Self, Error> {
+        let len = self.notes.len();
+        let required = positions.iter().copied().max().unwrap_or_default();
+        if len < required {
+            return Err(Error::NotEnoughNotes {
+                required,
+                found: len,
+            });
+        }
+
+        // Positions refer to the original ordering, so resolve them all before moving anything
+        let mut notes = self.notes.clone();
+        for position in positions {
+            let index = len - position;
+            notes[index] = octave_down(notes[index])?;
+        }
+
+        Ok(Self::new(notes))
+    }
+}
+
+impl<const N: usize> Chord<N> {
+    /// Returns the chord in root position followed by each of its inversions
+    ///
+    /// Each inversion moves the lowest note of the previous one up an octave,
+    /// see `Voicing::invert`. The inversions are voicings rather than chords, as
+    /// the root of a `Chord` is always its lowest note.
+    ///
+    /// # Returns
+    /// One voicing per note of the chord, or `Error::NoteOutOfRange` if an
+    /// inversion would go above MIDI 127
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let inversions = major_triad(C4).unwrap().inversions().unwrap();
+    /// assert_eq!(inversions[1].notes(), &[E4, G4, C5]);
+    /// assert_eq!(inversions[2].notes(), &[G4, C5, E5]);
+    /// assert_eq!(major_triad(G8).unwrap().inversions(), Err(Error::NoteOutOfRange(B8)));
+    /// ```
+    pub fn inversions(&self) -> Result<Vec<Voicing>, Error> {
+        let mut inversions = vec![Voicing::from(self)];
+        while inversions.len() < N {
+            let inversion = inversions[inversions.len() - 1].invert()?;
+            inversions.push(inversion);
+        }
+
+        Ok(inversions)
+    }
+
+    /// Returns a drop voicing of the chord in close position
+    ///
+    /// Drop voicings spread a close-position chord of four or more notes by
+    /// lowering some of its voices by an octave. A chord with fewer notes, or one
+    /// so low that a dropped voice would leave the MIDI range, is returned
+    /// unchanged, in close position.
+    ///
+    /// # Arguments
+    /// * `which` - The voices to lower
+    ///
+    /// # Returns
+    /// The notes of the voicing, from the lowest to the highest
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let cmaj7 = major_seventh(C4).unwrap();
+    /// assert_eq!(cmaj7.drop_voicing(DropType::Drop3).notes(), &[E3, C4, G4, B4]);
+    /// assert_eq!(major_triad(C4).unwrap().drop_voicing(DropType::Drop2).notes(), &[C4, E4, G4]);
+    /// ```
+    pub fn drop_voicing(&self, which: DropType) -> Voicing {
+        let close = Voicing::from(self);
+        if N < 4 {
+            return close;
+        }
+
+        close.drop(which.positions()).unwrap_or(close)
+    }
+
+    /// Returns the drop-2 voicing of the chord, see `drop_voicing`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(major_seventh(C4).unwrap().drop2().notes(), &[G3, C4, E4, B4]);
+    /// ```
+    pub fn drop2(&self) -> Voicing {
+        self.drop_voicing(DropType::Drop2)
+    }
+
+    /// Returns the drop-3 voicing of the chord, see `drop_voicing`
+    pub fn drop3(&self) -> Voicing {
+        self.drop_voicing(DropType::Drop3)
+    }
+
+    /// Returns the drop-2-and-4 voicing of the chord, see `drop_voicing`
+    pub fn drop2and4(&self) -> Voicing {
+        self.drop_voicing(DropType::Drop2And4)
+    }
+
+    /// Returns the chord without one of its tones, as in shell and rootless voicings
+    ///
+    /// The tone is found by its interval above the root, regardless of the
+    /// octave, so `MAJOR_SECOND` also removes the ninth.
+    ///
+    /// # Arguments
+    /// * `interval_from_root` - The interval of the tone to remove, `PERFECT_UNISON` for the root
+    ///
+    /// # Returns
+    /// The other notes of the chord, in close position
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(dominant_ninth(C4).unwrap().without(MAJOR_SECOND).notes(), &[C4, E4, G4, ASHARP4]);
+    /// ```
+    pub fn without(&self, interval_from_root: Interval) -> Voicing {
+        let root = self.root();
+        let omitted = interval_from_root.semitones() % SEMITONES_IN_OCTAVE;
+        Voicing::new(self.notes().iter().copied().filter(|&note| {
+            Interval::from_pitches(root, note).semitones() % SEMITONES_IN_OCTAVE != omitted
+        }))
+    }
+
+    /// Returns the chord without its root, as in rootless voicings
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(minor_ninth(D4).unwrap().omit_root().notes(), &[F4, A4, C5, E5]);
+    /// ```
+    pub fn omit_root(&self) -> Voicing {
+        self.without(PERFECT_UNISON)
+    }
+
+    /// Returns the chord without its fifth, whether perfect, diminished or augmented
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(half_diminished_seventh(B3).unwrap().omit_fifth().notes(), &[B3, D4, A4]);
+    /// ```
+    pub fn omit_fifth(&self) -> Voicing {
+        match self.quality().intervals().get(1) {
+            Some(&fifth) => self.without(fifth),
+            None => Voicing::from(self),
+        }
+    }
+}
+
+impl<const N: usize> From<&Chord<N>> for Voicing {
+    /// Creates the close-position voicing of a chord
+    fn from(chord: &Chord<N>) -> Self {
+        Self::new(chord.notes().iter().copied())
+    }
+}
+
+impl<const N: usize> From<Chord<N>> for Voicing {
+    /// Creates the close-position voicing of a chord
+    fn from(chord: Chord<N>) -> Self {
+        Self::from(&chord)
+    }
+}
+
+impl From<Vec<Note>> for Voicing {
+    /// Creates a voicing from its notes, in any order
+    fn from(notes: Vec<Note>) -> Self {
+        Self::new(notes)
+    }
+}
+
+impl From<Voicing> for Vec<Note> {
+    /// Returns the notes of the voicing, from the lowest to the highest
+    fn from(voicing: Voicing) -> Self {
+        voicing.notes
+    }
+}
+
+/// Returns all the drop-2 voicings of a chord, in every inversion, that fit in a range
+///
+/// Starting from the close position of the chord, each inversion is turned into a
+/// drop-2 voicing, which is then placed in every octave where all of its notes fall
+/// within the range. The voicings are ordered by their lowest note.
+///
+/// # Arguments
+/// * `chord` - The chord to voice
+/// * `range` - The lowest and highest notes that may be played
+///
+/// # Returns
+/// The notes of each drop-2 voicing, from the lowest to the highest
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let voicings = all_drop2_voicings(&major_seventh(C4).unwrap(), C3..=C5);
+/// assert_eq!(voicings[0], vec![C3, G3, B3, E4]);
+/// assert!(voicings.iter().flatten().all(|note| (C3..=C5).contains(note)));
+/// ```
+pub fn all_drop2_voicings<const N: usize>(
+    chord: &Chord<N>,
+    range: RangeInclusive<Note>,
+) -> Vec<Vec<Note>> {
+    let (low, high) = (range.start().midi_number(), range.end().midi_number());
+
+    // Build the drop-2 shapes one octave above the lowest one, so the dropped note stays in range
+    let octave = chord.root().midi_number() / SEMITONES_IN_OCTAVE;
+    let mut inversion = Voicing::new(chord.notes().iter().map(|note| {
+        Note::new(note.midi_number() + SEMITONES_IN_OCTAVE - octave * SEMITONES_IN_OCTAVE)
+    }));
+
+    let mut voicings = Vec::new();
+    for _ in 0..N {
+        if let Ok(shape) = inversion.drop2() {
+            let lowest = shape.notes[0].midi_number();
+            let highest = shape.notes[shape.notes.len() - 1].midi_number();
+
+            let mut shift = 0;
+            while highest + shift <= high {
+                if lowest + shift >= low {
+                    voicings.push(
+                        shape
+                            .notes
+                            .iter()
+                            .map(|note| Note::new(note.midi_number() + shift))
+                            .collect(),
+                    );
+                }
+                shift += SEMITONES_IN_OCTAVE;
+            }
+        }
+
+        inversion = match inversion.invert() {
+            Ok(next) => next,
+            Err(_) => break,
+        };
+    }
+
+    voicings.sort_by_key(|voicing: &Vec<Note>| voicing[0]);
+    voicings
+}
+
+/// Returns the note an octave above, or an error if it is outside of the MIDI range
+fn octave_up(note: Note) -> Result<Note, Error> {
+    match note.midi_number().checked_add(SEMITONES_IN_OCTAVE) {
+        Some(midi) if midi <= G9.midi_number() => Ok(Note::new(midi)),
+        _ => Err(Error::NoteOutOfRange(note)),
+    }
+}
+
+/// Returns the note an octave below, or an error if it is outside of the MIDI range
+fn octave_down(note: Note) -> Result<Note, Error> {
+    note.midi_number()
+        .checked_sub(SEMITONES_IN_OCTAVE)
+        .map(Note::new)
+        .ok_or(Error::NoteOutOfRange(note))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        augmented_triad, diminished_seventh, dominant_ninth, dominant_seventh, dominant_thirteenth,
+        major_seventh, major_triad, ChordQuality,
+    };
+
+    #[test]
+    fn test_drop2_inversions_of_cmaj7() {
+        let root = Voicing::from(&major_seventh(C4).unwrap());
+        let first = root.invert().unwrap();
+        let second = first.invert().unwrap();
+        let third = second.invert().unwrap();
+
+        assert_eq!(root.drop2().unwrap().notes(), &[G3, C4, E4, B4]);
+        assert_eq!(first.drop2().unwrap().notes(), &[B3, E4, G4, C5]);
+        assert_eq!(second.drop2().unwrap().notes(), &[C4, G4, B4, E5]);
+        assert_eq!(third.drop2().unwrap().notes(), &[E4, B4, C5, G5]);
+    }
+
+    #[test]
+    fn test_drop2_twice_is_not_idempotent() {
+        let once = Voicing::new([C4, E4, G4, B4]).drop2().unwrap();
+        let twice = once.drop2().unwrap();
+
+        assert_ne!(once, twice);
+        assert_eq!(twice.notes(), &[E3, G3, C4, B4]);
+    }
+
+    #[test]
+    fn test_drop3_and_drop2and4() {
+        let voicing = Voicing::new([C4, E4, G4, B4]);
+        assert_eq!(voicing.drop3().unwrap().notes(), &[E3, C4, G4, B4]);
+        assert_eq!(voicing.drop2and4().unwrap().notes(), &[C3, G3, E4, B4]);
+    }
+
+    #[test]
+    fn test_drop_errors() {
+        let voicing = Voicing::new([Note::new(5), Note::new(9), Note::new(12)]);
+        assert_eq!(voicing.drop2(), Err(Error::NoteOutOfRange(Note::new(9))));
+        assert_eq!(
+            voicing.drop2and4(),
+            Err(Error::NotEnoughNotes {
+                required: 4,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_all_drop2_voicings() {
+        let voicings = all_drop2_voicings(&major_seventh(C4).unwrap(), C3..=C5);
+        assert_eq!(
+            voicings,
+            vec![
+                vec![C3, G3, B3, E4],
+                vec![E3, B3, C4, G4],
+                vec![G3, C4, E4, B4],
+                vec![B3, E4, G4, C5],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inversions() {
+        let inversions = major_triad(C4).unwrap().inversions().unwrap();
+        let basses: Vec<_> = inversions
+            .iter()
+            .map(|voicing| voicing.notes()[0])
+            .collect();
+        assert_eq!(basses, vec![C4, E4, G4]);
+        assert_eq!(inversions[0].notes(), &[C4, E4, G4]);
+
+        let inversions = dominant_seventh(G3).unwrap().inversions().unwrap();
+        assert_eq!(inversions.len(), 4);
+        assert_eq!(inversions[3].notes(), &[F4, G4, B4, D5]);
+
+        // The second inversion would go above MIDI 127
+        assert_eq!(
+            major_triad(G8).unwrap().inversions(),
+            Err(Error::NoteOutOfRange(B8))
+        );
+        assert_eq!(major_triad(C8).unwrap().inversions().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_chord_drop_voicings() {
+        let cmaj7 = major_seventh(C4).unwrap();
+        let drop2 = cmaj7.drop2();
+        assert_eq!(drop2.notes(), &[G3, C4, E4, B4]);
+        assert_eq!(
+            drop2.notes()[0].midi_number() + SEMITONES_IN_OCTAVE,
+            cmaj7.notes()[2].midi_number()
+        );
+
+        assert_eq!(cmaj7.drop3().notes(), &[E3, C4, G4, B4]);
+        assert_eq!(cmaj7.drop2and4().notes(), &[C3, G3, E4, B4]);
+        assert_eq!(
+            dominant_ninth(C4).unwrap().drop2().notes(),
+            &[BFLAT3, C4, E4, G4, D5]
+        );
+    }
+
+    #[test]
+    fn test_omitted_tones() {
+        let c7 = dominant_seventh(C4).unwrap();
+        assert_eq!(c7.omit_fifth().notes(), &[C4, E4, ASHARP4]);
+        assert_eq!(c7.omit_root().notes(), &[E4, G4, ASHARP4]);
+        assert_eq!(c7.without(MINOR_SEVENTH).notes(), &[C4, E4, G4]);
+
+        // Removing a tone that isn't in the chord leaves it whole
+        assert_eq!(c7.without(MAJOR_SECOND), Voicing::from(&c7));
+
+        // The fifth of every chord quality is its second interval
+        assert_eq!(augmented_triad(C4).unwrap().omit_fifth().notes(), &[C4, E4]);
+        assert_eq!(
+            diminished_seventh(C4).unwrap().omit_fifth().notes(),
+            &[C4, DSHARP4, A4]
+        );
+        assert_eq!(
+            dominant_thirteenth(C3).unwrap().omit_fifth().notes().len(),
+            6
+        );
+        for quality in ChordQuality::ALL {
+            let fifth = quality.intervals()[1].semitones();
+            assert!((6..=8).contains(&fifth), "{quality:?}");
+        }
+    }
+
+    #[test]
+    fn test_chord_drop_voicings_unchanged() {
+        // Triads are too small for drop voicings
+        assert_eq!(
+            major_triad(C4).unwrap().drop2(),
+            Voicing::from(&major_triad(C4).unwrap())
+        );
+
+        // Dropping would go below MIDI 0
+        let low = major_seventh(Note::new(2)).unwrap();
+        assert_eq!(low.drop2(), Voicing::from(&low));
+    }
+}
@@ -82,7 +82,7 @@ pub const MINOR_MAJOR_SEVENTH_INTERVALS: [Interval; 3] =
 /// - Root
 /// - Major third (4 semitones above root)
 /// - Major sixth (9 semitones above root)
-pub const MAJOR_SIXTH_INTERVALS: [Interval; 3] = [MAJOR_THIRD, PERFECT_FIFTH, MINOR_SIXTH];
+pub const MAJOR_SIXTH_INTERVALS: [Interval; 3] = [MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
 
 /// Represents the intervals for a minor sixth chord, measured from the root note
 ///
@@ -90,8 +90,8 @@ pub const MAJOR_SIXTH_INTERVALS: [Interval; 3] = [MAJOR_THIRD, PERFECT_FIFTH, MI
 /// - Root
 /// - Minor third (3 semitones above root)
 /// - Perfect fifth (7 semitones above root)
-/// - Minor sixth (9 semitones above root)
-pub const MINOR_SIXTH_INTERVALS: [Interval; 3] = [MINOR_THIRD, PERFECT_FIFTH, MINOR_SIXTH];
+/// - Major sixth (9 semitones above root)
+pub const MINOR_SIXTH_INTERVALS: [Interval; 3] = [MINOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
 
 /// Represents the intervals for a major sixth ninth chord, measured from the root note
 ///
@@ -102,7 +102,7 @@ pub const MINOR_SIXTH_INTERVALS: [Interval; 3] = [MINOR_THIRD, PERFECT_FIFTH, MI
 /// - Major sixth (9 semitones above root)
 /// - Major ninth (14 semitones above root)
 pub const MAJOR_SIXTH_NINTH_INTERVALS: [Interval; 4] =
-    [MAJOR_THIRD, PERFECT_FIFTH, MINOR_SIXTH, MAJOR_NINTH];
+    [MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH, MAJOR_NINTH];
 
 /// Represents the intervals for a minor sixth ninth chord, measured from the root note
 ///
@@ -112,7 +112,7 @@ pub const MAJOR_SIXTH_NINTH_INTERVALS: [Interval; 4] =
 /// - Perfect fifth (7 semitones above root)
 /// - Major sixth (9 semitones above root)
 pub const MINOR_SIXTH_NINTH_INTERVALS: [Interval; 4] =
-    [MINOR_THIRD, PERFECT_FIFTH, MINOR_SIXTH, MAJOR_NINTH];
+    [MINOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH, MAJOR_NINTH];
 
 /// Represents the intervals for a suspended 2nd chord, measured from the root note
 ///
@@ -146,7 +146,7 @@ pub const DIMINISHED_TRIAD_INTERVALS: [Interval; 2] = [MINOR_THIRD, DIMINISHED_F
 /// - Diminished fifth (6 semitones above root)
 /// - Diminished seventh (9 semitones above root)
 pub const DIMINISHED_SEVENTH_INTERVALS: [Interval; 3] =
-    [MINOR_THIRD, DIMINISHED_FIFTH, MINOR_SIXTH];
+    [MINOR_THIRD, DIMINISHED_FIFTH, MAJOR_SIXTH];
 
 /// Represents the intervals for a half-diminished seventh chord, measured from the root note
 ///
@@ -172,8 +172,9 @@ pub const AUGMENTED_TRIAD_INTERVALS: [Interval; 2] = [MAJOR_THIRD, AUGMENTED_FIF
 /// - Root
 /// - Major third (4 semitones above root)
 /// - Augmented fifth (8 semitones above root)
-/// - Augmented seventh (12 semitones above root)
-pub const AUGMENTED_SEVENTH_INTERVALS: [Interval; 3] = [MAJOR_THIRD, AUGMENTED_FIFTH, MAJOR_SIXTH];
+/// - Minor seventh (10 semitones above root)
+pub const AUGMENTED_SEVENTH_INTERVALS: [Interval; 3] =
+    [MAJOR_THIRD, AUGMENTED_FIFTH, MINOR_SEVENTH];
 
 /// Represents the intervals for a dominant ninth chord, measured from the root note
 ///
@@ -216,7 +217,7 @@ pub const MAJOR_NINTH_INTERVALS: [Interval; 4] =
 /// - Perfect fifth (7 semitones above root)
 /// - Minor seventh (10 semitones above root)
 /// - Major ninth (14 semitones above root)
-/// - Perfect eleventh (18 semitones above root)
+/// - Perfect eleventh (17 semitones above root)
 pub const DOMINANT_ELEVENTH_INTERVALS: [Interval; 5] = [
     MAJOR_THIRD,
     PERFECT_FIFTH,
@@ -233,7 +234,7 @@ pub const DOMINANT_ELEVENTH_INTERVALS: [Interval; 5] = [
 /// - Perfect fifth (7 semitones above root)
 /// - Minor seventh (10 semitones above root)
 /// - Major ninth (14 semitones above root)
-/// - Perfect eleventh (18 semitones above root)
+/// - Perfect eleventh (17 semitones above root)
 pub const MINOR_ELEVENTH_INTERVALS: [Interval; 5] = [
     MINOR_THIRD,
     PERFECT_FIFTH,
@@ -250,7 +251,7 @@ pub const MINOR_ELEVENTH_INTERVALS: [Interval; 5] = [
 /// - Perfect fifth (7 semitones above root)
 /// - Major seventh (11 semitones above root)
 /// - Major ninth (14 semitones above root)
-/// - Perfect eleventh (18 semitones above root)
+/// - Perfect eleventh (17 semitones above root)
 pub const MAJOR_ELEVENTH_INTERVALS: [Interval; 5] = [
     MAJOR_THIRD,
     PERFECT_FIFTH,
@@ -267,15 +268,15 @@ pub const MAJOR_ELEVENTH_INTERVALS: [Interval; 5] = [
 /// - Perfect fifth (7 semitones above root)
 /// - Minor seventh (10 semitones above root)
 /// - Major ninth (14 semitones above root)
-/// - Perfect eleventh (18 semitones above root)
-/// - Minor thirteenth (21 semitones above root)
+/// - Perfect eleventh (17 semitones above root)
+/// - Major thirteenth (21 semitones above root)
 pub const DOMINANT_THIRTEENTH_INTERVALS: [Interval; 6] = [
     MAJOR_THIRD,
     PERFECT_FIFTH,
     MINOR_SEVENTH,
     MAJOR_NINTH,
     PERFECT_ELEVENTH,
-    MINOR_THIRTEENTH,
+    MAJOR_THIRTEENTH,
 ];
 
 /// Represents the intervals for a minor thirteenth chord, measured from the root note
@@ -286,15 +287,15 @@ pub const DOMINANT_THIRTEENTH_INTERVALS: [Interval; 6] = [
 /// - Perfect fifth (7 semitones above root)
 /// - Minor seventh (10 semitones above root)
 /// - Major ninth (14 semitones above root)
-/// - Perfect eleventh (18 semitones above root)
-/// - Minor thirteenth (21 semitones above root)
+/// - Perfect eleventh (17 semitones above root)
+/// - Major thirteenth (21 semitones above root)
 pub const MINOR_THIRTEENTH_INTERVALS: [Interval; 6] = [
     MINOR_THIRD,
     PERFECT_FIFTH,
     MINOR_SEVENTH,
     MAJOR_NINTH,
     PERFECT_ELEVENTH,
-    MINOR_THIRTEENTH,
+    MAJOR_THIRTEENTH,
 ];
 
 /// Represents the intervals for a major thirteenth chord, measured from the root note
@@ -305,13 +306,13 @@ pub const MINOR_THIRTEENTH_INTERVALS: [Interval; 6] = [
 /// - Perfect fifth (7 semitones above root)
 /// - Major seventh (11 semitones above root)
 /// - Major ninth (14 semitones above root)
-/// - Perfect eleventh (18 semitones above root)
-/// - Major thirteenth (22 semitones above root)
+/// - Perfect eleventh (17 semitones above root)
+/// - Major thirteenth (21 semitones above root)
 pub const MAJOR_THIRTEENTH_INTERVALS: [Interval; 6] = [
     MAJOR_THIRD,
     PERFECT_FIFTH,
     MAJOR_SEVENTH,
     MAJOR_NINTH,
     PERFECT_ELEVENTH,
-    MINOR_THIRTEENTH,
+    MAJOR_THIRTEENTH,
 ];
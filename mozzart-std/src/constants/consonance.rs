@@ -0,0 +1,23 @@
+/// Consonance weights of the intervals, indexed by their size in semitones within an octave
+///
+/// A weight of 1.0 is perfectly consonant and 0.0 is maximally dissonant. The
+/// octave and the perfect fifth are the most consonant intervals, while the minor
+/// second, the major seventh and the tritone are the most dissonant.
+///
+/// | Semitones | Interval        | Weight |
+/// |-----------|-----------------|--------|
+/// | 0         | Unison / octave | 1.00   |
+/// | 1         | Minor second    | 0.00   |
+/// | 2         | Major second    | 0.30   |
+/// | 3         | Minor third     | 0.60   |
+/// | 4         | Major third     | 0.70   |
+/// | 5         | Perfect fourth  | 0.75   |
+/// | 6         | Tritone         | 0.10   |
+/// | 7         | Perfect fifth   | 0.90   |
+/// | 8         | Minor sixth     | 0.55   |
+/// | 9         | Major sixth     | 0.65   |
+/// | 10        | Minor seventh   | 0.35   |
+/// | 11        | Major seventh   | 0.05   |
+pub const CONSONANCE_WEIGHTS: [f32; 12] = [
+    1.0, 0.0, 0.3, 0.6, 0.7, 0.75, 0.1, 0.9, 0.55, 0.65, 0.35, 0.05,
+];
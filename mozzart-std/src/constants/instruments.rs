@@ -0,0 +1,21 @@
+use crate::constants::*;
+use crate::{TransposingInstrument, TranspositionDirection};
+
+/// The Bb trumpet, sounding a major second lower than written
+pub const BB_TRUMPET: TransposingInstrument =
+    TransposingInstrument::new("Bb trumpet", MAJOR_SECOND, TranspositionDirection::Down);
+
+/// The horn in F, sounding a perfect fifth lower than written
+pub const F_HORN: TransposingInstrument =
+    TransposingInstrument::new("horn in F", PERFECT_FIFTH, TranspositionDirection::Down);
+
+/// The Eb alto saxophone, sounding a major sixth lower than written
+pub const EB_ALTO_SAX: TransposingInstrument = TransposingInstrument::new(
+    "Eb alto saxophone",
+    MAJOR_SIXTH,
+    TranspositionDirection::Down,
+);
+
+/// The guitar without a capo, sounding an octave lower than written
+pub const GUITAR: TransposingInstrument =
+    TransposingInstrument::new("guitar", PERFECT_OCTAVE, TranspositionDirection::Down);
@@ -21,12 +21,12 @@ pub const DIMINISHED_FIFTH: Interval = Interval::new(6);
 pub const PERFECT_FIFTH: Interval = Interval::new(7);
 /// Augmented fifth (8 semitones) - creates tension, common in augmented chords
 pub const AUGMENTED_FIFTH: Interval = Interval::new(8);
-/// Diminished sixth (8 semitones) - enharmonic equivalent of augmented fifth
-pub const DIMINISHED_SIXTH: Interval = Interval::new(8);
-/// Minor sixth (9 semitones) - creates gentle tension, common in minor keys
-pub const MINOR_SIXTH: Interval = Interval::new(9);
-/// Major sixth (10 semitones) - consonant interval common in major keys
-pub const MAJOR_SIXTH: Interval = Interval::new(10);
+/// Diminished sixth (7 semitones) - enharmonic equivalent of perfect fifth
+pub const DIMINISHED_SIXTH: Interval = Interval::new(7);
+/// Minor sixth (8 semitones) - creates gentle tension, common in minor keys
+pub const MINOR_SIXTH: Interval = Interval::new(8);
+/// Major sixth (9 semitones) - consonant interval common in major keys
+pub const MAJOR_SIXTH: Interval = Interval::new(9);
 /// Minor seventh (10 semitones) - creates tension seeking resolution, fundamental in dominant seventh chords
 pub const MINOR_SEVENTH: Interval = Interval::new(10);
 /// Major seventh (11 semitones) - creates bright tension, common in major seventh chords
@@ -53,13 +53,13 @@ pub const DIMINISHED_TWELFTH: Interval = Interval::new(18);
 pub const PERFECT_TWELFTH: Interval = Interval::new(19);
 /// Augmented twelfth (20 semitones) - octave plus augmented fifth, expanded augmented harmony
 pub const AUGMENTED_TWELFTH: Interval = Interval::new(20);
-/// Diminished thirteenth (20 semitones) - enharmonic equivalent of augmented twelfth
-pub const DIMINISHED_THIRTEENTH: Interval = Interval::new(20);
-/// Minor thirteenth (21 semitones) - octave plus minor sixth, expressive interval in jazz harmony
-pub const MINOR_THIRTEENTH: Interval = Interval::new(21);
-/// Major thirteenth (22 semitones) - octave plus major sixth, highest standard extension in jazz chords
-pub const MAJOR_THIRTEENTH: Interval = Interval::new(22);
-/// Minor fourteenth (22 semitones) - enharmonic equivalent of major thirteenth
+/// Diminished thirteenth (19 semitones) - enharmonic equivalent of perfect twelfth
+pub const DIMINISHED_THIRTEENTH: Interval = Interval::new(19);
+/// Minor thirteenth (20 semitones) - octave plus minor sixth, expressive interval in jazz harmony
+pub const MINOR_THIRTEENTH: Interval = Interval::new(20);
+/// Major thirteenth (21 semitones) - octave plus major sixth, highest standard extension in jazz chords
+pub const MAJOR_THIRTEENTH: Interval = Interval::new(21);
+/// Minor fourteenth (22 semitones) - octave plus minor seventh, common in extended dominant voicings
 pub const MINOR_FOURTEENTH: Interval = Interval::new(22);
 /// Major fourteenth (23 semitones) - octave plus major seventh, creates extreme tension
 pub const MAJOR_FOURTEENTH: Interval = Interval::new(23);
@@ -83,10 +83,10 @@ pub const AUGMENTED_EIGHTEENTH: Interval = Interval::new(30);
 pub const DIMINISHED_NINETEENTH: Interval = Interval::new(30);
 /// Perfect nineteenth (31 semitones) - two octaves plus perfect fifth, expanded harmonic anchor
 pub const PERFECT_NINETEENTH: Interval = Interval::new(31);
-/// Minor twentieth (33 semitones) - two octaves plus minor sixth
-pub const MINOR_TWENTIETH: Interval = Interval::new(33);
-/// Major twentieth (34 semitones) - two octaves plus major sixth
-pub const MAJOR_TWENTIETH: Interval = Interval::new(34);
+/// Minor twentieth (32 semitones) - two octaves plus minor sixth
+pub const MINOR_TWENTIETH: Interval = Interval::new(32);
+/// Major twentieth (33 semitones) - two octaves plus major sixth
+pub const MAJOR_TWENTIETH: Interval = Interval::new(33);
 /// Perfect twenty-first (35 semitones) - two octaves plus major seventh
 pub const PERFECT_TWENTY_FIRST: Interval = Interval::new(35);
 /// Triple octave (36 semitones) - spans three octaves, extreme range expansion
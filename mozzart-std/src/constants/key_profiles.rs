@@ -0,0 +1,28 @@
+use crate::KeyProfile;
+
+/// The Krumhansl-Kessler profile of the major keys, from the tonic to the major seventh
+///
+/// The values are the ratings listeners gave to each pitch class after hearing a
+/// major key context, as published in Krumhansl and Kessler, "Tracing the dynamic
+/// changes in perceived tonal organization in a spatial representation of musical
+/// keys" (Psychological Review, 1982), and used by the Krumhansl-Schmuckler
+/// key-finding algorithm in Krumhansl, "Cognitive Foundations of Musical Pitch" (1990).
+///
+/// | Degree | 1    | b2   | 2    | b3   | 3    | 4    | #4   | 5    | b6   | 6    | b7   | 7    |
+/// |--------|------|------|------|------|------|------|------|------|------|------|------|------|
+/// | Rating | 6.35 | 2.23 | 3.48 | 2.33 | 4.38 | 4.09 | 2.52 | 5.19 | 2.39 | 3.66 | 2.29 | 2.88 |
+pub const KRUMHANSL_MAJOR_PROFILE: KeyProfile = KeyProfile::new([
+    6.35, 2.23, 3.48, 2.33, 4.38, 4.09, 2.52, 5.19, 2.39, 3.66, 2.29, 2.88,
+]);
+
+/// The Krumhansl-Kessler profile of the minor keys, from the tonic to the major seventh
+///
+/// The values come from the same study as `KRUMHANSL_MAJOR_PROFILE`, after a
+/// minor key context.
+///
+/// | Degree | 1    | b2   | 2    | b3   | 3    | 4    | #4   | 5    | b6   | 6    | b7   | 7    |
+/// |--------|------|------|------|------|------|------|------|------|------|------|------|------|
+/// | Rating | 6.33 | 2.68 | 3.52 | 5.38 | 2.60 | 3.53 | 2.54 | 4.75 | 3.98 | 2.69 | 3.34 | 3.17 |
+pub const KRUMHANSL_MINOR_PROFILE: KeyProfile = KeyProfile::new([
+    6.33, 2.68, 3.52, 5.38, 2.60, 3.53, 2.54, 4.75, 3.98, 2.69, 3.34, 3.17,
+]);
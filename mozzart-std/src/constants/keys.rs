@@ -0,0 +1,65 @@
+use crate::KeySignature;
+
+// Major keys
+/// C major - no sharps or flats
+pub const C_MAJOR_KEY: KeySignature = KeySignature::new(0);
+/// G major - one sharp (F#)
+pub const G_MAJOR_KEY: KeySignature = KeySignature::new(1);
+/// D major - two sharps (F#, C#)
+pub const D_MAJOR_KEY: KeySignature = KeySignature::new(2);
+/// A major - three sharps (F#, C#, G#)
+pub const A_MAJOR_KEY: KeySignature = KeySignature::new(3);
+/// E major - four sharps (F#, C#, G#, D#)
+pub const E_MAJOR_KEY: KeySignature = KeySignature::new(4);
+/// B major - five sharps (F#, C#, G#, D#, A#)
+pub const B_MAJOR_KEY: KeySignature = KeySignature::new(5);
+/// F# major - six sharps (F#, C#, G#, D#, A#, E#)
+pub const FSHARP_MAJOR_KEY: KeySignature = KeySignature::new(6);
+/// C# major - seven sharps, every letter is sharpened
+pub const CSHARP_MAJOR_KEY: KeySignature = KeySignature::new(7);
+/// F major - one flat (Bb)
+pub const F_MAJOR_KEY: KeySignature = KeySignature::new(-1);
+/// Bb major - two flats (Bb, Eb)
+pub const BFLAT_MAJOR_KEY: KeySignature = KeySignature::new(-2);
+/// Eb major - three flats (Bb, Eb, Ab)
+pub const EFLAT_MAJOR_KEY: KeySignature = KeySignature::new(-3);
+/// Ab major - four flats (Bb, Eb, Ab, Db)
+pub const AFLAT_MAJOR_KEY: KeySignature = KeySignature::new(-4);
+/// Db major - five flats (Bb, Eb, Ab, Db, Gb)
+pub const DFLAT_MAJOR_KEY: KeySignature = KeySignature::new(-5);
+/// Gb major - six flats (Bb, Eb, Ab, Db, Gb, Cb)
+pub const GFLAT_MAJOR_KEY: KeySignature = KeySignature::new(-6);
+/// Cb major - seven flats, every letter is flattened
+pub const CFLAT_MAJOR_KEY: KeySignature = KeySignature::new(-7);
+
+// Minor keys (sharing the key signature of their relative major)
+/// A minor - relative of C major, no sharps or flats
+pub const A_MINOR_KEY: KeySignature = C_MAJOR_KEY;
+/// E minor - relative of G major, one sharp
+pub const E_MINOR_KEY: KeySignature = G_MAJOR_KEY;
+/// B minor - relative of D major, two sharps
+pub const B_MINOR_KEY: KeySignature = D_MAJOR_KEY;
+/// F# minor - relative of A major, three sharps
+pub const FSHARP_MINOR_KEY: KeySignature = A_MAJOR_KEY;
+/// C# minor - relative of E major, four sharps
+pub const CSHARP_MINOR_KEY: KeySignature = E_MAJOR_KEY;
+/// G# minor - relative of B major, five sharps
+pub const GSHARP_MINOR_KEY: KeySignature = B_MAJOR_KEY;
+/// D# minor - relative of F# major, six sharps
+pub const DSHARP_MINOR_KEY: KeySignature = FSHARP_MAJOR_KEY;
+/// A# minor - relative of C# major, seven sharps
+pub const ASHARP_MINOR_KEY: KeySignature = CSHARP_MAJOR_KEY;
+/// D minor - relative of F major, one flat
+pub const D_MINOR_KEY: KeySignature = F_MAJOR_KEY;
+/// G minor - relative of Bb major, two flats
+pub const G_MINOR_KEY: KeySignature = BFLAT_MAJOR_KEY;
+/// C minor - relative of Eb major, three flats
+pub const C_MINOR_KEY: KeySignature = EFLAT_MAJOR_KEY;
+/// F minor - relative of Ab major, four flats
+pub const F_MINOR_KEY: KeySignature = AFLAT_MAJOR_KEY;
+/// Bb minor - relative of Db major, five flats
+pub const BFLAT_MINOR_KEY: KeySignature = DFLAT_MAJOR_KEY;
+/// Eb minor - relative of Gb major, six flats
+pub const EFLAT_MINOR_KEY: KeySignature = GFLAT_MAJOR_KEY;
+/// Ab minor - relative of Cb major, seven flats
+pub const AFLAT_MINOR_KEY: KeySignature = CFLAT_MAJOR_KEY;
@@ -0,0 +1,13 @@
+use crate::{Duration, TimeSignature};
+
+/// Two quarter-note beats per bar
+pub const TWO_FOUR: TimeSignature = TimeSignature::new(2, Duration::Quarter).unwrap();
+
+/// Three quarter-note beats per bar
+pub const THREE_FOUR: TimeSignature = TimeSignature::new(3, Duration::Quarter).unwrap();
+
+/// Four quarter-note beats per bar, also known as common time
+pub const FOUR_FOUR: TimeSignature = TimeSignature::new(4, Duration::Quarter).unwrap();
+
+/// Six eighth notes per bar, felt as two dotted-quarter beats
+pub const SIX_EIGHT: TimeSignature = TimeSignature::new(6, Duration::Eighth).unwrap();
@@ -1,25 +1,43 @@
-//! Musical constants for the mazzart-ply library
+//! Musical constants for the mozzart-std library
 //!
 //! This module provides a comprehensive set of musical constants including:
+//! - Consonance weights of the intervals
+//! - Transposing instruments (Bb trumpet, horn in F, Eb alto saxophone and guitar)
 //! - Intervals (semitones, whole tones, thirds, fifths, etc.)
+//! - Key profiles of the major and minor keys, for key finding
+//! - Key signatures (major and minor keys with their sharps or flats)
+//! - Time signatures (2/4, 3/4, 4/4 and 6/8)
 //! - Notes (predefined MIDI note values)
 //! - Fundamental musical values (like semitones in an octave)
 //! - Steps (semitones, whole tones, etc.)
+//! - Vocal ranges of the soprano, alto, tenor and bass voices, and the ranges of the hands at the piano
 //!
 //! These constants serve as building blocks for more complex musical structures
 //! and calculations throughout the library.
 
 mod chords;
+mod consonance;
+mod instruments;
 mod intervals;
+mod key_profiles;
+mod keys;
+mod meters;
 mod notes;
 mod scales;
 mod steps;
+mod voices;
 
 pub use chords::*;
+pub use consonance::*;
+pub use instruments::*;
 pub use intervals::*;
+pub use key_profiles::*;
+pub use keys::*;
+pub use meters::*;
 pub use notes::*;
 pub use scales::*;
 pub use steps::*;
+pub use voices::*;
 
 /// Number of semitones in an octave in the standard Western equal temperament system
 ///
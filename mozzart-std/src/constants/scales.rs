@@ -150,3 +150,102 @@ pub const MELODIC_MINOR_SCALE_STEPS: [Step; 7] = [
     WHOLE, // 11
     HALF,  // 12
 ];
+
+/// Represents the step pattern for a whole-tone scale (Messiaen's first mode)
+///
+/// The whole-tone scale divides the octave into six equal whole steps: W-W-W-W-W-W.
+/// It has no half steps, so it lacks a leading tone and sounds ambiguous and dreamy.
+pub const WHOLE_TONE_SCALE_STEPS: [Step; 6] = [
+    WHOLE, // 2
+    WHOLE, // 4
+    WHOLE, // 6
+    WHOLE, // 8
+    WHOLE, // 10
+    WHOLE, // 12
+];
+
+/// Represents the step pattern for an octatonic scale (Messiaen's second mode)
+///
+/// The octatonic scale alternates half and whole steps: H-W-H-W-H-W-H-W.
+/// It is also known as the half-whole diminished scale.
+pub const OCTATONIC_SCALE_STEPS: [Step; 8] = [
+    HALF,  // 1
+    WHOLE, // 3
+    HALF,  // 4
+    WHOLE, // 6
+    HALF,  // 7
+    WHOLE, // 9
+    HALF,  // 10
+    WHOLE, // 12
+];
+
+/// Represents the step pattern for Messiaen's third mode of limited transposition
+///
+/// The pattern W-H-H repeats three times, giving nine notes per octave.
+pub const MESSIAEN_MODE_3_STEPS: [Step; 9] = [
+    WHOLE, // 2
+    HALF,  // 3
+    HALF,  // 4
+    WHOLE, // 6
+    HALF,  // 7
+    HALF,  // 8
+    WHOLE, // 10
+    HALF,  // 11
+    HALF,  // 12
+];
+
+/// Represents the step pattern for Messiaen's fourth mode of limited transposition
+///
+/// The pattern H-H-(W+H)-H repeats twice, giving eight notes per octave.
+pub const MESSIAEN_MODE_4_STEPS: [Step; 8] = [
+    HALF,           // 1
+    HALF,           // 2
+    WHOLE_AND_HALF, // 5
+    HALF,           // 6
+    HALF,           // 7
+    HALF,           // 8
+    WHOLE_AND_HALF, // 11
+    HALF,           // 12
+];
+
+/// Represents the step pattern for Messiaen's fifth mode of limited transposition
+///
+/// The pattern H-(W+W)-H repeats twice, giving six notes per octave.
+pub const MESSIAEN_MODE_5_STEPS: [Step; 6] = [
+    HALF,         // 1
+    DOUBLE_WHOLE, // 5
+    HALF,         // 6
+    HALF,         // 7
+    DOUBLE_WHOLE, // 11
+    HALF,         // 12
+];
+
+/// Represents the step pattern for Messiaen's sixth mode of limited transposition
+///
+/// The pattern W-W-H-H repeats twice, giving eight notes per octave.
+pub const MESSIAEN_MODE_6_STEPS: [Step; 8] = [
+    WHOLE, // 2
+    WHOLE, // 4
+    HALF,  // 5
+    HALF,  // 6
+    WHOLE, // 8
+    WHOLE, // 10
+    HALF,  // 11
+    HALF,  // 12
+];
+
+/// Represents the step pattern for Messiaen's seventh mode of limited transposition
+///
+/// The pattern H-H-H-W-H repeats twice, giving ten notes per octave.
+pub const MESSIAEN_MODE_7_STEPS: [Step; 10] = [
+    HALF,  // 1
+    HALF,  // 2
+    HALF,  // 3
+    WHOLE, // 5
+    HALF,  // 6
+    HALF,  // 7
+    HALF,  // 8
+    HALF,  // 9
+    WHOLE, // 11
+    HALF,  // 12
+];
@@ -8,3 +8,5 @@ pub const HALF: Step = Step::new(1);
 pub const WHOLE: Step = Step::new(2);
 /// Step and a half (3 semitones) - common in many scales including harmonic minor
 pub const WHOLE_AND_HALF: Step = Step::new(3);
+/// Two whole steps (4 semitones) - the widest step of some symmetric scales
+pub const DOUBLE_WHOLE: Step = Step::new(4);
@@ -0,0 +1,21 @@
+use crate::constants::*;
+use crate::Note;
+use std::ops::RangeInclusive;
+
+/// The comfortable range of a soprano voice, from C4 to G5
+pub const SOPRANO_RANGE: RangeInclusive<Note> = C4..=G5;
+
+/// The comfortable range of an alto voice, from G3 to D5
+pub const ALTO_RANGE: RangeInclusive<Note> = G3..=D5;
+
+/// The comfortable range of a tenor voice, from C3 to G4
+pub const TENOR_RANGE: RangeInclusive<Note> = C3..=G4;
+
+/// The comfortable range of a bass voice, from E2 to C4
+pub const BASS_RANGE: RangeInclusive<Note> = E2..=C4;
+
+/// The range usually given to the left hand in piano accompaniments, from C2 to C4
+pub const LEFT_HAND_RANGE: RangeInclusive<Note> = C2..=C4;
+
+/// The range usually given to the right hand in piano accompaniments, from C4 to C6
+pub const RIGHT_HAND_RANGE: RangeInclusive<Note> = C4..=C6;
@@ -0,0 +1,225 @@
+use crate::Note;
+use std::fmt;
+
+/// Represents the errors reported by the mozzart library
+///
+/// Operations that can fail on user input (parsing, constructing chords or
+/// scales from textual descriptions, etc.) return this error type so callers
+/// can report a meaningful message.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let c_major = major_scale(C4).unwrap();
+/// let error = Progression::from_roman(&c_major, &["IX"]).unwrap_err();
+/// assert_eq!(error, Error::InvalidRomanNumeral("IX".to_string()));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Error {
+    /// A Roman numeral chord symbol could not be parsed
+    InvalidRomanNumeral(String),
+    /// A note name could not be parsed
+    InvalidNoteName(String),
+    /// An interval name could not be parsed
+    InvalidIntervalName(String),
+    /// A MIDI note number is above 127
+    InvalidMidiNumber(u8),
+    /// A scale degree cannot be used by the operation
+    InvalidScaleDegree(u8),
+    /// A note would be moved outside of the MIDI range (0..=127)
+    NoteOutOfRange(Note),
+    /// An operation needs more notes than were provided
+    NotEnoughNotes { required: usize, found: usize },
+    /// The parameters of an exercise contradict each other
+    InvalidExerciseParams(String),
+    /// No exercise satisfying the constraints was found within the allowed attempts
+    ExerciseNotFound { attempts: usize },
+    /// A scale doesn't have the quality it is converted to
+    ScaleQualityMismatch {
+        expected: &'static str,
+        found: String,
+    },
+    /// A scale doesn't have the number of notes it is converted to
+    ScaleSizeMismatch { expected: usize, found: usize },
+    /// The MIDI export options cannot render the requested notes
+    InvalidMidiExportOptions(String),
+    /// A scale name is not a note followed by a scale quality
+    InvalidScaleName(String),
+    /// A scale quality name is not one of the known qualities
+    UnknownScaleQuality(String),
+    /// Two modifiers of a chord builder set the same degree differently
+    ContradictoryChord {
+        first: &'static str,
+        second: &'static str,
+    },
+    /// A note doesn't belong to the key it is used in
+    NoteOutsideKey(Note),
+    /// No harmonization satisfying the part-writing rules was found within the allowed attempts
+    HarmonizationNotFound { attempts: usize },
+    /// An interval cycle doesn't fit in the MIDI range
+    CycleOutOfRange { requested: usize, fits: usize },
+    /// A preset file could not be read or written
+    PresetIo(String),
+    /// A preset document is not valid JSON or doesn't have the expected layout
+    InvalidPresets(String),
+    /// A preset document was written by a newer version of the library
+    UnsupportedPresetVersion { found: u32, supported: u32 },
+    /// A pitch-class histogram has no weight to compare with a key profile
+    EmptyHistogram,
+    /// A note doesn't play any General MIDI percussion sound
+    NotAPercussionSound(Note),
+    /// A chord symbol is not a root followed by a chord suffix and an optional bass
+    InvalidChordSymbol(String),
+    /// A Roman numeral of a progression could not be parsed
+    InvalidProgressionNumeral { numeral: String, position: usize },
+    /// The form of an arrangement refers to a section it doesn't have
+    UnknownSection(String),
+    /// A spelled note would need more than a double sharp or a double flat
+    TooManyAccidentals { letter: char, alteration: i8 },
+    /// A number of a Nashville Number System chart could not be parsed
+    InvalidNashvilleNumber { number: String, position: usize },
+    /// The frequency range of a pitch detector is empty or not positive
+    InvalidFrequencyRange(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidRomanNumeral(numeral) => {
+                write!(f, "invalid roman numeral '{numeral}'")
+            }
+            Error::InvalidNoteName(name) => {
+                write!(f, "invalid note name '{name}'")
+            }
+            Error::InvalidIntervalName(name) => {
+                write!(f, "invalid interval name '{name}'")
+            }
+            Error::InvalidMidiNumber(midi) => {
+                write!(f, "invalid MIDI note number {midi}, expected 0..=127")
+            }
+            Error::InvalidScaleDegree(degree) => {
+                write!(f, "invalid scale degree {degree}")
+            }
+            Error::NoteOutOfRange(note) => {
+                write!(f, "note {note:?} cannot be moved outside of the MIDI range")
+            }
+            Error::NotEnoughNotes { required, found } => {
+                write!(f, "expected at least {required} notes, found {found}")
+            }
+            Error::InvalidExerciseParams(reason) => {
+                write!(f, "invalid exercise parameters: {reason}")
+            }
+            Error::ExerciseNotFound { attempts } => {
+                write!(
+                    f,
+                    "no exercise satisfies the constraints after {attempts} attempts"
+                )
+            }
+            Error::ScaleQualityMismatch { expected, found } => {
+                write!(f, "expected a {expected} scale, found a {found} scale")
+            }
+            Error::ScaleSizeMismatch { expected, found } => {
+                write!(f, "expected a scale of {expected} notes, found {found}")
+            }
+            Error::InvalidMidiExportOptions(reason) => {
+                write!(f, "invalid MIDI export options: {reason}")
+            }
+            Error::InvalidScaleName(name) => {
+                write!(
+                    f,
+                    "invalid scale name '{name}', expected a note and a quality"
+                )
+            }
+            Error::UnknownScaleQuality(quality) => {
+                write!(
+                    f,
+                    "unknown scale quality '{quality}', expected major, minor, harmonic or melodic"
+                )
+            }
+            Error::ContradictoryChord { first, second } => {
+                write!(f, "the {second} of the chord contradicts its {first}")
+            }
+            Error::NoteOutsideKey(note) => {
+                write!(f, "note {note:?} does not belong to the key")
+            }
+            Error::HarmonizationNotFound { attempts } => {
+                write!(
+                    f,
+                    "no harmonization follows the part-writing rules after {attempts} attempts"
+                )
+            }
+            Error::CycleOutOfRange { requested, fits } => {
+                write!(
+                    f,
+                    "only {fits} of the {requested} notes of the cycle fit in the MIDI range"
+                )
+            }
+            Error::PresetIo(reason) => {
+                write!(f, "cannot access the preset file: {reason}")
+            }
+            Error::InvalidPresets(reason) => {
+                write!(f, "invalid preset document: {reason}")
+            }
+            Error::UnsupportedPresetVersion { found, supported } => {
+                write!(
+                    f,
+                    "preset document version {found} is newer than the supported version {supported}"
+                )
+            }
+            Error::EmptyHistogram => write!(f, "the pitch-class histogram is empty"),
+            Error::NotAPercussionSound(note) => {
+                write!(f, "note {note:?} is not a General MIDI percussion sound")
+            }
+            Error::InvalidChordSymbol(symbol) => {
+                write!(f, "invalid chord symbol '{symbol}'")
+            }
+            Error::InvalidProgressionNumeral { numeral, position } => {
+                write!(
+                    f,
+                    "invalid roman numeral '{numeral}' at position {position}"
+                )
+            }
+            Error::UnknownSection(name) => {
+                write!(f, "unknown section '{name}'")
+            }
+            Error::TooManyAccidentals { letter, alteration } => {
+                let accidental = if *alteration >= 0 { "sharps" } else { "flats" };
+                write!(
+                    f,
+                    "{letter} would need {} {accidental}",
+                    alteration.unsigned_abs()
+                )
+            }
+            Error::InvalidNashvilleNumber { number, position } => {
+                write!(
+                    f,
+                    "invalid nashville number '{number}' at position {position}"
+                )
+            }
+            Error::InvalidFrequencyRange(reason) => {
+                write!(f, "invalid frequency range: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = Error::InvalidRomanNumeral("IX".to_string());
+        assert_eq!(error.to_string(), "invalid roman numeral 'IX'");
+
+        let error = Error::NotEnoughNotes {
+            required: 4,
+            found: 3,
+        };
+        assert_eq!(error.to_string(), "expected at least 4 notes, found 3");
+    }
+}
@@ -1,5 +1,7 @@
 use crate::constants::SEMITONES_IN_OCTAVE;
-use crate::Step;
+use crate::{Error, Note, Step};
+use std::fmt;
+use std::str::FromStr;
 
 /// Represents a musical interval measured in semitones
 ///
@@ -16,9 +18,21 @@ use crate::Step;
 ///
 /// The `Interval` struct provides a type-safe way to represent these musical
 /// distances and perform operations with them.
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Interval(u8);
 
+/// The short names of the intervals up to two octaves, indexed by semitones
+///
+/// Six semitones are named as an augmented fourth and eighteen as an augmented
+/// eleventh, their most common spellings.
+#[rustfmt::skip]
+const NAMES: [&str; 25] = [
+    "P1", "m2", "M2", "m3", "M3", "P4", "A4", "P5", "m6", "M6", "m7", "M7",
+    "P8", "m9", "M9", "m10", "M10", "P11", "A11", "P12", "m13", "M13", "m14", "M14",
+    "P15",
+];
+
 impl Interval {
     /// Creates a new `Interval` from the specified number of semitones
     ///
@@ -61,6 +75,33 @@ impl Interval {
         Self(semitones)
     }
 
+    /// Creates the interval between two notes
+    ///
+    /// The interval is a magnitude: it has no direction, so the order of the notes
+    /// does not matter and `from_pitches(G4, C4)` is the same perfect fifth as
+    /// `from_pitches(C4, G4)`. When the direction matters, compare the notes
+    /// themselves, since `Note` is ordered from the lowest to the highest.
+    ///
+    /// # Arguments
+    /// * `low` - The first note, usually the lower one
+    /// * `high` - The second note, usually the higher one
+    ///
+    /// # Returns
+    /// The interval spanning the number of semitones between the notes
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(Interval::from_pitches(C4, G4), PERFECT_FIFTH);
+    /// assert_eq!(Interval::from_pitches(G4, C4), PERFECT_FIFTH);
+    /// assert_eq!(Interval::from_pitches(C4, C5), PERFECT_OCTAVE);
+    /// ```
+    pub fn from_pitches(low: Note, high: Note) -> Self {
+        Self(low.distance_to(high))
+    }
+
     /// Returns the number of semitones in this interval
     ///
     /// # Returns
@@ -77,6 +118,245 @@ impl Interval {
     pub fn semitones(&self) -> u8 {
         self.0
     }
+
+    /// Returns the short name of this interval, such as `P5` or `m3`
+    ///
+    /// The quality comes first, `P` for perfect, `M` for major, `m` for minor
+    /// and `A` for augmented, followed by the size of the interval. The names
+    /// come from a static table, so this doesn't allocate.
+    ///
+    /// # Returns
+    /// The name of the interval, or `None` for intervals larger than two octaves
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(PERFECT_FIFTH.name(), Some("P5"));
+    /// assert_eq!(MAJOR_NINTH.name(), Some("M9"));
+    /// assert_eq!("m3".parse::<Interval>().unwrap().semitones(), 3);
+    /// ```
+    #[inline]
+    pub fn name(&self) -> Option<&'static str> {
+        NAMES.get(usize::from(self.0)).copied()
+    }
+
+    /// Returns the full name of the usual spelling of this interval, such as "Major Tenth"
+    ///
+    /// Intervals larger than an octave are named as compound intervals, up to
+    /// the double octave.
+    ///
+    /// # Returns
+    /// The name of the interval, or `None` for intervals larger than two octaves
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(MINOR_THIRD.quality_name(), Some("Minor Third"));
+    /// assert_eq!(MAJOR_THIRD.compound(1).unwrap().quality_name(), Some("Major Tenth"));
+    /// ```
+    #[inline]
+    pub fn quality_name(&self) -> Option<&'static str> {
+        SPELLINGS
+            .get(usize::from(self.0))
+            .map(|spellings| spellings[0])
+    }
+
+    /// Returns this interval widened by a number of octaves
+    ///
+    /// # Arguments
+    /// * `octaves` - The number of octaves to add
+    ///
+    /// # Returns
+    /// The compound interval, such as a major tenth for a major third and one octave,
+    /// or `None` if it would be larger than 255 semitones
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(MAJOR_THIRD.compound(1), Some(MAJOR_TENTH));
+    /// assert_eq!(PERFECT_UNISON.compound(2), Some(DOUBLE_OCTAVE));
+    /// assert_eq!(PERFECT_FIFTH.compound(0), Some(PERFECT_FIFTH));
+    /// assert_eq!(PERFECT_FIFTH.compound(21), None);
+    /// ```
+    pub fn compound(&self, octaves: u8) -> Option<Interval> {
+        octaves
+            .checked_mul(SEMITONES_IN_OCTAVE)
+            .and_then(|semitones| self.0.checked_add(semitones))
+            .map(Interval)
+    }
+
+    /// Returns the names this interval can be spelled with
+    ///
+    /// The same number of semitones is written differently depending on the
+    /// notes it spans: a tritone is an augmented fourth from C to F# but a
+    /// diminished fifth from C to Gb.
+    ///
+    /// # Returns
+    /// The common spelling first, followed by its enharmonic equivalents, or an
+    /// empty list for intervals larger than two octaves
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(
+    ///     AUGMENTED_FOURTH.enharmonic_spellings(),
+    ///     vec!["Augmented Fourth", "Diminished Fifth"]
+    /// );
+    /// ```
+    pub fn enharmonic_spellings(&self) -> Vec<&'static str> {
+        SPELLINGS
+            .get(usize::from(self.0))
+            .map_or_else(Vec::new, |spellings| spellings.to_vec())
+    }
+
+    /// Returns `true` if this interval is a unison, fourth, fifth or octave, or one of their compounds
+    ///
+    /// The quality is the one of the usual spelling returned by `name`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert!(PERFECT_FIFTH.is_perfect());
+    /// assert!(PERFECT_ELEVENTH.is_perfect());
+    /// assert!(!MAJOR_THIRD.is_perfect());
+    /// ```
+    pub fn is_perfect(&self) -> bool {
+        self.quality() == 'P'
+    }
+
+    /// Returns `true` if this interval is a major second, third, sixth or seventh, or one of their compounds
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert!(MAJOR_THIRD.is_major());
+    /// assert!(MAJOR_SIXTH.is_major());
+    /// assert!(MAJOR_NINTH.is_major());
+    /// assert!(!MINOR_SIXTH.is_major());
+    /// ```
+    pub fn is_major(&self) -> bool {
+        self.quality() == 'M'
+    }
+
+    /// Returns `true` if this interval is a minor second, third, sixth or seventh, or one of their compounds
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert!(MINOR_THIRD.is_minor());
+    /// assert!(MINOR_SIXTH.is_minor());
+    /// assert!(MINOR_SEVENTH.is_minor());
+    /// assert!(!MAJOR_SIXTH.is_minor());
+    /// ```
+    pub fn is_minor(&self) -> bool {
+        self.quality() == 'm'
+    }
+
+    /// Returns `true` if this interval is a tritone, or one of its compounds
+    ///
+    /// The tritone is the only interval whose usual spelling, the augmented
+    /// fourth, is neither perfect, major nor minor.
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert!(AUGMENTED_FOURTH.is_augmented_or_diminished());
+    /// assert!(DIMINISHED_FIFTH.is_augmented_or_diminished());
+    /// assert!(!PERFECT_FIFTH.is_augmented_or_diminished());
+    /// ```
+    pub fn is_augmented_or_diminished(&self) -> bool {
+        self.quality() == 'A'
+    }
+
+    /// Returns the quality letter of the usual spelling of this interval, reduced to an octave
+    fn quality(&self) -> char {
+        let name = NAMES[usize::from(self.0 % SEMITONES_IN_OCTAVE)];
+        name.chars().next().expect("interval names are never empty")
+    }
+}
+
+/// The common names of the intervals up to two octaves, indexed by semitones
+///
+/// The first name of each entry is the usual spelling, the following ones are
+/// its enharmonic equivalents.
+const SPELLINGS: [&[&str]; 25] = [
+    &["Perfect Unison", "Diminished Second"],
+    &["Minor Second", "Augmented Unison"],
+    &["Major Second", "Diminished Third"],
+    &["Minor Third", "Augmented Second"],
+    &["Major Third", "Diminished Fourth"],
+    &["Perfect Fourth", "Augmented Third"],
+    &["Augmented Fourth", "Diminished Fifth"],
+    &["Perfect Fifth", "Diminished Sixth"],
+    &["Minor Sixth", "Augmented Fifth"],
+    &["Major Sixth", "Diminished Seventh"],
+    &["Minor Seventh", "Augmented Sixth"],
+    &["Major Seventh", "Diminished Octave"],
+    &["Perfect Octave", "Augmented Seventh"],
+    &["Minor Ninth", "Augmented Octave"],
+    &["Major Ninth", "Diminished Tenth"],
+    &["Minor Tenth", "Augmented Ninth"],
+    &["Major Tenth", "Diminished Eleventh"],
+    &["Perfect Eleventh", "Augmented Tenth"],
+    &["Augmented Eleventh", "Diminished Twelfth"],
+    &["Perfect Twelfth", "Diminished Thirteenth"],
+    &["Minor Thirteenth", "Augmented Twelfth"],
+    &["Major Thirteenth", "Diminished Fourteenth"],
+    &["Minor Fourteenth", "Augmented Thirteenth"],
+    &["Major Fourteenth", "Diminished Fifteenth"],
+    &["Double Octave", "Augmented Fourteenth"],
+];
+
+/// Writes the full name of the interval, such as "Perfect Fifth" or "Major Tenth"
+///
+/// Intervals larger than two octaves, which have no name, are written as their
+/// number of semitones.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(MAJOR_THIRD.compound(1).unwrap().to_string(), "Major Tenth");
+/// assert_eq!(MAJOR_THIRD.compound(2).unwrap().to_string(), "28 semitones");
+/// ```
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.quality_name() {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "{} semitones", self.0),
+        }
+    }
+}
+
+/// Parses an interval from its short name, as returned by `Interval::name`
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!("P8".parse(), Ok(PERFECT_OCTAVE));
+/// assert_eq!("X3".parse::<Interval>(), Err(Error::InvalidIntervalName("X3".to_string())));
+/// ```
+impl FromStr for Interval {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        NAMES
+            .iter()
+            .position(|&candidate| candidate == name)
+            .map(|semitones| Interval(semitones as u8))
+            .ok_or_else(|| Error::InvalidIntervalName(name.to_string()))
+    }
 }
 
 /// Conversion from `Interval` to `u8` (number of semitones)
@@ -138,3 +418,217 @@ impl From<&Step> for Interval {
         Interval::new(step.semitones())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_from_pitches() {
+        assert_eq!(Interval::from_pitches(C4, G4), PERFECT_FIFTH);
+        assert_eq!(
+            Interval::from_pitches(G4, C4),
+            Interval::from_pitches(C4, G4)
+        );
+        assert_eq!(Interval::from_pitches(E4, E4), PERFECT_UNISON);
+        assert_eq!(Interval::from_pitches(C2, C5), TRIPLE_OCTAVE);
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        for semitones in 0..=24 {
+            let interval = Interval::new(semitones);
+            let name = interval.name().unwrap();
+            assert_eq!(name.parse(), Ok(interval), "{name}");
+        }
+        assert_eq!(PERFECT_UNISON.name(), Some("P1"));
+        assert_eq!(DIMINISHED_FIFTH.name(), Some("A4"));
+        assert_eq!(DOUBLE_OCTAVE.name(), Some("P15"));
+        assert_eq!(Interval::new(25).name(), None);
+        assert!("".parse::<Interval>().is_err());
+        assert!("p5".parse::<Interval>().is_err());
+    }
+
+    #[test]
+    fn test_constant_names() {
+        for (interval, name) in [
+            (PERFECT_UNISON, "P1"),
+            (MINOR_SECOND, "m2"),
+            (MAJOR_SECOND, "M2"),
+            (MINOR_THIRD, "m3"),
+            (MAJOR_THIRD, "M3"),
+            (PERFECT_FOURTH, "P4"),
+            (AUGMENTED_FOURTH, "A4"),
+            (PERFECT_FIFTH, "P5"),
+            (MINOR_SIXTH, "m6"),
+            (MAJOR_SIXTH, "M6"),
+            (MINOR_SEVENTH, "m7"),
+            (MAJOR_SEVENTH, "M7"),
+            (PERFECT_OCTAVE, "P8"),
+            (MINOR_NINTH, "m9"),
+            (MAJOR_NINTH, "M9"),
+            (MINOR_TENTH, "m10"),
+            (MAJOR_TENTH, "M10"),
+            (PERFECT_ELEVENTH, "P11"),
+            (AUGMENTED_ELEVENTH, "A11"),
+            (PERFECT_TWELFTH, "P12"),
+            (MINOR_THIRTEENTH, "m13"),
+            (MAJOR_THIRTEENTH, "M13"),
+            (MINOR_FOURTEENTH, "m14"),
+            (MAJOR_FOURTEENTH, "M14"),
+            (DOUBLE_OCTAVE, "P15"),
+        ] {
+            assert_eq!(interval.name(), Some(name), "{name}");
+            assert_eq!(name.parse(), Ok(interval), "{name}");
+        }
+
+        // The enharmonic constants share the size of the usual spelling
+        assert_eq!(DIMINISHED_FIFTH, AUGMENTED_FOURTH);
+        assert_eq!(AUGMENTED_FIFTH, MINOR_SIXTH);
+        assert_eq!(DIMINISHED_SIXTH, PERFECT_FIFTH);
+        assert_eq!(DIMINISHED_TWELFTH, AUGMENTED_ELEVENTH);
+        assert_eq!(AUGMENTED_TWELFTH, MINOR_THIRTEENTH);
+        assert_eq!(DIMINISHED_THIRTEENTH, PERFECT_TWELFTH);
+        assert_eq!(MINOR_TWENTIETH, MINOR_SIXTH.compound(2).unwrap());
+        assert_eq!(MAJOR_TWENTIETH, MAJOR_SIXTH.compound(2).unwrap());
+    }
+
+    #[test]
+    fn test_quality_predicates() {
+        for interval in [
+            PERFECT_UNISON,
+            PERFECT_FOURTH,
+            PERFECT_FIFTH,
+            PERFECT_OCTAVE,
+            PERFECT_ELEVENTH,
+            PERFECT_TWELFTH,
+            DOUBLE_OCTAVE,
+        ] {
+            assert!(interval.is_perfect(), "{interval:?}");
+            assert!(!interval.is_major() && !interval.is_minor());
+        }
+
+        for interval in [
+            MAJOR_SECOND,
+            MAJOR_THIRD,
+            MAJOR_SIXTH,
+            MAJOR_SEVENTH,
+            MAJOR_NINTH,
+            MAJOR_TENTH,
+            MAJOR_THIRTEENTH,
+            MAJOR_FOURTEENTH,
+        ] {
+            assert!(interval.is_major(), "{interval:?}");
+            assert!(!interval.is_perfect() && !interval.is_minor());
+        }
+        for interval in [
+            MINOR_SECOND,
+            MINOR_THIRD,
+            MINOR_SIXTH,
+            MINOR_SEVENTH,
+            MINOR_NINTH,
+            MINOR_TENTH,
+            MINOR_THIRTEENTH,
+            MINOR_FOURTEENTH,
+        ] {
+            assert!(interval.is_minor(), "{interval:?}");
+            assert!(!interval.is_perfect() && !interval.is_major());
+        }
+
+        // Compound intervals have the quality of their simple interval
+        assert!(MAJOR_SIXTH.compound(3).unwrap().is_major());
+        assert!(MINOR_SIXTH.compound(3).unwrap().is_minor());
+        assert!(Interval::new(40).is_major());
+
+        assert!(AUGMENTED_FOURTH.is_augmented_or_diminished());
+        assert!(AUGMENTED_ELEVENTH.is_augmented_or_diminished());
+        assert!(!AUGMENTED_FOURTH.is_perfect());
+
+        // Every interval has exactly one quality
+        for semitones in 0..=36 {
+            let interval = Interval::new(semitones);
+            let qualities = [
+                interval.is_perfect(),
+                interval.is_major(),
+                interval.is_minor(),
+                interval.is_augmented_or_diminished(),
+            ];
+            assert_eq!(qualities.iter().filter(|&&q| q).count(), 1, "{semitones}");
+        }
+    }
+
+    #[test]
+    fn test_enharmonic_spellings() {
+        let tritone = Interval::new(6).enharmonic_spellings();
+        assert!(tritone.contains(&"Augmented Fourth"));
+        assert!(tritone.contains(&"Diminished Fifth"));
+
+        let minor_third = Interval::new(3).enharmonic_spellings();
+        assert_eq!(minor_third, vec!["Minor Third", "Augmented Second"]);
+
+        assert_eq!(
+            MINOR_SIXTH.enharmonic_spellings(),
+            vec!["Minor Sixth", "Augmented Fifth"]
+        );
+        assert_eq!(MAJOR_SIXTH.enharmonic_spellings()[0], "Major Sixth");
+        assert!(AUGMENTED_FIFTH
+            .enharmonic_spellings()
+            .contains(&"Augmented Fifth"));
+        assert_eq!(
+            MINOR_THIRTEENTH.enharmonic_spellings()[0],
+            "Minor Thirteenth"
+        );
+        assert_eq!(
+            MAJOR_THIRTEENTH.enharmonic_spellings()[0],
+            "Major Thirteenth"
+        );
+        assert_eq!(MINOR_SEVENTH.enharmonic_spellings()[0], "Minor Seventh");
+        assert_eq!(PERFECT_OCTAVE.enharmonic_spellings()[0], "Perfect Octave");
+        assert_eq!(DOUBLE_OCTAVE.enharmonic_spellings()[0], "Double Octave");
+        assert!(Interval::new(25).enharmonic_spellings().is_empty());
+    }
+
+    #[test]
+    fn test_compound() {
+        assert_eq!(
+            MAJOR_THIRD.compound(1).unwrap().quality_name(),
+            Some("Major Tenth")
+        );
+        assert_eq!(
+            MINOR_SECOND.compound(1).unwrap().quality_name(),
+            Some("Minor Ninth")
+        );
+        assert_eq!(
+            PERFECT_FIFTH.compound(1).unwrap().to_string(),
+            "Perfect Twelfth"
+        );
+        assert_eq!(
+            PERFECT_OCTAVE.compound(1).unwrap().to_string(),
+            "Double Octave"
+        );
+        assert_eq!(MAJOR_SEVENTH.compound(1), Some(MAJOR_FOURTEENTH));
+        assert_eq!(PERFECT_FIFTH.compound(0), Some(PERFECT_FIFTH));
+        assert_eq!(MAJOR_SIXTH.to_string(), "Major Sixth");
+        assert_eq!(MINOR_SIXTH.to_string(), "Minor Sixth");
+        assert_eq!(MAJOR_SIXTH.compound(1), Some(MAJOR_THIRTEENTH));
+
+        for semitones in 0..=24 {
+            let interval = Interval::new(semitones);
+            assert_eq!(
+                interval.to_string(),
+                interval.enharmonic_spellings()[0],
+                "{semitones}"
+            );
+        }
+        assert_eq!(MINOR_THIRD.compound(2).unwrap().quality_name(), None);
+        assert_eq!(MINOR_THIRD.compound(2).unwrap().to_string(), "27 semitones");
+    }
+
+    #[test]
+    fn test_compound_overflow() {
+        assert_eq!(PERFECT_FIFTH.compound(21), None);
+        assert_eq!(PERFECT_UNISON.compound(21), Some(Interval::new(252)));
+        assert_eq!(PERFECT_UNISON.compound(22), None);
+    }
+}
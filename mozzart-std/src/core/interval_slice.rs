@@ -0,0 +1,131 @@
+use crate::{Interval, Note};
+
+/// Operations on sequences of intervals
+///
+/// This trait is implemented for slices of intervals, such as the intervals
+/// between the notes of a chord or the steps of a scale.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let steps = [MAJOR_THIRD, MINOR_THIRD];
+/// assert_eq!(steps.pitches(C4), vec![C4, E4, G4]);
+/// ```
+pub trait IntervalSlice {
+    /// Returns the notes obtained by stacking the intervals above a root, without allocating
+    ///
+    /// The notes are computed as the iterator is consumed. The root comes first,
+    /// then each interval is added to the previous note. This is the reverse of
+    /// `NoteSlice::intervals_iter` for notes going up.
+    ///
+    /// # Arguments
+    /// * `root` - The first note
+    ///
+    /// # Returns
+    /// An iterator over the notes, which stops before the first note above MIDI 127
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let mut pitches = [PERFECT_FIFTH].pitches_iter(C4);
+    /// assert_eq!(pitches.next(), Some(C4));
+    /// assert_eq!(pitches.next(), Some(G4));
+    /// assert_eq!(pitches.next(), None);
+    /// ```
+    fn pitches_iter(&self, root: Note) -> impl Iterator<Item = Note> + '_;
+
+    /// Returns the notes obtained by stacking the intervals above a root
+    ///
+    /// # Arguments
+    /// * `root` - The first note
+    ///
+    /// # Returns
+    /// The root followed by one note per interval, up to the last one within the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let steps = [MAJOR_SECOND, MAJOR_SECOND, MINOR_SECOND];
+    /// assert_eq!(steps.pitches(C4), vec![C4, D4, E4, F4]);
+    /// ```
+    fn pitches(&self, root: Note) -> Vec<Note> {
+        self.pitches_iter(root).collect()
+    }
+
+    /// Returns the notes obtained by stacking the intervals above each of several roots
+    ///
+    /// This applies the same pattern over a sequence of roots, such as an
+    /// arpeggio over the roots of a chord progression.
+    ///
+    /// # Arguments
+    /// * `roots` - The first note of each repetition of the pattern
+    ///
+    /// # Returns
+    /// The notes of the pattern for each root, in the order of the roots, see `pitches`
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let fifths = [PERFECT_FIFTH].transpose_pattern_to(&[C4, D4]);
+    /// assert_eq!(fifths, vec![vec![C4, G4], vec![D4, A4]]);
+    /// ```
+    fn transpose_pattern_to(&self, roots: &[Note]) -> Vec<Vec<Note>> {
+        roots.iter().map(|&root| self.pitches(root)).collect()
+    }
+}
+
+impl IntervalSlice for [Interval] {
+    fn pitches_iter(&self, root: Note) -> impl Iterator<Item = Note> + '_ {
+        let stacked = self.iter().scan(root, |note, interval| {
+            *note = note.checked_add_semitones(i16::from(interval.semitones()))?;
+            Some(*note)
+        });
+        std::iter::once(root).chain(stacked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::NoteSlice;
+
+    #[test]
+    fn test_pitches() {
+        let intervals = [MAJOR_THIRD, MINOR_THIRD, PERFECT_FOURTH];
+        assert_eq!(intervals.pitches(C4), vec![C4, E4, G4, C5]);
+        assert!(intervals.pitches_iter(C4).eq(intervals.pitches(C4)));
+
+        assert_eq!([].pitches(A4), vec![A4]);
+        assert_eq!([MAJOR_SECOND].pitches(A4), vec![A4, B4]);
+    }
+
+    #[test]
+    fn test_transpose_pattern_to() {
+        let major_triad = [MAJOR_THIRD, MINOR_THIRD];
+        assert_eq!(
+            major_triad.transpose_pattern_to(&[C4, F4, G4]),
+            vec![vec![C4, E4, G4], vec![F4, A4, C5], vec![G4, B4, D5]]
+        );
+        assert!(major_triad.transpose_pattern_to(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_pitches_stop_at_midi_range() {
+        assert_eq!([PERFECT_FIFTH, PERFECT_FIFTH].pitches(C9), vec![C9, G9]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let notes = [C3, G3, E4, ASHARP4, D5];
+        assert_eq!(notes.intervals().pitches(C3), notes.to_vec());
+    }
+}
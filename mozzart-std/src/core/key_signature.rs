@@ -0,0 +1,298 @@
+use crate::constants::SEMITONES_IN_OCTAVE;
+use crate::Note;
+
+/// The natural letter names, in ascending order starting from C
+pub(crate) const LETTERS: [&str; 7] = ["C", "D", "E", "F", "G", "A", "B"];
+
+/// The pitch class (0-11) of each natural letter name in `LETTERS`
+pub(crate) const NATURAL_PITCH_CLASSES: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// The letters that receive a sharp, in key signature order (F, C, G, D, A, E, B),
+/// expressed as indices into `LETTERS`. Flats are added in the reverse order.
+const SHARP_ORDER: [usize; 7] = [3, 0, 4, 1, 5, 2, 6];
+
+/// Names used for pitch classes that are not diatonic in a sharp (or natural) key
+const SHARP_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// Names used for pitch classes that are not diatonic in a flat key
+const FLAT_NAMES: [&str; 12] = [
+    "C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B",
+];
+
+/// Represents a key signature as a number of sharps or flats
+///
+/// A key signature tells which letter names are altered throughout a piece.
+/// It is stored as a signed count of accidentals:
+/// - Positive values are sharps (1 = one sharp, F#)
+/// - Negative values are flats (-1 = one flat, Bb)
+/// - Zero is the key of C major / A minor
+///
+/// Unlike a `Note`, a key signature knows about letter names, which makes it
+/// possible to choose between enharmonic spellings such as F# and Gb.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let g_major = KeySignature::major(G4);
+/// assert_eq!(g_major.sharps(), 1);
+/// assert_eq!(g_major, G_MAJOR_KEY);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct KeySignature(i8);
+
+impl KeySignature {
+    /// Creates a new `KeySignature` from a signed number of accidentals
+    ///
+    /// # Arguments
+    /// * `accidentals` - The number of sharps (positive) or flats (negative), in -7..=7
+    ///
+    /// # Returns
+    /// A new `KeySignature` instance
+    #[inline]
+    pub(crate) const fn new(accidentals: i8) -> Self {
+        Self(accidentals)
+    }
+
+    /// Creates a key signature with the given number of sharps
+    ///
+    /// # Arguments
+    /// * `sharps` - The number of sharps (0-7)
+    ///
+    /// # Returns
+    /// The key signature, or `None` if more than 7 sharps are requested
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(KeySignature::from_sharps(2), Some(D_MAJOR_KEY));
+    /// assert_eq!(KeySignature::from_sharps(8), None);
+    /// ```
+    pub fn from_sharps(sharps: u8) -> Option<Self> {
+        (sharps <= 7).then(|| Self::new(sharps as i8))
+    }
+
+    /// Creates a key signature with the given number of flats
+    ///
+    /// # Arguments
+    /// * `flats` - The number of flats (0-7)
+    ///
+    /// # Returns
+    /// The key signature, or `None` if more than 7 flats are requested
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(KeySignature::from_flats(3), Some(EFLAT_MAJOR_KEY));
+    /// assert_eq!(KeySignature::from_flats(8), None);
+    /// ```
+    pub fn from_flats(flats: u8) -> Option<Self> {
+        (flats <= 7).then(|| Self::new(-(flats as i8)))
+    }
+
+    /// Returns the conventional key signature of the major key on the given tonic
+    ///
+    /// Since a `Note` does not carry a spelling, enharmonic tonics are resolved to
+    /// the most common choice: Db, Eb, Ab and Bb use flats, while F# uses sharps.
+    /// Use the key constants (e.g. `GFLAT_MAJOR_KEY`) to pick the other spelling.
+    ///
+    /// # Arguments
+    /// * `tonic` - The tonic of the major key (the octave is ignored)
+    ///
+    /// # Returns
+    /// The key signature of the major key
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(KeySignature::major(D4).sharps(), 2);
+    /// assert_eq!(KeySignature::major(EFLAT4).flats(), 3);
+    /// ```
+    pub fn major(tonic: Note) -> Self {
+        const ACCIDENTALS: [i8; 12] = [0, -5, 2, -3, 4, -1, 6, 1, -4, 3, -2, 5];
+        Self::new(ACCIDENTALS[usize::from(tonic.pitch_class())])
+    }
+
+    /// Returns the conventional key signature of the minor key on the given tonic
+    ///
+    /// A minor key shares its key signature with its relative major,
+    /// which lies a minor third above the minor tonic.
+    ///
+    /// # Arguments
+    /// * `tonic` - The tonic of the minor key (the octave is ignored)
+    ///
+    /// # Returns
+    /// The key signature of the minor key
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(KeySignature::minor(A4), C_MAJOR_KEY);
+    /// assert_eq!(KeySignature::minor(E4).sharps(), 1);
+    /// ```
+    pub fn minor(tonic: Note) -> Self {
+        let relative_major = tonic.pitch_class() + 3;
+        Self::major(Note::new(relative_major))
+    }
+
+    /// Returns the signed number of accidentals (positive for sharps, negative for flats)
+    #[inline]
+    pub const fn accidentals(&self) -> i8 {
+        self.0
+    }
+
+    /// Returns the number of sharps in the key signature (0 for flat keys)
+    #[inline]
+    pub const fn sharps(&self) -> u8 {
+        if self.0 > 0 {
+            self.0 as u8
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of flats in the key signature (0 for sharp keys)
+    #[inline]
+    pub const fn flats(&self) -> u8 {
+        if self.0 < 0 {
+            self.0.unsigned_abs()
+        } else {
+            0
+        }
+    }
+
+    /// Returns the alteration, in semitones, applied by this key to a letter name
+    ///
+    /// # Arguments
+    /// * `letter` - The index of the letter name in `LETTERS` (0 = C, 6 = B)
+    ///
+    /// # Returns
+    /// 1 if the letter is sharpened, -1 if it is flattened, 0 otherwise
+    fn alteration(&self, letter: usize) -> i8 {
+        let count = self.0.unsigned_abs() as usize;
+        if self.0 > 0 && SHARP_ORDER[..count].contains(&letter) {
+            1
+        } else if self.0 < 0 && SHARP_ORDER[7 - count..].contains(&letter) {
+            -1
+        } else {
+            0
+        }
+    }
+
+    /// Spells a pitch class according to this key signature
+    ///
+    /// Pitch classes that belong to the key use the key's letter names
+    /// (so B# appears in C# major and Cb in Gb major). Chromatic pitch classes
+    /// fall back to sharps in sharp keys and flats in flat keys.
+    ///
+    /// # Arguments
+    /// * `pitch_class` - The pitch class to spell (0-11)
+    ///
+    /// # Returns
+    /// The spelled note name, without an octave number
+    pub(crate) fn spell(&self, pitch_class: u8) -> String {
+        let octave = SEMITONES_IN_OCTAVE as i8;
+        for (letter, natural) in NATURAL_PITCH_CLASSES.iter().enumerate() {
+            let alteration = self.alteration(letter);
+            let spelled = (*natural as i8 + alteration).rem_euclid(octave) as u8;
+            if spelled == pitch_class {
+                let accidental = match alteration {
+                    1 => "#",
+                    -1 => "b",
+                    _ => "",
+                };
+                return format!("{}{accidental}", LETTERS[letter]);
+            }
+        }
+
+        let names = if self.0 < 0 { FLAT_NAMES } else { SHARP_NAMES };
+        names[pitch_class as usize].to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_from_sharps_and_flats() {
+        assert_eq!(KeySignature::from_sharps(0), Some(C_MAJOR_KEY));
+        assert_eq!(KeySignature::from_sharps(7), Some(CSHARP_MAJOR_KEY));
+        assert_eq!(KeySignature::from_flats(7), Some(CFLAT_MAJOR_KEY));
+        assert_eq!(KeySignature::from_sharps(8), None);
+        assert_eq!(KeySignature::from_flats(8), None);
+    }
+
+    #[test]
+    fn test_major_keys() {
+        assert_eq!(KeySignature::major(C4), C_MAJOR_KEY);
+        assert_eq!(KeySignature::major(G4), G_MAJOR_KEY);
+        assert_eq!(KeySignature::major(F4), F_MAJOR_KEY);
+        assert_eq!(KeySignature::major(DFLAT4), DFLAT_MAJOR_KEY);
+        assert_eq!(KeySignature::major(FSHARP4), FSHARP_MAJOR_KEY);
+        assert_eq!(KeySignature::major(B2), B_MAJOR_KEY);
+    }
+
+    #[test]
+    fn test_minor_keys() {
+        assert_eq!(KeySignature::minor(A4), A_MINOR_KEY);
+        assert_eq!(KeySignature::minor(C4), C_MINOR_KEY);
+        assert_eq!(KeySignature::minor(FSHARP4), FSHARP_MINOR_KEY);
+        assert_eq!(C_MINOR_KEY, EFLAT_MAJOR_KEY);
+    }
+
+    #[test]
+    fn test_sharps_and_flats_counts() {
+        assert_eq!(E_MAJOR_KEY.sharps(), 4);
+        assert_eq!(E_MAJOR_KEY.flats(), 0);
+        assert_eq!(AFLAT_MAJOR_KEY.sharps(), 0);
+        assert_eq!(AFLAT_MAJOR_KEY.flats(), 4);
+        assert_eq!(AFLAT_MAJOR_KEY.accidentals(), -4);
+    }
+
+    #[test]
+    fn test_spell_diatonic_notes() {
+        let d_major: Vec<_> = [2, 4, 6, 7, 9, 11, 1]
+            .iter()
+            .map(|pc| D_MAJOR_KEY.spell(*pc))
+            .collect();
+        assert_eq!(d_major, ["D", "E", "F#", "G", "A", "B", "C#"]);
+
+        let eflat_major: Vec<_> = [3, 5, 7, 8, 10, 0, 2]
+            .iter()
+            .map(|pc| EFLAT_MAJOR_KEY.spell(*pc))
+            .collect();
+        assert_eq!(eflat_major, ["Eb", "F", "G", "Ab", "Bb", "C", "D"]);
+    }
+
+    #[test]
+    fn test_spell_extreme_keys() {
+        // C# major spells C as B# and F as E#
+        assert_eq!(CSHARP_MAJOR_KEY.spell(0), "B#");
+        assert_eq!(CSHARP_MAJOR_KEY.spell(5), "E#");
+
+        // Cb major spells B as Cb and E as Fb
+        assert_eq!(CFLAT_MAJOR_KEY.spell(11), "Cb");
+        assert_eq!(CFLAT_MAJOR_KEY.spell(4), "Fb");
+    }
+
+    #[test]
+    fn test_spell_chromatic_notes() {
+        assert_eq!(C_MAJOR_KEY.spell(6), "F#");
+        assert_eq!(G_MAJOR_KEY.spell(10), "A#");
+        assert_eq!(F_MAJOR_KEY.spell(6), "Gb");
+        assert_eq!(BFLAT_MAJOR_KEY.spell(1), "Db");
+    }
+}
@@ -1,7 +1,29 @@
+mod error;
 mod interval;
+mod interval_slice;
+mod key_signature;
+mod named_interval;
+mod notation;
 mod note;
+mod note_slice;
+mod pitch_class_mask;
+mod rational;
+mod spelling;
 mod step;
+mod temperament;
+mod tuning;
 
+pub use error::*;
 pub use interval::*;
+pub use interval_slice::*;
+pub use key_signature::*;
+pub use named_interval::*;
+pub use notation::*;
 pub use note::*;
+pub use note_slice::*;
+pub use pitch_class_mask::*;
+pub use rational::*;
+pub use spelling::*;
 pub use step::*;
+pub use temperament::*;
+pub use tuning::*;
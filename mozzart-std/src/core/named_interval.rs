@@ -0,0 +1,460 @@
+use crate::constants::SEMITONES_IN_OCTAVE;
+use crate::{Error, SpelledNote, NATURAL_PITCH_CLASSES};
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents the quality of a spelled interval, from doubly diminished to doubly augmented
+///
+/// Unisons, fourths, fifths and octaves are perfect, diminished or augmented;
+/// seconds, thirds, sixths and sevenths are major, minor, diminished or augmented.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IntervalQuality {
+    /// A half step smaller than diminished
+    DoublyDiminished,
+    /// A half step smaller than perfect or minor
+    Diminished,
+    /// A half step smaller than major
+    Minor,
+    /// The size of a unison, fourth, fifth or octave in the major scale
+    Perfect,
+    /// The size of a second, third, sixth or seventh in the major scale
+    Major,
+    /// A half step larger than perfect or major
+    Augmented,
+    /// A half step larger than augmented
+    DoublyAugmented,
+}
+
+impl IntervalQuality {
+    /// The qualities of the perfect intervals, from the smallest, and their offsets from perfect
+    const PERFECT: [(IntervalQuality, i8); 5] = [
+        (IntervalQuality::DoublyDiminished, -2),
+        (IntervalQuality::Diminished, -1),
+        (IntervalQuality::Perfect, 0),
+        (IntervalQuality::Augmented, 1),
+        (IntervalQuality::DoublyAugmented, 2),
+    ];
+
+    /// The qualities of the other intervals, from the smallest, and their offsets from major
+    const IMPERFECT: [(IntervalQuality, i8); 6] = [
+        (IntervalQuality::DoublyDiminished, -3),
+        (IntervalQuality::Diminished, -2),
+        (IntervalQuality::Minor, -1),
+        (IntervalQuality::Major, 0),
+        (IntervalQuality::Augmented, 1),
+        (IntervalQuality::DoublyAugmented, 2),
+    ];
+
+    /// Returns the qualities an interval of a number can have
+    fn all_for(number: u8) -> &'static [(IntervalQuality, i8)] {
+        if is_perfect_number(number) {
+            &Self::PERFECT
+        } else {
+            &Self::IMPERFECT
+        }
+    }
+
+    /// Returns the short name of the quality, as used by `NamedInterval::name`
+    const fn symbol(&self) -> &'static str {
+        match self {
+            IntervalQuality::DoublyDiminished => "dd",
+            IntervalQuality::Diminished => "d",
+            IntervalQuality::Minor => "m",
+            IntervalQuality::Perfect => "P",
+            IntervalQuality::Major => "M",
+            IntervalQuality::Augmented => "A",
+            IntervalQuality::DoublyAugmented => "AA",
+        }
+    }
+
+    /// Returns the full name of the quality, such as "Augmented"
+    const fn full_name(&self) -> &'static str {
+        match self {
+            IntervalQuality::DoublyDiminished => "Doubly Diminished",
+            IntervalQuality::Diminished => "Diminished",
+            IntervalQuality::Minor => "Minor",
+            IntervalQuality::Perfect => "Perfect",
+            IntervalQuality::Major => "Major",
+            IntervalQuality::Augmented => "Augmented",
+            IntervalQuality::DoublyAugmented => "Doubly Augmented",
+        }
+    }
+}
+
+/// The names of the interval numbers, from the unison to the octave
+const NUMBER_NAMES: [&str; 8] = [
+    "Unison", "Second", "Third", "Fourth", "Fifth", "Sixth", "Seventh", "Octave",
+];
+
+/// Returns `true` if the intervals of a number are perfect rather than major or minor
+fn is_perfect_number(number: u8) -> bool {
+    matches!(number, 1 | 4 | 5 | 8)
+}
+
+/// Returns the size in semitones of the perfect or major interval of a number
+fn natural_semitones(number: u8) -> i8 {
+    let letters = usize::from(number - 1);
+    let octaves = (letters / NATURAL_PITCH_CLASSES.len()) as u8;
+    (NATURAL_PITCH_CLASSES[letters % NATURAL_PITCH_CLASSES.len()] + octaves * SEMITONES_IN_OCTAVE)
+        as i8
+}
+
+/// Represents an interval named by its number and quality, such as an augmented second
+///
+/// An `Interval` only counts semitones, so an augmented second and a minor third
+/// are the same interval. A `NamedInterval` also counts letters: from C to D# is
+/// a second, because C and D are one letter apart, while from C to Eb is a third.
+/// The interval numbers go from the unison to the octave.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let c: SpelledNote = "C".parse().unwrap();
+/// let d_sharp: SpelledNote = "D#".parse().unwrap();
+/// let e_flat: SpelledNote = "Eb".parse().unwrap();
+///
+/// let second = NamedInterval::between(c, d_sharp).unwrap();
+/// let third = NamedInterval::between(c, e_flat).unwrap();
+/// assert_eq!(second.to_string(), "Augmented Second");
+/// assert_eq!(third.to_string(), "Minor Third");
+/// assert_eq!(second.semitones(), third.semitones());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "NamedIntervalRecord")
+)]
+pub struct NamedInterval {
+    quality: IntervalQuality,
+    number: u8,
+}
+
+/// The serialized form of a `NamedInterval`, checked by `NamedInterval::new` once read
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct NamedIntervalRecord {
+    quality: IntervalQuality,
+    number: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<NamedIntervalRecord> for NamedInterval {
+    type Error = Error;
+
+    fn try_from(record: NamedIntervalRecord) -> Result<Self, Self::Error> {
+        let NamedIntervalRecord { quality, number } = record;
+        NamedInterval::new(quality, number)
+            .ok_or_else(|| Error::InvalidIntervalName(format!("{quality:?} {number}")))
+    }
+}
+
+impl NamedInterval {
+    /// Creates a new `NamedInterval`
+    ///
+    /// # Arguments
+    /// * `quality` - The quality of the interval
+    /// * `number` - The number of the interval, from 1 for a unison to 8 for an octave
+    ///
+    /// # Returns
+    /// The interval, or `None` if the number is out of range or doesn't have the
+    /// quality, such as a perfect third or a major fifth
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// let fifth = NamedInterval::new(IntervalQuality::Perfect, 5).unwrap();
+    /// assert_eq!(fifth.semitones(), 7);
+    /// assert_eq!(NamedInterval::new(IntervalQuality::Major, 5), None);
+    /// ```
+    pub fn new(quality: IntervalQuality, number: u8) -> Option<Self> {
+        let valid = (1..=8).contains(&number)
+            && IntervalQuality::all_for(number)
+                .iter()
+                .any(|&(candidate, _)| candidate == quality);
+        valid.then_some(Self { quality, number })
+    }
+
+    /// Creates the interval of a number spanning a number of semitones
+    ///
+    /// The semitones are counted within an octave, so the interval is the one
+    /// closest to the perfect or major interval of its number.
+    ///
+    /// # Arguments
+    /// * `number` - The number of the interval, from 1 for a unison to 8 for an octave
+    /// * `semitones` - The size of the interval, in any octave
+    ///
+    /// # Returns
+    /// The interval, or `None` if the number is out of range or the size is
+    /// beyond a doubly augmented or doubly diminished interval of that number
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// assert_eq!(NamedInterval::with_semitones(2, 3).unwrap().name(), "A2");
+    /// assert_eq!(NamedInterval::with_semitones(3, 3).unwrap().name(), "m3");
+    /// assert_eq!(NamedInterval::with_semitones(5, 0), None);
+    /// ```
+    pub fn with_semitones(number: u8, semitones: u8) -> Option<Self> {
+        if !(1..=8).contains(&number) {
+            return None;
+        }
+
+        let octave = SEMITONES_IN_OCTAVE as i8;
+        let semitones = (semitones % SEMITONES_IN_OCTAVE) as i8;
+        // The offset from the perfect or major interval, between -6 and 5
+        let offset = (semitones - natural_semitones(number) + 6).rem_euclid(octave) - 6;
+        IntervalQuality::all_for(number)
+            .iter()
+            .find(|&&(_, candidate)| candidate == offset)
+            .map(|&(quality, _)| Self { quality, number })
+    }
+
+    /// Returns the interval from one spelled note up to another
+    ///
+    /// The number comes from the letters, counted up from the first note, and
+    /// the quality from the semitones between the notes.
+    ///
+    /// # Arguments
+    /// * `from` - The lower note
+    /// * `to` - The upper note
+    ///
+    /// # Returns
+    /// The ascending interval within an octave, or `None` if it would be beyond
+    /// doubly augmented or doubly diminished, as from Fbb up to B##
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// let a_flat: SpelledNote = "Ab".parse().unwrap();
+    /// let f_sharp: SpelledNote = "F#".parse().unwrap();
+    /// let g_flat: SpelledNote = "Gb".parse().unwrap();
+    /// assert_eq!(NamedInterval::between(a_flat, f_sharp).unwrap().name(), "A6");
+    /// assert_eq!(NamedInterval::between(a_flat, g_flat).unwrap().name(), "m7");
+    /// ```
+    pub fn between(from: SpelledNote, to: SpelledNote) -> Option<Self> {
+        let letters = NATURAL_PITCH_CLASSES.len() as u8;
+        let number = (to.letter_index() + letters - from.letter_index()) % letters + 1;
+        let semitones =
+            (to.pitch_class() + SEMITONES_IN_OCTAVE - from.pitch_class()) % SEMITONES_IN_OCTAVE;
+        Self::with_semitones(number, semitones)
+    }
+
+    /// Returns the quality of the interval
+    pub fn quality(&self) -> IntervalQuality {
+        self.quality
+    }
+
+    /// Returns the number of the interval, from 1 for a unison to 8 for an octave
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Returns the size of the interval in semitones
+    ///
+    /// The diminished unisons are the only intervals smaller than a unison, so
+    /// their size is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// let third: NamedInterval = "d3".parse().unwrap();
+    /// assert_eq!(third.semitones(), 2);
+    /// ```
+    pub fn semitones(&self) -> i8 {
+        let (_, offset) = IntervalQuality::all_for(self.number)
+            .iter()
+            .find(|&&(quality, _)| quality == self.quality)
+            .copied()
+            .unwrap_or((self.quality, 0));
+        natural_semitones(self.number) + offset
+    }
+
+    /// Returns the short name of the interval, such as `A2` or `dd7`
+    pub fn name(&self) -> String {
+        format!("{}{}", self.quality.symbol(), self.number)
+    }
+}
+
+/// Writes the full name of the interval, such as "Augmented Second"
+impl fmt::Display for NamedInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.quality.full_name(),
+            NUMBER_NAMES[usize::from(self.number - 1)]
+        )
+    }
+}
+
+/// Parses an interval from its short name, as returned by `NamedInterval::name`
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let second: NamedInterval = "A2".parse().unwrap();
+/// assert_eq!(second.quality(), IntervalQuality::Augmented);
+/// assert_eq!(second.number(), 2);
+/// assert!("P3".parse::<NamedInterval>().is_err());
+/// ```
+impl FromStr for NamedInterval {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidIntervalName(name.to_string());
+
+        let digits = name
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        let (symbol, number) = name.split_at(digits);
+        let quality = IntervalQuality::PERFECT
+            .iter()
+            .chain(IntervalQuality::IMPERFECT.iter())
+            .map(|&(quality, _)| quality)
+            .find(|quality| quality.symbol() == symbol)
+            .ok_or_else(invalid)?;
+        let number = number.parse().map_err(|_| invalid())?;
+
+        Self::new(quality, number).ok_or_else(invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spelled(name: &str) -> SpelledNote {
+        name.parse().unwrap()
+    }
+
+    fn between(from: &str, to: &str) -> String {
+        NamedInterval::between(spelled(from), spelled(to))
+            .unwrap()
+            .name()
+    }
+
+    #[test]
+    fn test_between() {
+        assert_eq!(between("C", "D#"), "A2");
+        assert_eq!(between("C", "Eb"), "m3");
+        assert_eq!(between("C", "G"), "P5");
+        assert_eq!(between("B", "F"), "d5");
+        assert_eq!(between("F", "B"), "A4");
+        // Augmented sixth and minor seventh, both ten semitones
+        assert_eq!(between("Ab", "F#"), "A6");
+        assert_eq!(between("Ab", "Gb"), "m7");
+        // Diminished third, two semitones
+        assert_eq!(between("C#", "Eb"), "d3");
+        assert_eq!(between("G#", "Bb"), "d3");
+        // Thirteen semitones written as a seventh
+        assert_eq!(between("Cb", "B#"), "AA7");
+        assert_eq!(between("B#", "Cb"), "dd2");
+        assert_eq!(between("C", "C#"), "A1");
+        assert_eq!(between("E", "E"), "P1");
+        assert_eq!(NamedInterval::between(spelled("Fbb"), spelled("B##")), None);
+    }
+
+    #[test]
+    fn test_semitones() {
+        let semitones = |name: &str| name.parse::<NamedInterval>().unwrap().semitones();
+        assert_eq!(semitones("P1"), 0);
+        assert_eq!(semitones("d1"), -1);
+        assert_eq!(semitones("A2"), 3);
+        assert_eq!(semitones("d3"), 2);
+        assert_eq!(semitones("A6"), 10);
+        assert_eq!(semitones("AA7"), 13);
+        assert_eq!(semitones("dd2"), -1);
+        assert_eq!(semitones("P8"), 12);
+        assert_eq!(semitones("d8"), 11);
+    }
+
+    #[test]
+    fn test_names() {
+        for number in 1..=8 {
+            for &(quality, _) in IntervalQuality::all_for(number) {
+                let interval = NamedInterval::new(quality, number).unwrap();
+                assert_eq!(interval.name().parse(), Ok(interval));
+            }
+        }
+        assert_eq!(
+            "AA7".parse::<NamedInterval>().unwrap().to_string(),
+            "Doubly Augmented Seventh"
+        );
+        assert_eq!(
+            "P8".parse::<NamedInterval>().unwrap().to_string(),
+            "Perfect Octave"
+        );
+        for name in ["", "5", "P", "M4", "P9", "P0", "x3", "m-3"] {
+            assert_eq!(
+                name.parse::<NamedInterval>(),
+                Err(Error::InvalidIntervalName(name.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_at_interval() {
+        let at = |from: &str, interval: &str| {
+            spelled(from)
+                .at_interval(interval.parse().unwrap())
+                .map(|note| note.to_string())
+        };
+        assert_eq!(at("C", "A2"), Ok("D#".to_string()));
+        assert_eq!(at("C", "m3"), Ok("Eb".to_string()));
+        assert_eq!(at("Cb", "AA7"), Ok("B#".to_string()));
+        assert_eq!(at("Ab", "A6"), Ok("F#".to_string()));
+        assert_eq!(at("C#", "d3"), Ok("Eb".to_string()));
+        assert_eq!(at("G#", "M7"), Ok("F##".to_string()));
+        assert_eq!(at("Db", "d7"), Ok("Cbb".to_string()));
+        assert_eq!(at("G", "P8"), Ok("G".to_string()));
+        assert_eq!(
+            at("F##", "A6"),
+            Err(Error::TooManyAccidentals {
+                letter: 'D',
+                alteration: 3
+            })
+        );
+
+        // Going up by the interval between two notes gives back the upper note
+        let names = ["C", "D", "E", "F", "G", "A", "B"];
+        let notes = names.iter().flat_map(|letter| {
+            ["bb", "b", "", "#", "##"].map(|accidental| spelled(&format!("{letter}{accidental}")))
+        });
+        let notes: Vec<_> = notes.collect();
+        for &from in &notes {
+            for &to in &notes {
+                if let Some(interval) = NamedInterval::between(from, to) {
+                    assert_eq!(from.at_interval(interval), Ok(to), "{from} {to}");
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let third: NamedInterval = "m3".parse().unwrap();
+        let json = serde_json::to_string(&third).unwrap();
+        assert_eq!(json, r#"{"quality":"Minor","number":3}"#);
+        assert_eq!(serde_json::from_str::<NamedInterval>(&json).unwrap(), third);
+
+        for json in [
+            r#"{"quality":"Major","number":5}"#,
+            r#"{"quality":"Perfect","number":3}"#,
+            r#"{"quality":"Perfect","number":9}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<NamedInterval>(json).is_err(),
+                "{json}"
+            );
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::constants::SEMITONES_IN_OCTAVE;
+use crate::{Error, Note};
+
+/// The octave of scientific pitch notation written with lowercase letters and no mark in Helmholtz notation
+const SMALL_OCTAVE: i8 = 3;
+
+/// Represents the ways of writing the octave of a note
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum NotationSystem {
+    /// The octave is a number, middle C being C4
+    Scientific,
+    /// The octave is written with the case of the letter and marks, middle C being c′
+    ///
+    /// The great octave is written in uppercase (C2 is `C`) and the small octave
+    /// in lowercase (C3 is `c`). Each prime raises a lowercase note by an octave
+    /// (C4 is `c′`, C5 is `c′′`), each comma lowers an uppercase note by an
+    /// octave (C1 is `C,`).
+    Helmholtz,
+}
+
+impl Note {
+    /// Returns the name of this note in a notation system
+    ///
+    /// The note is spelled with sharps. Helmholtz notation is written with
+    /// Unicode primes and ASCII commas.
+    ///
+    /// # Arguments
+    /// * `system` - The notation to write the octave with
+    ///
+    /// # Returns
+    /// The name of the note
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(A4.format_in(NotationSystem::Scientific), "A4");
+    /// assert_eq!(A4.format_in(NotationSystem::Helmholtz), "a′");
+    /// assert_eq!(FSHARP1.format_in(NotationSystem::Helmholtz), "F#,");
+    /// ```
+    pub fn format_in(&self, system: NotationSystem) -> String {
+        match system {
+            NotationSystem::Scientific => self.name().to_string(),
+            NotationSystem::Helmholtz => {
+                let octave = (self.midi_number() / SEMITONES_IN_OCTAVE) as i8 - 1;
+                let name = format!("{self:X}");
+                if octave >= SMALL_OCTAVE {
+                    let primes = usize::from(octave.abs_diff(SMALL_OCTAVE));
+                    name.to_lowercase() + &"′".repeat(primes)
+                } else {
+                    let commas = usize::from(octave.abs_diff(SMALL_OCTAVE - 1));
+                    name + &",".repeat(commas)
+                }
+            }
+        }
+    }
+
+    /// Parses a note written in a notation system
+    ///
+    /// Helmholtz names accept the ASCII apostrophe (`'`), the prime (`′`) and the
+    /// double prime (`″`) as octave marks above the small octave, and commas below
+    /// the great octave. Both systems accept sharps (`#`) and flats (`b`) after the letter.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the note
+    /// * `system` - The notation the name is written in
+    ///
+    /// # Returns
+    /// The note, or `Error::InvalidNoteName` if the name is not valid in the
+    /// system or the note is outside of the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(Note::parse_in("a'", NotationSystem::Helmholtz), Ok(A4));
+    /// assert_eq!(Note::parse_in("Bb,", NotationSystem::Helmholtz), Ok(ASHARP1));
+    /// assert_eq!(Note::parse_in("c″", NotationSystem::Helmholtz), Ok(C5));
+    /// assert_eq!(Note::parse_in("c4", NotationSystem::Scientific), Err(Error::InvalidNoteName("c4".to_string())));
+    /// ```
+    pub fn parse_in(name: &str, system: NotationSystem) -> Result<Note, Error> {
+        match system {
+            NotationSystem::Scientific => name.parse(),
+            NotationSystem::Helmholtz => parse_helmholtz(name),
+        }
+    }
+}
+
+/// Parses a note written in Helmholtz notation, through its scientific name
+fn parse_helmholtz(name: &str) -> Result<Note, Error> {
+    let invalid = || Error::InvalidNoteName(name.to_string());
+
+    let mut chars = name.chars();
+    let letter = chars
+        .next()
+        .filter(|letter| "CDEFGABcdefgab".contains(*letter))
+        .ok_or_else(invalid)?;
+    let rest = chars.as_str();
+    let marks = rest.trim_start_matches(['#', 'b']);
+    let accidentals = &rest[..rest.len() - marks.len()];
+
+    let mut octaves: i8 = 0;
+    for mark in marks.chars() {
+        octaves = match (letter.is_lowercase(), mark) {
+            (true, '\'' | '′') => octaves.checked_add(1),
+            (true, '″') => octaves.checked_add(2),
+            (false, ',') => octaves.checked_sub(1),
+            _ => None,
+        }
+        .ok_or_else(invalid)?;
+    }
+
+    let octave = if letter.is_lowercase() {
+        SMALL_OCTAVE + octaves
+    } else {
+        SMALL_OCTAVE - 1 + octaves
+    };
+    let scientific = format!("{}{accidentals}{octave}", letter.to_ascii_uppercase());
+    scientific.parse().map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_round_trip() {
+        for midi in 0..=127 {
+            let note = Note::try_from(midi).unwrap();
+            for system in [NotationSystem::Scientific, NotationSystem::Helmholtz] {
+                let name = note.format_in(system);
+                assert_eq!(Note::parse_in(&name, system), Ok(note), "{name}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_helmholtz_octaves() {
+        let helmholtz = |name| Note::parse_in(name, NotationSystem::Helmholtz);
+
+        assert_eq!(helmholtz("a′"), Ok(A4));
+        assert_eq!(helmholtz("a'"), Ok(A4));
+        assert_eq!(Note::parse_in("A4", NotationSystem::Scientific), Ok(A4));
+        assert_eq!(A4.midi_number(), 69);
+
+        assert_eq!(helmholtz("c′"), Ok(C4));
+        assert_eq!(helmholtz("b"), Ok(B3));
+        assert_eq!(helmholtz("c"), Ok(C3));
+        assert_eq!(helmholtz("B"), Ok(B2));
+        assert_eq!(helmholtz("C"), Ok(C2));
+        assert_eq!(helmholtz("C,"), Ok(C1));
+        assert_eq!(helmholtz("A,,"), Ok(A0));
+        assert_eq!(helmholtz("c''"), Ok(C5));
+        assert_eq!(helmholtz("c′″"), Ok(C6));
+        assert_eq!(helmholtz("bb"), Ok(ASHARP3));
+        assert_eq!(helmholtz("f#'"), Ok(FSHARP4));
+
+        assert_eq!(C4.format_in(NotationSystem::Helmholtz), "c′");
+        assert_eq!(C6.format_in(NotationSystem::Helmholtz), "c′′′");
+        assert_eq!(B2.format_in(NotationSystem::Helmholtz), "B");
+        assert_eq!(C0.format_in(NotationSystem::Helmholtz), "C,,");
+        assert_eq!(
+            Note::try_from(0)
+                .unwrap()
+                .format_in(NotationSystem::Helmholtz),
+            "C,,,"
+        );
+    }
+
+    #[test]
+    fn test_invalid_helmholtz() {
+        for name in ["", "h", "C'", "c,", "c4", "C,,,,", "a′′′′′′", "c#x"] {
+            assert_eq!(
+                Note::parse_in(name, NotationSystem::Helmholtz),
+                Err(Error::InvalidNoteName(name.to_string())),
+                "{name}"
+            );
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{constants::SEMITONES_IN_OCTAVE, *};
+use crate::{
+    constants::{A4, SEMITONES_IN_OCTAVE},
+    *,
+};
+use std::str::FromStr;
 
 /// Represents a musical note using MIDI note numbering
 ///
@@ -16,8 +20,32 @@ use crate::{constants::SEMITONES_IN_OCTAVE, *};
 /// pitches across all octaves without dealing with the complexities
 /// of frequency calculations or letter-based note naming.
 #[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u8", into = "u8")
+)]
 pub struct Note(u8);
 
+/// The number of notes in the MIDI range, from C-1 (0) to G9 (127)
+const MIDI_NOTES: u8 = 128;
+
+/// The names of all the notes of the MIDI range, spelled with sharps
+#[rustfmt::skip]
+const NAMES: [&str; MIDI_NOTES as usize] = [
+    "C-1", "C#-1", "D-1", "D#-1", "E-1", "F-1", "F#-1", "G-1", "G#-1", "A-1", "A#-1", "B-1",
+    "C0", "C#0", "D0", "D#0", "E0", "F0", "F#0", "G0", "G#0", "A0", "A#0", "B0",
+    "C1", "C#1", "D1", "D#1", "E1", "F1", "F#1", "G1", "G#1", "A1", "A#1", "B1",
+    "C2", "C#2", "D2", "D#2", "E2", "F2", "F#2", "G2", "G#2", "A2", "A#2", "B2",
+    "C3", "C#3", "D3", "D#3", "E3", "F3", "F#3", "G3", "G#3", "A3", "A#3", "B3",
+    "C4", "C#4", "D4", "D#4", "E4", "F4", "F#4", "G4", "G#4", "A4", "A#4", "B4",
+    "C5", "C#5", "D5", "D#5", "E5", "F5", "F#5", "G5", "G#5", "A5", "A#5", "B5",
+    "C6", "C#6", "D6", "D#6", "E6", "F6", "F#6", "G6", "G#6", "A6", "A#6", "B6",
+    "C7", "C#7", "D7", "D#7", "E7", "F7", "F#7", "G7", "G#7", "A7", "A#7", "B7",
+    "C8", "C#8", "D8", "D#8", "E8", "F8", "F#8", "G8", "G#8", "A8", "A#8", "B8",
+    "C9", "C#9", "D9", "D#9", "E9", "F9", "F#9", "G9",
+];
+
 impl Note {
     /// Creates a new `Note` from a MIDI note number
     ///
@@ -27,6 +55,10 @@ impl Note {
     /// # Returns
     /// A new `Note` instance
     ///
+    /// # Panics
+    /// In debug builds, if the number is above 127, the same way integer overflow
+    /// panics. This catches arithmetic that moves a note past the top of the MIDI range.
+    ///
     /// # Examples
     /// ```ignore
     /// use mozzart_std::Note;
@@ -35,6 +67,7 @@ impl Note {
     /// let a440 = Note::new(69);      // A4 (standard tuning reference at 440Hz)
     /// ```
     pub(crate) const fn new(note: u8) -> Self {
+        debug_assert!(note < MIDI_NOTES, "MIDI note number above 127");
         Self(note)
     }
 
@@ -219,522 +252,1007 @@ impl Note {
         self.0
     }
 
+    /// Returns the pitch class of this note, its place within the octave
+    ///
+    /// # Returns
+    /// The pitch class, from 0 for C to 11 for B
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(C4.pitch_class(), 0);
+    /// assert_eq!(FSHARP2.pitch_class(), 6);
+    /// assert_eq!(B7.pitch_class(), 11);
+    /// ```
+    #[inline]
+    pub fn pitch_class(&self) -> u8 {
+        self.0 % SEMITONES_IN_OCTAVE
+    }
+
+    /// Returns the name of this note with its octave, spelled with sharps
+    ///
+    /// The names come from a static table, so unlike `to_string` this doesn't
+    /// allocate. The name can be parsed back into the same note.
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(C4.name(), "C4");
+    /// assert_eq!(CSHARP4.name(), "C#4");
+    /// assert_eq!(Note::try_from(0).unwrap().name(), "C-1");
+    /// assert_eq!(A4.name().parse::<Note>(), Ok(A4));
+    /// ```
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        NAMES[usize::from(self.0)]
+    }
+
+    /// Returns the name of this note for a given octave numbering
+    ///
+    /// `name` numbers the octaves so that middle C (MIDI 60) is C4, but some
+    /// DAWs and hardware call it C3 or C5. This method shifts the octave numbers
+    /// accordingly.
+    ///
+    /// # Arguments
+    /// * `middle_c_octave` - The octave number of middle C, usually 3, 4 or 5
+    ///
+    /// # Returns
+    /// The note name with sharps, followed by its octave
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(C4.name_with_middle_c_octave(4), "C4");
+    /// assert_eq!(C4.name_with_middle_c_octave(3), "C3");
+    /// assert_eq!(FSHARP2.name_with_middle_c_octave(5), "F#3");
+    /// ```
+    pub fn name_with_middle_c_octave(&self, middle_c_octave: i8) -> String {
+        let octave = i16::from(self.0 / SEMITONES_IN_OCTAVE) + i16::from(middle_c_octave) - 5;
+        format!("{self:X}{octave}")
+    }
+
+    /// Returns the name of this note spelled according to a key signature
+    ///
+    /// The `{:X}` and `{:x}` formatters always use sharps or flats respectively.
+    /// This method instead picks the enharmonic spelling that is consistent with
+    /// the accidentals of the given key, so the same pitch class is rendered as
+    /// F# in G major but as Gb in Gb major. Notes outside the key are spelled with
+    /// sharps in sharp keys and with flats in flat keys.
+    ///
+    /// # Arguments
+    /// * `key` - The key signature providing the spelling context
+    ///
+    /// # Returns
+    /// The spelled note name, without an octave number
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(FSHARP4.spell_in_key(&G_MAJOR_KEY), "F#");
+    /// assert_eq!(FSHARP4.spell_in_key(&GFLAT_MAJOR_KEY), "Gb");
+    /// ```
+    pub fn spell_in_key(&self, key: &KeySignature) -> String {
+        key.spell(self.pitch_class())
+    }
+
+    /// Returns the name of this note in ABC notation
+    ///
+    /// Uppercase letters are the octave starting at middle C and lowercase
+    /// letters the octave above it. Every apostrophe raises a lowercase note by
+    /// an octave and every comma lowers an uppercase note by an octave. Black
+    /// keys are written as sharps, with a `^` before the letter.
+    ///
+    /// # Returns
+    /// The ABC pitch of the note, without a length
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(C4.abc(), "C");
+    /// assert_eq!(C5.abc(), "c");
+    /// assert_eq!(CSHARP4.abc(), "^C");
+    /// assert_eq!(C3.abc(), "C,");
+    /// ```
+    pub fn abc(&self) -> String {
+        if self.is_black_key() {
+            format!("^{}", self.abc_natural())
+        } else {
+            self.abc_natural()
+        }
+    }
+
+    /// Returns the ABC pitch of the white key this note is written on, without accidental
+    pub(crate) fn abc_natural(&self) -> String {
+        const LETTERS: [char; 12] = ['C', 'C', 'D', 'D', 'E', 'F', 'F', 'G', 'G', 'A', 'A', 'B'];
+        let letter = LETTERS[usize::from(self.pitch_class())];
+        // The uppercase octave starts at middle C, the fifth MIDI octave
+        let octave = i32::from(self.0 / SEMITONES_IN_OCTAVE) - 5;
+
+        if octave > 0 {
+            let marks = "'".repeat((octave - 1) as usize);
+            format!("{}{marks}", letter.to_ascii_lowercase())
+        } else {
+            let marks = ",".repeat(octave.unsigned_abs() as usize);
+            format!("{letter}{marks}")
+        }
+    }
+
+    /// Returns the frequency of this note in equal temperament
+    ///
+    /// # Arguments
+    /// * `a4_hz` - The frequency of A4, the reference pitch the other notes are tuned from
+    ///
+    /// # Returns
+    /// The frequency in hertz
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(A4.frequency_with_reference(440.0), 440.0);
+    /// assert_eq!(A5.frequency_with_reference(440.0), 880.0);
+    /// assert!((C4.frequency_with_reference(440.0) - 261.626).abs() < 0.001);
+    /// ```
+    pub fn frequency_with_reference(&self, a4_hz: f64) -> f64 {
+        let semitones = f64::from(self.0) - f64::from(A4.0);
+        a4_hz * 2f64.powf(semitones / f64::from(SEMITONES_IN_OCTAVE))
+    }
+
+    /// Returns true if this note is played on a black key of a piano keyboard
+    ///
+    /// The black keys are the five accidentals of every octave: C#, D#, F#, G# and A#.
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert!(FSHARP4.is_black_key());
+    /// assert!(!F4.is_black_key());
+    /// ```
+    #[inline]
+    pub fn is_black_key(&self) -> bool {
+        matches!(self.pitch_class(), 1 | 3 | 6 | 8 | 10)
+    }
+
+    /// Returns true if this note is played on a white key of a piano keyboard
+    ///
+    /// The white keys are the seven natural notes of every octave: C, D, E, F, G, A and B.
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert!(C4.is_white_key());
+    /// assert!(!CSHARP4.is_white_key());
+    /// ```
+    #[inline]
+    pub fn is_white_key(&self) -> bool {
+        !self.is_black_key()
+    }
+
+    /// Returns the position of this note among the white keys, counting from MIDI note 0
+    ///
+    /// White keys are numbered consecutively starting with 0 for C-1 (MIDI 0),
+    /// so C0 is 7 and middle C (C4) is 35. This is the horizontal slot used when
+    /// drawing a keyboard.
+    ///
+    /// # Returns
+    /// The 0-based white key index, or `None` if the note is a black key
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(C4.white_key_index(), Some(35));
+    /// assert_eq!(D4.white_key_index(), Some(36));
+    /// assert_eq!(CSHARP4.white_key_index(), None);
+    /// ```
+    pub fn white_key_index(&self) -> Option<u8> {
+        const WHITE_INDICES: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+        let octave = self.0 / SEMITONES_IN_OCTAVE;
+        let pitch_class = self.pitch_class();
+
+        WHITE_INDICES
+            .iter()
+            .position(|pc| *pc == pitch_class)
+            .map(|index| octave * 7 + index as u8)
+    }
+
+    /// Returns this note moved by a number of semitones, or `None` if it leaves the MIDI range
+    ///
+    /// # Arguments
+    /// * `semitones` - The number of semitones to move by, negative to move down
+    ///
+    /// # Returns
+    /// The moved note, or `None` if it would be below MIDI 0 or above MIDI 127
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(C4.checked_add_semitones(7), Some(G4));
+    /// assert_eq!(G9.checked_add_semitones(1), None);
+    /// ```
+    pub fn checked_add_semitones(&self, semitones: i16) -> Option<Note> {
+        let midi = i16::from(self.0).checked_add(semitones)?;
+        (0..i16::from(MIDI_NOTES))
+            .contains(&midi)
+            .then_some(Note(midi as u8))
+    }
+
+    /// Returns the note with the same pitch class in another octave
+    ///
+    /// Octaves are numbered as in `name`, middle C being C4.
+    ///
+    /// # Arguments
+    /// * `octave` - The octave to move the note to, from -1 to 9
+    ///
+    /// # Returns
+    /// The note in the octave, or `None` if it would be outside of the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(G5.transpose_to_octave(3), Some(G3));
+    /// assert_eq!(A4.transpose_to_octave(9), None);
+    /// ```
+    pub fn transpose_to_octave(&self, octave: i8) -> Option<Note> {
+        let octaves = i16::from(octave) - (i16::from(self.0 / SEMITONES_IN_OCTAVE) - 1);
+        self.checked_add_semitones(octaves * i16::from(SEMITONES_IN_OCTAVE))
+    }
+
+    /// Returns this note moved by a number of semitones, clamped to the MIDI range
+    ///
+    /// # Arguments
+    /// * `semitones` - The number of semitones to move by, negative to move down
+    ///
+    /// # Returns
+    /// The moved note, or the lowest or highest MIDI note if it would leave the range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(C4.saturating_add_semitones(-3), A3);
+    /// assert_eq!(G9.saturating_add_semitones(5), G9);
+    /// ```
+    pub fn saturating_add_semitones(&self, semitones: i16) -> Note {
+        let midi = i16::from(self.0).saturating_add(semitones);
+        Note(midi.clamp(0, i16::from(MIDI_NOTES - 1)) as u8)
+    }
+
+    /// Returns this note moved by a number of semitones, wrapping around the MIDI range
+    ///
+    /// Moving above MIDI 127 continues from MIDI 0 and moving below MIDI 0 continues
+    /// from MIDI 127, which keeps looping patterns within the playable notes.
+    ///
+    /// # Arguments
+    /// * `semitones` - The number of semitones to move by, negative to move down
+    ///
+    /// # Returns
+    /// The moved note, wrapped into the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(G9.wrapping_add_semitones(1), Note::try_from(0).unwrap());
+    /// assert_eq!(C4.wrapping_add_semitones(128), C4);
+    /// ```
+    pub fn wrapping_add_semitones(&self, semitones: i16) -> Note {
+        let range = i16::from(MIDI_NOTES);
+        Note((i16::from(self.0) + semitones % range).rem_euclid(range) as u8)
+    }
+
+    /// Returns the number of semitones between this note and another, regardless of their order
+    ///
+    /// # Arguments
+    /// * `other` - The other note
+    ///
+    /// # Returns
+    /// The number of semitones, from 0 for the same note
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(C4.distance_to(G4), 7);
+    /// assert_eq!(G4.distance_to(C4), 7);
+    /// ```
+    pub fn distance_to(&self, other: Note) -> u8 {
+        self.0.abs_diff(other.0)
+    }
+
+    /// Returns the number of semitones from this note to another
+    ///
+    /// # Arguments
+    /// * `other` - The note to move to
+    ///
+    /// # Returns
+    /// The number of semitones, positive when the other note is higher and
+    /// negative when it is lower, so that `checked_add_semitones` with it gives
+    /// the other note
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(C4.signed_distance_to(G4), 7);
+    /// assert_eq!(G4.signed_distance_to(C4), -7);
+    /// ```
+    pub fn signed_distance_to(&self, other: Note) -> i16 {
+        i16::from(other.0) - i16::from(self.0)
+    }
+
+    /// Returns the MIDI message that starts this note
+    ///
+    /// # Arguments
+    /// * `channel` - The MIDI channel, from 0 to 15; higher bits are ignored
+    /// * `velocity` - How hard the note is struck, from 0 to 127; higher bits are ignored
+    ///
+    /// # Returns
+    /// The three bytes of the note-on message
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(C4.note_on(0, 100), [0x90, 60, 100]);
+    /// assert_eq!(A4.note_on(9, 64), [0x99, 69, 64]);
+    /// ```
+    pub const fn note_on(&self, channel: u8, velocity: u8) -> [u8; 3] {
+        [0x90 | channel & 0x0F, self.0, velocity & 0x7F]
+    }
+
+    /// Returns the MIDI message that stops this note
+    ///
+    /// # Arguments
+    /// * `channel` - The MIDI channel, from 0 to 15; higher bits are ignored
+    ///
+    /// # Returns
+    /// The three bytes of the note-off message, with a release velocity of 0
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(C4.note_off(0), [0x80, 60, 0]);
+    /// ```
+    pub const fn note_off(&self, channel: u8) -> [u8; 3] {
+        [0x80 | channel & 0x0F, self.0, 0]
+    }
+
     /// Returns a major triad chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<3>` representing the major triad chord starting from this note
+    /// A `Chord<3>` representing the major triad chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_major_triad = C4.major_triad_chord();
+    /// let c_major_triad = C4.major_triad_chord().unwrap();
     /// assert_eq!(c_major_triad.notes(), &[C4, E4, G4]);
     /// ```
     #[inline]
-    pub fn major_triad_chord(&self) -> Chord<3> {
+    pub fn major_triad_chord(&self) -> Result<Chord<3>, Error> {
         major_triad(*self)
     }
 
     /// Returns a minor triad chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<3>` representing the minor triad chord starting from this note
+    /// A `Chord<3>` representing the minor triad chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_minor_triad = C4.minor_triad_chord();
+    /// let c_minor_triad = C4.minor_triad_chord().unwrap();
     /// assert_eq!(c_minor_triad.notes(), &[C4, DSHARP4, G4]);
     /// ```
     #[inline]
-    pub fn minor_triad_chord(&self) -> Chord<3> {
+    pub fn minor_triad_chord(&self) -> Result<Chord<3>, Error> {
         minor_triad(*self)
     }
 
     #[inline]
-    pub fn dominant_seventh_chord(&self) -> Chord<4> {
+    pub fn dominant_seventh_chord(&self) -> Result<Chord<4>, Error> {
         dominant_seventh(*self)
     }
 
     /// Returns a dominant seventh chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<4>` representing the dominant seventh chord starting from this note
+    /// A `Chord<4>` representing the dominant seventh chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_dominant_seventh = C4.dominant_seventh_chord();
+    /// let c_dominant_seventh = C4.dominant_seventh_chord().unwrap();
     /// assert_eq!(c_dominant_seventh.notes(), &[C4, E4, G4, BFLAT4]);
     /// ```
     #[inline]
-    pub fn dominant_seventh_ninth_chord(&self) -> Chord<5> {
+    pub fn dominant_seventh_ninth_chord(&self) -> Result<Chord<5>, Error> {
         dominant_seventh_ninth(*self)
     }
 
     /// Returns a minor seventh chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<4>` representing the minor seventh chord starting from this note
+    /// A `Chord<4>` representing the minor seventh chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_minor_seventh = C4.minor_seventh_chord();
+    /// let c_minor_seventh = C4.minor_seventh_chord().unwrap();
     /// assert_eq!(c_minor_seventh.notes(), &[C4, DSHARP4, G4, BFLAT4]);
     /// ```
     #[inline]
-    pub fn minor_seventh_chord(&self) -> Chord<4> {
+    pub fn minor_seventh_chord(&self) -> Result<Chord<4>, Error> {
         minor_seventh(*self)
     }
 
     /// Returns a minor seventh ninth chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<5>` representing the minor seventh ninth chord starting from this note
+    /// A `Chord<5>` representing the minor seventh ninth chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_minor_seventh_ninth = C4.minor_seventh_ninth_chord();
+    /// let c_minor_seventh_ninth = C4.minor_seventh_ninth_chord().unwrap();
     /// assert_eq!(c_minor_seventh_ninth.notes(), &[C4, EFLAT4, G4, BFLAT4, D5]);
     /// ```
     #[inline]
-    pub fn minor_seventh_ninth_chord(&self) -> Chord<5> {
+    pub fn minor_seventh_ninth_chord(&self) -> Result<Chord<5>, Error> {
         minor_seventh_ninth(*self)
     }
 
     /// Returns a major seventh chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<4>` representing the major seventh chord starting from this note
+    /// A `Chord<4>` representing the major seventh chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_major_seventh = C4.major_seventh_chord();
+    /// let c_major_seventh = C4.major_seventh_chord().unwrap();
     /// assert_eq!(c_major_seventh.notes(), &[C4, E4, G4, B4]);
     /// ```
     #[inline]
-    pub fn major_seventh_chord(&self) -> Chord<4> {
+    pub fn major_seventh_chord(&self) -> Result<Chord<4>, Error> {
         major_seventh(*self)
     }
 
     /// Returns a minor major seventh chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<4>` representing the minor major seventh chord starting from this note
+    /// A `Chord<4>` representing the minor major seventh chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_minor_major_seventh = C4.minor_major_seventh_chord();
+    /// let c_minor_major_seventh = C4.minor_major_seventh_chord().unwrap();
     /// assert_eq!(c_minor_major_seventh.notes(), &[C4, DSHARP4, G4, B4]);
     /// ```
     #[inline]
-    pub fn minor_major_seventh_chord(&self) -> Chord<4> {
+    pub fn minor_major_seventh_chord(&self) -> Result<Chord<4>, Error> {
         minor_major_seventh(*self)
     }
 
     /// Returns a major sixth chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<4>` representing the major sixth chord starting from this note
+    /// A `Chord<4>` representing the major sixth chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_major_sixth = C4.major_sixth_chord();
+    /// let c_major_sixth = C4.major_sixth_chord().unwrap();
     /// assert_eq!(c_major_sixth.notes(), &[C4, E4, G4, A4]);
     /// ```
     #[inline]
-    pub fn major_sixth_chord(&self) -> Chord<4> {
+    pub fn major_sixth_chord(&self) -> Result<Chord<4>, Error> {
         major_sixth(*self)
     }
 
     /// Returns a minor sixth chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<4>` representing the minor sixth chord starting from this note
+    /// A `Chord<4>` representing the minor sixth chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_minor_sixth = C4.minor_sixth_chord();
+    /// let c_minor_sixth = C4.minor_sixth_chord().unwrap();
     /// assert_eq!(c_minor_sixth.notes(), &[C4, DSHARP4, G4, A4]);
     /// ```
     #[inline]
-    pub fn minor_sixth_chord(&self) -> Chord<4> {
+    pub fn minor_sixth_chord(&self) -> Result<Chord<4>, Error> {
         minor_sixth(*self)
     }
 
     /// Returns a major sixth ninth chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<5>` representing the major sixth ninth chord starting from this note
+    /// A `Chord<5>` representing the major sixth ninth chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_major_sixth_ninth = C4.major_sixth_ninth_chord();
+    /// let c_major_sixth_ninth = C4.major_sixth_ninth_chord().unwrap();
     /// assert_eq!(c_major_sixth_ninth.notes(), &[C4, E4, G4, A4, D5]);
     /// ```
     #[inline]
-    pub fn major_sixth_ninth_chord(&self) -> Chord<5> {
+    pub fn major_sixth_ninth_chord(&self) -> Result<Chord<5>, Error> {
         major_sixth_ninth(*self)
     }
 
     /// Returns a minor sixth ninth chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<5>` representing the minor sixth ninth chord starting from this note
+    /// A `Chord<5>` representing the minor sixth ninth chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_minor_sixth_ninth = C4.minor_sixth_ninth_chord();
+    /// let c_minor_sixth_ninth = C4.minor_sixth_ninth_chord().unwrap();
     /// assert_eq!(c_minor_sixth_ninth.notes(), &[C4, EFLAT4, G4, A4, D5]);
     /// ```
     #[inline]
-    pub fn minor_sixth_ninth_chord(&self) -> Chord<5> {
+    pub fn minor_sixth_ninth_chord(&self) -> Result<Chord<5>, Error> {
         minor_sixth_ninth(*self)
     }
 
     /// Returns a sus2 chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<3>` representing the sus2 chord starting from this note
+    /// A `Chord<3>` representing the sus2 chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_sus2 = C4.sus2_chord();
+    /// let c_sus2 = C4.sus2_chord().unwrap();
     /// assert_eq!(c_sus2.notes(), &[C4, D4, G4]);
     /// ```
     #[inline]
-    pub fn sus2_chord(&self) -> Chord<3> {
+    pub fn sus2_chord(&self) -> Result<Chord<3>, Error> {
         sus2(*self)
     }
 
     /// Returns a sus4 chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<3>` representing the sus4 chord starting from this note
+    /// A `Chord<3>` representing the sus4 chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_sus4 = C4.sus4_chord();
+    /// let c_sus4 = C4.sus4_chord().unwrap();
     /// assert_eq!(c_sus4.notes(), &[C4, F4, G4]);
     /// ```
     #[inline]
-    pub fn sus4_chord(&self) -> Chord<3> {
+    pub fn sus4_chord(&self) -> Result<Chord<3>, Error> {
         sus4(*self)
     }
 
     /// Returns a diminished triad chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<3>` representing the diminished triad chord starting from this note
+    /// A `Chord<3>` representing the diminished triad chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_diminished_triad = C4.diminished_triad_chord();
+    /// let c_diminished_triad = C4.diminished_triad_chord().unwrap();
     /// assert_eq!(c_diminished_triad.notes(), &[C4, EFLAT4, GFLAT4]);
     /// ```
     #[inline]
-    pub fn diminished_triad_chord(&self) -> Chord<3> {
+    pub fn diminished_triad_chord(&self) -> Result<Chord<3>, Error> {
         diminished_triad(*self)
     }
 
     /// Returns a diminished seventh chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<4>` representing the diminished seventh chord starting from this note
+    /// A `Chord<4>` representing the diminished seventh chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_diminished_seventh = C4.diminished_seventh_chord();
+    /// let c_diminished_seventh = C4.diminished_seventh_chord().unwrap();
     /// assert_eq!(c_diminished_seventh.notes(), &[C4, EFLAT4, GFLAT4, A4]);
     /// ```
     #[inline]
-    pub fn diminished_seventh_chord(&self) -> Chord<4> {
+    pub fn diminished_seventh_chord(&self) -> Result<Chord<4>, Error> {
         diminished_seventh(*self)
     }
 
     /// Returns a half diminished seventh chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<4>` representing the half diminished seventh chord starting from this note
+    /// A `Chord<4>` representing the half diminished seventh chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_half_diminished_seventh = C4.half_diminished_seventh_chord();
+    /// let c_half_diminished_seventh = C4.half_diminished_seventh_chord().unwrap();
     /// assert_eq!(c_half_diminished_seventh.notes(), &[C4, EFLAT4, GFLAT4, BFLAT4]);
     /// ```
     #[inline]
-    pub fn half_diminished_seventh_chord(&self) -> Chord<4> {
+    pub fn half_diminished_seventh_chord(&self) -> Result<Chord<4>, Error> {
         half_diminished_seventh(*self)
     }
 
     /// Returns an augmented triad chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<3>` representing the augmented triad chord starting from this note
+    /// A `Chord<3>` representing the augmented triad chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_augmented_triad = C4.augmented_triad_chord();
+    /// let c_augmented_triad = C4.augmented_triad_chord().unwrap();
     /// assert_eq!(c_augmented_triad.notes(), &[C4, E4, GSHARP4]);
     /// ```
     #[inline]
-    pub fn augmented_triad_chord(&self) -> Chord<3> {
+    pub fn augmented_triad_chord(&self) -> Result<Chord<3>, Error> {
         augmented_triad(*self)
     }
 
     /// Returns an augmented seventh chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<4>` representing the augmented seventh chord starting from this note
+    /// A `Chord<4>` representing the augmented seventh chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_augmented_seventh = C4.augmented_seventh_chord();
+    /// let c_augmented_seventh = C4.augmented_seventh_chord().unwrap();
     /// assert_eq!(c_augmented_seventh.notes(), &[C4, E4, GSHARP4, BFLAT4]);
     /// ```
     #[inline]
-    pub fn augmented_seventh_chord(&self) -> Chord<4> {
+    pub fn augmented_seventh_chord(&self) -> Result<Chord<4>, Error> {
         augmented_seventh(*self)
     }
 
     /// Returns a dominant ninth chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<5>` representing the dominant ninth chord starting from this note
+    /// A `Chord<5>` representing the dominant ninth chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_dominant_ninth = C4.dominant_ninth_chord();
+    /// let c_dominant_ninth = C4.dominant_ninth_chord().unwrap();
     /// assert_eq!(c_dominant_ninth.notes(), &[C4, E4, G4, BFLAT4, D5]);
     /// ```
     #[inline]
-    pub fn dominant_ninth_chord(&self) -> Chord<5> {
+    pub fn dominant_ninth_chord(&self) -> Result<Chord<5>, Error> {
         dominant_ninth(*self)
     }
 
     /// Returns a minor ninth chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<5>` representing the minor ninth chord starting from this note
+    /// A `Chord<5>` representing the minor ninth chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_minor_ninth = C4.minor_ninth_chord();
+    /// let c_minor_ninth = C4.minor_ninth_chord().unwrap();
     /// assert_eq!(c_minor_ninth.notes(), &[C4, EFLAT4, G4, BFLAT4, D5]);
     /// ```
     #[inline]
-    pub fn minor_ninth_chord(&self) -> Chord<5> {
+    pub fn minor_ninth_chord(&self) -> Result<Chord<5>, Error> {
         minor_ninth(*self)
     }
 
     /// Returns a major ninth chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<5>` representing the major ninth chord starting from this note
+    /// A `Chord<5>` representing the major ninth chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_major_ninth = C4.major_ninth_chord();
+    /// let c_major_ninth = C4.major_ninth_chord().unwrap();
     /// assert_eq!(c_major_ninth.notes(), &[C4, E4, G4, B4, D5]);
     /// ```
     #[inline]
-    pub fn major_ninth_chord(&self) -> Chord<5> {
+    pub fn major_ninth_chord(&self) -> Result<Chord<5>, Error> {
         major_ninth(*self)
     }
 
     /// Returns a dominant eleventh chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<6>` representing the dominant eleventh chord starting from this note
+    /// A `Chord<6>` representing the dominant eleventh chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_dominant_eleventh = C4.dominant_eleventh_chord();
+    /// let c_dominant_eleventh = C4.dominant_eleventh_chord().unwrap();
     /// assert_eq!(c_dominant_eleventh.notes(), &[C4, E4, G4, BFLAT4, D5, F5]);
     /// ```
     #[inline]
-    pub fn dominant_eleventh_chord(&self) -> Chord<6> {
+    pub fn dominant_eleventh_chord(&self) -> Result<Chord<6>, Error> {
         dominant_eleventh(*self)
     }
 
     /// Returns a minor eleventh chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<6>` representing the minor eleventh chord starting from this note
+    /// A `Chord<6>` representing the minor eleventh chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_minor_eleventh = C4.minor_eleventh_chord();
+    /// let c_minor_eleventh = C4.minor_eleventh_chord().unwrap();
     /// assert_eq!(c_minor_eleventh.notes(), &[C4, EFLAT4, G4, BFLAT4, D5, F5]);
     /// ```
     #[inline]
-    pub fn minor_eleventh_chord(&self) -> Chord<6> {
+    pub fn minor_eleventh_chord(&self) -> Result<Chord<6>, Error> {
         minor_eleventh(*self)
     }
 
     /// Returns a major eleventh chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<6>` representing the major eleventh chord starting from this note
+    /// A `Chord<6>` representing the major eleventh chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_major_eleventh = C4.major_eleventh_chord();
+    /// let c_major_eleventh = C4.major_eleventh_chord().unwrap();
     /// assert_eq!(c_major_eleventh.notes(), &[C4, E4, G4, B4, D5, F5]);
     /// ```
     #[inline]
-    pub fn major_eleventh_chord(&self) -> Chord<6> {
+    pub fn major_eleventh_chord(&self) -> Result<Chord<6>, Error> {
         major_eleventh(*self)
     }
 
     /// Returns a dominant thirteenth chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<7>` representing the dominant thirteenth chord starting from this note
+    /// A `Chord<7>` representing the dominant thirteenth chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_dominant_thirteenth = C4.dominant_thirteenth_chord();
+    /// let c_dominant_thirteenth = C4.dominant_thirteenth_chord().unwrap();
     /// assert_eq!(c_dominant_thirteenth.notes(), &[C4, E4, G4, BFLAT4, D5, F5, A5]);
     /// ```
     #[inline]
-    pub fn dominant_thirteenth_chord(&self) -> Chord<7> {
+    pub fn dominant_thirteenth_chord(&self) -> Result<Chord<7>, Error> {
         dominant_thirteenth(*self)
     }
 
     /// Returns a minor thirteenth chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<7>` representing the minor thirteenth chord starting from this note
+    /// A `Chord<7>` representing the minor thirteenth chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_minor_thirteenth = C4.minor_thirteenth_chord();
+    /// let c_minor_thirteenth = C4.minor_thirteenth_chord().unwrap();
     /// assert_eq!(c_minor_thirteenth.notes(), &[C4, EFLAT4, G4, BFLAT4, D5, F5, A5]);
     /// ```
     #[inline]
-    pub fn minor_thirteenth_chord(&self) -> Chord<7> {
+    pub fn minor_thirteenth_chord(&self) -> Result<Chord<7>, Error> {
         minor_thirteenth(*self)
     }
 
     /// Returns a major thirteenth chord starting from this note
     ///
     /// # Returns
-    /// A `Chord<7>` representing the major thirteenth chord starting from this note
+    /// A `Chord<7>` representing the major thirteenth chord starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::*;
     /// use mozzart_std::constants::*;
     ///
-    /// let c_major_thirteenth = C4.major_thirteenth_chord();
+    /// let c_major_thirteenth = C4.major_thirteenth_chord().unwrap();
     /// assert_eq!(c_major_thirteenth.notes(), &[C4, E4, G4, B4, D5, F5, A5]);
     /// ```
     #[inline]
-    pub fn major_thirteenth_chord(&self) -> Chord<7> {
+    pub fn major_thirteenth_chord(&self) -> Result<Chord<7>, Error> {
         major_thirteenth(*self)
     }
 }
 
 impl IntoMajorScale for Note {
-    fn into_major_scale(self) -> Scale<MajorScaleQuality, 8> {
+    fn into_major_scale(self) -> Result<Scale<MajorScaleQuality, 8>, Error> {
         major_scale(self)
     }
 }
 
 impl IntoNaturalMinorScale for Note {
-    fn into_natural_minor_scale(self) -> Scale<MinorScaleQuality, 8> {
+    fn into_natural_minor_scale(self) -> Result<Scale<MinorScaleQuality, 8>, Error> {
         natural_minor_scale(self)
     }
 }
 
 impl IntoHarmonicMinorScale for Note {
-    fn into_harmonic_minor_scale(self) -> Scale<HarmonicMinorScaleQuality, 8> {
+    fn into_harmonic_minor_scale(self) -> Result<Scale<HarmonicMinorScaleQuality, 8>, Error> {
         harmonic_minor_scale(self)
     }
 }
 
 impl IntoMelodicMinorScale for Note {
-    fn into_melodic_minor_scale(self) -> Scale<MelodicMinorScaleQuality, 8> {
+    fn into_melodic_minor_scale(self) -> Result<Scale<MelodicMinorScaleQuality, 8>, Error> {
         melodic_minor_scale(self)
     }
 }
 
+/// Parses a note from its name and octave, as in `C4`, `F#3`, `Bb5` or `C-1`
+///
+/// The letter may be followed by any number of sharps (`#`) or flats (`b`).
+/// The octave follows scientific pitch notation, where middle C is C4.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!("C4".parse(), Ok(C4));
+/// assert_eq!("Bb3".parse(), Ok(ASHARP3));
+/// assert_eq!("H2".parse::<Note>(), Err(Error::InvalidNoteName("H2".to_string())));
+/// ```
+impl FromStr for Note {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidNoteName(name.to_string());
+
+        let mut chars = name.chars();
+        let pitch_class: i32 = match chars.next().ok_or_else(invalid)? {
+            'C' => 0,
+            'D' => 2,
+            'E' => 4,
+            'F' => 5,
+            'G' => 7,
+            'A' => 9,
+            'B' => 11,
+            _ => return Err(invalid()),
+        };
+
+        let rest = chars.as_str();
+        let octave = rest.trim_start_matches(['#', 'b']);
+        let accidentals = &rest[..rest.len() - octave.len()];
+        let alteration: i32 = accidentals
+            .chars()
+            .map(|accidental| if accidental == '#' { 1 } else { -1 })
+            .sum();
+
+        let octave: i32 = octave.parse().map_err(|_| invalid())?;
+        let midi = octave
+            .checked_add(1)
+            .and_then(|octave| octave.checked_mul(i32::from(SEMITONES_IN_OCTAVE)))
+            .and_then(|midi| midi.checked_add(pitch_class + alteration))
+            .ok_or_else(invalid)?;
+
+        u8::try_from(midi)
+            .ok()
+            .filter(|&midi| midi < MIDI_NOTES)
+            .map(Note)
+            .ok_or_else(invalid)
+    }
+}
+
+/// Conversion from `u8` (MIDI note number) to `Note`
+///
+/// This allows reading notes from MIDI byte streams, rejecting values above 127.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(Note::try_from(60), Ok(C4));
+/// assert_eq!(Note::try_from(200), Err(Error::InvalidMidiNumber(200)));
+/// ```
+impl TryFrom<u8> for Note {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(midi: u8) -> Result<Self, Self::Error> {
+        if midi < MIDI_NOTES {
+            Ok(Note(midi))
+        } else {
+            Err(Error::InvalidMidiNumber(midi))
+        }
+    }
+}
+
 /// Conversion from `Note` to `u8` (MIDI note number)
 ///
 /// This allows extracting the raw MIDI note number from a `Note`.
@@ -755,6 +1273,34 @@ impl From<&Note> for u8 {
     }
 }
 
+/// Returns every note from one note to another, one semitone apart
+///
+/// Both ends are included. Use `rev` to go down from `high` to `low`.
+///
+/// # Arguments
+/// * `low` - The first note
+/// * `high` - The last note
+///
+/// # Returns
+/// An iterator over the chromatic notes, empty if `low` is above `high`
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let notes: Vec<_> = pitch_range(C4, E4).collect();
+/// assert_eq!(notes, vec![C4, CSHARP4, D4, DSHARP4, E4]);
+/// assert_eq!(pitch_range(E4, C4).count(), 0);
+/// assert_eq!(pitch_range(C4, D4).rev().next(), Some(D4));
+/// ```
+pub fn pitch_range(
+    low: Note,
+    high: Note,
+) -> impl DoubleEndedIterator<Item = Note> + ExactSizeIterator {
+    (low.0..=high.0).map(Note)
+}
+
 mod ops {
     use super::*;
     use std::ops::{Add, AddAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
@@ -779,8 +1325,7 @@ mod ops {
     impl AddAssign<Interval> for Note {
         #[inline]
         fn add_assign(&mut self, interval: Interval) {
-            let interval: u8 = interval.into();
-            self.0 = self.0 + interval;
+            *self = *self + interval;
         }
     }
 
@@ -804,8 +1349,7 @@ mod ops {
     impl AddAssign<&Interval> for Note {
         #[inline]
         fn add_assign(&mut self, interval: &Interval) {
-            let interval: u8 = interval.into();
-            self.0 = self.0 + interval;
+            *self = *self + interval;
         }
     }
 
@@ -854,8 +1398,7 @@ mod ops {
     impl AddAssign<Step> for Note {
         #[inline]
         fn add_assign(&mut self, step: Step) {
-            let step: u8 = step.into();
-            self.0 = self.0 + step;
+            *self = *self + step;
         }
     }
 
@@ -879,8 +1422,7 @@ mod ops {
     impl AddAssign<&Step> for Note {
         #[inline]
         fn add_assign(&mut self, step: &Step) {
-            let step: u8 = step.into();
-            self.0 = self.0 + step;
+            *self = *self + step;
         }
     }
 
@@ -1009,15 +1551,15 @@ mod ops {
 
             // Test addition
             let d4 = c4 + WHOLE;
-            assert_eq!(62u8, d4.into());
+            assert_eq!(62u8, u8::from(d4));
             assert_eq!(D4, d4);
 
             let e4 = d4 + WHOLE;
-            assert_eq!(64u8, e4.into());
+            assert_eq!(64u8, u8::from(e4));
             assert_eq!(E4, e4);
 
             let f4 = e4 + HALF;
-            assert_eq!(65u8, f4.into());
+            assert_eq!(65u8, u8::from(f4));
             assert_eq!(F4, f4);
         }
 
@@ -1025,7 +1567,7 @@ mod ops {
         fn test_adding_step() {
             let c4 = C4;
             let d4 = c4 + WHOLE;
-            assert_eq!(62u8, d4.into());
+            assert_eq!(62u8, u8::from(d4));
             assert_eq!(D4, d4);
         }
 
@@ -1035,10 +1577,10 @@ mod ops {
 
             // Test in-place addition
             note += WHOLE;
-            assert_eq!(62u8, note.into()); // D4
+            assert_eq!(62u8, u8::from(note)); // D4
             assert_eq!(D4, note);
             note += HALF;
-            assert_eq!(63u8, note.into()); // D#4/Eb4
+            assert_eq!(63u8, u8::from(note)); // D#4/Eb4
             assert_eq!(DSHARP4, note);
         }
 
@@ -1046,7 +1588,7 @@ mod ops {
         fn test_adding_step_in_place() {
             let mut note = C4;
             note += WHOLE;
-            assert_eq!(62u8, note.into());
+            assert_eq!(62u8, u8::from(note));
             assert_eq!(D4, note);
         }
 
@@ -1056,7 +1598,7 @@ mod ops {
 
             // Test subtraction
             let g4 = a4 - WHOLE;
-            assert_eq!(67u8, g4.into());
+            assert_eq!(67u8, u8::from(g4));
             assert_eq!(G4, g4);
         }
 
@@ -1064,7 +1606,7 @@ mod ops {
         fn test_subtracting_step() {
             let a4 = A4;
             let g4 = a4 - WHOLE;
-            assert_eq!(67u8, g4.into());
+            assert_eq!(67u8, u8::from(g4));
             assert_eq!(G4, g4);
         }
 
@@ -1074,11 +1616,11 @@ mod ops {
 
             // Test in-place subtraction
             note -= WHOLE;
-            assert_eq!(67u8, note.into()); // G4
+            assert_eq!(67u8, u8::from(note)); // G4
             assert_eq!(G4, note);
 
             note -= HALF;
-            assert_eq!(66u8, note.into()); // F#4/Gb4
+            assert_eq!(66u8, u8::from(note)); // F#4/Gb4
             assert_eq!(FSHARP4, note);
         }
 
@@ -1086,7 +1628,7 @@ mod ops {
         fn test_subtracting_step_in_place() {
             let mut note = A4;
             note -= WHOLE;
-            assert_eq!(67u8, note.into());
+            assert_eq!(67u8, u8::from(note));
             assert_eq!(G4, note);
         }
 
@@ -1096,20 +1638,20 @@ mod ops {
 
             // Test right shift (transposing up)
             let c5 = c4 >> 1;
-            assert_eq!(72u8, c5.into());
+            assert_eq!(72u8, u8::from(c5));
             assert_eq!(C5, c5);
 
             let c6 = c4 >> 2;
-            assert_eq!(84u8, c6.into());
+            assert_eq!(84u8, u8::from(c6));
             assert_eq!(C6, c6);
 
             // Test left shift (transposing down)
             let c3 = c4 << 1;
-            assert_eq!(48u8, c3.into());
+            assert_eq!(48u8, u8::from(c3));
             assert_eq!(C3, c3);
 
             let c2 = c4 << 2;
-            assert_eq!(36u8, c2.into());
+            assert_eq!(36u8, u8::from(c2));
             assert_eq!(C2, c2);
         }
 
@@ -1119,12 +1661,12 @@ mod ops {
 
             // Test in-place right shift
             note >>= 1;
-            assert_eq!(72u8, note.into()); // C5
+            assert_eq!(72u8, u8::from(note)); // C5
             assert_eq!(C5, note);
 
             // Test in-place left shift
             note <<= 2;
-            assert_eq!(48u8, note.into()); // C3
+            assert_eq!(48u8, u8::from(note)); // C3
             assert_eq!(C3, note);
         }
     }
@@ -1139,8 +1681,7 @@ mod fmt {
             const NAMES: [&str; 12] = [
                 "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
             ];
-            let index = self.0 % SEMITONES_IN_OCTAVE;
-            let name = NAMES[index as usize];
+            let name = NAMES[usize::from(self.pitch_class())];
 
             write!(f, "{name}")
         }
@@ -1151,8 +1692,7 @@ mod fmt {
             const NAMES: [&str; 12] = [
                 "C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B",
             ];
-            let index = self.0 % SEMITONES_IN_OCTAVE;
-            let name = NAMES[index as usize];
+            let name = NAMES[usize::from(self.pitch_class())];
             write!(f, "{name}")
         }
     }
@@ -1178,13 +1718,13 @@ mod tests {
     #[test]
     fn test_note_new() {
         let note = Note::new(60);
-        assert_eq!(60u8, note.into());
+        assert_eq!(60u8, u8::from(note));
 
         let high_note = Note::new(127);
-        assert_eq!(127u8, high_note.into());
+        assert_eq!(127u8, u8::from(high_note));
 
         let low_note = Note::new(0);
-        assert_eq!(0u8, low_note.into());
+        assert_eq!(0u8, u8::from(low_note));
     }
 
     #[test]
@@ -1200,6 +1740,59 @@ mod tests {
         assert_eq!(60, midi_num);
     }
 
+    #[test]
+    fn test_note_try_from_u8() {
+        assert_eq!(Note::try_from(60), Ok(C4));
+        assert_eq!(Note::try_from(0), Ok(Note::new(0)));
+        assert_eq!(Note::try_from(127), Ok(G9));
+        assert_eq!(Note::try_from(128), Err(Error::InvalidMidiNumber(128)));
+        assert_eq!(Note::try_from(200), Err(Error::InvalidMidiNumber(200)));
+        assert_eq!(u8::from(C4), 60);
+    }
+
+    #[test]
+    fn test_add_semitones_at_boundaries() {
+        let lowest = Note::new(0);
+
+        assert_eq!(G9.saturating_add_semitones(5), G9);
+        assert_eq!(C0.saturating_add_semitones(-100), lowest);
+        assert_eq!(C4.saturating_add_semitones(i16::MAX), G9);
+
+        assert_eq!(lowest.checked_add_semitones(-1), None);
+        assert_eq!(C0.checked_add_semitones(-12), Some(lowest));
+        assert_eq!(G9.checked_add_semitones(1), None);
+        assert_eq!(C4.checked_add_semitones(i16::MIN), None);
+
+        assert_eq!(G9.wrapping_add_semitones(1), lowest);
+        assert_eq!(lowest.wrapping_add_semitones(-1), G9);
+        assert_eq!(C4.wrapping_add_semitones(-128 * 3), C4);
+        assert_eq!(G9.wrapping_add_semitones(i16::MAX), Note::new(126));
+    }
+
+    #[test]
+    fn test_distance_to() {
+        assert_eq!(C4.distance_to(G4), 7);
+        assert_eq!(G4.distance_to(C4), 7);
+        assert_eq!(C4.signed_distance_to(G4), 7);
+        assert_eq!(G4.signed_distance_to(C4), -7);
+        assert_eq!(E4.distance_to(E4), 0);
+        assert_eq!(E4.signed_distance_to(E4), 0);
+
+        let lowest = Note::new(0);
+        assert_eq!(lowest.distance_to(G9), 127);
+        assert_eq!(G9.signed_distance_to(lowest), -127);
+        for (from, to) in [(C4, G4), (G9, lowest), (A3, ASHARP5)] {
+            assert_eq!(
+                from.checked_add_semitones(from.signed_distance_to(to)),
+                Some(to)
+            );
+            assert_eq!(
+                from.signed_distance_to(to).unsigned_abs(),
+                u16::from(from.distance_to(to))
+            );
+        }
+    }
+
     #[test]
     fn test_note_comparison() {
         let c4 = C4;
@@ -1373,6 +1966,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::useless_conversion)]
     fn test_into_notes_from_steps_method_chaining() {
         // Test that into_notes can be used in method chains
         let chord: Vec<_> = C4
@@ -1390,7 +1984,7 @@ mod tests {
     fn test_notes_from_intervals_major_chord() {
         // Test creating a major chord using fixed intervals from the root
         let c4 = C4;
-        let intervals = [MAJOR_THIRD.into(), PERFECT_FIFTH.into()];
+        let intervals = [MAJOR_THIRD, PERFECT_FIFTH];
 
         let c_major_chord: Vec<_> = c4.notes_from_intervals(intervals).collect();
 
@@ -1533,4 +2127,133 @@ mod tests {
         assert_eq!(csus4[1], F4); // Perfect fourth instead of third
         assert_eq!(csus4[2], G4);
     }
+
+    #[test]
+    fn test_spell_in_key() {
+        // The F#/Gb pitch class follows the accidentals of the key
+        assert_eq!(FSHARP4.spell_in_key(&G_MAJOR_KEY), "F#");
+        assert_eq!(FSHARP4.spell_in_key(&D_MAJOR_KEY), "F#");
+        assert_eq!(FSHARP4.spell_in_key(&GFLAT_MAJOR_KEY), "Gb");
+        assert_eq!(GFLAT2.spell_in_key(&DFLAT_MAJOR_KEY), "Gb");
+
+        // Notes outside the key use the key's accidental direction
+        assert_eq!(CSHARP4.spell_in_key(&G_MAJOR_KEY), "C#");
+        assert_eq!(CSHARP4.spell_in_key(&F_MAJOR_KEY), "Db");
+    }
+
+    #[test]
+    fn test_black_and_white_keys() {
+        let octave = [
+            (C4, false),
+            (CSHARP4, true),
+            (D4, false),
+            (DSHARP4, true),
+            (E4, false),
+            (F4, false),
+            (FSHARP4, true),
+            (G4, false),
+            (GSHARP4, true),
+            (A4, false),
+            (ASHARP4, true),
+            (B4, false),
+        ];
+
+        for (note, black) in octave {
+            assert_eq!(note.is_black_key(), black, "{note:?}");
+            assert_eq!(note.is_white_key(), !black, "{note:?}");
+        }
+    }
+
+    #[test]
+    fn test_white_key_index() {
+        assert_eq!(Note::new(0).white_key_index(), Some(0));
+        assert_eq!(Note::new(2).white_key_index(), Some(1));
+        assert_eq!(B0.white_key_index(), Some(13));
+        assert_eq!(C4.white_key_index(), Some(35));
+        assert_eq!(G9.white_key_index(), Some(74));
+        assert_eq!(FSHARP4.white_key_index(), None);
+    }
+
+    #[test]
+    fn test_abc() {
+        assert_eq!(C4.abc(), "C");
+        assert_eq!(B4.abc(), "B");
+        assert_eq!(C5.abc(), "c");
+        assert_eq!(CSHARP4.abc(), "^C");
+        assert_eq!(ASHARP5.abc(), "^a");
+        assert_eq!(C3.abc(), "C,");
+        assert_eq!(C6.abc(), "c'");
+        assert_eq!(Note::new(0).abc(), "C,,,,,");
+        assert_eq!(G9.abc(), "g''''");
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        for midi in 0..MIDI_NOTES {
+            let note = Note::new(midi);
+            assert_eq!(note.name().parse(), Ok(note), "{}", note.name());
+        }
+        assert_eq!(G9.name(), "G9");
+        assert_eq!(DSHARP2.name(), "D#2");
+    }
+
+    #[test]
+    fn test_transpose_to_octave() {
+        assert_eq!(G5.transpose_to_octave(3), Some(G3));
+        assert_eq!(CSHARP2.transpose_to_octave(6), Some(CSHARP6));
+        assert_eq!(B4.transpose_to_octave(4), Some(B4));
+
+        let lowest = Note::try_from(0).unwrap();
+        assert_eq!(C4.transpose_to_octave(-1), Some(lowest));
+        assert_eq!(C4.transpose_to_octave(-2), None);
+        assert_eq!(GSHARP4.transpose_to_octave(9), None);
+        assert_eq!(GSHARP4.transpose_to_octave(i8::MAX), None);
+    }
+
+    #[test]
+    fn test_name_with_middle_c_octave() {
+        let middle_c = Note::try_from(60).unwrap();
+        assert_eq!(middle_c.name_with_middle_c_octave(4), "C4");
+        assert_eq!(middle_c.name_with_middle_c_octave(4), middle_c.name());
+        assert_eq!(middle_c.name_with_middle_c_octave(3), "C3");
+        assert_eq!(A4.name_with_middle_c_octave(3), "A3");
+
+        // The lowest notes get negative octaves
+        let lowest = Note::try_from(0).unwrap();
+        assert_eq!(lowest.name_with_middle_c_octave(3), "C-2");
+        assert_eq!(G9.name_with_middle_c_octave(5), "G10");
+    }
+
+    #[test]
+    fn test_pitch_range() {
+        let octave: Vec<_> = pitch_range(C4, C5).collect();
+        assert_eq!(octave.len(), 13);
+        assert_eq!(octave.first(), Some(&C4));
+        assert_eq!(octave.last(), Some(&C5));
+        assert!(octave.windows(2).all(|pair| pair[1] - pair[0] == HALF));
+
+        assert_eq!(pitch_range(C5, C4).len(), 0);
+        assert!(pitch_range(A4, A4).eq([A4]));
+        assert!(pitch_range(B4, CSHARP5).rev().eq([CSHARP5, C5, B4]));
+        assert_eq!(pitch_range(Note::new(0), Note::new(127)).len(), 128);
+    }
+
+    #[test]
+    fn test_parse_note() {
+        assert_eq!("Db4".parse(), Ok(CSHARP4));
+        assert_eq!("E#4".parse(), Ok(F4));
+        assert_eq!("Cb4".parse(), Ok(B3));
+        assert_eq!("F##3".parse(), Ok(G3));
+        assert_eq!("Bbb2".parse(), Ok(A2));
+
+        for name in [
+            "", "C", "c4", "C#", "Cb-1", "G#9", "C10", "C 4", "C4b", "Z4",
+        ] {
+            assert_eq!(
+                name.parse::<Note>(),
+                Err(Error::InvalidNoteName(name.to_string())),
+                "{name}"
+            );
+        }
+    }
 }
@@ -0,0 +1,170 @@
+use crate::constants::*;
+use crate::{Interval, Note};
+
+/// Operations on sequences of notes
+///
+/// This trait is implemented for slices of notes, so it works the same on the
+/// notes of a chord, a scale or a melody.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let notes = [C4, E4, C5, G4];
+/// assert_eq!(notes.unique_pitch_classes(), vec![C4, E4, G4]);
+/// ```
+pub trait NoteSlice {
+    /// Returns the notes without repeated pitch classes
+    ///
+    /// Only the first note of each pitch class is kept, in its original octave,
+    /// and the notes keep their order.
+    ///
+    /// # Returns
+    /// The first occurrence of each pitch class, in order
+    fn unique_pitch_classes(&self) -> Vec<Note>;
+
+    /// Returns the longest stretch of stepwise motion
+    ///
+    /// A scalar run is a sequence of notes where each one is a minor or a major
+    /// second above or below the previous one; a repeated note or a leap ends it.
+    /// When several runs are equally long, the first one is returned.
+    ///
+    /// # Returns
+    /// The index of the first note of the run and its number of notes, or `(0, 0)`
+    /// if no two consecutive notes are a second apart
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let melody = [G4, C4, D4, E4, F4, A4];
+    /// assert_eq!(melody.longest_scalar_run(), (1, 4));
+    /// ```
+    fn longest_scalar_run(&self) -> (usize, usize);
+
+    /// Returns the intervals between consecutive notes, without allocating
+    ///
+    /// The intervals are computed as the iterator is consumed, so this is the
+    /// variant to use on small slices in tight loops. Each interval is measured
+    /// from the lower to the higher note, whichever comes first.
+    ///
+    /// # Returns
+    /// An iterator over the intervals, empty if there are fewer than two notes
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let mut intervals = [C4, E4, G4].intervals_iter();
+    /// assert_eq!(intervals.next(), Some(MAJOR_THIRD));
+    /// assert_eq!(intervals.next(), Some(MINOR_THIRD));
+    /// assert_eq!(intervals.next(), None);
+    /// ```
+    fn intervals_iter(&self) -> impl Iterator<Item = Interval> + '_;
+
+    /// Returns the intervals between consecutive notes
+    ///
+    /// # Returns
+    /// The intervals, empty if there are fewer than two notes
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!([C4, G4, E4].intervals(), vec![PERFECT_FIFTH, MINOR_THIRD]);
+    /// ```
+    fn intervals(&self) -> Vec<Interval> {
+        self.intervals_iter().collect()
+    }
+}
+
+impl NoteSlice for [Note] {
+    fn unique_pitch_classes(&self) -> Vec<Note> {
+        let mut seen = [false; SEMITONES_IN_OCTAVE as usize];
+        self.iter()
+            .filter(|note| {
+                let pitch_class = usize::from(note.pitch_class());
+                !std::mem::replace(&mut seen[pitch_class], true)
+            })
+            .copied()
+            .collect()
+    }
+
+    fn longest_scalar_run(&self) -> (usize, usize) {
+        let mut longest = (0, 0);
+        let mut start = 0;
+
+        for (index, pair) in self.windows(2).enumerate() {
+            let step = pair[0].midi_number().abs_diff(pair[1].midi_number());
+            if !(1..=2).contains(&step) {
+                start = index + 1;
+                continue;
+            }
+
+            let length = index + 2 - start;
+            if length > longest.1 {
+                longest = (start, length);
+            }
+        }
+
+        longest
+    }
+
+    fn intervals_iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.windows(2)
+            .map(|pair| Interval::from_pitches(pair[0], pair[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique_pitch_classes() {
+        assert_eq!([C4, E4, C5, G4].unique_pitch_classes(), vec![C4, E4, G4]);
+        // The first occurrence wins, whatever its octave
+        assert_eq!(
+            [G5, C4, G3, E4, C6].unique_pitch_classes(),
+            vec![G5, C4, E4]
+        );
+        assert_eq!([A4, B4, D5].unique_pitch_classes(), vec![A4, B4, D5]);
+        assert!(Vec::<Note>::new().unique_pitch_classes().is_empty());
+    }
+
+    #[test]
+    fn test_longest_scalar_run() {
+        // A leap, then a scale down from A to D, then a shorter scale up
+        let melody = [C4, G4, A4, G4, F4, E4, D4, D4, E4, F4, C5];
+        assert_eq!(melody.longest_scalar_run(), (1, 6));
+        assert_eq!(melody[7..].longest_scalar_run(), (0, 3));
+
+        // Chromatic steps count as seconds
+        assert_eq!([C4, CSHARP4, D4, DSHARP4].longest_scalar_run(), (0, 4));
+        // The first of two runs of the same length wins
+        assert_eq!([C4, D4, A4, B4].longest_scalar_run(), (0, 2));
+
+        assert_eq!([C4, E4, G4, C5, G4].longest_scalar_run(), (0, 0));
+        assert_eq!([C4, C4, C4].longest_scalar_run(), (0, 0));
+        assert_eq!([C4].longest_scalar_run(), (0, 0));
+        assert_eq!(Vec::<Note>::new().longest_scalar_run(), (0, 0));
+    }
+
+    #[test]
+    fn test_intervals() {
+        let notes = [C4, E4, G4, C5, A4];
+        assert_eq!(
+            notes.intervals(),
+            vec![MAJOR_THIRD, MINOR_THIRD, PERFECT_FOURTH, MINOR_THIRD]
+        );
+        assert!(notes.intervals_iter().eq(notes.intervals()));
+
+        assert_eq!([C4].intervals_iter().next(), None);
+        assert_eq!(Vec::<Note>::new().intervals_iter().next(), None);
+        assert!([C4].intervals().is_empty());
+    }
+}
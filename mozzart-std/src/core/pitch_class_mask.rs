@@ -0,0 +1,171 @@
+use crate::constants::SEMITONES_IN_OCTAVE;
+use crate::{Interval, Note};
+
+/// The bits used by the twelve pitch classes
+const MASK: u16 = (1 << SEMITONES_IN_OCTAVE) - 1;
+
+/// Represents a set of pitch classes as a 12-bit mask
+///
+/// Bit 0 is C, bit 1 is C#, and so on up to bit 11 for B. Transposing the set
+/// is a rotation of the mask, which makes symmetry checks cheap.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let c_major = PitchClassMask::from_notes(&[C4, E4, G4, C5]);
+/// assert_eq!(c_major.len(), 3);
+/// assert_eq!(c_major.transpose(2), PitchClassMask::from_notes(&[D4, FSHARP4, A4]));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct PitchClassMask(u16);
+
+impl PitchClassMask {
+    /// Creates the mask of the pitch classes played by the notes
+    ///
+    /// # Arguments
+    /// * `notes` - The notes, in any octave
+    ///
+    /// # Returns
+    /// A new `PitchClassMask` instance
+    pub fn from_notes(notes: &[Note]) -> Self {
+        notes.iter().copied().collect()
+    }
+
+    /// Returns the raw 12-bit mask
+    #[inline]
+    pub const fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns `true` if the pitch class of the note is in the set
+    #[inline]
+    pub fn contains(&self, note: Note) -> bool {
+        self.0 & 1 << note.pitch_class() != 0
+    }
+
+    /// Returns the number of pitch classes in the set
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns `true` if the set has no pitch classes
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if every pitch class of the set is also in another set
+    ///
+    /// # Arguments
+    /// * `other` - The set which may contain this one
+    #[inline]
+    pub const fn is_subset_of(&self, other: &Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Returns the set transposed up by a number of semitones
+    ///
+    /// # Arguments
+    /// * `semitones` - The number of semitones to transpose by
+    ///
+    /// # Returns
+    /// The rotated mask
+    pub const fn transpose(&self, semitones: u8) -> Self {
+        let octave = SEMITONES_IN_OCTAVE as u32;
+        let shift = semitones as u32 % octave;
+        let rotated = (self.0 << shift) | (self.0 >> ((octave - shift) % octave));
+        Self(rotated & MASK)
+    }
+
+    /// Returns the non-zero transpositions that map the set onto itself
+    ///
+    /// # Returns
+    /// The intervals, from 1 to 11 semitones, under which the set is symmetric
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let diminished = PitchClassMask::from_notes(diminished_seventh(C4).unwrap().notes());
+    /// let semitones: Vec<_> = diminished
+    ///     .transpositional_symmetry()
+    ///     .iter()
+    ///     .map(Interval::semitones)
+    ///     .collect();
+    /// assert_eq!(semitones, vec![3, 6, 9]);
+    /// ```
+    pub fn transpositional_symmetry(&self) -> Vec<Interval> {
+        (1..SEMITONES_IN_OCTAVE)
+            .filter(|&semitones| self.transpose(semitones) == *self)
+            .map(Interval::new)
+            .collect()
+    }
+
+    /// Returns `true` if the set is one of Messiaen's modes of limited transposition
+    ///
+    /// Such a set maps onto itself under some transposition other than the octave,
+    /// so it has fewer than twelve distinct transpositions. The empty set and the
+    /// full chromatic set are trivially symmetric and are not counted.
+    pub fn is_mode_of_limited_transposition(&self) -> bool {
+        !self.is_empty()
+            && self.0 != MASK
+            && (1..SEMITONES_IN_OCTAVE).any(|semitones| self.transpose(semitones) == *self)
+    }
+}
+
+impl FromIterator<Note> for PitchClassMask {
+    fn from_iter<I: IntoIterator<Item = Note>>(notes: I) -> Self {
+        Self(
+            notes
+                .into_iter()
+                .fold(0, |mask, note| mask | 1 << note.pitch_class()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::*;
+
+    #[test]
+    fn test_from_notes() {
+        let mask = PitchClassMask::from_notes(&[C4, E4, G4, C5, E2]);
+        assert_eq!(mask.bits(), 0b0000_1001_0001);
+        assert_eq!(mask.len(), 3);
+        assert!(mask.contains(E6));
+        assert!(!mask.contains(F4));
+        assert!(PitchClassMask::default().is_empty());
+    }
+
+    #[test]
+    fn test_transpose_wraps_around() {
+        let b = PitchClassMask::from_notes(&[B4]);
+        assert_eq!(b.transpose(1), PitchClassMask::from_notes(&[C4]));
+        assert_eq!(b.transpose(12), b);
+        assert_eq!(b.transpose(0), b);
+    }
+
+    #[test]
+    fn test_symmetry() {
+        let augmented = PitchClassMask::from_notes(augmented_triad(C4).unwrap().notes());
+        assert_eq!(
+            augmented.transpositional_symmetry(),
+            vec![MAJOR_THIRD, AUGMENTED_FIFTH]
+        );
+        assert!(augmented.is_mode_of_limited_transposition());
+
+        let major = PitchClassMask::from_notes(major_triad(C4).unwrap().notes());
+        assert!(major.transpositional_symmetry().is_empty());
+        assert!(!major.is_mode_of_limited_transposition());
+
+        let chromatic = PitchClassMask(MASK);
+        assert_eq!(chromatic.transpositional_symmetry().len(), 11);
+        assert!(!chromatic.is_mode_of_limited_transposition());
+    }
+}
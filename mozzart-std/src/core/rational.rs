@@ -0,0 +1,236 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Represents an exact non-negative fraction, used for musical time
+///
+/// The fraction is always kept in lowest terms, so equal values compare equal
+/// whatever the way they were computed.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let dotted_quarter = Rational::new(1, 4) + Rational::new(1, 8);
+/// assert_eq!(dotted_quarter, Rational::new(3, 8));
+/// assert_eq!(dotted_quarter.to_string(), "3/8");
+/// assert!(dotted_quarter < Rational::new(1, 2));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Rational {
+    numerator: u32,
+    denominator: u32,
+}
+
+impl Rational {
+    /// The fraction 0
+    pub const ZERO: Rational = Rational::integer(0);
+
+    /// The fraction 1
+    pub const ONE: Rational = Rational::integer(1);
+
+    /// Creates a new `Rational` reduced to lowest terms
+    ///
+    /// # Arguments
+    /// * `numerator` - The numerator of the fraction
+    /// * `denominator` - The denominator of the fraction, which must not be 0
+    ///
+    /// # Returns
+    /// A new `Rational` instance
+    ///
+    /// # Panics
+    /// If the denominator is 0
+    pub const fn new(numerator: u32, denominator: u32) -> Self {
+        assert!(
+            denominator != 0,
+            "the denominator of a fraction cannot be 0"
+        );
+
+        let divisor = gcd(numerator as u64, denominator as u64) as u32;
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    /// Creates a `Rational` equal to a whole number
+    pub const fn integer(value: u32) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    /// Returns the numerator, in lowest terms
+    #[inline]
+    pub const fn numerator(&self) -> u32 {
+        self.numerator
+    }
+
+    /// Returns the denominator, in lowest terms
+    #[inline]
+    pub const fn denominator(&self) -> u32 {
+        self.denominator
+    }
+
+    /// Returns the largest whole number not greater than the fraction
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// assert_eq!(Rational::new(7, 4).floor(), 1);
+    /// assert_eq!(Rational::new(8, 4).floor(), 2);
+    /// ```
+    #[inline]
+    pub const fn floor(&self) -> u32 {
+        self.numerator / self.denominator
+    }
+
+    /// Returns the difference of two fractions, or `None` if it would be negative
+    pub fn checked_sub(self, other: Rational) -> Option<Rational> {
+        let (a, b, denominator) = common(self, other);
+        let numerator = a.checked_sub(b)?;
+        Some(reduce(numerator, denominator))
+    }
+}
+
+/// Returns the greatest common divisor of two numbers
+///
+/// The divisor of 0 and 0 is taken to be 1, so dividing by it is always safe.
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    if a == 0 {
+        1
+    } else {
+        a
+    }
+}
+
+/// Returns the numerators of two fractions over their common denominator
+fn common(a: Rational, b: Rational) -> (u64, u64, u64) {
+    let (ad, bd) = (u64::from(a.denominator), u64::from(b.denominator));
+    (
+        u64::from(a.numerator) * bd,
+        u64::from(b.numerator) * ad,
+        ad * bd,
+    )
+}
+
+/// Builds a fraction from a possibly wide numerator and denominator
+fn reduce(numerator: u64, denominator: u64) -> Rational {
+    let divisor = gcd(numerator, denominator);
+    let numerator = u32::try_from(numerator / divisor).expect("the numerator fits in 32 bits");
+    let denominator =
+        u32::try_from(denominator / divisor).expect("the denominator fits in 32 bits");
+    Rational {
+        numerator,
+        denominator,
+    }
+}
+
+impl From<u32> for Rational {
+    fn from(value: u32) -> Self {
+        Self::integer(value)
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Self::Output {
+        let (a, b, denominator) = common(self, other);
+        reduce(a + b, denominator)
+    }
+}
+
+/// Subtracts two fractions
+///
+/// # Panics
+/// If the result would be negative; use `checked_sub` to avoid it
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Self::Output {
+        self.checked_sub(other)
+            .expect("a fraction cannot become negative")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Self::Output {
+        reduce(
+            u64::from(self.numerator) * u64::from(other.numerator),
+            u64::from(self.denominator) * u64::from(other.denominator),
+        )
+    }
+}
+
+/// Divides two fractions
+///
+/// # Panics
+/// If the divisor is 0
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Self::Output {
+        assert!(other.numerator != 0, "cannot divide by a zero fraction");
+        reduce(
+            u64::from(self.numerator) * u64::from(other.denominator),
+            u64::from(self.denominator) * u64::from(other.numerator),
+        )
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b, _) = common(*self, *other);
+        a.cmp(&b)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lowest_terms() {
+        assert_eq!(Rational::new(4, 8), Rational::new(1, 2));
+        assert_eq!(Rational::new(0, 5), Rational::ZERO);
+        assert_eq!(Rational::new(6, 3), Rational::integer(2));
+        assert_eq!(Rational::new(6, 4).denominator(), 2);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(1, 3);
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert_eq!(third.checked_sub(half), None);
+        assert_eq!(Rational::new(3, 2).to_string(), "3/2");
+        assert_eq!(Rational::integer(2).to_string(), "2");
+    }
+}
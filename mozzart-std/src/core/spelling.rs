@@ -0,0 +1,308 @@
+use crate::constants::SEMITONES_IN_OCTAVE;
+use crate::{Error, NamedInterval, Note, LETTERS, NATURAL_PITCH_CLASSES};
+use std::fmt;
+use std::str::FromStr;
+
+/// The largest alteration of a spelled note, a double sharp or a double flat
+const MAX_ALTERATION: i8 = 2;
+
+/// Represents a pitch class spelled with a letter name and an accidental
+///
+/// A `Note` is a MIDI number, so C# and Db are the same note. A `SpelledNote`
+/// keeps the letter, which is needed to write a scale with one note per letter:
+/// G# major has an F## as its seventh degree, not a G. Alterations go from a
+/// double flat to a double sharp.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let f_double_sharp: SpelledNote = "F##".parse().unwrap();
+/// assert_eq!(f_double_sharp.letter(), 'F');
+/// assert_eq!(f_double_sharp.alteration(), 2);
+/// assert_eq!(f_double_sharp.pitch_class(), 7);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct SpelledNote {
+    letter: u8,
+    alteration: i8,
+}
+
+impl SpelledNote {
+    /// Creates a new `SpelledNote`
+    ///
+    /// # Arguments
+    /// * `letter` - The letter name, from `A` to `G`
+    /// * `alteration` - The number of sharps (positive) or flats (negative), from -2 to 2
+    ///
+    /// # Returns
+    /// The spelled note, or `None` if the letter or the alteration is not valid
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// assert_eq!(SpelledNote::new('B', -1).unwrap().to_string(), "Bb");
+    /// assert_eq!(SpelledNote::new('H', 0), None);
+    /// assert_eq!(SpelledNote::new('C', 3), None);
+    /// ```
+    pub fn new(letter: char, alteration: i8) -> Option<Self> {
+        let letter = LETTERS.iter().position(|name| name.starts_with(letter))?;
+        (alteration.abs() <= MAX_ALTERATION).then_some(Self {
+            letter: letter as u8,
+            alteration,
+        })
+    }
+
+    /// Returns the spelling of a pitch class on a letter, if it needs at most a double accidental
+    ///
+    /// # Arguments
+    /// * `letter` - The index of the letter in `LETTERS` (0 = C, 6 = B)
+    /// * `pitch_class` - The pitch class to spell (0-11)
+    fn on_letter(letter: usize, pitch_class: u8) -> Option<Self> {
+        let octave = SEMITONES_IN_OCTAVE as i8;
+        let natural = NATURAL_PITCH_CLASSES[letter] as i8;
+        // The alteration is the distance to the natural note, between -6 and 5
+        let alteration = (pitch_class as i8 - natural + 6).rem_euclid(octave) - 6;
+        (alteration.abs() <= MAX_ALTERATION).then_some(Self {
+            letter: letter as u8,
+            alteration,
+        })
+    }
+
+    /// Returns the letter name of the note
+    pub fn letter(&self) -> char {
+        LETTERS[usize::from(self.letter)]
+            .chars()
+            .next()
+            .unwrap_or('C')
+    }
+
+    /// Returns the index of the letter name in `LETTERS`, from 0 for C to 6 for B
+    pub(crate) fn letter_index(&self) -> u8 {
+        self.letter
+    }
+
+    /// Returns the number of sharps (positive) or flats (negative) of the note
+    pub fn alteration(&self) -> i8 {
+        self.alteration
+    }
+
+    /// Returns the pitch class of the note, from 0 for C to 11 for B
+    pub fn pitch_class(&self) -> u8 {
+        let natural = NATURAL_PITCH_CLASSES[usize::from(self.letter)] as i8;
+        (natural + self.alteration).rem_euclid(SEMITONES_IN_OCTAVE as i8) as u8
+    }
+
+    /// Returns the note a spelled interval above this one
+    ///
+    /// The letter comes from the number of the interval and the accidental from
+    /// its size, so the same number of semitones can give different notes: an
+    /// augmented second above C is D#, a minor third above C is Eb.
+    ///
+    /// # Arguments
+    /// * `interval` - The interval to go up by
+    ///
+    /// # Returns
+    /// The spelled note, or `Error::TooManyAccidentals` if it would need more
+    /// than a double sharp or a double flat
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// let c: SpelledNote = "C".parse().unwrap();
+    /// assert_eq!(c.at_interval("A2".parse().unwrap()).unwrap().to_string(), "D#");
+    /// assert_eq!(c.at_interval("m3".parse().unwrap()).unwrap().to_string(), "Eb");
+    /// ```
+    pub fn at_interval(&self, interval: NamedInterval) -> Result<SpelledNote, Error> {
+        let letter =
+            (usize::from(self.letter) + usize::from(interval.number()) - 1) % LETTERS.len();
+        let octave = SEMITONES_IN_OCTAVE as i8;
+        let pitch_class = (self.pitch_class() as i8 + interval.semitones()).rem_euclid(octave);
+
+        Self::on_letter(letter, pitch_class as u8).ok_or_else(|| {
+            let natural = NATURAL_PITCH_CLASSES[letter] as i8;
+            Error::TooManyAccidentals {
+                letter: LETTERS[letter].chars().next().unwrap_or('C'),
+                alteration: (pitch_class - natural + 6).rem_euclid(octave) - 6,
+            }
+        })
+    }
+
+    /// Returns the other spellings of the same pitch class
+    ///
+    /// # Returns
+    /// The spellings with at most a double accidental, by letter from C to B
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// let c: SpelledNote = "C".parse().unwrap();
+    /// let names: Vec<_> = c.enharmonic_equivalents().iter().map(|note| note.to_string()).collect();
+    /// assert_eq!(names, vec!["Dbb", "B#"]);
+    /// ```
+    pub fn enharmonic_equivalents(&self) -> Vec<SpelledNote> {
+        (0..LETTERS.len())
+            .filter(|&letter| letter != usize::from(self.letter))
+            .filter_map(|letter| Self::on_letter(letter, self.pitch_class()))
+            .collect()
+    }
+
+    /// Returns the usual spelling of the pitch class of a note
+    ///
+    /// The natural name is used when there is one, otherwise the sharp or the
+    /// flat name depending on the preference.
+    ///
+    /// # Arguments
+    /// * `note` - The note to spell, in any octave
+    /// * `preference` - The accidental used for the black keys
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(SpelledNote::spell(CSHARP4, SpellingPreference::Flats).to_string(), "Db");
+    /// assert_eq!(SpelledNote::spell(CSHARP4, SpellingPreference::Sharps).to_string(), "C#");
+    /// assert_eq!(SpelledNote::spell(E2, SpellingPreference::Flats).to_string(), "E");
+    /// ```
+    pub fn spell(note: Note, preference: SpellingPreference) -> SpelledNote {
+        let spellings = Self::simple_spellings(note);
+        let preferred = spellings.iter().find(|spelling| match preference {
+            SpellingPreference::Sharps => spelling.alteration >= 0,
+            SpellingPreference::Flats => spelling.alteration <= 0,
+        });
+        // Every pitch class has a natural, sharp or flat name
+        *preferred.unwrap_or(&spellings[0])
+    }
+
+    /// Returns the spellings of the pitch class of a note, with at most one accidental
+    ///
+    /// # Returns
+    /// The natural name if there is one, otherwise the sharp then the flat name,
+    /// followed by the other spellings with one accidental, such as E# for F
+    pub(crate) fn simple_spellings(note: Note) -> Vec<SpelledNote> {
+        let pitch_class = note.pitch_class();
+        let mut spellings: Vec<_> = (0..LETTERS.len())
+            .filter_map(|letter| Self::on_letter(letter, pitch_class))
+            .filter(|note| note.alteration.abs() <= 1)
+            .collect();
+        spellings.sort_by_key(|note| (note.alteration.abs(), -note.alteration));
+        spellings
+    }
+}
+
+/// Represents the accidental used to name the black keys, when there is no key to spell them in
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let name = SpelledNote::spell(ASHARP3, SpellingPreference::Flats);
+/// assert_eq!(name.to_string(), "Bb");
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpellingPreference {
+    /// The black keys are named with sharps, as in C#, F# and G#
+    #[default]
+    Sharps,
+    /// The black keys are named with flats, as in Db, Gb and Ab
+    Flats,
+}
+
+impl fmt::Display for SpelledNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let accidental = if self.alteration >= 0 { "#" } else { "b" };
+        let count = usize::from(self.alteration.unsigned_abs());
+        write!(
+            f,
+            "{}{}",
+            LETTERS[usize::from(self.letter)],
+            accidental.repeat(count)
+        )
+    }
+}
+
+impl FromStr for SpelledNote {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidNoteName(name.to_string());
+
+        let mut chars = name.chars();
+        let letter = chars.next().ok_or_else(invalid)?;
+        let accidentals = chars.as_str();
+        let alteration = match accidentals {
+            "" => 0,
+            "#" => 1,
+            "##" | "x" => 2,
+            "b" => -1,
+            "bb" => -2,
+            _ => return Err(invalid()),
+        };
+
+        Self::new(letter, alteration).ok_or_else(invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        for name in ["C", "F#", "Bb", "F##", "Ebb"] {
+            let note: SpelledNote = name.parse().unwrap();
+            assert_eq!(note.to_string(), name);
+        }
+        assert_eq!("Fx".parse::<SpelledNote>().unwrap().to_string(), "F##");
+        for name in ["", "H", "C###", "c", "C#b"] {
+            assert_eq!(
+                name.parse::<SpelledNote>(),
+                Err(Error::InvalidNoteName(name.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_pitch_classes() {
+        let pitch_class = |name: &str| name.parse::<SpelledNote>().unwrap().pitch_class();
+        assert_eq!(pitch_class("B#"), 0);
+        assert_eq!(pitch_class("Cb"), 11);
+        assert_eq!(pitch_class("Dbb"), 0);
+        assert_eq!(pitch_class("F##"), 7);
+    }
+
+    #[test]
+    fn test_enharmonic_equivalents() {
+        let names = |name: &str| -> Vec<String> {
+            name.parse::<SpelledNote>()
+                .unwrap()
+                .enharmonic_equivalents()
+                .iter()
+                .map(|note| note.to_string())
+                .collect()
+        };
+        assert_eq!(names("G#"), vec!["Ab"]);
+        assert_eq!(names("F##"), vec!["G", "Abb"]);
+        assert_eq!(names("E"), vec!["D##", "Fb"]);
+    }
+
+    #[test]
+    fn test_simple_spellings() {
+        let names = |note| -> Vec<String> {
+            SpelledNote::simple_spellings(note)
+                .iter()
+                .map(|note| note.to_string())
+                .collect()
+        };
+        assert_eq!(names(C4), vec!["C", "B#"]);
+        assert_eq!(names(CSHARP4), vec!["C#", "Db"]);
+        assert_eq!(names(F4), vec!["F", "E#"]);
+    }
+}
@@ -1,4 +1,12 @@
 use crate::Interval;
+use std::fmt;
+use std::ops::{Add, AddAssign};
+
+/// Represents the distance between two adjacent notes, measured in semitones
+///
+/// Steps describe how consecutive notes of a scale relate to each other
+/// (whole step, half step, step and a half), while intervals are usually
+/// measured from a fixed root.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Step(u8);
 
@@ -38,6 +46,25 @@ impl Step {
     pub fn semitones(&self) -> u8 {
         self.0
     }
+
+    /// Adds another step to this one, checking for overflow
+    ///
+    /// # Arguments
+    /// * `other` - The step to add
+    ///
+    /// # Returns
+    /// The combined step, or `None` if it would be larger than 255 semitones
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(WHOLE.checked_add(&HALF), Some(WHOLE_AND_HALF));
+    /// ```
+    #[inline]
+    pub fn checked_add(&self, other: &Step) -> Option<Step> {
+        self.0.checked_add(other.0).map(Step::new)
+    }
 }
 
 /// Conversion from `Step` to `u8` (number of semitones)
@@ -99,3 +126,114 @@ impl From<&Interval> for Step {
         Step::new(interval.semitones())
     }
 }
+
+/// Implements addition of two steps, producing a larger step
+///
+/// The addition saturates at 255 semitones instead of overflowing; use
+/// `Step::checked_add` to detect it.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::Step;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(WHOLE + HALF, WHOLE_AND_HALF);
+/// ```
+impl Add<Step> for Step {
+    type Output = Step;
+
+    #[inline]
+    fn add(self, other: Step) -> Self::Output {
+        Step::new(self.0.saturating_add(other.0))
+    }
+}
+
+/// Implements in-place addition of a step to another step, saturating at 255 semitones
+impl AddAssign<Step> for Step {
+    #[inline]
+    fn add_assign(&mut self, other: Step) {
+        self.0 = self.0.saturating_add(other.0);
+    }
+}
+
+/// Formats a step using whole (`W`) and half (`H`) step symbols
+///
+/// Each pair of semitones is rendered as a `W`, with a trailing `H` for an odd
+/// semitone, so the augmented second of the harmonic minor scale renders as `WH`.
+/// A step of zero semitones renders as `U` (unison).
+///
+/// # Examples
+/// ```
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(HALF.to_string(), "H");
+/// assert_eq!(WHOLE.to_string(), "W");
+/// assert_eq!(WHOLE_AND_HALF.to_string(), "WH");
+/// ```
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "U");
+        }
+
+        for _ in 0..self.0 / 2 {
+            write!(f, "W")?;
+        }
+        if self.0 % 2 == 1 {
+            write!(f, "H")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_semitones() {
+        assert_eq!(UNISON.semitones(), 0);
+        assert_eq!(HALF.semitones(), 1);
+        assert_eq!(WHOLE.semitones(), 2);
+        assert_eq!(WHOLE_AND_HALF.semitones(), 3);
+    }
+
+    #[test]
+    fn test_add_steps() {
+        assert_eq!(WHOLE + HALF, WHOLE_AND_HALF);
+        assert_eq!(WHOLE + HALF, Step::from(MINOR_THIRD));
+        assert_eq!(HALF + HALF, WHOLE);
+        assert_eq!(UNISON + WHOLE, WHOLE);
+
+        let mut step = HALF;
+        step += WHOLE;
+        assert_eq!(step, WHOLE_AND_HALF);
+    }
+
+    #[test]
+    fn test_add_overflow() {
+        assert_eq!(WHOLE.checked_add(&HALF), Some(WHOLE_AND_HALF));
+        assert_eq!(
+            Step::new(u8::MAX).checked_add(&UNISON),
+            Some(Step::new(u8::MAX))
+        );
+        assert_eq!(Step::new(u8::MAX).checked_add(&HALF), None);
+
+        assert_eq!(Step::new(254) + WHOLE, Step::new(u8::MAX));
+        let mut step = Step::new(u8::MAX);
+        step += HALF;
+        assert_eq!(step, Step::new(u8::MAX));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(UNISON.to_string(), "U");
+        assert_eq!(HALF.to_string(), "H");
+        assert_eq!(WHOLE.to_string(), "W");
+        assert_eq!(WHOLE_AND_HALF.to_string(), "WH");
+        assert_eq!(Step::from(MAJOR_THIRD).to_string(), "WW");
+        assert_eq!(Step::from(PERFECT_FIFTH).to_string(), "WWWH");
+    }
+}
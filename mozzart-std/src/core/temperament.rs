@@ -0,0 +1,290 @@
+use crate::constants::*;
+use crate::{Interval, Note, Tuning};
+
+/// The number of cents in an octave
+const CENTS_IN_OCTAVE: f64 = 1200.0;
+
+/// The position of each degree above the tonal center on the line of fifths of Pythagorean tuning
+///
+/// The degrees go from the minor second, five fifths below, to the augmented
+/// fourth, six fifths above, as in the usual table.
+const PYTHAGOREAN_FIFTHS: [i8; 12] = [0, -5, 2, -3, 4, -1, 6, 1, -4, 3, -2, 5];
+
+/// The position of each degree above the tonal center on the line of fifths of meantone
+///
+/// The degrees go from the minor third, three fifths below, to the augmented
+/// fifth, eight fifths above, as on a meantone keyboard from Eb to G#.
+const MEANTONE_FIFTHS: [i8; 12] = [0, 7, 2, -3, 4, -1, 6, 1, 8, 3, -2, 5];
+
+/// The ratio of each degree above the tonal center in five-limit just intonation
+const JUST_RATIOS: [(u8, u8); 12] = [
+    (1, 1),
+    (16, 15),
+    (9, 8),
+    (6, 5),
+    (5, 4),
+    (4, 3),
+    (45, 32),
+    (3, 2),
+    (8, 5),
+    (5, 3),
+    (9, 5),
+    (15, 8),
+];
+
+/// Represents the ways of dividing the octave into twelve notes
+///
+/// Except for equal temperament, the notes are tuned by ratios from a tonal
+/// center, which is given with the note whose frequency is computed. The tonal
+/// center itself keeps its frequency in equal temperament, so a piece sounds in
+/// the same key whatever the temperament.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let tuning = Tuning::default();
+/// let root = Temperament::Just.frequency(C4, C4, tuning);
+/// assert_eq!(Temperament::Just.frequency(E4, C4, tuning) / root, 1.25);
+/// assert_eq!(Temperament::Equal.frequency(A4, C4, tuning), 440.0);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Temperament {
+    /// Every semitone is the twelfth root of two, the tonal center makes no difference
+    #[default]
+    Equal,
+    /// Every note is a number of pure fifths, 3/2, away from the tonal center
+    Pythagorean,
+    /// Five-limit just intonation, with pure major thirds (5/4) and fifths (3/2) above the tonal center
+    Just,
+    /// Every note is a number of fifths narrowed by a quarter of the syntonic comma, giving pure major thirds
+    QuarterCommaMeantone,
+}
+
+impl Temperament {
+    /// Returns the ratio of a degree above the tonal center, within an octave
+    ///
+    /// # Arguments
+    /// * `degree` - The number of semitones above the tonal center, below 12
+    fn degree_ratio(&self, degree: u8) -> f64 {
+        let degree = usize::from(degree);
+        match self {
+            Temperament::Equal => 2f64.powf(degree as f64 / f64::from(SEMITONES_IN_OCTAVE)),
+            Temperament::Pythagorean => stack_fifths(1.5, PYTHAGOREAN_FIFTHS[degree]),
+            Temperament::Just => {
+                let (numerator, denominator) = JUST_RATIOS[degree];
+                f64::from(numerator) / f64::from(denominator)
+            }
+            Temperament::QuarterCommaMeantone => {
+                stack_fifths(5f64.powf(0.25), MEANTONE_FIFTHS[degree])
+            }
+        }
+    }
+
+    /// Returns the frequency of a note in this temperament
+    ///
+    /// # Arguments
+    /// * `note` - The note whose frequency is returned
+    /// * `root` - The tonal center the temperament is built on, only its pitch class matters
+    /// * `tuning` - The reference pitch, which tunes the tonal center in equal temperament
+    ///
+    /// # Returns
+    /// The frequency in hertz
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let tuning = Tuning::default();
+    /// let fifth = Temperament::Pythagorean.frequency(E5, A4, tuning);
+    /// assert_eq!(fifth, 660.0);
+    /// ```
+    pub fn frequency(&self, note: Note, root: Note, tuning: Tuning) -> f64 {
+        if *self == Temperament::Equal {
+            return tuning.frequency(note);
+        }
+
+        let degree = degree_above(note, root);
+        let root_below = i16::from(note.midi_number()) - i16::from(degree);
+        let semitones = f64::from(root_below - i16::from(A4.midi_number()));
+        let root_hz = tuning.a4_hz() * 2f64.powf(semitones / f64::from(SEMITONES_IN_OCTAVE));
+        root_hz * self.degree_ratio(degree)
+    }
+}
+
+/// Returns the ratio of a number of fifths above the tonal center, brought within an octave
+fn stack_fifths(fifth: f64, fifths: i8) -> f64 {
+    let ratio = fifth.powi(i32::from(fifths));
+    ratio / 2f64.powf(ratio.log2().floor())
+}
+
+/// Returns the number of semitones from the closest tonal center at or below a note, below 12
+fn degree_above(note: Note, root: Note) -> u8 {
+    let semitones = i16::from(note.midi_number()) - i16::from(root.midi_number());
+    semitones.rem_euclid(i16::from(SEMITONES_IN_OCTAVE)) as u8
+}
+
+/// Returns the size of a ratio in cents
+fn cents(ratio: f64) -> f64 {
+    CENTS_IN_OCTAVE * ratio.log2()
+}
+
+impl Interval {
+    /// Returns the frequency ratio of this interval above the tonal center of a temperament
+    ///
+    /// Except in equal temperament, an interval has several sizes depending on
+    /// the notes it is between, and the one above the tonal center is returned.
+    /// Compound intervals add pure octaves.
+    ///
+    /// # Arguments
+    /// * `temperament` - The temperament the interval is tuned in
+    ///
+    /// # Returns
+    /// The ratio of the frequency of the upper note to the lower one
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(MAJOR_THIRD.ratio_in(Temperament::Just), 1.25);
+    /// assert_eq!(PERFECT_FIFTH.ratio_in(Temperament::Pythagorean), 1.5);
+    /// assert_eq!(PERFECT_OCTAVE.ratio_in(Temperament::Equal), 2.0);
+    /// ```
+    pub fn ratio_in(&self, temperament: Temperament) -> f64 {
+        let octaves = self.semitones() / SEMITONES_IN_OCTAVE;
+        let degree = self.semitones() % SEMITONES_IN_OCTAVE;
+        temperament.degree_ratio(degree) * 2f64.powi(i32::from(octaves))
+    }
+}
+
+/// Returns how far a note is tuned from equal temperament
+///
+/// # Arguments
+/// * `note` - The note to compare
+/// * `temperament` - The temperament the note is tuned in
+/// * `root` - The tonal center the temperament is built on, only its pitch class matters
+///
+/// # Returns
+/// The difference in cents, positive when the note is higher than in equal temperament
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// // The Pythagorean major third is almost 8 cents wider than the equal one
+/// let deviation = cents_deviation_from_equal(E4, Temperament::Pythagorean, C4);
+/// assert!((deviation - 7.82).abs() < 0.01);
+/// ```
+pub fn cents_deviation_from_equal(note: Note, temperament: Temperament, root: Note) -> f64 {
+    let degree = degree_above(note, root);
+    cents(temperament.degree_ratio(degree)) - 100.0 * f64::from(degree)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPERAMENTS: [Temperament; 4] = [
+        Temperament::Equal,
+        Temperament::Pythagorean,
+        Temperament::Just,
+        Temperament::QuarterCommaMeantone,
+    ];
+
+    #[test]
+    fn test_just_major_third() {
+        let tuning = Tuning::default();
+        let temperament = Temperament::Just;
+        let root = temperament.frequency(C4, C4, tuning);
+        assert_eq!(root, tuning.frequency(C4));
+        assert_eq!(temperament.frequency(E4, C4, tuning) / root, 1.25);
+        assert!((cents(MAJOR_THIRD.ratio_in(temperament)) - 386.3).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_pythagorean_fifth() {
+        let fifth = PERFECT_FIFTH.ratio_in(Temperament::Pythagorean);
+        assert!((cents(fifth) - 702.0).abs() < 0.05);
+        assert_eq!(
+            Temperament::Pythagorean.frequency(D4, G3, Tuning::default())
+                / Temperament::Pythagorean.frequency(G3, G3, Tuning::default()),
+            1.5
+        );
+    }
+
+    #[test]
+    fn test_equal_temperament() {
+        for tuning in [Tuning::default(), Tuning::baroque(), Tuning::verdi()] {
+            for midi in 0..=127 {
+                let note = Note::new(midi);
+                assert_eq!(
+                    Temperament::Equal.frequency(note, D4, tuning),
+                    tuning.frequency(note)
+                );
+                assert!(cents_deviation_from_equal(note, Temperament::Equal, D4).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_published_deviations() {
+        let tables = [
+            (
+                Temperament::Pythagorean,
+                [
+                    0.0, -9.78, 3.91, -5.87, 7.82, -1.96, 11.73, 1.96, -7.82, 5.87, -3.91, 9.78,
+                ],
+            ),
+            (
+                Temperament::Just,
+                [
+                    0.0, 11.73, 3.91, 15.64, -13.69, -1.96, -9.78, 1.96, 13.69, -15.64, 17.60,
+                    -11.73,
+                ],
+            ),
+            (
+                Temperament::QuarterCommaMeantone,
+                [
+                    0.0, -23.95, -6.84, 10.26, -13.69, 3.42, -20.53, -3.42, -27.37, -10.26, 6.84,
+                    -17.11,
+                ],
+            ),
+        ];
+        for (temperament, deviations) in tables {
+            for (degree, expected) in deviations.into_iter().enumerate() {
+                // The degrees are taken above a tonal center other than C
+                let note = Note::new(62 + degree as u8);
+                let deviation = cents_deviation_from_equal(note, temperament, D2);
+                assert!(
+                    (deviation - expected).abs() < 0.1,
+                    "{temperament:?} degree {degree}: {deviation}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_octaves() {
+        let tuning = Tuning::default();
+        for temperament in TEMPERAMENTS {
+            for degree in 0..SEMITONES_IN_OCTAVE {
+                let note = Note::new(60 + degree);
+                let octave_above = Note::new(72 + degree);
+                let ratio = temperament.frequency(octave_above, A3, tuning)
+                    / temperament.frequency(note, A3, tuning);
+                assert!((ratio - 2.0).abs() < 1e-12);
+            }
+            assert!((PERFECT_OCTAVE.ratio_in(temperament) - 2.0).abs() < 1e-12);
+        }
+        // The tonal center keeps its equal-tempered frequency below A4
+        assert_eq!(
+            Temperament::QuarterCommaMeantone.frequency(A0, A4, tuning),
+            27.5
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::constants::*;
+use crate::Note;
+
+/// Represents the reference pitch notes are tuned from
+///
+/// A `Tuning` is set up once and then used for every frequency, instead of
+/// passing the frequency of A4 around.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(Tuning::default().frequency(A4), 440.0);
+/// assert_eq!(Tuning::baroque().frequency(A5), 830.0);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tuning {
+    a4_hz: f64,
+}
+
+impl Tuning {
+    /// Creates a new `Tuning`
+    ///
+    /// # Arguments
+    /// * `a4_hz` - The frequency of A4 in hertz
+    ///
+    /// # Returns
+    /// A new `Tuning` instance
+    pub const fn new(a4_hz: f64) -> Self {
+        Self { a4_hz }
+    }
+
+    /// Returns the usual baroque pitch, with A4 at 415 Hz
+    pub const fn baroque() -> Self {
+        Self::new(415.0)
+    }
+
+    /// Returns the pitch named after Verdi, with A4 at 432 Hz
+    pub const fn verdi() -> Self {
+        Self::new(432.0)
+    }
+
+    /// Returns the frequency of A4 in hertz
+    pub const fn a4_hz(&self) -> f64 {
+        self.a4_hz
+    }
+
+    /// Returns the frequency of a note in equal temperament
+    ///
+    /// # Arguments
+    /// * `note` - The note whose frequency is returned
+    ///
+    /// # Returns
+    /// The frequency in hertz
+    pub fn frequency(&self, note: Note) -> f64 {
+        note.frequency_with_reference(self.a4_hz)
+    }
+
+    /// Returns the note closest to a frequency, and how far the frequency is from it
+    ///
+    /// # Arguments
+    /// * `frequency` - The frequency in hertz
+    ///
+    /// # Returns
+    /// The closest note in equal temperament and the distance to it in cents, from
+    /// -50 to 50, or `None` if the frequency is not positive or is outside of the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(Tuning::default().nearest_note(440.0), Some((A4, 0.0)));
+    /// let (note, cents) = Tuning::default().nearest_note(445.0).unwrap();
+    /// assert_eq!(note, A4);
+    /// assert!((cents - 19.56).abs() < 0.01);
+    /// assert_eq!(Tuning::baroque().nearest_note(415.0), Some((A4, 0.0)));
+    /// ```
+    pub fn nearest_note(&self, frequency: f64) -> Option<(Note, f64)> {
+        if !frequency.is_finite() || frequency <= 0.0 {
+            return None;
+        }
+
+        let octave = f64::from(SEMITONES_IN_OCTAVE);
+        let semitones = f64::from(A4.midi_number()) + octave * (frequency / self.a4_hz).log2();
+        let midi = semitones.round();
+        if !(0.0..=f64::from(G9.midi_number())).contains(&midi) {
+            return None;
+        }
+
+        Some((Note::new(midi as u8), (semitones - midi) * 100.0))
+    }
+}
+
+impl Default for Tuning {
+    /// Returns the standard concert pitch, with A4 at 440 Hz
+    fn default() -> Self {
+        Self::new(440.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_a4_frequency() {
+        assert_eq!(Tuning::default().frequency(A4), 440.0);
+        assert_eq!(Tuning::baroque().frequency(A4), 415.0);
+        assert_eq!(Tuning::verdi().frequency(A4), 432.0);
+        assert_eq!(Tuning::verdi().a4_hz(), 432.0);
+    }
+
+    #[test]
+    fn test_frequency() {
+        let tuning = Tuning::default();
+        assert_eq!(tuning.frequency(A3), 220.0);
+        assert!((tuning.frequency(C4) - 261.626).abs() < 0.001);
+        assert!((Tuning::verdi().frequency(C4) - 256.869).abs() < 0.001);
+        // The presets keep the ratios between the notes
+        let baroque = Tuning::baroque();
+        let ratio = baroque.frequency(E5) / baroque.frequency(A4);
+        assert!((ratio - tuning.frequency(E5) / tuning.frequency(A4)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_nearest_note() {
+        for tuning in [Tuning::default(), Tuning::baroque(), Tuning::verdi()] {
+            for note in [C0, C4, A4, FSHARP5, G9] {
+                let (nearest, cents) = tuning.nearest_note(tuning.frequency(note)).unwrap();
+                assert_eq!(nearest, note);
+                assert!(cents.abs() < 1e-9);
+            }
+        }
+
+        let tuning = Tuning::default();
+        // A quarter tone above A4 is closer to A4 than to A#4 until 50 cents
+        let (note, cents) = tuning.nearest_note(440.0 * 2f64.powf(0.49 / 12.0)).unwrap();
+        assert_eq!(note, A4);
+        assert!((cents - 49.0).abs() < 1e-9);
+        let (note, cents) = tuning.nearest_note(440.0 * 2f64.powf(0.51 / 12.0)).unwrap();
+        assert_eq!(note, ASHARP4);
+        assert!((cents + 49.0).abs() < 1e-9);
+
+        assert_eq!(tuning.nearest_note(0.0), None);
+        assert_eq!(tuning.nearest_note(-440.0), None);
+        assert_eq!(tuning.nearest_note(f64::NAN), None);
+        assert_eq!(tuning.nearest_note(20_000.0), None);
+        assert_eq!(tuning.nearest_note(1.0), None);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::Note;
+
+/// The width of a white key, used as the unit for keyboard geometry
+pub const WHITE_KEY_WIDTH: f32 = 1.0;
+
+/// The width of a black key, relative to the width of a white key
+pub const BLACK_KEY_WIDTH: f32 = 0.6;
+
+/// The color of a piano key
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum KeyColor {
+    White,
+    Black,
+}
+
+/// Represents a single key of a piano keyboard, ready for drawing
+///
+/// The horizontal position is measured in white-key widths from the left edge
+/// of MIDI note 0. White keys sit side by side, while each black key is centered
+/// on the boundary between the two white keys it separates.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let keys: Vec<_> = Keyboard::range(C4, D4).collect();
+/// assert_eq!(keys[1].note, CSHARP4);
+/// assert_eq!(keys[1].color, KeyColor::Black);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Key {
+    /// The note played by the key
+    pub note: Note,
+    /// The color of the key
+    pub color: KeyColor,
+    /// The left edge of the key, in white-key widths from MIDI note 0
+    pub x_position: f32,
+}
+
+impl Key {
+    /// Creates the key for the given note, computing its color and position
+    ///
+    /// # Arguments
+    /// * `note` - The note played by the key
+    ///
+    /// # Returns
+    /// A new `Key` instance
+    pub fn new(note: Note) -> Self {
+        match note.white_key_index() {
+            Some(index) => Self {
+                note,
+                color: KeyColor::White,
+                x_position: index as f32 * WHITE_KEY_WIDTH,
+            },
+            None => {
+                // A black key is always one semitone above a white key
+                let below = Note::new(note.midi_number() - 1);
+                let boundary = (below.white_key_index().unwrap_or_default() + 1) as f32;
+                Self {
+                    note,
+                    color: KeyColor::Black,
+                    x_position: boundary * WHITE_KEY_WIDTH - BLACK_KEY_WIDTH / 2.0,
+                }
+            }
+        }
+    }
+
+    /// Returns the width of the key, in white-key widths
+    #[inline]
+    pub fn width(&self) -> f32 {
+        match self.color {
+            KeyColor::White => WHITE_KEY_WIDTH,
+            KeyColor::Black => BLACK_KEY_WIDTH,
+        }
+    }
+}
+
+/// Helpers for laying out a piano keyboard
+///
+/// `Keyboard` provides the geometry needed by piano-roll and keyboard UIs,
+/// so callers don't have to re-derive which MIDI notes are black keys and
+/// where each key should be drawn.
+pub struct Keyboard;
+
+impl Keyboard {
+    /// Returns the keys between two notes, inclusive, from left to right
+    ///
+    /// # Arguments
+    /// * `from` - The lowest note of the range
+    /// * `to` - The highest note of the range
+    ///
+    /// # Returns
+    /// An iterator over the keys in the range, empty if `from` is above `to`
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let keys: Vec<_> = Keyboard::range(C4, C5).collect();
+    /// assert_eq!(keys.len(), 13);
+    /// assert_eq!(keys.iter().filter(|k| k.color == KeyColor::White).count(), 8);
+    /// ```
+    pub fn range(from: Note, to: Note) -> impl Iterator<Item = Key> {
+        (from.midi_number()..=to.midi_number()).map(|midi| Key::new(Note::new(midi)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_white_keys_between_c2_and_c7() {
+        let white = Keyboard::range(C2, C7)
+            .filter(|key| key.color == KeyColor::White)
+            .count();
+        assert_eq!(white, 36);
+
+        let black = Keyboard::range(C2, C7)
+            .filter(|key| key.color == KeyColor::Black)
+            .count();
+        assert_eq!(black, 25);
+    }
+
+    #[test]
+    fn test_x_positions_increase() {
+        let keys: Vec<_> = Keyboard::range(Note::new(0), G9).collect();
+        assert_eq!(keys.len(), 128);
+        for pair in keys.windows(2) {
+            assert!(pair[0].x_position < pair[1].x_position, "{:?}", pair);
+        }
+    }
+
+    #[test]
+    fn test_key_geometry() {
+        let c4 = Key::new(C4);
+        assert_eq!(c4.color, KeyColor::White);
+        assert_eq!(c4.x_position, 35.0);
+        assert_eq!(c4.width(), WHITE_KEY_WIDTH);
+
+        // C#4 straddles the boundary between C4 and D4
+        let csharp4 = Key::new(CSHARP4);
+        assert_eq!(csharp4.color, KeyColor::Black);
+        assert_eq!(csharp4.x_position, 36.0 - BLACK_KEY_WIDTH / 2.0);
+        assert_eq!(csharp4.width(), BLACK_KEY_WIDTH);
+    }
+
+    #[test]
+    fn test_empty_range() {
+        assert_eq!(Keyboard::range(C5, C4).count(), 0);
+    }
+}
@@ -0,0 +1,5 @@
+mod keyboard;
+mod transposing;
+
+pub use keyboard::*;
+pub use transposing::*;
@@ -0,0 +1,334 @@
+use crate::constants::*;
+use crate::{Chord, DynScale, Error, Interval, KeySignature, Melody, Note, Scale, ScaleQuality};
+
+/// The direction in which an instrument sounds, relative to its written notes
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum TranspositionDirection {
+    /// The instrument sounds higher than written
+    Up,
+    /// The instrument sounds lower than written
+    Down,
+}
+
+/// Represents an instrument whose music is written at a different pitch than it sounds
+///
+/// A Bb trumpet playing a written D4 sounds C4, a major second lower: its
+/// transposition is a major second down. The guitar is written an octave above
+/// its sounding pitch, and a capo raises the sounding pitch by one semitone per fret.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(to_written(&C4, &BB_TRUMPET), Ok(D4));
+/// assert_eq!(to_sounding(&G4, &F_HORN), Ok(C4));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct TransposingInstrument {
+    name: &'static str,
+    interval: Interval,
+    direction: TranspositionDirection,
+}
+
+impl TransposingInstrument {
+    /// Creates a new `TransposingInstrument`
+    ///
+    /// # Arguments
+    /// * `name` - The name of the instrument
+    /// * `interval` - The interval between the written and the sounding notes
+    /// * `direction` - Whether the instrument sounds higher or lower than written
+    ///
+    /// # Returns
+    /// A new `TransposingInstrument` instance
+    pub const fn new(
+        name: &'static str,
+        interval: Interval,
+        direction: TranspositionDirection,
+    ) -> Self {
+        Self {
+            name,
+            interval,
+            direction,
+        }
+    }
+
+    /// Returns a guitar with a capo on a fret
+    ///
+    /// The capo raises the guitar by one semitone per fret, so a chord shape
+    /// written as A major with a capo on the third fret sounds as C major.
+    ///
+    /// # Arguments
+    /// * `fret` - The fret of the capo, 0 for no capo
+    ///
+    /// # Returns
+    /// The instrument, or `None` if the capo is above the twelfth fret
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let guitar = TransposingInstrument::guitar_capo(3).unwrap();
+    /// assert_eq!(to_sounding(&A3, &guitar), Ok(C3));
+    /// assert_eq!(TransposingInstrument::guitar_capo(0), Some(GUITAR));
+    /// ```
+    pub fn guitar_capo(fret: u8) -> Option<Self> {
+        (fret <= SEMITONES_IN_OCTAVE).then(|| {
+            Self::new(
+                GUITAR.name,
+                Interval::new(SEMITONES_IN_OCTAVE - fret),
+                TranspositionDirection::Down,
+            )
+        })
+    }
+
+    /// Returns the name of the instrument
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the interval between the written and the sounding notes
+    pub fn interval(&self) -> Interval {
+        self.interval
+    }
+
+    /// Returns whether the instrument sounds higher or lower than written
+    pub fn direction(&self) -> TranspositionDirection {
+        self.direction
+    }
+
+    /// Returns the number of semitones from a written note to the note it sounds
+    fn sounding_offset(&self) -> i16 {
+        let semitones = i16::from(self.interval.semitones());
+        match self.direction {
+            TranspositionDirection::Up => semitones,
+            TranspositionDirection::Down => -semitones,
+        }
+    }
+}
+
+/// Types made of notes which can be moved by a number of semitones
+///
+/// This is what the written and sounding conversions of a transposing instrument
+/// work on, so a whole melody, chord or scale is converted in one call.
+pub trait Transposable: Sized {
+    /// Returns a copy moved by a number of semitones
+    ///
+    /// # Arguments
+    /// * `semitones` - The number of semitones to move by, negative to move down
+    ///
+    /// # Returns
+    /// The transposed value, or `Error::NoteOutOfRange` with the first note that
+    /// would leave the MIDI range
+    fn checked_transpose(&self, semitones: i16) -> Result<Self, Error>;
+}
+
+/// Transposes every note, stopping at the first one out of range
+fn transpose_notes<'a, B>(
+    notes: impl IntoIterator<Item = &'a Note>,
+    semitones: i16,
+) -> Result<B, Error>
+where
+    B: FromIterator<Note>,
+{
+    notes
+        .into_iter()
+        .map(|note| note.checked_transpose(semitones))
+        .collect()
+}
+
+impl Transposable for Note {
+    fn checked_transpose(&self, semitones: i16) -> Result<Self, Error> {
+        self.checked_add_semitones(semitones)
+            .ok_or(Error::NoteOutOfRange(*self))
+    }
+}
+
+impl Transposable for Melody {
+    fn checked_transpose(&self, semitones: i16) -> Result<Self, Error> {
+        self.try_map_notes(|note| note.checked_transpose(semitones))
+    }
+}
+
+impl<const N: usize> Transposable for Chord<N> {
+    fn checked_transpose(&self, semitones: i16) -> Result<Self, Error> {
+        let notes: Vec<_> = transpose_notes(self.notes(), semitones)?;
+        Ok(Chord::new(self.quality(), notes))
+    }
+}
+
+impl<Q, const N: usize> Transposable for Scale<Q, N>
+where
+    Q: ScaleQuality,
+{
+    fn checked_transpose(&self, semitones: i16) -> Result<Self, Error> {
+        let notes: Vec<_> = transpose_notes(self.notes(), semitones)?;
+        Ok(Scale::new(notes))
+    }
+}
+
+impl Transposable for DynScale {
+    fn checked_transpose(&self, semitones: i16) -> Result<Self, Error> {
+        let notes = transpose_notes(self.notes(), semitones)?;
+        Ok(DynScale::new(self.name(), notes))
+    }
+}
+
+/// Returns the notes a transposing instrument reads to sound the given notes
+///
+/// # Arguments
+/// * `sounding` - The notes as they sound, a note, melody, chord or scale
+/// * `instrument` - The instrument playing them
+///
+/// # Returns
+/// The written notes, or `Error::NoteOutOfRange` if a written note would leave the MIDI range
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(to_written(&C4, &EB_ALTO_SAX), Ok(A4));
+/// assert_eq!(to_written(&major_triad(BFLAT3).unwrap(), &BB_TRUMPET), Ok(major_triad(C4).unwrap()));
+/// ```
+pub fn to_written<T>(sounding: &T, instrument: &TransposingInstrument) -> Result<T, Error>
+where
+    T: Transposable,
+{
+    sounding.checked_transpose(-instrument.sounding_offset())
+}
+
+/// Returns the notes a transposing instrument sounds when reading the given notes
+///
+/// # Arguments
+/// * `written` - The notes as they are written, a note, melody, chord or scale
+/// * `instrument` - The instrument reading them
+///
+/// # Returns
+/// The sounding notes, or `Error::NoteOutOfRange` if a sounding note would leave the MIDI range
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(to_sounding(&D4, &BB_TRUMPET), Ok(C4));
+/// assert_eq!(to_sounding(&E4, &GUITAR), Ok(E3));
+/// ```
+pub fn to_sounding<T>(written: &T, instrument: &TransposingInstrument) -> Result<T, Error>
+where
+    T: Transposable,
+{
+    written.checked_transpose(instrument.sounding_offset())
+}
+
+/// Returns the key signature a transposing instrument reads for a piece in a sounding key
+///
+/// The key is moved by the transposition of the instrument. When both spellings
+/// are possible, the one with fewer accidentals is chosen, and F# major is
+/// preferred to Gb major.
+///
+/// # Arguments
+/// * `sounding_key` - The key of the piece as it sounds, the concert key
+/// * `instrument` - The instrument reading the part
+///
+/// # Returns
+/// The key signature of the written part
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(written_key(C_MAJOR_KEY, &BB_TRUMPET), D_MAJOR_KEY);
+/// assert_eq!(written_key(EFLAT_MAJOR_KEY, &BB_TRUMPET), F_MAJOR_KEY);
+/// assert_eq!(written_key(F_MAJOR_KEY, &F_HORN), C_MAJOR_KEY);
+/// ```
+pub fn written_key(sounding_key: KeySignature, instrument: &TransposingInstrument) -> KeySignature {
+    // Each semitone up adds seven fifths, that is seven sharps
+    let fifths = i16::from(sounding_key.accidentals()) - 7 * instrument.sounding_offset();
+    let accidentals = (fifths + 5).rem_euclid(i16::from(SEMITONES_IN_OCTAVE)) - 5;
+    KeySignature::new(accidentals as i8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_written_notes() {
+        assert_eq!(to_written(&C4, &BB_TRUMPET), Ok(D4));
+        assert_eq!(to_written(&C4, &F_HORN), Ok(G4));
+        assert_eq!(to_written(&C4, &EB_ALTO_SAX), Ok(A4));
+        assert_eq!(to_written(&C3, &GUITAR), Ok(C4));
+    }
+
+    #[test]
+    fn test_capo() {
+        let guitar = TransposingInstrument::guitar_capo(3).unwrap();
+        let shape = major_triad(A3).unwrap();
+        assert_eq!(to_sounding(&shape, &guitar), Ok(major_triad(C3).unwrap()));
+        assert_eq!(to_written(&major_triad(C3).unwrap(), &guitar), Ok(shape));
+        assert_eq!(written_key(C_MAJOR_KEY, &guitar), A_MAJOR_KEY);
+
+        assert_eq!(
+            TransposingInstrument::guitar_capo(12).unwrap().interval(),
+            PERFECT_UNISON
+        );
+        assert_eq!(TransposingInstrument::guitar_capo(13), None);
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let melody = Melody::new([
+            (C4, Duration::Quarter),
+            (E4, Duration::Eighth),
+            (G4, Duration::Half),
+        ]);
+        let instruments = [
+            BB_TRUMPET,
+            F_HORN,
+            EB_ALTO_SAX,
+            GUITAR,
+            TransposingInstrument::guitar_capo(5).unwrap(),
+        ];
+        for instrument in &instruments {
+            let written = to_written(&melody, instrument).unwrap();
+            assert_eq!(to_sounding(&written, instrument), Ok(melody.clone()));
+
+            let scale = major_scale(D4).unwrap();
+            let written = to_written(&scale, instrument).unwrap();
+            assert_eq!(to_sounding(&written, instrument), Ok(scale));
+
+            let scale = messiaen_mode(2, C4).unwrap();
+            let written = to_written(&scale, instrument).unwrap();
+            assert_eq!(to_sounding(&written, instrument), Ok(scale));
+        }
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(to_written(&G9, &F_HORN), Err(Error::NoteOutOfRange(G9)));
+        let lowest = Note::new(0);
+        assert_eq!(
+            to_sounding(&lowest, &BB_TRUMPET),
+            Err(Error::NoteOutOfRange(lowest))
+        );
+        let melody = Melody::new([(C4, Duration::Quarter), (C9, Duration::Quarter)]);
+        assert_eq!(
+            to_written(&melody, &EB_ALTO_SAX),
+            Err(Error::NoteOutOfRange(C9))
+        );
+    }
+
+    #[test]
+    fn test_written_key() {
+        assert_eq!(written_key(C_MAJOR_KEY, &EB_ALTO_SAX), A_MAJOR_KEY);
+        assert_eq!(written_key(E_MAJOR_KEY, &BB_TRUMPET), FSHARP_MAJOR_KEY);
+        assert_eq!(written_key(B_MAJOR_KEY, &BB_TRUMPET), DFLAT_MAJOR_KEY);
+        assert_eq!(written_key(A_MINOR_KEY, &F_HORN), E_MINOR_KEY);
+        assert_eq!(written_key(D_MAJOR_KEY, &GUITAR), D_MAJOR_KEY);
+    }
+}
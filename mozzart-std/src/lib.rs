@@ -1,10 +1,34 @@
+mod analysis;
+#[cfg(feature = "audio")]
+mod audio;
 mod chords;
 pub mod constants;
 mod core;
+mod instruments;
+mod melodies;
+mod midi;
+#[cfg(feature = "serde")]
+mod presets;
+#[cfg(feature = "serde")]
+pub mod reference;
 mod scales;
+mod serial;
+#[cfg(feature = "rand")]
+mod training;
 mod utils;
 
+pub use analysis::*;
+#[cfg(feature = "audio")]
+pub use audio::*;
 pub use chords::*;
 pub use core::*;
+pub use instruments::*;
+pub use melodies::*;
+pub use midi::*;
+#[cfg(feature = "serde")]
+pub use presets::*;
 pub use scales::*;
+pub use serial::*;
+#[cfg(feature = "rand")]
+pub use training::*;
 pub use utils::*;
@@ -0,0 +1,87 @@
+use crate::{Duration, Melody};
+use std::collections::HashMap;
+
+impl Duration {
+    /// Returns the length of the note in ABC notation, with an eighth note as the unit
+    const fn abc_length(&self) -> &'static str {
+        match self {
+            Duration::Whole => "8",
+            Duration::Half => "4",
+            Duration::Quarter => "2",
+            Duration::Eighth => "",
+            Duration::Sixteenth => "/",
+        }
+    }
+}
+
+impl Melody {
+    /// Returns the melody as an ABC tune
+    ///
+    /// The tune has the minimal header, in C major with an eighth note as the
+    /// unit length, followed by the notes separated by spaces and a final
+    /// barline. As an accidental in ABC carries over to the following notes of
+    /// the same pitch, a natural sign is written when a white key follows its
+    /// sharp.
+    ///
+    /// # Returns
+    /// The ABC text of the tune, one header or body line per line
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let melody = Melody::new([
+    ///     (C4, Duration::Quarter),
+    ///     (CSHARP4, Duration::Eighth),
+    ///     (C4, Duration::Eighth),
+    ///     (G4, Duration::Half),
+    /// ]);
+    /// assert_eq!(melody.to_abc(), "X:1\nL:1/8\nK:C\nC2 ^C =C G4 |]\n");
+    /// ```
+    pub fn to_abc(&self) -> String {
+        let mut sharps = HashMap::new();
+        let mut body = Vec::with_capacity(self.len() + 1);
+
+        for (note, duration) in self.events() {
+            let natural = note.abc_natural();
+            let sharp = note.is_black_key();
+            let previous = sharps.insert(natural.clone(), sharp);
+            let accidental = if sharp {
+                "^"
+            } else if previous == Some(true) {
+                "="
+            } else {
+                ""
+            };
+            body.push(format!("{accidental}{natural}{}", duration.abc_length()));
+        }
+        body.push("|]".to_string());
+
+        format!("X:1\nL:1/8\nK:C\n{}\n", body.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::*;
+    use crate::*;
+
+    #[test]
+    fn test_to_abc() {
+        let melody = Melody::new([
+            (C3, Duration::Whole),
+            (D5, Duration::Sixteenth),
+            (FSHARP4, Duration::Quarter),
+            (F5, Duration::Quarter),
+            (F4, Duration::Half),
+            (F4, Duration::Eighth),
+        ]);
+        assert_eq!(melody.to_abc(), "X:1\nL:1/8\nK:C\nC,8 d/ ^F2 f2 =F4 F |]\n");
+    }
+
+    #[test]
+    fn test_empty_melody_to_abc() {
+        assert_eq!(Melody::default().to_abc(), "X:1\nL:1/8\nK:C\n|]\n");
+    }
+}
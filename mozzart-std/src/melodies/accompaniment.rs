@@ -0,0 +1,384 @@
+use crate::constants::*;
+use crate::{Duration, Note, Phrase, Progression, Rational, TimeSignature, TimedNote, Voicing};
+
+/// Represents the figures of a piano accompaniment
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum AccompanimentStyle {
+    /// The left hand alternates the root and the fifth on the beats, the right
+    /// hand holds the chord from the downbeat
+    BlockChords,
+    /// The left hand breaks the chord in eighths, root, fifth, third, fifth, the
+    /// right hand holds the chord from the downbeat
+    Alberti,
+    /// The left hand plays the root on the downbeat, the right hand the chord on
+    /// the other beats
+    WaltzBass,
+    /// The left hand holds the root, the right hand goes up the chord in eighths
+    BrokenArpeggio,
+}
+
+/// Returns a piano accompaniment of a progression, one chord per bar
+///
+/// The left hand plays within `LEFT_HAND_RANGE` and the right hand within
+/// `RIGHT_HAND_RANGE`. The right hand plays the chords in close position,
+/// each voicing being the one that moves the least from the previous chord.
+/// The figures follow the beats of the time signature, and notes held from the
+/// downbeat last the longest standard duration that fits in the bar.
+///
+/// # Arguments
+/// * `progression` - The chords, one per bar
+/// * `style` - The figures of the two hands
+/// * `ts` - The time signature of the accompaniment
+///
+/// # Returns
+/// The phrases of the left and the right hand
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let progression = Progression::new([major_triad(C4).unwrap(), major_triad(G3).unwrap()]);
+/// let (left, right) = accompaniment(&progression, AccompanimentStyle::Alberti, FOUR_FOUR);
+///
+/// let first_bar: Vec<_> = left.notes()[..4].iter().map(|note| note.note).collect();
+/// assert_eq!(first_bar, vec![C3, G3, E3, G3]);
+/// assert_eq!(right.notes()[3].note, D4);
+/// assert_eq!(left.length(), Rational::integer(2));
+/// ```
+pub fn accompaniment(
+    progression: &Progression,
+    style: AccompanimentStyle,
+    ts: TimeSignature,
+) -> (Phrase, Phrase) {
+    let bar_length = ts.bar_length();
+    let beat = ts.beat_unit();
+    let eighth = if beat.value() < Duration::Eighth.value() {
+        beat
+    } else {
+        Duration::Eighth
+    };
+    let held = Duration::ALL
+        .into_iter()
+        .find(|duration| duration.value() <= bar_length)
+        .unwrap_or(Duration::Sixteenth);
+
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    let mut previous: Option<Vec<Note>> = None;
+
+    for (bar, chord) in progression.chords().iter().enumerate() {
+        let downbeat = Rational::integer(bar as u32) * bar_length;
+        let at = |step: Duration, index: usize| {
+            downbeat + Rational::integer(index as u32) * step.value()
+        };
+        let timed = |note, onset, duration: Duration| TimedNote {
+            note,
+            onset,
+            duration: duration.value(),
+        };
+
+        let Some((pitch_class, offsets)) = chord_shape(chord) else {
+            continue;
+        };
+        let [root, third, fifth] = bass_notes(pitch_class, &offsets);
+        let voicing = right_hand_voicing(pitch_class, &offsets, previous.as_deref());
+
+        match style {
+            AccompanimentStyle::BlockChords => {
+                for index in 0..steps_in_bar(ts, beat) {
+                    let note = if index % 2 == 0 { root } else { fifth };
+                    left.push(timed(note, at(beat, index), beat));
+                }
+                right.extend(voicing.iter().map(|&note| timed(note, downbeat, held)));
+            }
+            AccompanimentStyle::Alberti => {
+                for index in 0..steps_in_bar(ts, eighth) {
+                    let note = [root, fifth, third, fifth][index % 4];
+                    left.push(timed(note, at(eighth, index), eighth));
+                }
+                right.extend(voicing.iter().map(|&note| timed(note, downbeat, held)));
+            }
+            AccompanimentStyle::WaltzBass => {
+                left.push(timed(root, downbeat, beat));
+                for index in 1..steps_in_bar(ts, beat) {
+                    right.extend(
+                        voicing
+                            .iter()
+                            .map(|&note| timed(note, at(beat, index), beat)),
+                    );
+                }
+            }
+            AccompanimentStyle::BrokenArpeggio => {
+                left.push(timed(root, downbeat, held));
+                for index in 0..steps_in_bar(ts, eighth) {
+                    let note = voicing[index % voicing.len()];
+                    right.push(timed(note, at(eighth, index), eighth));
+                }
+            }
+        }
+
+        previous = Some(voicing);
+    }
+
+    let length = Rational::integer(progression.len() as u32) * bar_length;
+    (Phrase::new(left, length), Phrase::new(right, length))
+}
+
+/// Returns how many notes of a duration fill a bar
+fn steps_in_bar(ts: TimeSignature, step: Duration) -> usize {
+    (ts.bar_length() / step.value()).floor() as usize
+}
+
+/// Returns the pitch class of the root of a chord and the offsets of its notes above the root
+///
+/// A chord recognized by `Voicing::chord` gives the offsets of its quality, in
+/// the order of its intervals, so the third comes before the fifth. Other
+/// voicings are read from their lowest note, in the order of their pitch classes.
+///
+/// # Returns
+/// The pitch class of the root and the offsets within an octave, the root
+/// first, or `None` for a voicing without notes
+fn chord_shape(chord: &Voicing) -> Option<(u8, Vec<u8>)> {
+    match chord.chord() {
+        Some((root, quality)) => {
+            let offsets = std::iter::once(0)
+                .chain(
+                    quality
+                        .intervals()
+                        .iter()
+                        .map(|interval| interval.semitones() % SEMITONES_IN_OCTAVE),
+                )
+                .collect();
+            Some((root.pitch_class(), offsets))
+        }
+        None => {
+            let root = chord.notes().first()?.pitch_class();
+            let mut offsets: Vec<u8> = Vec::new();
+            for note in chord.notes() {
+                let offset =
+                    (note.pitch_class() + SEMITONES_IN_OCTAVE - root) % SEMITONES_IN_OCTAVE;
+                if !offsets.contains(&offset) {
+                    offsets.push(offset);
+                }
+            }
+            Some((root, offsets))
+        }
+    }
+}
+
+/// Returns the root, third and fifth of a chord for the left hand
+///
+/// The root is placed between F2 and E3, so the figures built on it stay below C4.
+fn bass_notes(root: u8, offsets: &[u8]) -> [Note; 3] {
+    let lowest = F2.midi_number();
+    let bass = lowest + (root + SEMITONES_IN_OCTAVE - F2.pitch_class()) % SEMITONES_IN_OCTAVE;
+
+    let above_bass = |index: usize| Note::new(bass + offsets.get(index).copied().unwrap_or(0));
+    [Note::new(bass), above_bass(1), above_bass(2)]
+}
+
+/// Returns the close-position voicing of a chord for the right hand
+///
+/// Every inversion of the chord, folded within an octave, is placed in every
+/// octave of `RIGHT_HAND_RANGE`. The first chord is the lowest of them, and the
+/// following ones move the least from the previous voicing.
+fn right_hand_voicing(root: u8, offsets: &[u8], previous: Option<&[Note]>) -> Vec<Note> {
+    let mut offsets = offsets.to_vec();
+    offsets.sort();
+    offsets.dedup();
+
+    let (low, high) = (
+        RIGHT_HAND_RANGE.start().midi_number(),
+        RIGHT_HAND_RANGE.end().midi_number(),
+    );
+    let mut candidates = Vec::new();
+    for inversion in 0..offsets.len() {
+        let shape: Vec<u8> = offsets[inversion..]
+            .iter()
+            .copied()
+            .chain(
+                offsets[..inversion]
+                    .iter()
+                    .map(|offset| offset + SEMITONES_IN_OCTAVE),
+            )
+            .map(|offset| offset - offsets[inversion])
+            .collect();
+        let pitch_class = (root + offsets[inversion]) % SEMITONES_IN_OCTAVE;
+        let span = shape[shape.len() - 1];
+
+        for bottom in
+            (low..=high - span).filter(|bottom| bottom % SEMITONES_IN_OCTAVE == pitch_class)
+        {
+            candidates.push(
+                shape
+                    .iter()
+                    .map(|offset| Note::new(bottom + offset))
+                    .collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    let movement = |candidate: &Vec<Note>| {
+        previous.map_or(u32::from(candidate[0].midi_number()), |previous| {
+            previous
+                .iter()
+                .zip(candidate)
+                .map(|(from, to)| u32::from(from.midi_number().abs_diff(to.midi_number())))
+                .sum()
+        })
+    };
+    candidates
+        .into_iter()
+        .min_by_key(movement)
+        .expect("a chord folded within an octave always fits in the right hand")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    /// C, G, Am, F in root position
+    fn pop_progression() -> Progression {
+        Progression::new([
+            major_triad(C4).unwrap(),
+            major_triad(G3).unwrap(),
+            minor_triad(A3).unwrap(),
+            major_triad(F3).unwrap(),
+        ])
+    }
+
+    fn pitches(notes: &[TimedNote]) -> Vec<Note> {
+        notes.iter().map(|note| note.note).collect()
+    }
+
+    fn assert_in_ranges(left: &Phrase, right: &Phrase) {
+        for note in left.notes() {
+            assert!(LEFT_HAND_RANGE.contains(&note.note), "{note:?}");
+        }
+        for note in right.notes() {
+            assert!(RIGHT_HAND_RANGE.contains(&note.note), "{note:?}");
+        }
+    }
+
+    #[test]
+    fn test_alberti() {
+        let progression = pop_progression();
+        let (left, right) = accompaniment(&progression, AccompanimentStyle::Alberti, FOUR_FOUR);
+        assert_in_ranges(&left, &right);
+
+        // Eight eighths per bar, low-high-mid-high twice
+        assert_eq!(left.notes().len(), 32);
+        assert!(left
+            .notes()
+            .iter()
+            .all(|note| note.duration == Duration::Eighth.value()));
+        let bars: Vec<_> = left.notes().chunks(8).map(pitches).collect();
+        assert_eq!(bars[0], vec![C3, G3, E3, G3, C3, G3, E3, G3]);
+        assert_eq!(bars[1], vec![G2, D3, B2, D3, G2, D3, B2, D3]);
+        assert_eq!(bars[2], vec![A2, E3, C3, E3, A2, E3, C3, E3]);
+        assert_eq!(bars[3], vec![F2, C3, A2, C3, F2, C3, A2, C3]);
+        for bar in &bars {
+            assert_eq!(
+                contour(bar).directions(),
+                &[
+                    Direction::Up,
+                    Direction::Down,
+                    Direction::Up,
+                    Direction::Down,
+                    Direction::Up,
+                    Direction::Down,
+                    Direction::Up
+                ]
+            );
+        }
+
+        // Each bar starts on its downbeat, with the chord of the bar in both hands
+        for (bar, chord) in progression.chords().iter().enumerate() {
+            let harmony = PitchClassMask::from_notes(chord.notes());
+            let downbeat = Rational::integer(bar as u32);
+            assert_eq!(left.notes()[8 * bar].onset, downbeat);
+            assert!(left.notes()[8 * bar..8 * bar + 8]
+                .iter()
+                .all(|note| harmony.contains(note.note)));
+
+            let held = &right.notes()[3 * bar..3 * bar + 3];
+            assert!(held.iter().all(|note| note.onset == downbeat));
+            assert!(held
+                .iter()
+                .all(|note| note.duration == Duration::Whole.value()));
+            assert_eq!(PitchClassMask::from_notes(&pitches(held)), harmony);
+        }
+        assert_eq!(left.length(), Rational::integer(4));
+    }
+
+    #[test]
+    fn test_smooth_right_hand() {
+        let (_, right) = accompaniment(
+            &pop_progression(),
+            AccompanimentStyle::BlockChords,
+            FOUR_FOUR,
+        );
+        let voicings: Vec<_> = right.notes().chunks(3).map(pitches).collect();
+        assert_eq!(
+            voicings,
+            vec![
+                vec![C4, E4, G4],
+                vec![D4, G4, B4],
+                vec![E4, A4, C5],
+                vec![F4, A4, C5]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_block_chords() {
+        let (left, right) = accompaniment(
+            &pop_progression(),
+            AccompanimentStyle::BlockChords,
+            THREE_FOUR,
+        );
+        assert_in_ranges(&left, &right);
+
+        assert_eq!(pitches(&left.notes()[..3]), vec![C3, G3, C3]);
+        assert_eq!(left.notes()[3].onset, Rational::new(3, 4));
+        // A dotted half doesn't exist, so the chord is held for a half
+        assert!(right
+            .notes()
+            .iter()
+            .all(|note| note.duration == Duration::Half.value()));
+    }
+
+    #[test]
+    fn test_waltz_bass() {
+        let (left, right) = accompaniment(
+            &pop_progression(),
+            AccompanimentStyle::WaltzBass,
+            THREE_FOUR,
+        );
+        assert_in_ranges(&left, &right);
+
+        assert_eq!(pitches(left.notes()), vec![C3, G2, A2, F2]);
+        let onsets: Vec<_> = right.notes()[..6].iter().map(|note| note.onset).collect();
+        let beats = [1, 1, 1, 2, 2, 2].map(|beat| Rational::new(beat, 4));
+        assert_eq!(onsets, beats);
+        assert_eq!(right.notes().len(), 4 * 2 * 3);
+    }
+
+    #[test]
+    fn test_broken_arpeggio() {
+        let progression =
+            Progression::new([dominant_seventh(G3).unwrap(), major_seventh(C4).unwrap()]);
+        let (left, right) =
+            accompaniment(&progression, AccompanimentStyle::BrokenArpeggio, SIX_EIGHT);
+        assert_in_ranges(&left, &right);
+
+        assert_eq!(pitches(left.notes()), vec![G2, C3]);
+        assert_eq!(left.notes()[0].duration, Duration::Half.value());
+        assert_eq!(right.notes().len(), 12);
+        assert_eq!(pitches(&right.notes()[..6]), vec![D4, F4, G4, B4, D4, F4]);
+        assert_eq!(right.notes()[6].onset, Rational::new(3, 4));
+        assert_eq!(left.length(), Rational::new(3, 2));
+    }
+}
@@ -0,0 +1,437 @@
+use crate::{Error, Interval, Phrase, Rational, Tempo, TimedNote};
+use std::collections::BTreeMap;
+
+/// Represents a named part of a piece, such as a verse or a chorus
+///
+/// The phrases of a section are played one after the other, each starting
+/// when the previous one ends, rests included.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let riff = RhythmPattern::euclidean(3, 8).apply([C4, E4, G4], Duration::Eighth);
+/// let verse = Section::new("verse", [riff.clone(), riff]);
+/// assert_eq!(verse.name(), "verse");
+/// assert_eq!(verse.length(), Rational::integer(2));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Section {
+    name: String,
+    phrases: Vec<Phrase>,
+}
+
+impl Section {
+    /// Creates a new `Section`
+    ///
+    /// # Arguments
+    /// * `name` - The name the arrangement refers to the section by
+    /// * `phrases` - The phrases of the section, in the order they are played
+    ///
+    /// # Returns
+    /// A new `Section` instance
+    pub fn new(name: impl Into<String>, phrases: impl IntoIterator<Item = Phrase>) -> Self {
+        Self {
+            name: name.into(),
+            phrases: phrases.into_iter().collect(),
+        }
+    }
+
+    /// Returns the name of the section
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the phrases of the section, in the order they are played
+    pub fn phrases(&self) -> &[Phrase] {
+        &self.phrases
+    }
+
+    /// Returns the length of the section, as a fraction of a whole note
+    pub fn length(&self) -> Rational {
+        self.phrases
+            .iter()
+            .fold(Rational::integer(0), |length, phrase| {
+                length + phrase.length()
+            })
+    }
+}
+
+/// Represents a section played in an arrangement, possibly in another key
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SectionRef {
+    name: String,
+    transpose: Option<Interval>,
+}
+
+impl SectionRef {
+    /// Creates a reference to a section, played as written
+    ///
+    /// # Arguments
+    /// * `name` - The name of the section
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            transpose: None,
+        }
+    }
+
+    /// Returns the reference with the section transposed up by an interval
+    pub fn transposed(mut self, interval: Interval) -> Self {
+        self.transpose = Some(interval);
+        self
+    }
+
+    /// Returns the name of the section
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the interval the section is transposed up by, if any
+    pub fn transpose(&self) -> Option<Interval> {
+        self.transpose
+    }
+}
+
+impl From<&str> for SectionRef {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+/// Represents a step of the form of an arrangement
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ArrangementItem {
+    /// A section, played once
+    Section(SectionRef),
+    /// Steps played a number of times in a row, which may contain other repeats
+    Repeat(Vec<ArrangementItem>, usize),
+}
+
+impl ArrangementItem {
+    /// Returns the step playing a section once, as written
+    pub fn section(name: impl Into<String>) -> Self {
+        ArrangementItem::Section(SectionRef::new(name))
+    }
+
+    /// Returns the step playing some steps a number of times
+    pub fn repeat(items: impl IntoIterator<Item = ArrangementItem>, count: usize) -> Self {
+        ArrangementItem::Repeat(items.into_iter().collect(), count)
+    }
+
+    /// Returns the length of the step, given the length of each section
+    fn length(&self, sections: &BTreeMap<String, Section>) -> Rational {
+        match self {
+            ArrangementItem::Section(reference) => sections
+                .get(reference.name())
+                .map_or(Rational::integer(0), Section::length),
+            ArrangementItem::Repeat(items, count) => {
+                let once = items.iter().fold(Rational::integer(0), |length, item| {
+                    length + item.length(sections)
+                });
+                once * Rational::integer(*count as u32)
+            }
+        }
+    }
+
+    /// Returns the first reference to a section missing from an arrangement
+    fn missing_section<'a>(&'a self, sections: &BTreeMap<String, Section>) -> Option<&'a str> {
+        match self {
+            ArrangementItem::Section(reference) => {
+                (!sections.contains_key(reference.name())).then_some(reference.name())
+            }
+            ArrangementItem::Repeat(items, _) => {
+                items.iter().find_map(|item| item.missing_section(sections))
+            }
+        }
+    }
+}
+
+impl From<SectionRef> for ArrangementItem {
+    fn from(reference: SectionRef) -> Self {
+        ArrangementItem::Section(reference)
+    }
+}
+
+/// Represents a whole piece, as named sections played in a form
+///
+/// The form lists the sections in the order they are played, with repeats
+/// which may be nested, and each section may be transposed where it is played,
+/// such as a last chorus a whole step higher. `flatten` lays the form out into
+/// a single phrase.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let bar = |note| RhythmPattern::new([true]).apply([note], Duration::Whole);
+/// let arrangement = Arrangement::new(
+///     [Section::new("verse", [bar(C4)]), Section::new("chorus", [bar(G4)])],
+///     [
+///         ArrangementItem::repeat([ArrangementItem::section("verse")], 2),
+///         ArrangementItem::section("chorus"),
+///         SectionRef::new("chorus").transposed(MAJOR_SECOND).into(),
+///     ],
+/// )
+/// .unwrap();
+///
+/// let piece = arrangement.flatten().unwrap();
+/// let notes: Vec<_> = piece.notes().iter().map(|timed| timed.note).collect();
+/// assert_eq!(notes, vec![C4, C4, G4, A4]);
+/// assert_eq!(piece.notes()[3].onset, Rational::integer(3));
+/// assert_eq!(arrangement.total_duration(Tempo::new(120.0)), 8000.0);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Arrangement {
+    sections: BTreeMap<String, Section>,
+    form: Vec<ArrangementItem>,
+}
+
+impl Arrangement {
+    /// Creates a new `Arrangement`
+    ///
+    /// # Arguments
+    /// * `sections` - The sections of the piece; a later section replaces an earlier one with the same name
+    /// * `form` - The steps of the form, in the order they are played
+    ///
+    /// # Returns
+    /// The arrangement, or `Error::UnknownSection` if the form refers to a section
+    /// which is not given
+    pub fn new(
+        sections: impl IntoIterator<Item = Section>,
+        form: impl IntoIterator<Item = ArrangementItem>,
+    ) -> Result<Self, Error> {
+        let sections: BTreeMap<_, _> = sections
+            .into_iter()
+            .map(|section| (section.name.clone(), section))
+            .collect();
+        let form: Vec<_> = form.into_iter().collect();
+        if let Some(name) = form.iter().find_map(|item| item.missing_section(&sections)) {
+            return Err(Error::UnknownSection(name.to_string()));
+        }
+
+        Ok(Self { sections, form })
+    }
+
+    /// Returns the section with a name
+    pub fn section(&self, name: &str) -> Option<&Section> {
+        self.sections.get(name)
+    }
+
+    /// Returns the steps of the form, in the order they are played
+    pub fn form(&self) -> &[ArrangementItem] {
+        &self.form
+    }
+
+    /// Returns the length of the whole piece, repeats included, as a fraction of a whole note
+    pub fn length(&self) -> Rational {
+        self.form.iter().fold(Rational::integer(0), |length, item| {
+            length + item.length(&self.sections)
+        })
+    }
+
+    /// Returns how long the whole piece lasts at a tempo
+    ///
+    /// # Arguments
+    /// * `tempo` - The tempo of the piece
+    ///
+    /// # Returns
+    /// The length of the piece in milliseconds
+    pub fn total_duration(&self, tempo: Tempo) -> f64 {
+        tempo.length_ms(self.length())
+    }
+
+    /// Lays the form out into a single phrase
+    ///
+    /// The repeats are played out and every section starts when the previous
+    /// one ends, so the onsets count from the start of the piece. The notes of
+    /// a transposed section are moved up by its interval.
+    ///
+    /// # Returns
+    /// The phrase of the whole piece, or `Error::NoteOutOfRange` if a transposed
+    /// note would be above the MIDI range
+    pub fn flatten(&self) -> Result<Phrase, Error> {
+        let mut notes = Vec::new();
+        let mut onset = Rational::integer(0);
+        for item in &self.form {
+            self.lay_out(item, &mut onset, &mut notes)?;
+        }
+
+        Ok(Phrase::new(notes, onset))
+    }
+
+    /// Appends the notes of a step, starting at an onset which is moved to the end of the step
+    fn lay_out(
+        &self,
+        item: &ArrangementItem,
+        onset: &mut Rational,
+        notes: &mut Vec<TimedNote>,
+    ) -> Result<(), Error> {
+        match item {
+            ArrangementItem::Section(reference) => {
+                // The form was checked against the sections when the arrangement was created
+                let Some(section) = self.sections.get(reference.name()) else {
+                    return Ok(());
+                };
+                let semitones = reference
+                    .transpose()
+                    .map_or(0, |interval| i16::from(interval.semitones()));
+
+                for phrase in section.phrases() {
+                    for timed in phrase.notes() {
+                        let note = timed
+                            .note
+                            .checked_add_semitones(semitones)
+                            .ok_or(Error::NoteOutOfRange(timed.note))?;
+                        notes.push(TimedNote {
+                            note,
+                            onset: *onset + timed.onset,
+                            duration: timed.duration,
+                        });
+                    }
+                    *onset = *onset + phrase.length();
+                }
+            }
+            ArrangementItem::Repeat(items, count) => {
+                for _ in 0..*count {
+                    for item in items {
+                        self.lay_out(item, onset, notes)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::{Duration, Note, RhythmPattern, TimeSignature};
+
+    /// Returns a section of a number of 4/4 bars, each playing four quarter notes from a note up
+    fn section(name: &str, bars: usize, first: Note) -> Section {
+        let bar = RhythmPattern::new([true; 4]);
+        let notes = first.into_notes_from_intervals([MINOR_SECOND, MAJOR_SECOND, MINOR_THIRD]);
+        let notes: Vec<_> = std::iter::once(first).chain(notes).collect();
+        Section::new(
+            name,
+            (0..bars).map(|_| bar.apply(notes.iter().copied(), Duration::Quarter)),
+        )
+    }
+
+    /// Returns the number of 4/4 bars of a length
+    fn bars(length: Rational) -> Rational {
+        length
+            / TimeSignature::new(4, Duration::Quarter)
+                .unwrap()
+                .bar_length()
+    }
+
+    #[test]
+    fn test_aaba() {
+        let a = ArrangementItem::section("A");
+        let arrangement = Arrangement::new(
+            [section("A", 8, C4), section("B", 8, F4)],
+            [a.clone(), a.clone(), ArrangementItem::section("B"), a],
+        )
+        .unwrap();
+
+        assert_eq!(bars(arrangement.length()), Rational::integer(32));
+        let piece = arrangement.flatten().unwrap();
+        assert_eq!(bars(piece.length()), Rational::integer(32));
+        assert_eq!(piece.notes().len(), 32 * 4);
+        // The bridge starts after two A sections, at bar 17
+        assert_eq!(piece.notes()[64].note, F4);
+        assert_eq!(bars(piece.notes()[64].onset), Rational::integer(16));
+        assert_eq!(piece.notes()[96].note, C4);
+
+        // Every note starts when the previous one ends
+        for pair in piece.notes().windows(2) {
+            assert_eq!(pair[0].onset + pair[0].duration, pair[1].onset);
+        }
+
+        // 32 bars of 4 beats at 120 beats per minute
+        assert_eq!(arrangement.total_duration(Tempo::new(120.0)), 64_000.0);
+    }
+
+    #[test]
+    fn test_nested_repeats() {
+        let inner = ArrangementItem::repeat(
+            [ArrangementItem::section("A"), ArrangementItem::section("B")],
+            2,
+        );
+        let outer = ArrangementItem::repeat([inner, ArrangementItem::section("C")], 2);
+        let arrangement = Arrangement::new(
+            [
+                section("A", 1, C4),
+                section("B", 2, D4),
+                section("C", 4, E4),
+            ],
+            [outer, ArrangementItem::repeat([], 3)],
+        )
+        .unwrap();
+
+        // ((A B) x2 C) x2 = 2 * (2 * (1 + 2) + 4) bars
+        assert_eq!(bars(arrangement.length()), Rational::integer(20));
+        let piece = arrangement.flatten().unwrap();
+        assert_eq!(bars(piece.length()), Rational::integer(20));
+        let firsts: Vec<_> = piece
+            .notes()
+            .iter()
+            .step_by(4)
+            .map(|timed| timed.note)
+            .collect();
+        let once = [C4, D4, D4, C4, D4, D4, E4, E4, E4, E4];
+        assert_eq!(firsts, [once, once].concat());
+
+        let empty = Arrangement::new([section("A", 1, C4)], []).unwrap();
+        assert!(empty.flatten().unwrap().notes().is_empty());
+        assert_eq!(empty.length(), Rational::integer(0));
+    }
+
+    #[test]
+    fn test_transposed_final_chorus() {
+        let arrangement = Arrangement::new(
+            [section("verse", 2, C4), section("chorus", 2, G4)],
+            [
+                ArrangementItem::section("verse"),
+                ArrangementItem::section("chorus"),
+                ArrangementItem::section("verse"),
+                SectionRef::new("chorus").transposed(MAJOR_SECOND).into(),
+            ],
+        )
+        .unwrap();
+
+        let piece = arrangement.flatten().unwrap();
+        let (earlier, last) = piece.notes().split_at(3 * 8);
+        let chorus = &earlier[8..16];
+        assert_eq!(chorus[0].note, G4);
+        for (original, transposed) in chorus.iter().zip(last) {
+            assert_eq!(transposed.note, original.note + MAJOR_SECOND);
+            assert_eq!(transposed.duration, original.duration);
+        }
+        assert_eq!(earlier[16].note, C4);
+
+        let high = Arrangement::new(
+            [section("top", 1, G9.checked_add_semitones(-3).unwrap())],
+            [SectionRef::new("top").transposed(MAJOR_SECOND).into()],
+        )
+        .unwrap();
+        assert!(matches!(high.flatten(), Err(Error::NoteOutOfRange(_))));
+    }
+
+    #[test]
+    fn test_unknown_section() {
+        let error = Arrangement::new(
+            [section("A", 1, C4)],
+            [ArrangementItem::repeat([ArrangementItem::section("B")], 2)],
+        )
+        .unwrap_err();
+        assert_eq!(error, Error::UnknownSection("B".to_string()));
+    }
+}
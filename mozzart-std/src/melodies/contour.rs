@@ -0,0 +1,279 @@
+use crate::Note;
+
+/// Represents the direction of the move from one note to the next
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Up,
+    Down,
+    Same,
+}
+
+/// Represents the shape of a melody: whether each note goes up, down or repeats
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let shape = contour(&[C4, E4, E4, D4]);
+/// assert_eq!(shape.directions(), &[Direction::Up, Direction::Same, Direction::Down]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contour {
+    directions: Vec<Direction>,
+}
+
+impl Contour {
+    /// Returns the directions of the moves, one fewer than the notes of the melody
+    pub fn directions(&self) -> &[Direction] {
+        &self.directions
+    }
+
+    /// Returns the number of moves in the contour
+    pub fn len(&self) -> usize {
+        self.directions.len()
+    }
+
+    /// Returns `true` if the melody has fewer than two notes
+    pub fn is_empty(&self) -> bool {
+        self.directions.is_empty()
+    }
+}
+
+/// Represents how closely a motif has to match a passage
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum MatchMode {
+    /// The very same notes
+    Exact,
+    /// The same intervals, starting on any note
+    Transposed,
+    /// The same directions, whatever the size of the intervals
+    ContourOnly,
+}
+
+/// Returns the contour of a melody
+///
+/// # Arguments
+/// * `melody` - The notes of the melody
+///
+/// # Returns
+/// The direction of each move, one fewer than the notes
+pub fn contour(melody: &[Note]) -> Contour {
+    let directions = interval_profile(melody)
+        .into_iter()
+        .map(|delta| match delta {
+            d if d > 0 => Direction::Up,
+            d if d < 0 => Direction::Down,
+            _ => Direction::Same,
+        })
+        .collect();
+
+    Contour { directions }
+}
+
+/// Returns the signed number of semitones of each move of a melody
+///
+/// # Arguments
+/// * `melody` - The notes of the melody
+///
+/// # Returns
+/// The semitones from each note to the next, negative when the melody goes down
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(interval_profile(&[C4, G4, E4]), vec![7, -3]);
+/// ```
+pub fn interval_profile(melody: &[Note]) -> Vec<i16> {
+    melody
+        .windows(2)
+        .map(|pair| i16::from(pair[1].midi_number()) - i16::from(pair[0].midi_number()))
+        .collect()
+}
+
+/// Returns how similar the contours of two melodies are
+///
+/// The similarity is the edit distance between the contours, normalized by the
+/// longest of them, so melodies of different lengths can be compared.
+///
+/// # Arguments
+/// * `a` - The first melody
+/// * `b` - The second melody
+///
+/// # Returns
+/// A score from 0.0, for contours with nothing in common, to 1.0 for identical contours
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(contour_similarity(&[C4, D4, E4], &[G4, B4, D5]), 1.0);
+/// assert_eq!(contour_similarity(&[C4, D4, E4], &[C4, D4, C4]), 0.5);
+/// ```
+pub fn contour_similarity(a: &[Note], b: &[Note]) -> f32 {
+    let a = contour(a);
+    let b = contour(b);
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    1.0 - edit_distance(a.directions(), b.directions()) as f32 / longest as f32
+}
+
+/// Returns `true` if both melodies have the same intervals, whatever note they start on
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert!(transposition_invariant_match(&[C4, E4, G4], &[F4, A4, C5]));
+/// assert!(!transposition_invariant_match(&[C4, E4, G4], &[C4, EFLAT4, G4]));
+/// ```
+pub fn transposition_invariant_match(a: &[Note], b: &[Note]) -> bool {
+    a.len() == b.len() && interval_profile(a) == interval_profile(b)
+}
+
+/// Returns where a motif occurs in a longer melody
+///
+/// Every window of the melody as long as the motif is compared with it, so
+/// overlapping occurrences are all found.
+///
+/// # Arguments
+/// * `haystack` - The melody to search
+/// * `needle` - The motif to look for
+/// * `mode` - How closely a passage has to match the motif
+///
+/// # Returns
+/// The index of the first note of each occurrence, in order; none for an empty motif
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let line = [C4, D4, E4, C4, F4, G4, A4];
+/// assert_eq!(find_motif(&line, &[C4, D4, E4], MatchMode::Exact), vec![0]);
+/// assert_eq!(find_motif(&line, &[C4, D4, E4], MatchMode::Transposed), vec![0, 4]);
+/// assert_eq!(find_motif(&line, &[C4, D4, E4], MatchMode::ContourOnly), vec![0, 3, 4]);
+/// ```
+pub fn find_motif(haystack: &[Note], needle: &[Note], mode: MatchMode) -> Vec<usize> {
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let profile = interval_profile(needle);
+    let shape = contour(needle);
+    haystack
+        .windows(needle.len())
+        .enumerate()
+        .filter(|(_, window)| match mode {
+            MatchMode::Exact => *window == needle,
+            MatchMode::Transposed => interval_profile(window) == profile,
+            MatchMode::ContourOnly => contour(window) == shape,
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Returns the number of insertions, deletions and substitutions that turn one sequence into the other
+fn edit_distance<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, x) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(x != y);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_contour() {
+        use Direction::*;
+
+        assert_eq!(contour(&[C4, E4, E4, D4]).directions(), &[Up, Same, Down]);
+        assert!(contour(&[C4]).is_empty());
+        assert!(contour(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_interval_profile() {
+        assert_eq!(interval_profile(&[G4, G4, G4, DSHARP4]), vec![0, 0, -4]);
+        assert_eq!(interval_profile(&[C4, C5]), vec![12]);
+        assert!(interval_profile(&[C4]).is_empty());
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance(b"kitten", b"sitting"), 3);
+        assert_eq!(edit_distance(b"", b"abc"), 3);
+        assert_eq!(edit_distance(b"abc", b"abc"), 0);
+    }
+
+    #[test]
+    fn test_contour_similarity() {
+        assert_eq!(
+            contour_similarity(&[C4, D4, E4, F4], &[C4, D4, E4, F4]),
+            1.0
+        );
+        assert_eq!(contour_similarity(&[C4, D4, E4], &[E4, D4, C4]), 0.0);
+        // One move out of four differs
+        assert_eq!(
+            contour_similarity(&[C4, D4, E4, F4, G4], &[C4, D4, E4, D4, G4]),
+            0.75
+        );
+        assert_eq!(contour_similarity(&[C4, D4], &[C4, D4, E4]), 0.5);
+        assert_eq!(contour_similarity(&[], &[C4]), 1.0);
+    }
+
+    #[test]
+    fn test_transposition_invariant_match() {
+        assert!(transposition_invariant_match(
+            &[G4, G4, G4, DSHARP4],
+            &[D5, D5, D5, ASHARP4]
+        ));
+        // Same contour, but a minor third instead of a major third
+        assert!(!transposition_invariant_match(
+            &[G4, G4, G4, DSHARP4],
+            &[F4, F4, F4, D4]
+        ));
+        assert!(!transposition_invariant_match(&[C4, D4], &[C4, D4, E4]));
+        assert!(transposition_invariant_match(&[C4], &[A5]));
+    }
+
+    #[test]
+    fn test_find_fifth_symphony_motif() {
+        // The opening of Beethoven's Fifth, then its answer a step lower with a
+        // minor third, the motif a fifth higher and the motif with a wider leap
+        let motif = [G4, G4, G4, DSHARP4];
+        let line = [
+            G4, G4, G4, DSHARP4, F4, F4, F4, D4, C4, D5, D5, D5, ASHARP4, C4, G4, G4, G4, C4,
+        ];
+
+        assert_eq!(find_motif(&line, &motif, MatchMode::Exact), vec![0]);
+        assert_eq!(find_motif(&line, &motif, MatchMode::Transposed), vec![0, 9]);
+        assert_eq!(
+            find_motif(&line, &motif, MatchMode::ContourOnly),
+            vec![0, 4, 9, 14]
+        );
+        assert!(find_motif(&line, &[], MatchMode::Exact).is_empty());
+        assert!(find_motif(&motif[..2], &motif, MatchMode::ContourOnly).is_empty());
+    }
+}
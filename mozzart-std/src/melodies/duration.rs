@@ -0,0 +1,60 @@
+use crate::Rational;
+
+/// Represents the written length of a note
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// assert_eq!(Duration::Quarter.beats(), 1.0);
+/// assert_eq!(Duration::Eighth.beats(), 0.5);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Duration {
+    Whole,
+    Half,
+    Quarter,
+    Eighth,
+    Sixteenth,
+}
+
+impl Duration {
+    /// All the durations, from the longest to the shortest
+    pub const ALL: [Duration; 5] = [
+        Duration::Whole,
+        Duration::Half,
+        Duration::Quarter,
+        Duration::Eighth,
+        Duration::Sixteenth,
+    ];
+
+    /// Returns the length of the note in quarter-note beats
+    pub const fn beats(&self) -> f32 {
+        match self {
+            Duration::Whole => 4.0,
+            Duration::Half => 2.0,
+            Duration::Quarter => 1.0,
+            Duration::Eighth => 0.5,
+            Duration::Sixteenth => 0.25,
+        }
+    }
+
+    /// Returns the exact length of the note as a fraction of a whole note
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// assert_eq!(Duration::Eighth.value(), Rational::new(1, 8));
+    /// ```
+    pub const fn value(&self) -> Rational {
+        match self {
+            Duration::Whole => Rational::integer(1),
+            Duration::Half => Rational::new(1, 2),
+            Duration::Quarter => Rational::new(1, 4),
+            Duration::Eighth => Rational::new(1, 8),
+            Duration::Sixteenth => Rational::new(1, 16),
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{
+    expand_ornament, Duration, Error, Note, Ornament, OrnamentParams, Phrase, Rational, ScaleLike,
+    TimedNote,
+};
+use std::collections::BTreeMap;
+
+/// Represents a monophonic melody, as a sequence of notes with their durations
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let melody = Melody::new([(C4, Duration::Quarter), (E4, Duration::Quarter), (G4, Duration::Half)]);
+/// assert_eq!(melody.len(), 3);
+/// assert_eq!(melody.notes().collect::<Vec<_>>(), vec![C4, E4, G4]);
+/// assert_eq!(melody.beats(), 4.0);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Melody {
+    events: Vec<(Note, Duration)>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    ornaments: BTreeMap<usize, Ornament>,
+}
+
+impl Melody {
+    /// Creates a new `Melody` from its notes and their durations
+    ///
+    /// # Arguments
+    /// * `events` - The notes of the melody with their durations, in the order they are played
+    ///
+    /// # Returns
+    /// A new `Melody` instance
+    pub fn new(events: impl IntoIterator<Item = (Note, Duration)>) -> Self {
+        Self {
+            events: events.into_iter().collect(),
+            ornaments: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the melody with an ornament on one of its notes
+    ///
+    /// The ornament replaces any ornament already on the note.
+    ///
+    /// # Arguments
+    /// * `index` - The position of the note in the melody
+    /// * `ornament` - The ornament decorating the note
+    ///
+    /// # Returns
+    /// The ornamented melody, or `None` if the melody has no note at the index
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let melody = Melody::new([(E4, Duration::Quarter), (C4, Duration::Half)])
+    ///     .with_ornament(0, Ornament::UpperMordent)
+    ///     .unwrap();
+    /// assert_eq!(melody.ornament(0), Some(Ornament::UpperMordent));
+    /// assert_eq!(melody.ornament(1), None);
+    /// ```
+    pub fn with_ornament(mut self, index: usize, ornament: Ornament) -> Option<Self> {
+        if index >= self.len() {
+            return None;
+        }
+
+        self.ornaments.insert(index, ornament);
+        Some(self)
+    }
+
+    /// Returns the ornament on a note of the melody, if any
+    pub fn ornament(&self, index: usize) -> Option<Ornament> {
+        self.ornaments.get(&index).copied()
+    }
+
+    /// Returns the notes of the melody with their durations
+    pub fn events(&self) -> &[(Note, Duration)] {
+        &self.events
+    }
+
+    /// Returns the notes of the melody, in the order they are played
+    pub fn notes(&self) -> impl Iterator<Item = Note> + '_ {
+        self.events.iter().map(|&(note, _)| note)
+    }
+
+    /// Returns the durations of the notes of the melody
+    pub fn durations(&self) -> impl Iterator<Item = Duration> + '_ {
+        self.events.iter().map(|&(_, duration)| duration)
+    }
+
+    /// Returns the total length of the melody in quarter-note beats
+    pub fn beats(&self) -> f32 {
+        self.durations().map(|duration| duration.beats()).sum()
+    }
+
+    /// Returns the number of notes in the melody
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if the melody has no notes
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the notes actually played, one after the other, with the ornaments expanded
+    ///
+    /// # Arguments
+    /// * `key` - The scale giving the neighbors used by the ornaments
+    /// * `params` - The settings of the realization of the ornaments
+    ///
+    /// # Returns
+    /// The phrase, or `Error::NoteOutOfRange` if an ornament would go outside of the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let melody = Melody::new([(D4, Duration::Half), (C4, Duration::Half)])
+    ///     .with_ornament(0, Ornament::Appoggiatura)
+    ///     .unwrap();
+    /// let phrase = melody.realize(&major_scale(C4).unwrap(), OrnamentParams::default()).unwrap();
+    /// let notes: Vec<_> = phrase.notes().iter().map(|note| note.note).collect();
+    /// assert_eq!(notes, vec![E4, D4, C4]);
+    /// assert_eq!(phrase.length(), Rational::ONE);
+    /// ```
+    pub fn realize(&self, key: &impl ScaleLike, params: OrnamentParams) -> Result<Phrase, Error> {
+        let mut notes = Vec::with_capacity(self.len());
+        let mut onset = Rational::ZERO;
+
+        for (index, &(note, duration)) in self.events.iter().enumerate() {
+            let timed = TimedNote {
+                note,
+                onset,
+                duration: duration.value(),
+            };
+            match self.ornament(index) {
+                Some(ornament) => notes.extend(expand_ornament(&timed, ornament, key, params)?),
+                None => notes.push(timed),
+            }
+            onset = onset + timed.duration;
+        }
+
+        Ok(Phrase::new(notes, onset))
+    }
+
+    /// Moves each note of the melody to the closest member of a scale
+    ///
+    /// The notes are snapped one by one with `ScaleLike::quantize`, so a note
+    /// halfway between two scale members goes down. The durations and the
+    /// ornaments are kept.
+    ///
+    /// # Arguments
+    /// * `scale` - The scale the notes are moved into, in any octave
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let mut melody = Melody::new([(C4, Duration::Quarter), (FSHARP4, Duration::Half)]);
+    /// melody.quantize_to_scale(&major_scale(C4).unwrap());
+    /// assert_eq!(melody.events(), &[(C4, Duration::Quarter), (F4, Duration::Half)]);
+    /// ```
+    pub fn quantize_to_scale(&mut self, scale: &impl ScaleLike) {
+        for (note, _) in &mut self.events {
+            *note = scale.quantize(*note);
+        }
+    }
+
+    /// Returns the melody with each note replaced, keeping the durations and the ornaments
+    ///
+    /// # Arguments
+    /// * `f` - Returns the new note for a note, or an error to stop
+    ///
+    /// # Returns
+    /// The new melody, or the first error returned by `f`
+    pub(crate) fn try_map_notes(
+        &self,
+        mut f: impl FnMut(Note) -> Result<Note, Error>,
+    ) -> Result<Self, Error> {
+        let events = self
+            .events
+            .iter()
+            .map(|&(note, duration)| Ok((f(note)?, duration)))
+            .collect::<Result<_, Error>>()?;
+        Ok(Self {
+            events,
+            ornaments: self.ornaments.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::{major_scale, DynScale};
+
+    #[test]
+    fn test_quantize_to_scale() {
+        let events = [
+            (C4, Duration::Quarter),
+            (CSHARP4, Duration::Eighth),
+            (DSHARP4, Duration::Eighth),
+            (FSHARP4, Duration::Half),
+            (GSHARP2, Duration::Quarter),
+            (ASHARP5, Duration::Whole),
+            (B4, Duration::Sixteenth),
+        ];
+        let mut melody = Melody::new(events)
+            .with_ornament(3, Ornament::Turn)
+            .unwrap();
+        let c_major = major_scale(C4).unwrap();
+        melody.quantize_to_scale(&c_major);
+
+        assert_eq!(
+            melody.notes().collect::<Vec<_>>(),
+            vec![C4, C4, D4, F4, G2, A5, B4]
+        );
+        assert!(melody
+            .notes()
+            .all(|note| c_major.contains_pitch_class(note)));
+        assert!(melody
+            .durations()
+            .eq(events.iter().map(|&(_, duration)| duration)));
+        assert!(melody.ornament(3).is_some());
+
+        // A scale known only at runtime works the same
+        let mut dynamic = Melody::new(events);
+        dynamic.quantize_to_scale(&DynScale::from(c_major));
+        assert!(dynamic.notes().eq(melody.notes()));
+    }
+}
@@ -0,0 +1,375 @@
+use crate::{interval_profile, MatchMode, Note};
+use std::collections::{BTreeMap, HashMap};
+
+/// The default number of moves in each indexed gram
+const DEFAULT_GRAM_LENGTH: usize = 4;
+
+/// The modes each melody is indexed for
+const MODES: [MatchMode; 3] = [
+    MatchMode::Exact,
+    MatchMode::Transposed,
+    MatchMode::ContourOnly,
+];
+
+/// Represents a collection of melodies searchable by fragment
+///
+/// Each melody is cut into overlapping grams of a few moves, which are kept in
+/// an inverted index from each gram to the melodies containing it. A query only
+/// looks at the melodies sharing grams with the fragment, so it stays fast over
+/// thousands of melodies, where `find_motif` scans every note of every melody.
+///
+/// The grams are the notes themselves for `MatchMode::Exact`, the intervals for
+/// `MatchMode::Transposed` and the directions for `MatchMode::ContourOnly`, so a
+/// transposed or reshaped fragment finds its melody in the matching mode.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let mut index = MelodyIndex::new();
+/// index.insert("scale", &[C4, D4, E4, F4, G4, A4, B4, C5]);
+/// index.insert("arpeggio", &[C4, E4, G4, C5, G4, E4, C4]);
+///
+/// let results = index.query(&[G4, A4, B4, C5, D5], MatchMode::Transposed, 10);
+/// assert_eq!(results, vec![("scale", 1.0)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MelodyIndex<Id> {
+    gram_length: usize,
+    melodies: BTreeMap<Id, Vec<Note>>,
+    postings: HashMap<(MatchMode, Vec<i16>), BTreeMap<Id, u32>>,
+}
+
+impl<Id> MelodyIndex<Id>
+where
+    Id: Ord + Clone,
+{
+    /// Creates an empty index of grams of four moves
+    pub fn new() -> Self {
+        Self::with_gram_length(DEFAULT_GRAM_LENGTH)
+    }
+
+    /// Creates an empty index of grams of a given number of moves
+    ///
+    /// Shorter grams find shorter fragments but match more melodies by chance.
+    ///
+    /// # Arguments
+    /// * `gram_length` - The number of moves in each gram, at least 1
+    pub fn with_gram_length(gram_length: usize) -> Self {
+        Self {
+            gram_length: gram_length.max(1),
+            melodies: BTreeMap::new(),
+            postings: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of moves in each gram
+    pub fn gram_length(&self) -> usize {
+        self.gram_length
+    }
+
+    /// Returns the number of melodies in the index
+    pub fn len(&self) -> usize {
+        self.melodies.len()
+    }
+
+    /// Returns `true` if the index has no melody
+    pub fn is_empty(&self) -> bool {
+        self.melodies.is_empty()
+    }
+
+    /// Returns the melody stored under an identifier
+    pub fn get(&self, id: &Id) -> Option<&[Note]> {
+        self.melodies.get(id).map(Vec::as_slice)
+    }
+
+    /// Adds a melody to the index, replacing the melody with the same identifier
+    ///
+    /// # Arguments
+    /// * `id` - The identifier returned by the queries
+    /// * `melody` - The notes of the melody
+    pub fn insert(&mut self, id: Id, melody: &[Note]) {
+        self.remove(&id);
+
+        for mode in MODES {
+            for gram in grams(melody, self.gram_length, mode) {
+                let postings = self.postings.entry((mode, gram)).or_default();
+                *postings.entry(id.clone()).or_default() += 1;
+            }
+        }
+        self.melodies.insert(id, melody.to_vec());
+    }
+
+    /// Removes a melody and its grams from the index
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the melody
+    ///
+    /// # Returns
+    /// The removed melody, or `None` if no melody had this identifier
+    pub fn remove(&mut self, id: &Id) -> Option<Vec<Note>> {
+        let melody = self.melodies.remove(id)?;
+
+        for mode in MODES {
+            for gram in grams(&melody, self.gram_length, mode) {
+                let key = (mode, gram);
+                if let Some(postings) = self.postings.get_mut(&key) {
+                    postings.remove(id);
+                    if postings.is_empty() {
+                        self.postings.remove(&key);
+                    }
+                }
+            }
+        }
+
+        Some(melody)
+    }
+
+    /// Returns the melodies containing the most grams of a fragment
+    ///
+    /// The score of a melody is the number of grams of the fragment found in it,
+    /// each counted at most as many times as the melody has it, divided by the
+    /// number of grams of the fragment. A fragment within a melody scores 1.0.
+    ///
+    /// # Arguments
+    /// * `fragment` - The notes to look for, at least one more than the gram length
+    /// * `mode` - How closely the melodies have to match the fragment
+    /// * `max_results` - The largest number of melodies returned
+    ///
+    /// # Returns
+    /// The identifiers and scores of the matching melodies, from the best score,
+    /// then from the shortest melody; empty if the fragment is shorter than a gram
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let mut index = MelodyIndex::with_gram_length(2);
+    /// index.insert(1, &[C4, E4, G4, E4, C4]);
+    ///
+    /// // Same directions, different intervals
+    /// let fragment = [D4, E4, A4, F4];
+    /// assert!(index.query(&fragment, MatchMode::Transposed, 5).is_empty());
+    /// assert_eq!(index.query(&fragment, MatchMode::ContourOnly, 5), vec![(1, 1.0)]);
+    /// ```
+    pub fn query(&self, fragment: &[Note], mode: MatchMode, max_results: usize) -> Vec<(Id, f32)> {
+        let mut wanted: HashMap<Vec<i16>, u32> = HashMap::new();
+        for gram in grams(fragment, self.gram_length, mode) {
+            *wanted.entry(gram).or_default() += 1;
+        }
+        let total: u32 = wanted.values().sum();
+        if total == 0 {
+            return Vec::new();
+        }
+
+        let mut matched: BTreeMap<&Id, u32> = BTreeMap::new();
+        for (gram, count) in wanted {
+            if let Some(postings) = self.postings.get(&(mode, gram)) {
+                for (id, &found) in postings {
+                    *matched.entry(id).or_default() += count.min(found);
+                }
+            }
+        }
+
+        let mut results: Vec<_> = matched
+            .into_iter()
+            .map(|(id, count)| (id, count as f32 / total as f32))
+            .collect();
+        results.sort_by(|(a, a_score), (b, b_score)| {
+            b_score
+                .total_cmp(a_score)
+                .then_with(|| self.melodies[*a].len().cmp(&self.melodies[*b].len()))
+        });
+        results
+            .into_iter()
+            .take(max_results)
+            .map(|(id, score)| (id.clone(), score))
+            .collect()
+    }
+}
+
+impl<Id> Default for MelodyIndex<Id>
+where
+    Id: Ord + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the grams of a melody in a match mode, in order
+///
+/// # Arguments
+/// * `melody` - The notes of the melody
+/// * `gram_length` - The number of moves in each gram
+/// * `mode` - Whether the grams hold the notes, the intervals or the directions
+fn grams(melody: &[Note], gram_length: usize, mode: MatchMode) -> Vec<Vec<i16>> {
+    let profile = interval_profile(melody);
+    match mode {
+        MatchMode::Exact => melody
+            .windows(gram_length + 1)
+            .map(|notes| {
+                notes
+                    .iter()
+                    .map(|note| i16::from(note.midi_number()))
+                    .collect()
+            })
+            .collect(),
+        MatchMode::Transposed => profile.windows(gram_length).map(<[i16]>::to_vec).collect(),
+        MatchMode::ContourOnly => profile
+            .windows(gram_length)
+            .map(|moves| moves.iter().map(|delta| delta.signum()).collect())
+            .collect(),
+    }
+}
+
+/// The serialized form of a `MelodyIndex`, its grams being recomputed from the melodies
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct MelodyIndexRecord<Id: Ord> {
+    gram_length: usize,
+    melodies: BTreeMap<Id, Vec<Note>>,
+}
+
+#[cfg(feature = "serde")]
+impl<Id> serde::Serialize for MelodyIndex<Id>
+where
+    Id: Ord + Clone + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let record = MelodyIndexRecord {
+            gram_length: self.gram_length,
+            melodies: self.melodies.clone(),
+        };
+        record.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, Id> serde::Deserialize<'de> for MelodyIndex<Id>
+where
+    Id: Ord + Clone + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let record = MelodyIndexRecord::<Id>::deserialize(deserializer)?;
+        let mut index = Self::with_gram_length(record.gram_length);
+        for (id, melody) in record.melodies {
+            index.insert(id, &melody);
+        }
+
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    /// Returns an index of three folk tunes
+    fn tunes() -> MelodyIndex<&'static str> {
+        let mut index = MelodyIndex::new();
+        index.insert(
+            "ode to joy",
+            &[E4, E4, F4, G4, G4, F4, E4, D4, C4, C4, D4, E4, E4, D4, D4],
+        );
+        index.insert(
+            "frere jacques",
+            &[C4, D4, E4, C4, C4, D4, E4, C4, E4, F4, G4, E4, F4, G4],
+        );
+        index.insert(
+            "twinkle",
+            &[C4, C4, G4, G4, A4, A4, G4, F4, F4, E4, E4, D4, D4, C4],
+        );
+        index
+    }
+
+    #[test]
+    fn test_transposed_fragment_ranks_first() {
+        let index = tunes();
+        // The opening of Twinkle, a fourth higher
+        let fragment = [F4, F4, C5, C5, D5, D5, C5];
+
+        let results = index.query(&fragment, MatchMode::Transposed, 3);
+        assert_eq!(results[0], ("twinkle", 1.0));
+        assert!(results[1..].iter().all(|&(_, score)| score < 1.0));
+
+        assert!(index.query(&fragment, MatchMode::Exact, 3).is_empty());
+        assert_eq!(index.query(&fragment, MatchMode::Transposed, 0), vec![]);
+    }
+
+    #[test]
+    fn test_contour_only_match() {
+        let index = tunes();
+        // Up, up, down, up: the shape of Frere Jacques' third bar with other steps
+        let fragment = [C4, E4, A4, D4, G4];
+
+        assert!(index.query(&fragment, MatchMode::Transposed, 3).is_empty());
+        let results = index.query(&fragment, MatchMode::ContourOnly, 3);
+        assert_eq!(results[0], ("frere jacques", 1.0));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut index = tunes();
+        let ode = index.get(&"ode to joy").unwrap().to_vec();
+        let posting_count = |index: &MelodyIndex<&str>| {
+            index
+                .postings
+                .values()
+                .filter(|postings| postings.contains_key("ode to joy"))
+                .count()
+        };
+        assert!(posting_count(&index) > 0);
+
+        assert_eq!(index.remove(&"ode to joy"), Some(ode.clone()));
+        assert_eq!(posting_count(&index), 0);
+        assert!(index.postings.values().all(|postings| !postings.is_empty()));
+        assert_eq!(index.len(), 2);
+        for mode in MODES {
+            assert!(index
+                .query(&ode, mode, 3)
+                .iter()
+                .all(|&(id, _)| id != "ode to joy"));
+        }
+        assert_eq!(index.remove(&"ode to joy"), None);
+
+        // Removing every melody leaves no gram behind
+        index.remove(&"frere jacques");
+        index.remove(&"twinkle");
+        assert!(index.is_empty());
+        assert!(index.postings.is_empty());
+    }
+
+    #[test]
+    fn test_insert_replaces() {
+        let mut index = MelodyIndex::with_gram_length(2);
+        index.insert(7, &[C4, D4, E4]);
+        index.insert(7, &[C4, B3, A3]);
+        assert_eq!(index.len(), 1);
+        assert!(index.query(&[C4, D4, E4], MatchMode::Exact, 1).is_empty());
+        assert_eq!(
+            index.query(&[D4, CSHARP4, B3], MatchMode::Transposed, 1),
+            vec![(7, 1.0)]
+        );
+        assert_eq!(index.query(&[C4, D4], MatchMode::Exact, 1), vec![]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let index = tunes();
+        let json = serde_json::to_string(&index).unwrap();
+        let restored: MelodyIndex<String> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.gram_length(), index.gram_length());
+        assert_eq!(restored.postings.len(), index.postings.len());
+        let fragment = [F4, F4, C5, C5, D5, D5, C5];
+        assert_eq!(
+            restored.query(&fragment, MatchMode::Transposed, 1),
+            vec![("twinkle".to_string(), 1.0)]
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use crate::{Duration, Melody, Note, Rational};
+use std::fmt;
+
+/// Represents the meter of a piece: how many beats a bar holds and which note gets a beat
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(FOUR_FOUR.bar_length(), Rational::integer(1));
+/// assert_eq!(SIX_EIGHT.bar_length(), Rational::new(3, 4));
+/// assert_eq!(SIX_EIGHT.to_string(), "6/8");
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct TimeSignature {
+    beats: u8,
+    beat_unit: Duration,
+}
+
+impl TimeSignature {
+    /// Creates a new `TimeSignature`
+    ///
+    /// # Arguments
+    /// * `beats` - The number of beats in a bar, which must not be 0
+    /// * `beat_unit` - The note that gets one beat
+    ///
+    /// # Returns
+    /// A new `TimeSignature` instance, or `None` if the number of beats is 0
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// let nine_eight = TimeSignature::new(9, Duration::Eighth).unwrap();
+    /// assert_eq!(nine_eight.to_string(), "9/8");
+    /// assert_eq!(TimeSignature::new(0, Duration::Quarter), None);
+    /// ```
+    pub const fn new(beats: u8, beat_unit: Duration) -> Option<Self> {
+        if beats == 0 {
+            return None;
+        }
+        Some(Self { beats, beat_unit })
+    }
+
+    /// Returns the number of beats in a bar, the upper number of the signature
+    pub const fn beats(&self) -> u8 {
+        self.beats
+    }
+
+    /// Returns the note that gets one beat, the lower number of the signature
+    pub const fn beat_unit(&self) -> Duration {
+        self.beat_unit
+    }
+
+    /// Returns the length of a bar as a fraction of a whole note
+    pub fn bar_length(&self) -> Rational {
+        Rational::integer(u32::from(self.beats)) * self.beat_unit.value()
+    }
+
+    /// Returns `true` for compound meters such as 6/8, 9/8 or 12/8
+    ///
+    /// In a compound meter the beats are grouped by three, and each group is
+    /// felt as a single dotted beat.
+    pub fn is_compound(&self) -> bool {
+        self.beats > 3
+            && self.beats.is_multiple_of(3)
+            && matches!(self.beat_unit, Duration::Eighth | Duration::Sixteenth)
+    }
+
+    /// Returns how the beats of a bar are grouped into felt pulses
+    ///
+    /// # Returns
+    /// The number of beats in each group: groups of three for compound meters,
+    /// single beats otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(SIX_EIGHT.beats_per_bar_grouping(), vec![3, 3]);
+    /// assert_eq!(THREE_FOUR.beats_per_bar_grouping(), vec![1, 1, 1]);
+    /// ```
+    pub fn beats_per_bar_grouping(&self) -> Vec<u8> {
+        if self.is_compound() {
+            vec![3; usize::from(self.beats / 3)]
+        } else {
+            vec![1; usize::from(self.beats)]
+        }
+    }
+
+    /// Returns the position in a bar of a point in time
+    ///
+    /// # Arguments
+    /// * `offset` - The time from the start of the first bar, as a fraction of a whole note
+    fn position_at(&self, offset: Rational) -> BarPosition {
+        let bar = (offset / self.bar_length()).floor();
+        let in_bar = offset - Rational::integer(bar) * self.bar_length();
+
+        BarPosition {
+            bar: bar + 1,
+            beat: in_bar / self.beat_unit.value() + Rational::ONE,
+        }
+    }
+}
+
+impl fmt::Display for TimeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}/{}", self.beats, self.beat_unit.value().denominator())
+    }
+}
+
+/// Represents where a note starts within the bars of a piece
+///
+/// Both the bar and the beat count from 1; the beat is exact, so an eighth note
+/// after the downbeat of a 4/4 bar is on beat 3/2.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct BarPosition {
+    pub bar: u32,
+    pub beat: Rational,
+}
+
+/// Represents a note written within a bar
+///
+/// A note that crosses a barline is written as several notes tied together;
+/// every note of such a chain but the last one is tied to the next.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct TiedNote {
+    pub note: Note,
+    pub duration: Duration,
+    pub tied: bool,
+}
+
+impl Melody {
+    /// Returns where each note of the melody starts
+    ///
+    /// # Arguments
+    /// * `ts` - The time signature of the melody, which starts on the downbeat of the first bar
+    ///
+    /// # Returns
+    /// The position of each note, in the order they are played
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let melody = Melody::new([(C4, Duration::Half), (E4, Duration::Half), (G4, Duration::Quarter)]);
+    /// let positions = melody.bar_positions(THREE_FOUR);
+    /// assert_eq!(positions[1], BarPosition { bar: 1, beat: Rational::integer(3) });
+    /// assert_eq!(positions[2], BarPosition { bar: 2, beat: Rational::integer(2) });
+    /// ```
+    pub fn bar_positions(&self, ts: TimeSignature) -> Vec<BarPosition> {
+        self.durations()
+            .scan(Rational::ZERO, |offset, duration| {
+                let start = *offset;
+                *offset = start + duration.value();
+                Some(ts.position_at(start))
+            })
+            .collect()
+    }
+
+    /// Splits the melody into bars, tying the notes that cross a barline
+    ///
+    /// The part of a note that does not fit in its bar continues in the next
+    /// one. Each part is written with the fewest standard durations, longest
+    /// first, all tied together.
+    ///
+    /// # Arguments
+    /// * `ts` - The time signature of the melody, which starts on the downbeat of the first bar
+    ///
+    /// # Returns
+    /// The notes of each bar; the last bar may be incomplete
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let melody = Melody::new([(C4, Duration::Half), (D4, Duration::Quarter), (E4, Duration::Half)]);
+    /// let bars = melody.split_into_bars(THREE_FOUR);
+    /// assert_eq!(bars.len(), 2);
+    /// assert_eq!(bars[1], vec![TiedNote { note: E4, duration: Duration::Half, tied: false }]);
+    /// ```
+    pub fn split_into_bars(&self, ts: TimeSignature) -> Vec<Vec<TiedNote>> {
+        let bar_length = ts.bar_length();
+        let mut bars = Vec::new();
+        let mut bar = Vec::new();
+        let mut filled = Rational::ZERO;
+
+        for &(note, duration) in self.events() {
+            let mut remaining = duration.value();
+            while remaining > Rational::ZERO {
+                let part = remaining.min(bar_length - filled);
+                remaining = remaining - part;
+                filled = filled + part;
+
+                let pieces = fill(part);
+                let last = pieces.len() - 1;
+                bar.extend(
+                    pieces
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, duration)| TiedNote {
+                            note,
+                            duration,
+                            tied: i < last || remaining > Rational::ZERO,
+                        }),
+                );
+
+                if filled == bar_length {
+                    bars.push(std::mem::take(&mut bar));
+                    filled = Rational::ZERO;
+                }
+            }
+        }
+
+        if !bar.is_empty() {
+            bars.push(bar);
+        }
+        bars
+    }
+}
+
+/// Returns the fewest standard durations that add up to a length, longest first
+fn fill(mut length: Rational) -> Vec<Duration> {
+    let mut durations = Vec::new();
+    while length > Rational::ZERO {
+        let duration = Duration::ALL
+            .into_iter()
+            .find(|duration| duration.value() <= length)
+            .expect("lengths are multiples of a sixteenth note");
+        durations.push(duration);
+        length = length - duration.value();
+    }
+    durations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    fn tied(note: Note, duration: Duration, tied: bool) -> TiedNote {
+        TiedNote {
+            note,
+            duration,
+            tied,
+        }
+    }
+
+    #[test]
+    fn test_fifth_quarter_starts_the_second_bar() {
+        let melody = Melody::new([C4, D4, E4, F4, G4].map(|note| (note, Duration::Quarter)));
+
+        let positions = melody.bar_positions(FOUR_FOUR);
+        assert_eq!(
+            positions[3],
+            BarPosition {
+                bar: 1,
+                beat: Rational::integer(4)
+            }
+        );
+        assert_eq!(
+            positions[4],
+            BarPosition {
+                bar: 2,
+                beat: Rational::ONE
+            }
+        );
+
+        let bars = melody.split_into_bars(FOUR_FOUR);
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].len(), 4);
+        assert_eq!(bars[1], vec![tied(G4, Duration::Quarter, false)]);
+    }
+
+    #[test]
+    fn test_half_note_on_the_last_beat_is_tied() {
+        let melody = Melody::new([
+            (C4, Duration::Half),
+            (D4, Duration::Quarter),
+            (E4, Duration::Half),
+            (F4, Duration::Quarter),
+        ]);
+
+        let bars = melody.split_into_bars(FOUR_FOUR);
+        assert_eq!(
+            bars,
+            vec![
+                vec![
+                    tied(C4, Duration::Half, false),
+                    tied(D4, Duration::Quarter, false),
+                    tied(E4, Duration::Quarter, true),
+                ],
+                vec![
+                    tied(E4, Duration::Quarter, false),
+                    tied(F4, Duration::Quarter, false),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_in_compound_meter() {
+        // A whole note after an eighth in 6/8 fills the rest of the bar with a half
+        // and an eighth, and spills a quarter and an eighth over
+        let melody = Melody::new([(C4, Duration::Eighth), (D4, Duration::Whole)]);
+        let bars = melody.split_into_bars(SIX_EIGHT);
+        assert_eq!(
+            bars,
+            vec![
+                vec![
+                    tied(C4, Duration::Eighth, false),
+                    tied(D4, Duration::Half, true),
+                    tied(D4, Duration::Eighth, true),
+                ],
+                vec![
+                    tied(D4, Duration::Quarter, true),
+                    tied(D4, Duration::Eighth, false),
+                ],
+            ]
+        );
+
+        let positions = melody.bar_positions(SIX_EIGHT);
+        assert_eq!(positions[1].beat, Rational::integer(2));
+    }
+
+    #[test]
+    fn test_grouping() {
+        assert!(SIX_EIGHT.is_compound());
+        assert!(!FOUR_FOUR.is_compound());
+        assert_eq!(
+            TimeSignature::new(12, Duration::Eighth)
+                .unwrap()
+                .beats_per_bar_grouping(),
+            vec![3, 3, 3, 3]
+        );
+        assert_eq!(FOUR_FOUR.to_string(), "4/4");
+    }
+
+    #[test]
+    fn test_no_beats() {
+        assert_eq!(TimeSignature::new(0, Duration::Quarter), None);
+        assert_eq!(TimeSignature::new(0, Duration::Eighth), None);
+        assert_eq!(
+            TimeSignature::new(1, Duration::Whole).map(|ts| ts.beats()),
+            Some(1)
+        );
+    }
+}
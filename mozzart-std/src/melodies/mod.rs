@@ -0,0 +1,22 @@
+mod abc;
+mod accompaniment;
+mod arrangement;
+mod contour;
+mod duration;
+mod melody;
+mod melody_index;
+mod meter;
+mod ornament;
+mod rhythm;
+mod tempo;
+
+pub use accompaniment::*;
+pub use arrangement::*;
+pub use contour::*;
+pub use duration::*;
+pub use melody::*;
+pub use melody_index::*;
+pub use meter::*;
+pub use ornament::*;
+pub use rhythm::*;
+pub use tempo::*;
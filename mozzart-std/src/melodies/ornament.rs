@@ -0,0 +1,347 @@
+use crate::{Error, Note, Rational, ScaleLike, TimedNote};
+
+/// Represents an ornament decorating a note
+///
+/// The auxiliary note of an ornament is the neighbor of the main note in the
+/// key, or a semitone away when the ornament is chromatic, see `OrnamentParams`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ornament {
+    /// A fast alternation of the main note with its upper or lower neighbor
+    Trill { auxiliary_above: bool },
+    /// The main note, its upper neighbor and the main note again
+    UpperMordent,
+    /// The main note, its lower neighbor and the main note again
+    LowerMordent,
+    /// The upper neighbor, the main note, the lower neighbor and the main note, in equal parts
+    Turn,
+    /// The upper neighbor, leaning on the beat for half of the note
+    Appoggiatura,
+    /// The upper neighbor, crushed into a quick note on the beat
+    Acciaccatura,
+}
+
+/// Settings of the realization of ornaments
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let params = OrnamentParams {
+///     start_on_auxiliary: true,
+///     ..OrnamentParams::default()
+/// };
+/// assert_eq!(params.quick_note, Rational::new(1, 32));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct OrnamentParams {
+    /// The length of the quick notes of the trills, mordents and acciaccaturas, a thirty-second note by default
+    pub quick_note: Rational,
+    /// `true` if trills start on the auxiliary note rather than on the main note
+    pub start_on_auxiliary: bool,
+    /// `true` if the auxiliary notes are a semitone away rather than the neighbors in the key
+    pub chromatic: bool,
+}
+
+impl Default for OrnamentParams {
+    fn default() -> Self {
+        Self {
+            quick_note: Rational::new(1, 32),
+            start_on_auxiliary: false,
+            chromatic: false,
+        }
+    }
+}
+
+/// Realizes an ornament into the notes that are actually played
+///
+/// The notes share the onset and the duration of the ornamented note, which
+/// they fill exactly:
+/// - a trill alternates the two notes as many times as quick notes fit, rounded
+///   down to an even count of at least 2, and slightly longer notes fill the rest
+/// - a mordent plays two quick notes and holds the main note for the rest
+/// - a turn plays its four notes in equal parts
+/// - an appoggiatura takes half of the note
+/// - an acciaccatura plays a quick note and holds the main note for the rest
+///
+/// Quick notes never take more than a quarter of the note, so an upper mordent
+/// on a quarter note plays two thirty-second notes then a dotted eighth.
+///
+/// # Arguments
+/// * `note` - The ornamented note
+/// * `ornament` - The ornament to realize
+/// * `key` - The scale giving the neighbors of the main note
+/// * `params` - The settings of the realization
+///
+/// # Returns
+/// The notes in the order they are played, or `Error::NoteOutOfRange` if the
+/// auxiliary note would be outside of the MIDI range
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let note = TimedNote { note: E4, onset: Rational::ZERO, duration: Duration::Quarter.value() };
+/// let notes = expand_ornament(&note, Ornament::UpperMordent, &major_scale(C4).unwrap(), OrnamentParams::default()).unwrap();
+///
+/// let pitches: Vec<_> = notes.iter().map(|note| note.note).collect();
+/// assert_eq!(pitches, vec![E4, F4, E4]);
+/// assert_eq!(notes[2].duration, Rational::new(3, 16));
+/// ```
+pub fn expand_ornament(
+    note: &TimedNote,
+    ornament: Ornament,
+    key: &impl ScaleLike,
+    params: OrnamentParams,
+) -> Result<Vec<TimedNote>, Error> {
+    let main = note.note;
+    let upper = || auxiliary(main, key, params.chromatic, true);
+    let lower = || auxiliary(main, key, params.chromatic, false);
+    let quick = params.quick_note.min(note.duration / Rational::integer(4));
+
+    let (pitches, durations) = match ornament {
+        Ornament::Trill { auxiliary_above } => {
+            let auxiliary = auxiliary(main, key, params.chromatic, auxiliary_above)?;
+            let count = trill_count(note.duration, params.quick_note);
+            let (first, second) = if params.start_on_auxiliary {
+                (auxiliary, main)
+            } else {
+                (main, auxiliary)
+            };
+            let pitches = [first, second].repeat(count / 2);
+            let durations = vec![note.duration / Rational::integer(count as u32); count];
+            (pitches, durations)
+        }
+        Ornament::UpperMordent | Ornament::LowerMordent => {
+            let auxiliary = if ornament == Ornament::UpperMordent {
+                upper()?
+            } else {
+                lower()?
+            };
+            let rest = note.duration - quick - quick;
+            (vec![main, auxiliary, main], vec![quick, quick, rest])
+        }
+        Ornament::Turn => {
+            let part = note.duration / Rational::integer(4);
+            (vec![upper()?, main, lower()?, main], vec![part; 4])
+        }
+        Ornament::Appoggiatura => {
+            let half = note.duration / Rational::integer(2);
+            (vec![upper()?, main], vec![half, half])
+        }
+        Ornament::Acciaccatura => (vec![upper()?, main], vec![quick, note.duration - quick]),
+    };
+
+    let mut onset = note.onset;
+    Ok(pitches
+        .into_iter()
+        .zip(durations)
+        .map(|(pitch, duration)| {
+            let timed = TimedNote {
+                note: pitch,
+                onset,
+                duration,
+            };
+            onset = onset + duration;
+            timed
+        })
+        .collect())
+}
+
+/// Returns the neighbor of a note above or below it, in the key or a semitone away
+fn auxiliary(
+    note: Note,
+    key: &impl ScaleLike,
+    chromatic: bool,
+    above: bool,
+) -> Result<Note, Error> {
+    let neighbor = match (chromatic, above) {
+        (true, true) => note.checked_add_semitones(1),
+        (true, false) => note.checked_add_semitones(-1),
+        (false, true) => key.upper_neighbor(note),
+        (false, false) => key.lower_neighbor(note),
+    };
+    neighbor.ok_or(Error::NoteOutOfRange(note))
+}
+
+/// Returns the number of notes of a trill, even and at least 2
+fn trill_count(duration: Rational, quick_note: Rational) -> usize {
+    let count = if quick_note == Rational::ZERO {
+        2
+    } else {
+        (duration / quick_note).floor() as usize
+    };
+    (count - count % 2).max(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::{major_scale, natural_minor_scale, Duration};
+
+    fn quarter(note: Note) -> TimedNote {
+        TimedNote {
+            note,
+            onset: Rational::new(1, 2),
+            duration: Duration::Quarter.value(),
+        }
+    }
+
+    fn expand(note: &TimedNote, ornament: Ornament, params: OrnamentParams) -> Vec<TimedNote> {
+        expand_ornament(note, ornament, &major_scale(C4).unwrap(), params).unwrap()
+    }
+
+    fn pitches(notes: &[TimedNote]) -> Vec<Note> {
+        notes.iter().map(|note| note.note).collect()
+    }
+
+    /// Checks that the notes follow each other and fill the ornamented note exactly
+    fn assert_fills(notes: &[TimedNote], note: &TimedNote) {
+        let mut onset = note.onset;
+        for timed in notes {
+            assert_eq!(timed.onset, onset);
+            onset = onset + timed.duration;
+        }
+        assert_eq!(onset, note.onset + note.duration);
+    }
+
+    #[test]
+    fn test_mordents() {
+        let note = quarter(E4);
+        let notes = expand(&note, Ornament::UpperMordent, OrnamentParams::default());
+        assert_eq!(pitches(&notes), vec![E4, F4, E4]);
+        let durations: Vec<_> = notes.iter().map(|note| note.duration).collect();
+        assert_eq!(
+            durations,
+            vec![
+                Rational::new(1, 32),
+                Rational::new(1, 32),
+                Rational::new(3, 16)
+            ]
+        );
+        assert_fills(&notes, &note);
+
+        let notes = expand(&note, Ornament::LowerMordent, OrnamentParams::default());
+        assert_eq!(pitches(&notes), vec![E4, D4, E4]);
+
+        // The quick notes never take more than a quarter of a short note
+        let sixteenth = TimedNote {
+            duration: Duration::Sixteenth.value(),
+            ..note
+        };
+        let notes = expand(
+            &sixteenth,
+            Ornament::UpperMordent,
+            OrnamentParams::default(),
+        );
+        assert_eq!(notes[0].duration, Rational::new(1, 64));
+        assert_fills(&notes, &sixteenth);
+    }
+
+    #[test]
+    fn test_trills() {
+        let note = quarter(C5);
+        let notes = expand(
+            &note,
+            Ornament::Trill {
+                auxiliary_above: true,
+            },
+            OrnamentParams::default(),
+        );
+        assert_eq!(notes.len(), 8);
+        assert_eq!(pitches(&notes[..2]), vec![C5, D5]);
+        assert_fills(&notes, &note);
+
+        // A dotted quarter holds 12 thirty-second notes
+        let dotted = TimedNote {
+            duration: Rational::new(3, 8),
+            ..note
+        };
+        let params = OrnamentParams {
+            start_on_auxiliary: true,
+            ..OrnamentParams::default()
+        };
+        let notes = expand(
+            &dotted,
+            Ornament::Trill {
+                auxiliary_above: false,
+            },
+            params,
+        );
+        assert_eq!(notes.len(), 12);
+        assert_eq!(pitches(&notes[10..]), vec![B4, C5]);
+        assert_fills(&notes, &dotted);
+
+        // When the quick notes don't divide the note, the count is rounded down to an even number
+        let params = OrnamentParams {
+            quick_note: Rational::new(1, 20),
+            ..OrnamentParams::default()
+        };
+        let trill = Ornament::Trill {
+            auxiliary_above: true,
+        };
+        let notes = expand(&note, trill, params);
+        assert_eq!(notes.len(), 4);
+        assert!(notes
+            .iter()
+            .all(|note| note.duration == Rational::new(1, 16)));
+
+        let params = OrnamentParams {
+            quick_note: Rational::ONE,
+            ..OrnamentParams::default()
+        };
+        assert_eq!(expand(&note, trill, params).len(), 2);
+    }
+
+    #[test]
+    fn test_turn_and_grace_notes() {
+        let note = quarter(E4);
+        let notes = expand(&note, Ornament::Turn, OrnamentParams::default());
+        assert_eq!(pitches(&notes), vec![F4, E4, D4, E4]);
+        assert_fills(&notes, &note);
+
+        // The appoggiatura steals half of the main note
+        let notes = expand(&note, Ornament::Appoggiatura, OrnamentParams::default());
+        assert_eq!(pitches(&notes), vec![F4, E4]);
+        assert_eq!(notes[0].duration, Duration::Eighth.value());
+        assert_eq!(notes[1].duration, Duration::Eighth.value());
+
+        let notes = expand(&note, Ornament::Acciaccatura, OrnamentParams::default());
+        assert_eq!(pitches(&notes), vec![F4, E4]);
+        assert_eq!(notes[1].duration, Rational::new(7, 32));
+        assert_fills(&notes, &note);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        // In C minor, the upper neighbor of G is A flat
+        let note = quarter(G4);
+        let notes = expand_ornament(
+            &note,
+            Ornament::UpperMordent,
+            &natural_minor_scale(C4).unwrap(),
+            OrnamentParams::default(),
+        )
+        .unwrap();
+        assert_eq!(notes[1].note, GSHARP4);
+
+        let chromatic = OrnamentParams {
+            chromatic: true,
+            ..OrnamentParams::default()
+        };
+        let notes = expand(&quarter(E4), Ornament::Turn, chromatic);
+        assert_eq!(pitches(&notes), vec![F4, E4, DSHARP4, E4]);
+
+        assert_eq!(
+            expand_ornament(
+                &quarter(G9),
+                Ornament::UpperMordent,
+                &major_scale(C4).unwrap(),
+                OrnamentParams::default()
+            ),
+            Err(Error::NoteOutOfRange(G9))
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{Duration, Note, Rational, Tempo};
+use std::fmt;
+
+/// Represents the onsets of a rhythm, one step per subdivision of a bar
+///
+/// Each step is either an onset, where a note starts, or a rest. The pattern
+/// is written with `x` for the onsets and `.` for the rests.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let tresillo = RhythmPattern::euclidean(3, 8);
+/// assert_eq!(tresillo.to_string(), "x..x..x.");
+/// assert_eq!(tresillo.density(), 0.375);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RhythmPattern {
+    onsets: Vec<bool>,
+}
+
+/// Represents a note placed at a point in time
+///
+/// The onset and the duration are exact, as fractions of a whole note, the onset
+/// counting from the start of the phrase. The duration doesn't have to be a
+/// standard `Duration`, so ornaments can divide a note into any number of parts.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct TimedNote {
+    pub note: Note,
+    pub onset: Rational,
+    pub duration: Rational,
+}
+
+/// Represents notes placed in time, which may be separated by rests
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Phrase {
+    notes: Vec<TimedNote>,
+    length: Rational,
+}
+
+impl RhythmPattern {
+    /// Creates a new `RhythmPattern` from its steps
+    ///
+    /// # Arguments
+    /// * `onsets` - For each step, `true` if a note starts on it
+    ///
+    /// # Returns
+    /// A new `RhythmPattern` instance
+    pub fn new(onsets: impl IntoIterator<Item = bool>) -> Self {
+        Self {
+            onsets: onsets.into_iter().collect(),
+        }
+    }
+
+    /// Creates the Euclidean rhythm spreading some onsets as evenly as possible over some steps
+    ///
+    /// The rhythm is built with Bjorklund's algorithm, and starts with an onset.
+    /// Many traditional rhythms are Euclidean, such as the tresillo, E(3, 8), or
+    /// the cinquillo, E(5, 8).
+    ///
+    /// # Arguments
+    /// * `pulses` - The number of onsets, at most the number of steps
+    /// * `steps` - The number of steps of the pattern
+    ///
+    /// # Returns
+    /// The pattern, with an onset on every step if there are more pulses than steps
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// assert_eq!(RhythmPattern::euclidean(5, 8).to_string(), "x.xx.xx.");
+    /// assert_eq!(RhythmPattern::euclidean(2, 4).to_string(), "x.x.");
+    /// ```
+    pub fn euclidean(pulses: usize, steps: usize) -> Self {
+        let pulses = pulses.min(steps);
+        let mut groups = vec![vec![true]; pulses];
+        let mut remainders = vec![vec![false]; steps - pulses];
+
+        // Each pass appends a remainder to each group, until a single remainder is left
+        while remainders.len() > 1 && !groups.is_empty() {
+            let pairs = groups.len().min(remainders.len());
+            let leftovers = if groups.len() > pairs {
+                groups.split_off(pairs)
+            } else {
+                remainders.split_off(pairs)
+            };
+            for (group, remainder) in groups.iter_mut().zip(&remainders) {
+                group.extend(remainder);
+            }
+            remainders = leftovers;
+        }
+
+        Self::new(groups.into_iter().chain(remainders).flatten())
+    }
+
+    /// Returns the steps of the pattern, `true` for the onsets
+    pub fn onsets(&self) -> &[bool] {
+        &self.onsets
+    }
+
+    /// Returns the number of steps of the pattern
+    pub fn len(&self) -> usize {
+        self.onsets.len()
+    }
+
+    /// Returns `true` if the pattern has no steps
+    pub fn is_empty(&self) -> bool {
+        self.onsets.is_empty()
+    }
+
+    /// Returns the pattern started a number of steps later, the skipped steps moving to the end
+    ///
+    /// # Arguments
+    /// * `steps` - The number of steps to rotate by
+    ///
+    /// # Returns
+    /// The rotated pattern
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// assert_eq!(RhythmPattern::euclidean(3, 8).rotate(3).to_string(), "x..x.x..");
+    /// ```
+    pub fn rotate(&self, steps: usize) -> Self {
+        let mut onsets = self.onsets.clone();
+        if !onsets.is_empty() {
+            onsets.rotate_left(steps % self.len());
+        }
+        Self { onsets }
+    }
+
+    /// Returns the proportion of the steps that are onsets
+    ///
+    /// # Returns
+    /// A value from 0.0, for a pattern of rests or without steps, to 1.0 for a pattern of onsets
+    pub fn density(&self) -> f32 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        let pulses = self.onsets.iter().filter(|&&onset| onset).count();
+        pulses as f32 / self.len() as f32
+    }
+
+    /// Places successive notes on the onsets of the pattern
+    ///
+    /// Each step lasts one subdivision, and each note lasts until the next step.
+    /// The phrase ends when the notes or the pattern run out; its length is the
+    /// length of the whole pattern.
+    ///
+    /// # Arguments
+    /// * `notes` - The notes to place, in order
+    /// * `subdivision` - The length of one step
+    ///
+    /// # Returns
+    /// The phrase, with one note on each onset
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let phrase = RhythmPattern::euclidean(3, 8).apply([C4, E4, G4], Duration::Eighth);
+    /// assert_eq!(phrase.notes()[1].onset, Rational::new(3, 8));
+    /// assert_eq!(phrase.onsets_ms(Tempo::new(120.0)), vec![0.0, 750.0, 1500.0]);
+    /// ```
+    pub fn apply(&self, notes: impl IntoIterator<Item = Note>, subdivision: Duration) -> Phrase {
+        let step = subdivision.value();
+        let onsets = self
+            .onsets
+            .iter()
+            .enumerate()
+            .filter(|(_, &onset)| onset)
+            .map(|(index, _)| Rational::integer(index as u32) * step);
+
+        let notes = onsets
+            .zip(notes)
+            .map(|(onset, note)| TimedNote {
+                note,
+                onset,
+                duration: step,
+            })
+            .collect();
+        Phrase::new(notes, Rational::integer(self.len() as u32) * step)
+    }
+}
+
+impl fmt::Display for RhythmPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &onset in &self.onsets {
+            f.write_str(if onset { "x" } else { "." })?;
+        }
+        Ok(())
+    }
+}
+
+impl Phrase {
+    /// Creates a new `Phrase` from its notes
+    ///
+    /// # Arguments
+    /// * `notes` - The notes of the phrase, in the order they are played
+    /// * `length` - The length of the phrase, which may end with a rest
+    ///
+    /// # Returns
+    /// A new `Phrase` instance
+    pub(crate) fn new(notes: Vec<TimedNote>, length: Rational) -> Self {
+        Self { notes, length }
+    }
+
+    /// Returns the notes of the phrase, in the order they are played
+    pub fn notes(&self) -> &[TimedNote] {
+        &self.notes
+    }
+
+    /// Returns the length of the phrase, as a fraction of a whole note
+    pub fn length(&self) -> Rational {
+        self.length
+    }
+
+    /// Returns when each note of the phrase starts at a tempo
+    ///
+    /// # Arguments
+    /// * `tempo` - The tempo of the phrase
+    ///
+    /// # Returns
+    /// The onset of each note in milliseconds from the start of the phrase
+    pub fn onsets_ms(&self, tempo: Tempo) -> Vec<f64> {
+        self.notes
+            .iter()
+            .map(|note| tempo.length_ms(note.onset))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_euclidean() {
+        assert_eq!(RhythmPattern::euclidean(3, 8).to_string(), "x..x..x.");
+        assert_eq!(RhythmPattern::euclidean(5, 8).to_string(), "x.xx.xx.");
+        assert_eq!(
+            RhythmPattern::euclidean(7, 16).to_string(),
+            "x..x.x.x..x.x.x."
+        );
+        assert_eq!(RhythmPattern::euclidean(4, 12).to_string(), "x..x..x..x..");
+        assert_eq!(RhythmPattern::euclidean(5, 13).to_string(), "x..x.x..x.x..");
+
+        assert_eq!(RhythmPattern::euclidean(0, 4).to_string(), "....");
+        assert_eq!(RhythmPattern::euclidean(4, 4).to_string(), "xxxx");
+        assert_eq!(RhythmPattern::euclidean(6, 4).to_string(), "xxxx");
+        assert!(RhythmPattern::euclidean(0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let tresillo = RhythmPattern::euclidean(3, 8);
+        assert_eq!(tresillo.rotate(1).to_string(), "..x..x.x");
+        assert_eq!(tresillo.rotate(8), tresillo);
+        assert_eq!(tresillo.rotate(11), tresillo.rotate(3));
+        assert!(RhythmPattern::new([]).rotate(2).is_empty());
+    }
+
+    #[test]
+    fn test_density() {
+        assert_eq!(RhythmPattern::euclidean(3, 8).density(), 0.375);
+        assert_eq!(RhythmPattern::euclidean(7, 16).rotate(5).density(), 0.4375);
+        assert_eq!(RhythmPattern::new([]).density(), 0.0);
+    }
+
+    #[test]
+    fn test_apply() {
+        let pattern = RhythmPattern::euclidean(5, 8);
+        let phrase = pattern.apply([C4, D4, E4, F4, G4, A4], Duration::Sixteenth);
+
+        // Only as many notes as onsets are placed
+        assert_eq!(phrase.notes().len(), 5);
+        let onsets: Vec<_> = phrase.notes().iter().map(|note| note.onset).collect();
+        assert_eq!(
+            onsets,
+            [0, 2, 3, 5, 6].map(|step| Rational::new(step, 16)).to_vec()
+        );
+        assert_eq!(phrase.notes()[2].note, E4);
+        assert_eq!(phrase.notes()[2].duration, Duration::Sixteenth.value());
+        assert_eq!(phrase.length(), Rational::new(1, 2));
+
+        // A sixteenth lasts 125 ms at 120 beats per minute
+        assert_eq!(
+            phrase.onsets_ms(Tempo::new(120.0)),
+            vec![0.0, 250.0, 375.0, 625.0, 750.0]
+        );
+
+        // The phrase stops when the notes run out
+        assert_eq!(pattern.apply([C4, D4], Duration::Eighth).notes().len(), 2);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{Duration, Rational};
+
+/// The number of milliseconds in a minute
+const MS_PER_MINUTE: f64 = 60_000.0;
+
+/// Represents the speed of a piece, in quarter-note beats per minute
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let tempo = Tempo::new(120.0);
+/// assert_eq!(tempo.duration_ms(Duration::Quarter), 500.0);
+/// assert_eq!(tempo.length_ms(Rational::new(3, 8)), 750.0);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tempo(f64);
+
+impl Tempo {
+    /// Creates a new `Tempo`
+    ///
+    /// # Arguments
+    /// * `bpm` - The number of quarter-note beats per minute
+    ///
+    /// # Returns
+    /// A new `Tempo` instance
+    pub const fn new(bpm: f64) -> Self {
+        Self(bpm)
+    }
+
+    /// Returns the number of quarter-note beats per minute
+    pub const fn bpm(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns how long a note lasts at this tempo
+    ///
+    /// # Arguments
+    /// * `duration` - The written length of the note
+    ///
+    /// # Returns
+    /// The length of the note in milliseconds
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// assert_eq!(Tempo::new(60.0).duration_ms(Duration::Half), 2000.0);
+    /// ```
+    pub fn duration_ms(&self, duration: Duration) -> f64 {
+        self.length_ms(duration.value())
+    }
+
+    /// Returns how long any length of music lasts at this tempo
+    ///
+    /// Unlike `duration_ms`, the length can be any fraction of a whole note, such
+    /// as `3/8` for a dotted quarter or `1/12` for an eighth-note triplet.
+    ///
+    /// # Arguments
+    /// * `length` - The length as a fraction of a whole note
+    ///
+    /// # Returns
+    /// The length in milliseconds
+    pub fn length_ms(&self, length: Rational) -> f64 {
+        let whole_notes = f64::from(length.numerator()) / f64::from(length.denominator());
+        whole_notes * Duration::Whole.beats() as f64 * MS_PER_MINUTE / self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_ms() {
+        let tempo = Tempo::new(120.0);
+        assert_eq!(tempo.bpm(), 120.0);
+        assert_eq!(tempo.duration_ms(Duration::Quarter), 500.0);
+        assert_eq!(tempo.duration_ms(Duration::Eighth), 250.0);
+        assert_eq!(tempo.duration_ms(Duration::Whole), 2000.0);
+
+        let tempo = Tempo::new(90.0);
+        assert!((tempo.duration_ms(Duration::Quarter) - 666.666).abs() < 0.001);
+        assert!((tempo.duration_ms(Duration::Eighth) - 333.333).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_dotted_quarter() {
+        let dotted_quarter = Duration::Quarter.value() + Duration::Eighth.value();
+        assert_eq!(Tempo::new(120.0).length_ms(dotted_quarter), 750.0);
+        assert_eq!(Tempo::new(60.0).length_ms(dotted_quarter), 1500.0);
+    }
+}
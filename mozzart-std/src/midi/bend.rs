@@ -0,0 +1,421 @@
+use super::smf::write_smf_events;
+use crate::{Error, MidiEvent, Note};
+use std::io::{self, Write};
+
+/// The channel playing the notes that need no pitch bend
+const PRIMARY_CHANNEL: u8 = 0;
+
+/// The channel reserved for percussion by General MIDI
+const PERCUSSION_CHANNEL: u8 = 9;
+
+/// The velocity of the notes rendered by `MidiExportOptions`
+const VELOCITY: u8 = 100;
+
+/// The pitch bend value that leaves a note untouched
+const BEND_CENTER: u16 = 0x2000;
+
+/// The largest 14-bit pitch bend value
+const BEND_MAX: u16 = 0x3FFF;
+
+/// The number of cents in a semitone
+const CENTS_IN_SEMITONE: i32 = 100;
+
+/// Represents a note played off the equal-tempered grid, at a given time
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// // A quarter-tone above A4, for one beat at 480 ticks per quarter note
+/// let note = DetunedNote::new(A4, 50, 0, 480);
+/// assert_eq!(note.cents, 50);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct DetunedNote {
+    pub note: Note,
+    /// The offset from the equal-tempered note, in cents
+    pub cents: i16,
+    /// The tick at which the note starts
+    pub start: u32,
+    /// The length of the note, in ticks
+    pub length: u32,
+}
+
+impl DetunedNote {
+    /// Creates a new `DetunedNote`
+    ///
+    /// # Arguments
+    /// * `note` - The nearest equal-tempered note
+    /// * `cents` - The offset from that note, in cents
+    /// * `start` - The tick at which the note starts
+    /// * `length` - The length of the note, in ticks
+    ///
+    /// # Returns
+    /// A new `DetunedNote` instance
+    pub const fn new(note: Note, cents: i16, start: u32, length: u32) -> Self {
+        Self {
+            note,
+            cents,
+            start,
+            length,
+        }
+    }
+}
+
+/// The settings used to render detuned notes as MIDI events
+///
+/// MIDI applies a pitch bend to a whole channel, so every detuned note is
+/// played on a channel of its own, taken in turn from the channel pool. The
+/// notes without an offset are played on channel 1 (0), which must therefore
+/// stay out of the pool.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let options = MidiExportOptions {
+///     bend_range_semitones: 12,
+///     ..MidiExportOptions::default()
+/// };
+/// assert_eq!(options.channel_pool.len(), 14);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MidiExportOptions {
+    /// The pitch change, in semitones, of a full pitch bend in either direction
+    pub bend_range_semitones: u8,
+    /// The channels, from 0 to 15, the detuned notes are spread over
+    pub channel_pool: Vec<u8>,
+}
+
+impl Default for MidiExportOptions {
+    fn default() -> Self {
+        Self {
+            bend_range_semitones: 2,
+            channel_pool: (1..16).filter(|&c| c != PERCUSSION_CHANNEL).collect(),
+        }
+    }
+}
+
+impl MidiExportOptions {
+    /// Returns the MIDI events that play the detuned notes
+    ///
+    /// A note without an offset is switched on and off on channel 1. Any other
+    /// note gets the next free channel of the pool, round-robin, with a pitch
+    /// bend sent right before it starts and reset right after it stops. Offsets
+    /// of more than half a semitone are first moved to the nearest note. A
+    /// channel is never shared, since a new bend would detune the note still
+    /// sounding on it.
+    ///
+    /// # Arguments
+    /// * `notes` - The notes to play, in any order
+    ///
+    /// # Returns
+    /// The events in time order, or an error if the options cannot render a
+    /// detuned note, more detuned notes sound at once than the pool has
+    /// channels, or an offset moves a note outside of the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let options = MidiExportOptions::default();
+    /// let events = options.events(&[DetunedNote::new(A4, 50, 0, 480)]).unwrap();
+    /// assert_eq!(events[0], MidiEvent::new(0, [0xE1, 0x00, 0x50]));
+    /// assert_eq!(events[1], MidiEvent::new(0, A4.note_on(1, 100)));
+    /// assert_eq!(events[2], MidiEvent::new(480, A4.note_off(1)));
+    /// assert_eq!(events[3], MidiEvent::new(480, [0xE1, 0x00, 0x40]));
+    /// ```
+    pub fn events(&self, notes: &[DetunedNote]) -> Result<Vec<MidiEvent>, Error> {
+        let mut notes = notes.to_vec();
+        notes.sort_by_key(|note| note.start);
+
+        // Every event is tagged so that, at the same tick, the notes stop
+        // before others start, and each note keeps its messages in order.
+        let mut tagged = Vec::with_capacity(4 * notes.len());
+        let mut busy_until = [0u32; 16];
+        let mut next = 0;
+
+        for detuned in &notes {
+            let end = detuned.start.saturating_add(detuned.length);
+            let (note, cents) = self.nearest(detuned)?;
+
+            if cents == 0 {
+                tagged.push((detuned.start, 1, note.note_on(PRIMARY_CHANNEL, VELOCITY)));
+                tagged.push((end, 0, note.note_off(PRIMARY_CHANNEL)));
+                continue;
+            }
+
+            self.validate()?;
+            let pool = &self.channel_pool;
+            let free = (0..pool.len())
+                .map(|offset| (next + offset) % pool.len())
+                .find(|&index| busy_until[usize::from(pool[index])] <= detuned.start)
+                .ok_or_else(|| {
+                    Error::InvalidMidiExportOptions(format!(
+                        "more than {} detuned notes sound at tick {}",
+                        pool.len(),
+                        detuned.start
+                    ))
+                })?;
+            let channel = pool[free];
+            next = (free + 1) % pool.len();
+            busy_until[usize::from(channel)] = end;
+
+            let bend = self.bend_value(cents);
+            tagged.push((detuned.start, 1, pitch_bend(channel, bend)));
+            tagged.push((detuned.start, 1, note.note_on(channel, VELOCITY)));
+            tagged.push((end, 0, note.note_off(channel)));
+            tagged.push((end, 0, pitch_bend(channel, BEND_CENTER)));
+        }
+
+        tagged.sort_by_key(|&(tick, order, _)| (tick, order));
+        Ok(tagged
+            .into_iter()
+            .map(|(tick, _, bytes)| MidiEvent::new(tick, bytes))
+            .collect())
+    }
+
+    /// Writes the detuned notes as a Standard MIDI File
+    ///
+    /// The file is of type 0, like the one written by `Melody::write_smf`.
+    ///
+    /// # Arguments
+    /// * `writer` - Where the file is written
+    /// * `ppq` - The resolution of the file, in ticks per quarter note, from 1 to 32767
+    /// * `notes` - The notes to play, timed in ticks of that resolution
+    ///
+    /// # Returns
+    /// An error if the notes cannot be rendered, the resolution is out of range or the writer fails
+    pub fn write_smf<W: Write>(
+        &self,
+        writer: &mut W,
+        ppq: u16,
+        notes: &[DetunedNote],
+    ) -> io::Result<()> {
+        let events = self
+            .events(notes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        write_smf_events(writer, ppq, &events)
+    }
+
+    /// Returns the 14-bit pitch bend value that detunes a note by some cents
+    ///
+    /// The value is rounded to the nearest step and clamped to the bend range.
+    fn bend_value(&self, cents: i32) -> u16 {
+        let range = i32::from(self.bend_range_semitones) * CENTS_IN_SEMITONE;
+        let center = i32::from(BEND_CENTER);
+        let offset = (cents * center + cents.signum() * range / 2) / range;
+        (center + offset).clamp(0, i32::from(BEND_MAX)) as u16
+    }
+
+    /// Moves whole semitones of the offset of a note into the note itself
+    ///
+    /// The remaining offset is within half a semitone either way.
+    fn nearest(&self, detuned: &DetunedNote) -> Result<(Note, i32), Error> {
+        let cents = i32::from(detuned.cents);
+        let half = CENTS_IN_SEMITONE / 2;
+        let semitones = cents.signum() * ((cents.abs() + half - 1) / CENTS_IN_SEMITONE);
+        let note = detuned
+            .note
+            .checked_add_semitones(semitones as i16)
+            .ok_or(Error::NoteOutOfRange(detuned.note))?;
+        Ok((note, cents - semitones * CENTS_IN_SEMITONE))
+    }
+
+    /// Checks that the options can render detuned notes
+    fn validate(&self) -> Result<(), Error> {
+        let invalid = |reason: &str| Err(Error::InvalidMidiExportOptions(reason.to_string()));
+
+        if self.bend_range_semitones == 0 {
+            return invalid("the bend range must be at least one semitone");
+        }
+        if self.channel_pool.is_empty() {
+            return invalid("the channel pool is empty");
+        }
+        if self.channel_pool.iter().any(|&channel| channel > 15) {
+            return invalid("the channels must be in 0..=15");
+        }
+        if self.channel_pool.contains(&PRIMARY_CHANNEL) {
+            return invalid("the primary channel cannot be in the channel pool");
+        }
+        Ok(())
+    }
+}
+
+/// Returns the MIDI message that bends the pitch of a channel
+fn pitch_bend(channel: u8, value: u16) -> [u8; 3] {
+    [
+        0xE0 | channel & 0x0F,
+        (value & 0x7F) as u8,
+        (value >> 7 & 0x7F) as u8,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    /// Reads the messages of the single track of a type 0 file, with their absolute ticks
+    fn parse_track(file: &[u8]) -> Vec<(u32, [u8; 3])> {
+        assert_eq!(&file[14..18], b"MTrk");
+        let mut track = &file[22..];
+        let mut tick = 0;
+        let mut messages = Vec::new();
+        loop {
+            let mut delta = 0;
+            while track[0] & 0x80 != 0 {
+                delta = delta << 7 | u32::from(track[0] & 0x7F);
+                track = &track[1..];
+            }
+            tick += delta << 7 | u32::from(track[0]);
+            track = &track[1..];
+            if track[0] == 0xFF {
+                return messages;
+            }
+            messages.push((tick, [track[0], track[1], track[2]]));
+            track = &track[3..];
+        }
+    }
+
+    fn bend_of(message: [u8; 3]) -> u16 {
+        assert_eq!(message[0] & 0xF0, 0xE0);
+        u16::from(message[1]) | u16::from(message[2]) << 7
+    }
+
+    #[test]
+    fn test_bend_values() {
+        let options = MidiExportOptions::default();
+        let notes = [
+            DetunedNote::new(A4, 50, 0, 96),
+            DetunedNote::new(C4, -25, 96, 96),
+        ];
+        let mut file = Vec::new();
+        options.write_smf(&mut file, 96, &notes).unwrap();
+        let messages = parse_track(&file);
+
+        assert_eq!(messages[0], (0, [0xE1, 0x00, 0x50]));
+        assert_eq!(bend_of(messages[0].1), 10240);
+        assert_eq!(messages[1], (0, A4.note_on(1, VELOCITY)));
+
+        let bend = messages
+            .iter()
+            .find(|(tick, m)| *tick == 96 && m[0] == 0xE2);
+        let (_, message) = bend.unwrap();
+        assert_eq!(bend_of(*message), 7168);
+    }
+
+    #[test]
+    fn test_simultaneous_notes_get_their_own_channels() {
+        let options = MidiExportOptions::default();
+        let notes = [
+            DetunedNote::new(C4, 14, 0, 96),
+            DetunedNote::new(E4, -14, 0, 96),
+            DetunedNote::new(G4, 0, 0, 96),
+        ];
+        let events = options.events(&notes).unwrap();
+        let channel_of = |note: Note| {
+            events
+                .iter()
+                .find(|event| event.bytes[0] & 0xF0 == 0x90 && event.bytes[1] == note.midi_number())
+                .map(|event| event.bytes[0] & 0x0F)
+                .unwrap()
+        };
+        assert_eq!(channel_of(C4), 1);
+        assert_eq!(channel_of(E4), 2);
+        assert_eq!(channel_of(G4), PRIMARY_CHANNEL);
+        assert_eq!(events.len(), 10);
+    }
+
+    #[test]
+    fn test_busy_channels_are_not_stolen() {
+        let options = MidiExportOptions {
+            channel_pool: vec![1, 2],
+            ..MidiExportOptions::default()
+        };
+        let notes = [
+            DetunedNote::new(C4, 14, 0, 96),
+            DetunedNote::new(E4, -14, 0, 96),
+            DetunedNote::new(G4, 20, 48, 96),
+        ];
+        assert_eq!(
+            options.events(&notes),
+            Err(Error::InvalidMidiExportOptions(
+                "more than 2 detuned notes sound at tick 48".to_string()
+            ))
+        );
+
+        // The third note can start once a channel is released
+        let notes = [notes[0], notes[1], DetunedNote::new(G4, 20, 96, 96)];
+        let events = options.events(&notes).unwrap();
+        assert!(events.contains(&MidiEvent::new(96, G4.note_on(1, VELOCITY))));
+    }
+
+    #[test]
+    fn test_bend_is_reset_after_note_off() {
+        let options = MidiExportOptions::default();
+        let notes = [
+            DetunedNote::new(C4, 30, 0, 96),
+            DetunedNote::new(D4, 30, 96, 96),
+        ];
+        let mut file = Vec::new();
+        options.write_smf(&mut file, 96, &notes).unwrap();
+        let messages = parse_track(&file);
+
+        let off = messages.iter().position(|m| m.1 == C4.note_off(1)).unwrap();
+        assert_eq!(messages[off + 1], (96, [0xE1, 0x00, 0x40]));
+        assert_eq!(bend_of(messages[off + 1].1), BEND_CENTER);
+        // The next note starts once the first one is fully released
+        assert_eq!(messages[off + 2].0, 96);
+        assert_eq!(messages[off + 3].1, D4.note_on(2, VELOCITY));
+        assert_eq!(messages.last().unwrap(), &(192, [0xE2, 0x00, 0x40]));
+    }
+
+    #[test]
+    fn test_large_offsets_move_to_the_nearest_note() {
+        let options = MidiExportOptions::default();
+        let events = options.events(&[DetunedNote::new(C4, 170, 0, 96)]).unwrap();
+        assert_eq!(events[1].bytes, D4.note_on(1, VELOCITY));
+        assert_eq!(bend_of(events[0].bytes), BEND_CENTER - 1229);
+
+        let events = options
+            .events(&[DetunedNote::new(C4, -100, 0, 96)])
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![
+                MidiEvent::new(0, B3.note_on(0, VELOCITY)),
+                MidiEvent::new(96, B3.note_off(0)),
+            ]
+        );
+
+        let high = Note::try_from(127).unwrap();
+        assert_eq!(
+            options.events(&[DetunedNote::new(high, 60, 0, 96)]),
+            Err(Error::NoteOutOfRange(high))
+        );
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        let notes = [DetunedNote::new(C4, 10, 0, 96)];
+        let options = MidiExportOptions {
+            channel_pool: vec![0, 1],
+            ..MidiExportOptions::default()
+        };
+        assert!(matches!(
+            options.events(&notes),
+            Err(Error::InvalidMidiExportOptions(_))
+        ));
+
+        let options = MidiExportOptions {
+            bend_range_semitones: 0,
+            channel_pool: vec![],
+        };
+        assert!(options.events(&notes).is_err());
+        // Notes without offsets never need a bend
+        assert!(options.events(&[DetunedNote::new(C4, 0, 0, 96)]).is_ok());
+    }
+}
@@ -0,0 +1,33 @@
+/// Represents a MIDI message scheduled at a tick of a sequence
+///
+/// The tick counts from the start of the sequence, in the resolution chosen by
+/// whoever builds the events.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let event = MidiEvent::new(480, C4.note_on(0, 100));
+/// assert_eq!(event.tick, 480);
+/// assert_eq!(event.bytes, [0x90, 60, 100]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct MidiEvent {
+    pub tick: u32,
+    pub bytes: [u8; 3],
+}
+
+impl MidiEvent {
+    /// Creates a new `MidiEvent`
+    ///
+    /// # Arguments
+    /// * `tick` - The time of the event, in ticks from the start of the sequence
+    /// * `bytes` - The MIDI message
+    ///
+    /// # Returns
+    /// A new `MidiEvent` instance
+    pub const fn new(tick: u32, bytes: [u8; 3]) -> Self {
+        Self { tick, bytes }
+    }
+}
@@ -0,0 +1,8 @@
+mod bend;
+mod event;
+mod percussion;
+mod smf;
+
+pub use bend::*;
+pub use event::*;
+pub use percussion::*;
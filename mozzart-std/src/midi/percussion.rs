@@ -0,0 +1,426 @@
+use super::smf::{ticks, write_smf_events, VELOCITY};
+use crate::{Duration, Error, MidiEvent, Note, Rational, RhythmPattern};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Write};
+
+/// The General MIDI channel of the percussion, channel 10 counted from 1
+pub const PERCUSSION_CHANNEL: u8 = 9;
+
+/// Represents the percussion sounds of the General MIDI standard
+///
+/// On channel 10 each note number plays a percussion sound rather than a pitch,
+/// from the acoustic bass drum on note 35 to the open triangle on note 81.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let snare = GmPercussion::AcousticSnare;
+/// assert_eq!(Note::from(snare).midi_number(), 38);
+/// assert_eq!(snare.to_string(), "Acoustic Snare");
+/// assert_eq!(GmPercussion::try_from(Note::from(snare)), Ok(snare));
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum GmPercussion {
+    AcousticBassDrum = 35,
+    BassDrum1 = 36,
+    SideStick = 37,
+    AcousticSnare = 38,
+    HandClap = 39,
+    ElectricSnare = 40,
+    LowFloorTom = 41,
+    ClosedHiHat = 42,
+    HighFloorTom = 43,
+    PedalHiHat = 44,
+    LowTom = 45,
+    OpenHiHat = 46,
+    LowMidTom = 47,
+    HiMidTom = 48,
+    CrashCymbal1 = 49,
+    HighTom = 50,
+    RideCymbal1 = 51,
+    ChineseCymbal = 52,
+    RideBell = 53,
+    Tambourine = 54,
+    SplashCymbal = 55,
+    Cowbell = 56,
+    CrashCymbal2 = 57,
+    Vibraslap = 58,
+    RideCymbal2 = 59,
+    HiBongo = 60,
+    LowBongo = 61,
+    MuteHiConga = 62,
+    OpenHiConga = 63,
+    LowConga = 64,
+    HighTimbale = 65,
+    LowTimbale = 66,
+    HighAgogo = 67,
+    LowAgogo = 68,
+    Cabasa = 69,
+    Maracas = 70,
+    ShortWhistle = 71,
+    LongWhistle = 72,
+    ShortGuiro = 73,
+    LongGuiro = 74,
+    Claves = 75,
+    HiWoodBlock = 76,
+    LowWoodBlock = 77,
+    MuteCuica = 78,
+    OpenCuica = 79,
+    MuteTriangle = 80,
+    OpenTriangle = 81,
+}
+
+impl GmPercussion {
+    /// All the percussion sounds, from note 35 to note 81
+    pub const ALL: [GmPercussion; 47] = [
+        GmPercussion::AcousticBassDrum,
+        GmPercussion::BassDrum1,
+        GmPercussion::SideStick,
+        GmPercussion::AcousticSnare,
+        GmPercussion::HandClap,
+        GmPercussion::ElectricSnare,
+        GmPercussion::LowFloorTom,
+        GmPercussion::ClosedHiHat,
+        GmPercussion::HighFloorTom,
+        GmPercussion::PedalHiHat,
+        GmPercussion::LowTom,
+        GmPercussion::OpenHiHat,
+        GmPercussion::LowMidTom,
+        GmPercussion::HiMidTom,
+        GmPercussion::CrashCymbal1,
+        GmPercussion::HighTom,
+        GmPercussion::RideCymbal1,
+        GmPercussion::ChineseCymbal,
+        GmPercussion::RideBell,
+        GmPercussion::Tambourine,
+        GmPercussion::SplashCymbal,
+        GmPercussion::Cowbell,
+        GmPercussion::CrashCymbal2,
+        GmPercussion::Vibraslap,
+        GmPercussion::RideCymbal2,
+        GmPercussion::HiBongo,
+        GmPercussion::LowBongo,
+        GmPercussion::MuteHiConga,
+        GmPercussion::OpenHiConga,
+        GmPercussion::LowConga,
+        GmPercussion::HighTimbale,
+        GmPercussion::LowTimbale,
+        GmPercussion::HighAgogo,
+        GmPercussion::LowAgogo,
+        GmPercussion::Cabasa,
+        GmPercussion::Maracas,
+        GmPercussion::ShortWhistle,
+        GmPercussion::LongWhistle,
+        GmPercussion::ShortGuiro,
+        GmPercussion::LongGuiro,
+        GmPercussion::Claves,
+        GmPercussion::HiWoodBlock,
+        GmPercussion::LowWoodBlock,
+        GmPercussion::MuteCuica,
+        GmPercussion::OpenCuica,
+        GmPercussion::MuteTriangle,
+        GmPercussion::OpenTriangle,
+    ];
+
+    /// Returns the name of the sound in the General MIDI standard
+    pub const fn name(&self) -> &'static str {
+        match self {
+            GmPercussion::AcousticBassDrum => "Acoustic Bass Drum",
+            GmPercussion::BassDrum1 => "Bass Drum 1",
+            GmPercussion::SideStick => "Side Stick",
+            GmPercussion::AcousticSnare => "Acoustic Snare",
+            GmPercussion::HandClap => "Hand Clap",
+            GmPercussion::ElectricSnare => "Electric Snare",
+            GmPercussion::LowFloorTom => "Low Floor Tom",
+            GmPercussion::ClosedHiHat => "Closed Hi-Hat",
+            GmPercussion::HighFloorTom => "High Floor Tom",
+            GmPercussion::PedalHiHat => "Pedal Hi-Hat",
+            GmPercussion::LowTom => "Low Tom",
+            GmPercussion::OpenHiHat => "Open Hi-Hat",
+            GmPercussion::LowMidTom => "Low-Mid Tom",
+            GmPercussion::HiMidTom => "Hi-Mid Tom",
+            GmPercussion::CrashCymbal1 => "Crash Cymbal 1",
+            GmPercussion::HighTom => "High Tom",
+            GmPercussion::RideCymbal1 => "Ride Cymbal 1",
+            GmPercussion::ChineseCymbal => "Chinese Cymbal",
+            GmPercussion::RideBell => "Ride Bell",
+            GmPercussion::Tambourine => "Tambourine",
+            GmPercussion::SplashCymbal => "Splash Cymbal",
+            GmPercussion::Cowbell => "Cowbell",
+            GmPercussion::CrashCymbal2 => "Crash Cymbal 2",
+            GmPercussion::Vibraslap => "Vibraslap",
+            GmPercussion::RideCymbal2 => "Ride Cymbal 2",
+            GmPercussion::HiBongo => "Hi Bongo",
+            GmPercussion::LowBongo => "Low Bongo",
+            GmPercussion::MuteHiConga => "Mute Hi Conga",
+            GmPercussion::OpenHiConga => "Open Hi Conga",
+            GmPercussion::LowConga => "Low Conga",
+            GmPercussion::HighTimbale => "High Timbale",
+            GmPercussion::LowTimbale => "Low Timbale",
+            GmPercussion::HighAgogo => "High Agogo",
+            GmPercussion::LowAgogo => "Low Agogo",
+            GmPercussion::Cabasa => "Cabasa",
+            GmPercussion::Maracas => "Maracas",
+            GmPercussion::ShortWhistle => "Short Whistle",
+            GmPercussion::LongWhistle => "Long Whistle",
+            GmPercussion::ShortGuiro => "Short Guiro",
+            GmPercussion::LongGuiro => "Long Guiro",
+            GmPercussion::Claves => "Claves",
+            GmPercussion::HiWoodBlock => "Hi Wood Block",
+            GmPercussion::LowWoodBlock => "Low Wood Block",
+            GmPercussion::MuteCuica => "Mute Cuica",
+            GmPercussion::OpenCuica => "Open Cuica",
+            GmPercussion::MuteTriangle => "Mute Triangle",
+            GmPercussion::OpenTriangle => "Open Triangle",
+        }
+    }
+}
+
+impl From<GmPercussion> for Note {
+    /// Returns the note that plays the sound on the percussion channel
+    fn from(percussion: GmPercussion) -> Self {
+        Note::new(percussion as u8)
+    }
+}
+
+impl TryFrom<Note> for GmPercussion {
+    type Error = Error;
+
+    /// Returns the sound a note plays on the percussion channel
+    ///
+    /// # Returns
+    /// The sound, or `Error::NotAPercussionSound` if the note is outside of 35..=81
+    fn try_from(note: Note) -> Result<Self, Self::Error> {
+        let first = GmPercussion::AcousticBassDrum as u8;
+        note.midi_number()
+            .checked_sub(first)
+            .and_then(|index| GmPercussion::ALL.get(usize::from(index)))
+            .copied()
+            .ok_or(Error::NotAPercussionSound(note))
+    }
+}
+
+impl fmt::Display for GmPercussion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Represents a drum groove, a rhythm for each percussion sound
+///
+/// The patterns share the same subdivision and start together; each onset
+/// strikes the sound for one step.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let pattern = DrumPattern::new(Duration::Quarter)
+///     .with(GmPercussion::BassDrum1, RhythmPattern::euclidean(4, 4));
+/// let events = pattern.to_midi_events(96);
+/// assert_eq!(events[0].bytes, [0x99, 36, 100]);
+/// assert_eq!(events.last().unwrap().tick, 384);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DrumPattern {
+    subdivision: Duration,
+    patterns: BTreeMap<GmPercussion, RhythmPattern>,
+}
+
+impl DrumPattern {
+    /// Creates a new `DrumPattern` without any sound
+    ///
+    /// # Arguments
+    /// * `subdivision` - The length of one step of the patterns
+    ///
+    /// # Returns
+    /// A new `DrumPattern` instance
+    pub fn new(subdivision: Duration) -> Self {
+        Self {
+            subdivision,
+            patterns: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the pattern with a rhythm played on a sound, replacing its previous rhythm
+    ///
+    /// # Arguments
+    /// * `percussion` - The sound playing the rhythm
+    /// * `pattern` - The onsets of the sound, one step per subdivision
+    pub fn with(mut self, percussion: GmPercussion, pattern: RhythmPattern) -> Self {
+        self.patterns.insert(percussion, pattern);
+        self
+    }
+
+    /// Returns the basic rock beat: closed hi-hat on every eighth, kick on beats 1 and 3, snare on 2 and 4
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// let rock = DrumPattern::rock_beat();
+    /// assert_eq!(rock.pattern(GmPercussion::AcousticSnare).unwrap().to_string(), "..x...x.");
+    /// ```
+    pub fn rock_beat() -> Self {
+        Self::new(Duration::Eighth)
+            .with(GmPercussion::BassDrum1, steps("x...x..."))
+            .with(GmPercussion::AcousticSnare, steps("..x...x."))
+            .with(GmPercussion::ClosedHiHat, steps("xxxxxxxx"))
+    }
+
+    /// Returns the four-on-the-floor beat: kick on every beat, clap on 2 and 4, open hi-hat on the off-beats
+    pub fn four_on_the_floor() -> Self {
+        Self::new(Duration::Eighth)
+            .with(GmPercussion::BassDrum1, steps("x.x.x.x."))
+            .with(GmPercussion::HandClap, steps("..x...x."))
+            .with(GmPercussion::OpenHiHat, steps(".x.x.x.x"))
+    }
+
+    /// Returns the length of one step of the patterns
+    pub fn subdivision(&self) -> Duration {
+        self.subdivision
+    }
+
+    /// Returns the rhythm played on a sound, if any
+    pub fn pattern(&self, percussion: GmPercussion) -> Option<&RhythmPattern> {
+        self.patterns.get(&percussion)
+    }
+
+    /// Returns the sounds of the pattern with their rhythms, by note number
+    pub fn patterns(&self) -> impl Iterator<Item = (GmPercussion, &RhythmPattern)> + '_ {
+        self.patterns
+            .iter()
+            .map(|(&percussion, pattern)| (percussion, pattern))
+    }
+
+    /// Returns the MIDI events playing the pattern once on the percussion channel
+    ///
+    /// Each onset is a note-on at a fixed velocity followed by a note-off one step
+    /// later. The events are in time order, the note-offs before the note-ons at
+    /// the same tick.
+    ///
+    /// # Arguments
+    /// * `ppq` - The resolution, in ticks per quarter note
+    ///
+    /// # Returns
+    /// The events, on channel 10
+    pub fn to_midi_events(&self, ppq: u16) -> Vec<MidiEvent> {
+        let quarter = Rational::new(1, 4);
+        let step = self.subdivision.value();
+        let mut events = Vec::new();
+
+        for (percussion, pattern) in self.patterns() {
+            let note = Note::from(percussion);
+            let onsets = pattern.onsets().iter().enumerate();
+            for (index, _) in onsets.filter(|(_, &onset)| onset) {
+                let start = Rational::integer(index as u32) * step;
+                events.push(MidiEvent::new(
+                    ticks(start, quarter, ppq),
+                    note.note_on(PERCUSSION_CHANNEL, VELOCITY),
+                ));
+                events.push(MidiEvent::new(
+                    ticks(start + step, quarter, ppq),
+                    note.note_off(PERCUSSION_CHANNEL),
+                ));
+            }
+        }
+
+        // Note-off messages (0x8n) sort before note-on messages (0x9n)
+        events.sort_by_key(|event| (event.tick, event.bytes[0]));
+        events
+    }
+
+    /// Writes the pattern, played once, as a Standard MIDI File on the percussion channel
+    ///
+    /// # Arguments
+    /// * `writer` - Where the file is written
+    /// * `ppq` - The resolution of the file, in ticks per quarter note, from 1 to 32767
+    ///
+    /// # Returns
+    /// An error if the resolution is out of range or the writer fails
+    pub fn write_smf<W: Write>(&self, writer: &mut W, ppq: u16) -> io::Result<()> {
+        write_smf_events(writer, ppq, &self.to_midi_events(ppq))
+    }
+}
+
+/// Returns the rhythm written with `x` for the onsets and `.` for the rests
+fn steps(pattern: &str) -> RhythmPattern {
+    RhythmPattern::new(pattern.chars().map(|step| step == 'x'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_range() {
+        let numbers: Vec<u8> = GmPercussion::ALL
+            .iter()
+            .map(|&percussion| Note::from(percussion).midi_number())
+            .collect();
+        assert_eq!(numbers, (35..=81).collect::<Vec<_>>());
+
+        assert_eq!(GmPercussion::AcousticBassDrum as u8, 35);
+        assert_eq!(GmPercussion::OpenTriangle as u8, 81);
+        assert_eq!(GmPercussion::ClosedHiHat.to_string(), "Closed Hi-Hat");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for percussion in GmPercussion::ALL {
+            assert_eq!(
+                GmPercussion::try_from(Note::from(percussion)),
+                Ok(percussion)
+            );
+        }
+        for midi in (0..35).chain(82..128) {
+            let note = Note::new(midi);
+            assert_eq!(
+                GmPercussion::try_from(note),
+                Err(Error::NotAPercussionSound(note))
+            );
+        }
+    }
+
+    #[test]
+    fn test_rock_beat_bar() {
+        let ppq = 96;
+        let events = DrumPattern::rock_beat().to_midi_events(ppq);
+        let strikes = |percussion: GmPercussion| -> Vec<u32> {
+            let on = Note::from(percussion).note_on(PERCUSSION_CHANNEL, VELOCITY);
+            events
+                .iter()
+                .filter(|event| event.bytes == on)
+                .map(|event| event.tick)
+                .collect()
+        };
+
+        let beat = u32::from(ppq);
+        assert_eq!(strikes(GmPercussion::BassDrum1), vec![0, 2 * beat]);
+        assert_eq!(strikes(GmPercussion::AcousticSnare), vec![beat, 3 * beat]);
+        assert_eq!(strikes(GmPercussion::ClosedHiHat).len(), 8);
+
+        assert!(events.iter().all(|event| event.bytes[0] & 0x0F == 9));
+        assert!(events.windows(2).all(|pair| pair[0].tick <= pair[1].tick));
+        assert_eq!(events.last().unwrap().tick, 4 * beat);
+
+        let mut file = Vec::new();
+        DrumPattern::rock_beat().write_smf(&mut file, ppq).unwrap();
+        assert_eq!(&file[..4], b"MThd");
+    }
+
+    #[test]
+    fn test_four_on_the_floor() {
+        let pattern = DrumPattern::four_on_the_floor();
+        assert_eq!(
+            pattern.pattern(GmPercussion::BassDrum1).unwrap().density(),
+            0.5
+        );
+        assert_eq!(pattern.pattern(GmPercussion::AcousticSnare), None);
+        assert_eq!(pattern.patterns().count(), 3);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::constants::C4;
+use crate::{major_scale, Melody, MidiEvent, OrnamentParams, Rational, ScaleLike};
+use std::io::{self, Write};
+
+/// The velocity of the notes written by `Melody::write_smf`
+pub(crate) const VELOCITY: u8 = 100;
+
+/// The largest number of ticks per quarter note; above it the division field means SMPTE time
+const MAX_PPQ: u16 = 0x7FFF;
+
+/// The largest value a variable-length quantity can hold
+const MAX_VLQ: u32 = 0x0FFF_FFFF;
+
+impl Melody {
+    /// Writes the melody as a Standard MIDI File
+    ///
+    /// The file is of type 0, with a single track playing the notes one after
+    /// the other on channel 1, at a fixed velocity and at the default tempo of
+    /// 120 beats per minute. Ornaments depend on the key of the melody, so an
+    /// ornamented melody must be written with `write_smf_in`.
+    ///
+    /// # Arguments
+    /// * `writer` - Where the file is written
+    /// * `ppq` - The resolution of the file, in ticks per quarter note, from 1 to 32767
+    ///
+    /// # Returns
+    /// An error if the melody has ornaments, the resolution is out of range or the writer fails
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let melody = Melody::new([(C4, Duration::Quarter), (G4, Duration::Half)]);
+    /// let mut file = Vec::new();
+    /// melody.write_smf(&mut file, 480).unwrap();
+    /// assert_eq!(&file[..4], b"MThd");
+    /// assert_eq!(&file[14..18], b"MTrk");
+    ///
+    /// let mordent = melody.with_ornament(0, Ornament::UpperMordent).unwrap();
+    /// assert!(mordent.write_smf(&mut Vec::new(), 480).is_err());
+    /// ```
+    pub fn write_smf<W: Write>(&self, writer: &mut W, ppq: u16) -> io::Result<()> {
+        if (0..self.len()).any(|index| self.ornament(index).is_some()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the melody has ornaments, use write_smf_in to give its key",
+            ));
+        }
+
+        // Without ornaments the key is never used
+        let c_major = major_scale(C4).expect("C4 major is in the MIDI range");
+        self.write_smf_in(writer, ppq, &c_major, OrnamentParams::default())
+    }
+
+    /// Writes the melody as a Standard MIDI File, expanding its ornaments in a key
+    ///
+    /// # Arguments
+    /// * `writer` - Where the file is written
+    /// * `ppq` - The resolution of the file, in ticks per quarter note, from 1 to 32767
+    /// * `key` - The scale giving the neighbors used by the ornaments
+    /// * `params` - The settings of the realization of the ornaments
+    ///
+    /// # Returns
+    /// An error if the resolution is out of range, an ornament can't be expanded or the writer fails
+    pub fn write_smf_in<W: Write>(
+        &self,
+        writer: &mut W,
+        ppq: u16,
+        key: &impl ScaleLike,
+        params: OrnamentParams,
+    ) -> io::Result<()> {
+        let phrase = self
+            .realize(key, params)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error.to_string()))?;
+        let quarter = Rational::new(1, 4);
+        let mut events = Vec::with_capacity(2 * phrase.notes().len());
+
+        for timed in phrase.notes() {
+            let end = timed.onset + timed.duration;
+            events.push(MidiEvent::new(
+                ticks(timed.onset, quarter, ppq),
+                timed.note.note_on(0, VELOCITY),
+            ));
+            events.push(MidiEvent::new(
+                ticks(end, quarter, ppq),
+                timed.note.note_off(0),
+            ));
+        }
+
+        write_smf_events(writer, ppq, &events)
+    }
+}
+
+/// Returns the tick at which a point in time falls, rounded down
+pub(crate) fn ticks(time: Rational, quarter: Rational, ppq: u16) -> u32 {
+    (time / quarter * Rational::integer(u32::from(ppq))).floor()
+}
+
+/// Writes events, in time order, as a type 0 Standard MIDI File
+pub(crate) fn write_smf_events<W: Write>(
+    writer: &mut W,
+    ppq: u16,
+    events: &[MidiEvent],
+) -> io::Result<()> {
+    if ppq == 0 || ppq > MAX_PPQ {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid resolution {ppq}, expected 1..={MAX_PPQ} ticks per quarter note"),
+        ));
+    }
+
+    let mut track = Vec::new();
+    let mut last = 0;
+    for event in events {
+        let delta = event.tick.checked_sub(last).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "events are not in time order")
+        })?;
+        write_vlq(&mut track, delta)?;
+        track.extend_from_slice(&event.bytes);
+        last = event.tick;
+    }
+    write_vlq(&mut track, 0)?;
+    track.extend_from_slice(&[0xFF, 0x2F, 0x00]);
+
+    let length = u32::try_from(track.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "the track is too long"))?;
+
+    writer.write_all(b"MThd")?;
+    writer.write_all(&6u32.to_be_bytes())?;
+    writer.write_all(&0u16.to_be_bytes())?;
+    writer.write_all(&1u16.to_be_bytes())?;
+    writer.write_all(&ppq.to_be_bytes())?;
+    writer.write_all(b"MTrk")?;
+    writer.write_all(&length.to_be_bytes())?;
+    writer.write_all(&track)
+}
+
+/// Writes a number as a MIDI variable-length quantity
+///
+/// The number is split in groups of seven bits, most significant first, and
+/// every byte but the last one has its high bit set.
+pub(crate) fn write_vlq(bytes: &mut Vec<u8>, value: u32) -> io::Result<()> {
+    if value > MAX_VLQ {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("the delta time {value} does not fit in a variable-length quantity"),
+        ));
+    }
+
+    let mut shift = 21;
+    while shift > 0 && value >> shift == 0 {
+        shift -= 7;
+    }
+    while shift > 0 {
+        bytes.push(0x80 | (value >> shift & 0x7F) as u8);
+        shift -= 7;
+    }
+    bytes.push((value & 0x7F) as u8);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::{natural_minor_scale, Duration, Ornament};
+
+    fn vlq(value: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_vlq(&mut bytes, value).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_vlq() {
+        assert_eq!(vlq(0), vec![0x00]);
+        assert_eq!(vlq(0x40), vec![0x40]);
+        assert_eq!(vlq(0x7F), vec![0x7F]);
+        assert_eq!(vlq(0x80), vec![0x81, 0x00]);
+        assert_eq!(vlq(0x3FFF), vec![0xFF, 0x7F]);
+        assert_eq!(vlq(0x20_0000), vec![0x81, 0x80, 0x80, 0x00]);
+        assert_eq!(vlq(MAX_VLQ), vec![0xFF, 0xFF, 0xFF, 0x7F]);
+        assert!(write_vlq(&mut Vec::new(), MAX_VLQ + 1).is_err());
+    }
+
+    #[test]
+    fn test_write_smf() {
+        let melody = Melody::new([
+            (C4, Duration::Quarter),
+            (E4, Duration::Eighth),
+            (G4, Duration::Half),
+        ]);
+        let mut file = Vec::new();
+        melody.write_smf(&mut file, 96).unwrap();
+
+        // The header chunk: type 0, one track, 96 ticks per quarter note
+        assert_eq!(&file[..4], b"MThd");
+        assert_eq!(file[4..8], 6u32.to_be_bytes());
+        assert_eq!(file[8..14], [0, 0, 0, 1, 0, 96]);
+
+        // The track chunk holds exactly the rest of the file
+        assert_eq!(&file[14..18], b"MTrk");
+        let length = u32::from_be_bytes(file[18..22].try_into().unwrap()) as usize;
+        let track = &file[22..];
+        assert_eq!(track.len(), length);
+
+        #[rustfmt::skip]
+        let expected = [
+            0x00, 0x90, 60, VELOCITY,
+            0x60, 0x80, 60, 0,
+            0x00, 0x90, 64, VELOCITY,
+            0x30, 0x80, 64, 0,
+            0x00, 0x90, 67, VELOCITY,
+            0x81, 0x40, 0x80, 67, 0,
+            0x00, 0xFF, 0x2F, 0x00,
+        ];
+        assert_eq!(track, expected);
+    }
+
+    #[test]
+    fn test_write_ornaments() {
+        let melody = Melody::new([(E4, Duration::Quarter)])
+            .with_ornament(0, Ornament::UpperMordent)
+            .unwrap();
+        assert_eq!(
+            melody.write_smf(&mut Vec::new(), 96).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+        let mut file = Vec::new();
+        melody
+            .write_smf_in(
+                &mut file,
+                96,
+                &major_scale(C4).unwrap(),
+                OrnamentParams::default(),
+            )
+            .unwrap();
+
+        // E, F and E again: two thirty-second notes of 12 ticks then 72 ticks
+        #[rustfmt::skip]
+        let expected = [
+            0x00, 0x90, 64, VELOCITY,
+            0x0C, 0x80, 64, 0,
+            0x00, 0x90, 65, VELOCITY,
+            0x0C, 0x80, 65, 0,
+            0x00, 0x90, 64, VELOCITY,
+            0x48, 0x80, 64, 0,
+            0x00, 0xFF, 0x2F, 0x00,
+        ];
+        assert_eq!(&file[22..], expected);
+
+        let mut in_a_minor = Vec::new();
+        melody
+            .write_smf_in(
+                &mut in_a_minor,
+                96,
+                &natural_minor_scale(A3).unwrap(),
+                OrnamentParams::default(),
+            )
+            .unwrap();
+        assert_eq!(in_a_minor, file);
+
+        let out_of_range = Melody::new([(G9, Duration::Quarter)])
+            .with_ornament(0, Ornament::Turn)
+            .unwrap();
+        assert!(out_of_range
+            .write_smf_in(
+                &mut Vec::new(),
+                96,
+                &major_scale(C4).unwrap(),
+                OrnamentParams::default()
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_invalid_resolution() {
+        let melody = Melody::new([(C4, Duration::Quarter)]);
+        assert!(melody.write_smf(&mut Vec::new(), 0).is_err());
+        assert!(melody.write_smf(&mut Vec::new(), 0x8000).is_err());
+    }
+}
@@ -0,0 +1,8 @@
+//! Named presets saved by the user, such as progressions, voicings, scales and melodies
+//!
+//! The presets are kept in a `PresetStore`, which is saved to and loaded from a
+//! versioned JSON document.
+
+mod store;
+
+pub use store::*;
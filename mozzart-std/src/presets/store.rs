@@ -0,0 +1,405 @@
+use crate::{DynScale, Error, Melody, Progression, Voicing};
+use serde::{Deserialize, Serialize};
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The version of the layout of the preset documents
+///
+/// It is bumped whenever a field is renamed, removed or changes meaning, so
+/// documents written by a newer version are rejected instead of misread. Unknown
+/// fields are ignored, so adding new fields does not change the version.
+pub const PRESETS_SCHEMA_VERSION: u32 = 1;
+
+/// Represents what `PresetStore::merge` does when both stores have a preset with the same name
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum ConflictPolicy {
+    /// The preset already in the store is kept
+    KeepExisting,
+    /// The preset already in the store is replaced
+    Overwrite,
+    /// The incoming preset is added under a new name, such as `"blues (2)"`
+    Rename,
+}
+
+/// Represents the named presets of a user: progressions, voicings, scales and melodies
+///
+/// Each kind of preset has its own names, so a progression and a voicing may
+/// both be called `"blues"`. Progressions are written as the voicings of their
+/// chords.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let mut presets = PresetStore::new();
+/// presets.insert_voicing("shell", Voicing::new([C3, E3, B3]));
+/// presets.insert_scale("home", DynScale::from(major_scale(C4).unwrap()));
+///
+/// let loaded = PresetStore::from_json(&presets.to_json()).unwrap();
+/// assert_eq!(loaded, presets);
+/// assert_eq!(loaded.voicing("shell").unwrap().notes(), &[C3, E3, B3]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetStore {
+    progressions: BTreeMap<String, Progression>,
+    voicings: BTreeMap<String, Voicing>,
+    scales: BTreeMap<String, DynScale>,
+    melodies: BTreeMap<String, Melody>,
+}
+
+/// The version of a preset document, read before the rest of it
+#[derive(Deserialize)]
+struct Header {
+    schema_version: u32,
+}
+
+/// The layout of a preset document when it is written
+#[derive(Serialize)]
+struct DocumentRef<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    presets: &'a PresetStore,
+}
+
+/// The layout of a preset document when it is read
+#[derive(Deserialize)]
+struct Document {
+    #[serde(flatten)]
+    presets: PresetStore,
+}
+
+impl PresetStore {
+    /// Creates a new `PresetStore` without any preset
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the presets saved in a file
+    ///
+    /// # Arguments
+    /// * `path` - The path of the file
+    ///
+    /// # Returns
+    /// The presets, or `Error::PresetIo` if the file cannot be read, and the errors
+    /// of `from_json` if its content is not a valid preset document
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let json =
+            std::fs::read_to_string(path).map_err(|error| Error::PresetIo(error.to_string()))?;
+        Self::from_json(&json)
+    }
+
+    /// Saves the presets to a file, replacing its content
+    ///
+    /// # Arguments
+    /// * `path` - The path of the file
+    ///
+    /// # Returns
+    /// Nothing, or `Error::PresetIo` if the file cannot be written
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        std::fs::write(path, self.to_json()).map_err(|error| Error::PresetIo(error.to_string()))
+    }
+
+    /// Reads the presets from a preset document
+    ///
+    /// Fields unknown to this version of the library are ignored, so documents
+    /// written by a newer version of the same schema can still be read.
+    ///
+    /// # Arguments
+    /// * `json` - The preset document
+    ///
+    /// # Returns
+    /// The presets, `Error::UnsupportedPresetVersion` if the document has a newer
+    /// schema version, or `Error::InvalidPresets` if it cannot be read
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// let presets = PresetStore::from_json(r#"{ "schema_version": 1 }"#).unwrap();
+    /// assert_eq!(presets, PresetStore::new());
+    ///
+    /// assert_eq!(
+    ///     PresetStore::from_json(r#"{ "schema_version": 2 }"#),
+    ///     Err(Error::UnsupportedPresetVersion { found: 2, supported: 1 })
+    /// );
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let invalid = |error: serde_json::Error| Error::InvalidPresets(error.to_string());
+
+        let header: Header = serde_json::from_str(json).map_err(invalid)?;
+        if header.schema_version > PRESETS_SCHEMA_VERSION {
+            return Err(Error::UnsupportedPresetVersion {
+                found: header.schema_version,
+                supported: PRESETS_SCHEMA_VERSION,
+            });
+        }
+
+        let document: Document = serde_json::from_str(json).map_err(invalid)?;
+        Ok(document.presets)
+    }
+
+    /// Writes the presets as a preset document
+    ///
+    /// # Returns
+    /// The preset document, as pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        let document = DocumentRef {
+            schema_version: PRESETS_SCHEMA_VERSION,
+            presets: self,
+        };
+
+        serde_json::to_string_pretty(&document).expect("the presets are always serializable")
+    }
+
+    /// Adds the presets of another store to this one
+    ///
+    /// # Arguments
+    /// * `other` - The presets to add
+    /// * `policy` - What to do with a preset whose name is already used
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let mut presets = PresetStore::new();
+    /// presets.insert_voicing("shell", Voicing::new([C3, E3, B3]));
+    /// let mut other = PresetStore::new();
+    /// other.insert_voicing("shell", Voicing::new([C3, B3, E4]));
+    ///
+    /// presets.merge(other, ConflictPolicy::Rename);
+    /// assert_eq!(presets.voicing("shell (2)").unwrap().notes(), &[C3, B3, E4]);
+    /// ```
+    pub fn merge(&mut self, other: PresetStore, policy: ConflictPolicy) {
+        merge_presets(&mut self.progressions, other.progressions, policy);
+        merge_presets(&mut self.voicings, other.voicings, policy);
+        merge_presets(&mut self.scales, other.scales, policy);
+        merge_presets(&mut self.melodies, other.melodies, policy);
+    }
+
+    /// Saves a progression under a name
+    ///
+    /// # Returns
+    /// The progression previously saved under the name, if any
+    pub fn insert_progression(
+        &mut self,
+        name: impl Into<String>,
+        progression: Progression,
+    ) -> Option<Progression> {
+        self.progressions.insert(name.into(), progression)
+    }
+
+    /// Returns the progression saved under a name
+    pub fn progression(&self, name: &str) -> Option<&Progression> {
+        self.progressions.get(name)
+    }
+
+    /// Saves a voicing under a name
+    ///
+    /// # Returns
+    /// The voicing previously saved under the name, if any
+    pub fn insert_voicing(&mut self, name: impl Into<String>, voicing: Voicing) -> Option<Voicing> {
+        self.voicings.insert(name.into(), voicing)
+    }
+
+    /// Returns the voicing saved under a name
+    pub fn voicing(&self, name: &str) -> Option<&Voicing> {
+        self.voicings.get(name)
+    }
+
+    /// Saves a scale under a name
+    ///
+    /// # Returns
+    /// The scale previously saved under the name, if any
+    pub fn insert_scale(&mut self, name: impl Into<String>, scale: DynScale) -> Option<DynScale> {
+        self.scales.insert(name.into(), scale)
+    }
+
+    /// Returns the scale saved under a name
+    pub fn scale(&self, name: &str) -> Option<&DynScale> {
+        self.scales.get(name)
+    }
+
+    /// Saves a melody under a name
+    ///
+    /// # Returns
+    /// The melody previously saved under the name, if any
+    pub fn insert_melody(&mut self, name: impl Into<String>, melody: Melody) -> Option<Melody> {
+        self.melodies.insert(name.into(), melody)
+    }
+
+    /// Returns the melody saved under a name
+    pub fn melody(&self, name: &str) -> Option<&Melody> {
+        self.melodies.get(name)
+    }
+}
+
+/// Adds the presets of one kind to those of a store, resolving name conflicts with a policy
+fn merge_presets<T>(
+    presets: &mut BTreeMap<String, T>,
+    incoming: BTreeMap<String, T>,
+    policy: ConflictPolicy,
+) {
+    for (name, preset) in incoming {
+        match (presets.entry(name), policy) {
+            (Entry::Vacant(entry), _) => {
+                entry.insert(preset);
+            }
+            (Entry::Occupied(_), ConflictPolicy::KeepExisting) => {}
+            (Entry::Occupied(mut entry), ConflictPolicy::Overwrite) => {
+                entry.insert(preset);
+            }
+            (Entry::Occupied(entry), ConflictPolicy::Rename) => {
+                let name = entry.key().clone();
+                let renamed = (2..)
+                    .map(|copy| format!("{name} ({copy})"))
+                    .find(|renamed| !presets.contains_key(renamed))
+                    .expect("a store has fewer presets than there are copy numbers");
+                presets.insert(renamed, preset);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::*;
+
+    /// Returns a store with one preset of each kind
+    fn workspace() -> PresetStore {
+        let mut presets = PresetStore::new();
+        let c_major = major_scale(C4).unwrap();
+        let progression = Progression::from_roman(&c_major, &["I", "vi", "IV", "V"]).unwrap();
+        presets.insert_progression("pop", progression);
+        presets.insert_voicing("shell", Voicing::new([C3, E3, B3]));
+        presets.insert_scale("mode 3", messiaen_mode(3, C4).unwrap());
+        presets.insert_melody(
+            "opening",
+            Melody::new([(E4, Duration::Quarter), (D4, Duration::Half)]),
+        );
+        presets
+    }
+
+    /// Returns a path in the temporary directory, unique to this process
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("mozzart-{}-{name}.json", std::process::id()))
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let presets = workspace();
+        let loaded = PresetStore::from_json(&presets.to_json()).unwrap();
+        assert_eq!(loaded, presets);
+
+        assert_eq!(loaded.progression("pop").unwrap().len(), 4);
+        assert_eq!(
+            loaded.progression("pop").unwrap().chords()[1].notes(),
+            &[A4, C5, E5]
+        );
+        assert_eq!(
+            loaded.scale("mode 3").unwrap().to_string(),
+            "C messiaen mode 3"
+        );
+        assert_eq!(loaded.melody("opening").unwrap().len(), 2);
+        assert!(loaded.voicing("pop").is_none());
+    }
+
+    #[test]
+    fn test_every_scale_round_trips() {
+        let lydian = [2, 2, 2, 1, 2, 2, 1].map(Step::new);
+        let scales = [
+            Mode::Dorian.scale(D4).unwrap(),
+            messiaen_mode(2, C4).unwrap().transpose(3).unwrap(),
+            DynScale::from_steps("lydian", F4, &lydian).unwrap(),
+        ];
+
+        let mut presets = PresetStore::new();
+        for (index, scale) in scales.iter().enumerate() {
+            presets.insert_scale(index.to_string(), scale.clone());
+        }
+        let loaded = PresetStore::from_json(&presets.to_json()).unwrap();
+        assert_eq!(loaded, presets);
+        assert_eq!(loaded.scale("0").unwrap().to_string(), "D dorian");
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = temp_path("presets");
+        let presets = workspace();
+        presets.save(&path).unwrap();
+        let loaded = PresetStore::load(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, Ok(presets));
+        assert!(matches!(
+            PresetStore::load(temp_path("missing")),
+            Err(Error::PresetIo(_))
+        ));
+    }
+
+    #[test]
+    fn test_merge_policies() {
+        let mut incoming = PresetStore::new();
+        incoming.insert_voicing("shell", Voicing::new([C3, B3, E4]));
+        incoming.insert_voicing("spread", Voicing::new([C2, G3, E4]));
+
+        let mut kept = workspace();
+        kept.merge(incoming.clone(), ConflictPolicy::KeepExisting);
+        assert_eq!(kept.voicing("shell").unwrap().notes(), &[C3, E3, B3]);
+        assert!(kept.voicing("spread").is_some());
+
+        let mut overwritten = workspace();
+        overwritten.merge(incoming.clone(), ConflictPolicy::Overwrite);
+        assert_eq!(overwritten.voicing("shell").unwrap().notes(), &[C3, B3, E4]);
+
+        let mut renamed = workspace();
+        renamed.merge(incoming.clone(), ConflictPolicy::Rename);
+        renamed.merge(incoming, ConflictPolicy::Rename);
+        assert_eq!(renamed.voicing("shell").unwrap().notes(), &[C3, E3, B3]);
+        assert_eq!(renamed.voicing("shell (2)").unwrap().notes(), &[C3, B3, E4]);
+        assert!(renamed.voicing("shell (3)").is_some());
+        assert!(renamed.voicing("spread (2)").is_some());
+        // The other kinds of presets are untouched
+        assert!(renamed.progression("pop").is_some());
+    }
+
+    #[test]
+    fn test_unknown_fields_are_ignored() {
+        let json = r#"{
+            "schema_version": 1,
+            "voicings": { "shell": [48, 52, 59] },
+            "drum_kits": { "rock": [36, 38] }
+        }"#;
+
+        let presets = PresetStore::from_json(json).unwrap();
+        assert_eq!(presets.voicing("shell").unwrap().notes(), &[C3, E3, B3]);
+    }
+
+    #[test]
+    fn test_invalid_documents() {
+        assert_eq!(
+            PresetStore::from_json(r#"{ "schema_version": 7, "voicings": 3 }"#),
+            Err(Error::UnsupportedPresetVersion {
+                found: 7,
+                supported: PRESETS_SCHEMA_VERSION
+            })
+        );
+
+        for json in [
+            "{ \"schema_version\": 1, \"voic",
+            r#"{ "voicings": {} }"#,
+            r#"{ "schema_version": 1, "voicings": { "high": [200] } }"#,
+            r#"{ "schema_version": 1, "scales": { "x": { "name": "bogus", "notes": [60] } } }"#,
+        ] {
+            assert!(
+                matches!(PresetStore::from_json(json), Err(Error::InvalidPresets(_))),
+                "{json}"
+            );
+        }
+    }
+}
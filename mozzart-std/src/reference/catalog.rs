@@ -0,0 +1,327 @@
+use crate::chords::chord_suffix;
+use crate::constants::*;
+use crate::{
+    harmonic_minor_scale, major_scale, melodic_minor_scale, messiaen_mode, natural_minor_scale,
+    octatonic_scale, whole_tone_scale, ChordQuality, DynScale, Interval,
+};
+use serde::Serialize;
+
+/// The version of the layout of the exported catalog
+///
+/// It is bumped whenever a field is renamed, removed or changes meaning, so
+/// consumers can detect documents they do not understand. Adding new entries
+/// to the catalog does not change the version.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The chord qualities, with the name of the function that builds each of them
+const CHORDS: [(ChordQuality, &str); 28] = [
+    (ChordQuality::MajorTriad, "major_triad"),
+    (ChordQuality::MinorTriad, "minor_triad"),
+    (ChordQuality::DominantSeventh, "dominant_seventh"),
+    (ChordQuality::DominantSeventhNinth, "dominant_seventh_ninth"),
+    (ChordQuality::MinorSeventh, "minor_seventh"),
+    (ChordQuality::MinorSeventhNinth, "minor_seventh_ninth"),
+    (ChordQuality::MajorSeventh, "major_seventh"),
+    (ChordQuality::MinorMajorSeventh, "minor_major_seventh"),
+    (ChordQuality::MajorSixth, "major_sixth"),
+    (ChordQuality::MinorSixth, "minor_sixth"),
+    (ChordQuality::MajorSixthNinth, "major_sixth_ninth"),
+    (ChordQuality::MinorSixthNinth, "minor_sixth_ninth"),
+    (ChordQuality::Sus2, "sus2"),
+    (ChordQuality::Sus4, "sus4"),
+    (ChordQuality::DiminishedTriad, "diminished_triad"),
+    (ChordQuality::DiminishedSeventh, "diminished_seventh"),
+    (
+        ChordQuality::HalfDiminishedSeventh,
+        "half_diminished_seventh",
+    ),
+    (ChordQuality::AugmentedTriad, "augmented_triad"),
+    (ChordQuality::AugmentedSeventh, "augmented_seventh"),
+    (ChordQuality::DominantNinth, "dominant_ninth"),
+    (ChordQuality::MinorNinth, "minor_ninth"),
+    (ChordQuality::MajorNinth, "major_ninth"),
+    (ChordQuality::DominantEleventh, "dominant_eleventh"),
+    (ChordQuality::MinorEleventh, "minor_eleventh"),
+    (ChordQuality::MajorEleventh, "major_eleventh"),
+    (ChordQuality::DominantThirteenth, "dominant_thirteenth"),
+    (ChordQuality::MinorThirteenth, "minor_thirteenth"),
+    (ChordQuality::MajorThirteenth, "major_thirteenth"),
+];
+
+/// The names of the pitch classes, as used by the pitch constants
+const PITCH_CLASSES: [(&str, Option<&str>); 12] = [
+    ("C", None),
+    ("CSHARP", Some("DFLAT")),
+    ("D", None),
+    ("DSHARP", Some("EFLAT")),
+    ("E", None),
+    ("F", None),
+    ("FSHARP", Some("GFLAT")),
+    ("G", None),
+    ("GSHARP", Some("AFLAT")),
+    ("A", None),
+    ("ASHARP", Some("BFLAT")),
+    ("B", None),
+];
+
+/// The MIDI number of the lowest pitch constant, `C0`
+const LOWEST_PITCH: u8 = 12;
+
+/// The MIDI number of the highest pitch constant, `G9`
+const HIGHEST_PITCH: u8 = 127;
+
+/// Lists named interval constants along with their names
+macro_rules! named_intervals {
+    ($($name:ident),* $(,)?) => {
+        [$((stringify!($name), &$name)),*]
+    };
+}
+
+#[derive(Serialize)]
+struct Catalog {
+    schema_version: u32,
+    scales: Vec<ScaleEntry>,
+    chords: Vec<ChordEntry>,
+    intervals: Vec<IntervalEntry>,
+    pitches: Vec<PitchEntry>,
+}
+
+#[derive(Serialize)]
+struct ScaleEntry {
+    name: String,
+    steps: Vec<u8>,
+    intervals: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct ChordEntry {
+    quality: ChordQuality,
+    constructor: &'static str,
+    symbol: &'static str,
+    intervals: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct IntervalEntry {
+    name: &'static str,
+    semitones: u8,
+}
+
+#[derive(Serialize)]
+struct PitchEntry {
+    name: String,
+    midi: u8,
+}
+
+/// Exports the musical definitions of the library as a JSON document
+///
+/// The document has a top-level `schema_version` field (see `SCHEMA_VERSION`)
+/// and four lists:
+/// - `scales`: every scale constructor, the Messiaen modes included, with its steps and the cumulative intervals from the root
+/// - `chords`: every chord quality, with its constructor, its symbol and its intervals from the root
+/// - `intervals`: every named interval constant, with its number of semitones
+/// - `pitches`: every pitch constant with an octave number, from `C0` to `G9`, with its MIDI number
+///
+/// # Returns
+/// The catalog, as pretty-printed JSON
+///
+/// # Examples
+/// ```
+/// use mozzart_std::reference::*;
+///
+/// let json = export_json();
+/// assert!(json.contains("\"schema_version\": 1"));
+/// assert!(json.contains("\"symbol\": \"maj7\""));
+/// ```
+pub fn export_json() -> String {
+    let catalog = Catalog {
+        schema_version: SCHEMA_VERSION,
+        scales: scales(),
+        chords: chords(),
+        intervals: intervals(),
+        pitches: pitches(),
+    };
+
+    serde_json::to_string_pretty(&catalog).expect("the catalog is always serializable")
+}
+
+fn scales() -> Vec<ScaleEntry> {
+    let in_range = "scales on C4 are in the MIDI range";
+    let fixed = [
+        DynScale::from(major_scale(C4).expect(in_range)),
+        DynScale::from(natural_minor_scale(C4).expect(in_range)),
+        DynScale::from(harmonic_minor_scale(C4).expect(in_range)),
+        DynScale::from(melodic_minor_scale(C4).expect(in_range)),
+        DynScale::from(whole_tone_scale(C4).expect(in_range)),
+        DynScale::from(octatonic_scale(C4).expect(in_range)),
+    ];
+    // Every mode up to the first number messiaen_mode doesn't know
+    let messiaen = (1..).map_while(|mode| messiaen_mode(mode, C4));
+
+    fixed.into_iter().chain(messiaen).map(scale).collect()
+}
+
+fn scale(scale: DynScale) -> ScaleEntry {
+    let steps: Vec<_> = scale.steps().iter().map(Interval::semitones).collect();
+    let intervals = steps
+        .iter()
+        .scan(0, |total, step| {
+            *total += step;
+            Some(*total)
+        })
+        .collect();
+
+    ScaleEntry {
+        name: scale.name().to_string(),
+        steps,
+        intervals,
+    }
+}
+
+fn chords() -> Vec<ChordEntry> {
+    CHORDS
+        .iter()
+        .map(|&(quality, constructor)| ChordEntry {
+            quality,
+            constructor,
+            symbol: chord_suffix(quality),
+            intervals: quality
+                .intervals()
+                .iter()
+                .map(Interval::semitones)
+                .collect(),
+        })
+        .collect()
+}
+
+fn intervals() -> Vec<IntervalEntry> {
+    named_intervals![
+        PERFECT_UNISON,
+        MINOR_SECOND,
+        MAJOR_SECOND,
+        MINOR_THIRD,
+        MAJOR_THIRD,
+        PERFECT_FOURTH,
+        AUGMENTED_FOURTH,
+        DIMINISHED_FIFTH,
+        PERFECT_FIFTH,
+        AUGMENTED_FIFTH,
+        DIMINISHED_SIXTH,
+        MINOR_SIXTH,
+        MAJOR_SIXTH,
+        MINOR_SEVENTH,
+        MAJOR_SEVENTH,
+        PERFECT_OCTAVE,
+        MINOR_NINTH,
+        MAJOR_NINTH,
+        MINOR_TENTH,
+        MAJOR_TENTH,
+        PERFECT_ELEVENTH,
+        AUGMENTED_ELEVENTH,
+        DIMINISHED_TWELFTH,
+        PERFECT_TWELFTH,
+        AUGMENTED_TWELFTH,
+        DIMINISHED_THIRTEENTH,
+        MINOR_THIRTEENTH,
+        MAJOR_THIRTEENTH,
+        MINOR_FOURTEENTH,
+        MAJOR_FOURTEENTH,
+        DOUBLE_OCTAVE,
+        MINOR_SIXTEENTH,
+        MAJOR_SIXTEENTH,
+        MINOR_SEVENTEENTH,
+        MAJOR_SEVENTEENTH,
+        PERFECT_EIGHTEENTH,
+        AUGMENTED_EIGHTEENTH,
+        DIMINISHED_NINETEENTH,
+        PERFECT_NINETEENTH,
+        MINOR_TWENTIETH,
+        MAJOR_TWENTIETH,
+        PERFECT_TWENTY_FIRST,
+        TRIPLE_OCTAVE,
+    ]
+    .iter()
+    .map(|(name, interval)| IntervalEntry {
+        name,
+        semitones: interval.semitones(),
+    })
+    .collect()
+}
+
+fn pitches() -> Vec<PitchEntry> {
+    (LOWEST_PITCH..=HIGHEST_PITCH)
+        .flat_map(|midi| {
+            let (sharp, flat) = PITCH_CLASSES[usize::from(midi % SEMITONES_IN_OCTAVE)];
+            let octave = midi / SEMITONES_IN_OCTAVE - 1;
+            std::iter::once(sharp)
+                .chain(flat)
+                .map(move |name| PitchEntry {
+                    name: format!("{name}{octave}"),
+                    midi,
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The committed snapshot of the catalog
+    ///
+    /// Run the tests with `UPDATE_SNAPSHOT=1` to regenerate it after an intended change.
+    const SNAPSHOT: &str = include_str!("catalog.json");
+
+    #[test]
+    fn test_export_json_matches_snapshot() {
+        let json = export_json();
+        if std::env::var_os("UPDATE_SNAPSHOT").is_some() {
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/reference/catalog.json");
+            std::fs::write(path, &json).unwrap();
+            return;
+        }
+
+        assert!(
+            json == SNAPSHOT.trim_end(),
+            "the catalog differs from src/reference/catalog.json; \
+             if the change is intended, rerun the tests with UPDATE_SNAPSHOT=1"
+        );
+    }
+
+    #[test]
+    fn test_pitches_match_constants() {
+        let pitches = pitches();
+        let midi = |name: &str| pitches.iter().find(|p| p.name == name).unwrap().midi;
+
+        assert_eq!(midi("C0"), C0.midi_number());
+        assert_eq!(midi("C4"), C4.midi_number());
+        assert_eq!(midi("BFLAT3"), BFLAT3.midi_number());
+        assert_eq!(midi("FSHARP7"), FSHARP7.midi_number());
+        assert_eq!(midi("G9"), G9.midi_number());
+        assert_eq!(pitches.len(), 164);
+    }
+
+    #[test]
+    fn test_scale_intervals_are_cumulative() {
+        let major = &scales()[0];
+        assert_eq!(major.name, "major");
+        assert_eq!(major.steps, vec![2, 2, 1, 2, 2, 2, 1]);
+        assert_eq!(major.intervals, vec![2, 4, 5, 7, 9, 11, 12]);
+    }
+
+    #[test]
+    fn test_scales_include_every_messiaen_mode() {
+        let scales = scales();
+        for mode in 1..=7 {
+            let expected = messiaen_mode(mode, C4).unwrap();
+            let entry = scales
+                .iter()
+                .find(|entry| entry.name == expected.name())
+                .unwrap();
+            let steps: Vec<_> = expected.steps().iter().map(Interval::semitones).collect();
+            assert_eq!(entry.steps, steps, "{}", entry.name);
+        }
+        assert_eq!(messiaen_mode(8, C4), None);
+        assert_eq!(scales.len(), 13);
+    }
+}
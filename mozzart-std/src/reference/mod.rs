@@ -0,0 +1,9 @@
+//! Machine-readable reference data for the mozzart-std library
+//!
+//! This module exports the musical definitions of the library (scales, chords,
+//! intervals and pitch constants) as a single JSON document, so documentation
+//! and web tools can use them without scraping the doc comments.
+
+mod catalog;
+
+pub use catalog::*;
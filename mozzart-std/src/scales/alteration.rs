@@ -0,0 +1,307 @@
+use crate::{
+    Error, HarmonicMinorScaleQuality, Interval, MajorScaleQuality, MelodicMinorScaleQuality,
+    MinorScaleQuality, Note, Scale, ScaleQuality,
+};
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+/// Represents the alteration of a single scale degree
+///
+/// Degrees are numbered from 1 (the tonic) to 8 (the octave).
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let harmonic = harmonic_minor_scale(A4).unwrap();
+/// let natural = natural_minor_scale(A4).unwrap();
+/// assert_eq!(harmonic.alterations_from(&natural), vec![Alteration::Raised(7)]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Alteration {
+    /// The degree is a semitone lower
+    Flattened(u8),
+    /// The degree is a semitone higher
+    Raised(u8),
+}
+
+/// Represents the quality of a scale with one or more altered degrees
+///
+/// The type parameter keeps track of the original quality, so a C major scale
+/// with a flattened seventh is a `Scale<AlteredScaleQuality<MajorScaleQuality>, 8>`.
+/// Altering an already altered scale doesn't nest the quality: the C major scale
+/// with a flattened third and seventh has the same type.
+pub struct AlteredScaleQuality<Q>(PhantomData<Q>);
+
+/// Defines the scale qualities whose degrees can be altered
+///
+/// The trait is sealed: it is implemented for the major, natural minor, harmonic
+/// minor and melodic minor qualities, and for their altered qualities.
+pub trait AlterableQuality: ScaleQuality + sealed::Sealed {
+    /// The unaltered quality, `Self` for the diatonic qualities
+    type Original: AlterableQuality;
+
+    /// Returns the name of the quality once altered, such as `altered major`
+    fn altered_name() -> &'static str;
+}
+
+mod sealed {
+    /// Keeps `AlterableQuality` from being implemented outside of the crate
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for MajorScaleQuality {}
+impl AlterableQuality for MajorScaleQuality {
+    type Original = Self;
+
+    fn altered_name() -> &'static str {
+        "altered major"
+    }
+}
+
+impl sealed::Sealed for MinorScaleQuality {}
+impl AlterableQuality for MinorScaleQuality {
+    type Original = Self;
+
+    fn altered_name() -> &'static str {
+        "altered minor"
+    }
+}
+
+impl sealed::Sealed for HarmonicMinorScaleQuality {}
+impl AlterableQuality for HarmonicMinorScaleQuality {
+    type Original = Self;
+
+    fn altered_name() -> &'static str {
+        "altered harmonic minor"
+    }
+}
+
+impl sealed::Sealed for MelodicMinorScaleQuality {}
+impl AlterableQuality for MelodicMinorScaleQuality {
+    type Original = Self;
+
+    fn altered_name() -> &'static str {
+        "altered melodic minor"
+    }
+}
+
+impl<Q> ScaleQuality for AlteredScaleQuality<Q>
+where
+    Q: AlterableQuality,
+{
+    fn name() -> &'static str {
+        Q::altered_name()
+    }
+}
+
+impl<Q> sealed::Sealed for AlteredScaleQuality<Q> where Q: AlterableQuality {}
+impl<Q> AlterableQuality for AlteredScaleQuality<Q>
+where
+    Q: AlterableQuality,
+{
+    type Original = Q::Original;
+
+    fn altered_name() -> &'static str {
+        Q::altered_name()
+    }
+}
+
+impl<Q> Scale<Q, 8>
+where
+    Q: AlterableQuality,
+{
+    /// Returns a copy of the scale with one degree lowered by a semitone
+    ///
+    /// # Arguments
+    /// * `degree` - The degree to alter, from 2 to 7
+    ///
+    /// # Returns
+    /// The altered scale, or `Error::InvalidScaleDegree` if the degree is the tonic,
+    /// the octave, outside of the scale, or would collide with the degree below
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let mixolydian = major_scale(C4).unwrap().with_flattened(7).unwrap();
+    /// assert_eq!(mixolydian.notes()[6], ASHARP4);
+    /// assert_eq!(mixolydian.to_string(), "C altered major");
+    /// ```
+    pub fn with_flattened(
+        &self,
+        degree: u8,
+    ) -> Result<Scale<AlteredScaleQuality<Q::Original>, 8>, Error> {
+        self.alter(degree, false)
+    }
+
+    /// Returns a copy of the scale with one degree raised by a semitone
+    ///
+    /// # Arguments
+    /// * `degree` - The degree to alter, from 2 to 7
+    ///
+    /// # Returns
+    /// The altered scale, or `Error::InvalidScaleDegree` if the degree is the tonic,
+    /// the octave, outside of the scale, or would collide with the degree above
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let lydian = major_scale(C4).unwrap().with_raised(4).unwrap();
+    /// assert_eq!(lydian.notes()[3], FSHARP4);
+    /// ```
+    pub fn with_raised(
+        &self,
+        degree: u8,
+    ) -> Result<Scale<AlteredScaleQuality<Q::Original>, 8>, Error> {
+        self.alter(degree, true)
+    }
+
+    /// Moves a degree by a semitone, checking it stays between its neighbors
+    fn alter(
+        &self,
+        degree: u8,
+        raise: bool,
+    ) -> Result<Scale<AlteredScaleQuality<Q::Original>, 8>, Error> {
+        if !(2..=7).contains(&degree) {
+            return Err(Error::InvalidScaleDegree(degree));
+        }
+
+        let mut notes = *self.notes();
+        let index = (degree - 1) as usize;
+        let semitone = Interval::new(1);
+        notes[index] = if raise {
+            notes[index] + semitone
+        } else {
+            notes[index] - semitone
+        };
+
+        if notes[index] <= notes[index - 1] || notes[index] >= notes[index + 1] {
+            return Err(Error::InvalidScaleDegree(degree));
+        }
+
+        Ok(Scale::new(notes))
+    }
+}
+
+impl<Q> Scale<Q, 8>
+where
+    Q: ScaleQuality,
+{
+    /// Returns how the degrees of this scale differ from the degrees of another scale
+    ///
+    /// Each degree is compared by its distance from the root of its own scale, so
+    /// scales built on different tonics are compared by their shape.
+    ///
+    /// # Arguments
+    /// * `other` - The scale used as the reference
+    ///
+    /// # Returns
+    /// The alterations that turn `other` into this scale, ordered by degree
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let alterations = major_scale(C4).unwrap().alterations_from(&natural_minor_scale(A4).unwrap());
+    /// assert_eq!(
+    ///     alterations,
+    ///     vec![Alteration::Raised(3), Alteration::Raised(6), Alteration::Raised(7)]
+    /// );
+    /// ```
+    pub fn alterations_from<P>(&self, other: &Scale<P, 8>) -> Vec<Alteration>
+    where
+        P: ScaleQuality,
+    {
+        let offsets = |notes: &[Note; 8]| notes.map(|n| n.midi_number() - notes[0].midi_number());
+        let (ours, theirs) = (offsets(self.notes()), offsets(other.notes()));
+
+        (1..8u8)
+            .filter_map(|degree| {
+                let index = (degree - 1) as usize;
+                match ours[index].cmp(&theirs[index]) {
+                    Ordering::Greater => Some(Alteration::Raised(degree)),
+                    Ordering::Less => Some(Alteration::Flattened(degree)),
+                    Ordering::Equal => None,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::*;
+
+    #[test]
+    fn test_flattened_major_is_natural_minor() {
+        let c_major = major_scale(C4).unwrap();
+        // The quality stays the one of the major scale, however often it is altered
+        let altered: Scale<AlteredScaleQuality<MajorScaleQuality>, 8> = c_major
+            .with_flattened(3)
+            .and_then(|s| s.with_flattened(6))
+            .and_then(|s| s.with_flattened(7))
+            .unwrap();
+
+        assert_eq!(altered.notes(), natural_minor_scale(C4).unwrap().notes());
+        assert_eq!(altered.steps(), natural_minor_scale(C4).unwrap().steps());
+        assert_eq!(format!("{altered}"), "C altered major");
+    }
+
+    #[test]
+    fn test_invalid_degrees() {
+        let c_major = major_scale(C4).unwrap();
+        for degree in [0, 1, 8, 9] {
+            assert_eq!(
+                c_major.with_flattened(degree).unwrap_err(),
+                Error::InvalidScaleDegree(degree)
+            );
+            assert_eq!(
+                c_major.with_raised(degree).unwrap_err(),
+                Error::InvalidScaleDegree(degree)
+            );
+        }
+
+        // F is only a semitone above E, so it can't be flattened
+        assert_eq!(
+            c_major.with_flattened(4).unwrap_err(),
+            Error::InvalidScaleDegree(4)
+        );
+    }
+
+    #[test]
+    fn test_alterations_from() {
+        let harmonic = harmonic_minor_scale(C4).unwrap();
+        let natural = natural_minor_scale(C4).unwrap();
+        assert_eq!(
+            harmonic.alterations_from(&natural),
+            vec![Alteration::Raised(7)]
+        );
+        assert_eq!(
+            natural.alterations_from(&harmonic),
+            vec![Alteration::Flattened(7)]
+        );
+        assert!(natural.alterations_from(&natural).is_empty());
+    }
+
+    #[test]
+    fn test_borrowed_chords() {
+        let c_major = major_scale(C4).unwrap();
+        let mixture = c_major.with_flattened(7).unwrap();
+        let progression = Progression::from_roman(&mixture, &["I", "VII", "IV", "I"]).unwrap();
+
+        // bVII is realized from the altered seventh degree
+        assert_eq!(progression.chords()[1].notes(), &[ASHARP4, D5, F5]);
+        assert_eq!(
+            progression.roman_numerals(&c_major),
+            vec!["I", "bVII", "IV", "I"]
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use crate::chords::avoid_notes_over;
+use crate::constants::*;
+use crate::{
+    major_scale, natural_minor_scale, Alteration, Note, PitchClassMask, Scale, ScaleQuality,
+};
+
+impl<Q> Scale<Q, 8>
+where
+    Q: ScaleQuality,
+{
+    /// Returns the degrees which give this scale its color
+    ///
+    /// The scale is compared with the major scale on the same tonic when its
+    /// third is major, and with the natural minor scale otherwise. The degrees
+    /// which differ are the ones to bring out when writing in a mode, such as
+    /// the raised fourth of lydian or the raised seventh of harmonic minor.
+    ///
+    /// # Returns
+    /// The altered degrees, from 2 to 7, in ascending order; empty for the major
+    /// and natural minor scales themselves
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let lydian = major_scale(C4).unwrap().with_raised(4).unwrap();
+    /// assert_eq!(lydian.characteristic_degrees(), vec![4]);
+    /// let dorian = natural_minor_scale(D4).unwrap().with_raised(6).unwrap();
+    /// assert_eq!(dorian.characteristic_degrees(), vec![6]);
+    /// assert!(major_scale(C4).unwrap().characteristic_degrees().is_empty());
+    /// ```
+    pub fn characteristic_degrees(&self) -> Vec<u8> {
+        let root = self.root();
+        let third = self.notes()[2].midi_number() - root.midi_number();
+        // The scale reaches the octave of its root, so does its unaltered scale
+        let in_range = "the scale spans the octave above its root";
+        let alterations = if third == MAJOR_THIRD.semitones() {
+            self.alterations_from(&major_scale(root).expect(in_range))
+        } else {
+            self.alterations_from(&natural_minor_scale(root).expect(in_range))
+        };
+
+        alterations
+            .into_iter()
+            .map(|alteration| match alteration {
+                Alteration::Flattened(degree) | Alteration::Raised(degree) => degree,
+            })
+            .collect()
+    }
+
+    /// Returns the notes of the degrees which give this scale its color
+    ///
+    /// # Returns
+    /// The notes of the scale at the `characteristic_degrees`, in ascending order
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let mixolydian = major_scale(G3).unwrap().with_flattened(7).unwrap();
+    /// assert_eq!(mixolydian.characteristic_pitches(), vec![F4]);
+    /// ```
+    pub fn characteristic_pitches(&self) -> Vec<Note> {
+        self.characteristic_degrees()
+            .into_iter()
+            .map(|degree| self.notes()[usize::from(degree - 1)])
+            .collect()
+    }
+
+    /// Returns `true` if a degree is an avoid note over the tonic seventh chord
+    ///
+    /// The tonic seventh chord stacks the first, third, fifth and seventh degrees
+    /// of the scale, and a degree is avoided when it lies a half step above one
+    /// of them, see `avoid_notes`.
+    ///
+    /// # Arguments
+    /// * `degree` - The degree to check, from 1 to 8
+    ///
+    /// # Returns
+    /// `true` if the degree is an avoid note, `false` otherwise or if the degree is outside of the scale
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// // F clashes with E over C major seventh, F# doesn't
+    /// assert!(major_scale(C4).unwrap().is_avoid_degree(4));
+    /// assert!(!major_scale(C4).unwrap().with_raised(4).unwrap().is_avoid_degree(4));
+    /// ```
+    pub fn is_avoid_degree(&self, degree: u8) -> bool {
+        if !(1..=8).contains(&degree) {
+            return false;
+        }
+
+        let notes = self.notes();
+        let tonic_chord = [notes[0], notes[2], notes[4], notes[6]];
+        PitchClassMask::from_notes(&avoid_notes_over(&tonic_chord, self))
+            .contains(notes[usize::from(degree - 1)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::*;
+    use crate::*;
+
+    #[test]
+    fn test_modes_of_c() {
+        let major = major_scale(C4).unwrap();
+        let minor = natural_minor_scale(C4).unwrap();
+
+        assert!(major.characteristic_degrees().is_empty());
+        assert_eq!(
+            major.with_flattened(7).unwrap().characteristic_degrees(),
+            vec![7]
+        );
+        assert_eq!(
+            major.with_raised(4).unwrap().characteristic_degrees(),
+            vec![4]
+        );
+        assert!(minor.characteristic_degrees().is_empty());
+        assert_eq!(
+            minor.with_raised(6).unwrap().characteristic_degrees(),
+            vec![6]
+        );
+        assert_eq!(
+            minor.with_flattened(2).unwrap().characteristic_degrees(),
+            vec![2]
+        );
+
+        let locrian = minor.with_flattened(2).unwrap().with_flattened(5).unwrap();
+        assert_eq!(
+            locrian.notes(),
+            &[C4, CSHARP4, DSHARP4, F4, FSHARP4, GSHARP4, ASHARP4, C5]
+        );
+        assert_eq!(locrian.characteristic_degrees(), vec![2, 5]);
+        assert_eq!(locrian.characteristic_pitches(), vec![CSHARP4, FSHARP4]);
+    }
+
+    #[test]
+    fn test_minor_variants() {
+        assert!(natural_minor_scale(A3)
+            .unwrap()
+            .characteristic_degrees()
+            .is_empty());
+        assert_eq!(
+            harmonic_minor_scale(A3).unwrap().characteristic_degrees(),
+            vec![7]
+        );
+        assert_eq!(
+            harmonic_minor_scale(A3).unwrap().characteristic_pitches(),
+            vec![GSHARP4]
+        );
+        assert_eq!(
+            melodic_minor_scale(A3).unwrap().characteristic_degrees(),
+            vec![6, 7]
+        );
+        assert_eq!(
+            melodic_minor_scale(A3).unwrap().characteristic_pitches(),
+            vec![FSHARP4, GSHARP4]
+        );
+    }
+
+    #[test]
+    fn test_avoid_degrees() {
+        fn avoid_degrees<Q: ScaleQuality>(scale: &Scale<Q, 8>) -> Vec<u8> {
+            (1..=8)
+                .filter(|&degree| scale.is_avoid_degree(degree))
+                .collect()
+        }
+
+        assert_eq!(avoid_degrees(&major_scale(C4).unwrap()), vec![4]);
+        assert_eq!(avoid_degrees(&natural_minor_scale(C4).unwrap()), vec![6]);
+        assert!(
+            avoid_degrees(&natural_minor_scale(C4).unwrap().with_raised(6).unwrap()).is_empty()
+        );
+        assert_eq!(avoid_degrees(&harmonic_minor_scale(C4).unwrap()), vec![6]);
+        assert!(!major_scale(C4).unwrap().is_avoid_degree(0));
+        assert!(!major_scale(C4).unwrap().is_avoid_degree(9));
+    }
+}
@@ -0,0 +1,250 @@
+use crate::constants::*;
+use crate::{Interval, Scale, ScaleLike, ScaleQuality, SpelledNote, TranspositionDirection};
+use std::fmt;
+
+/// Represents what changes between two scales
+///
+/// The notes are compared as pitch classes and spelled as in their scale, see
+/// `Scale::note_names`. Notes on the same letter in both scales are reported as
+/// an altered note, such as F becoming F# from C major to G major.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let diff = scale_diff(&major_scale(C4).unwrap(), &major_scale(G4).unwrap());
+/// assert_eq!(
+///     diff.to_string(),
+///     "C major → G major: F becomes F#; 6 common tones; transposition: up P5"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ScaleDiff {
+    from: String,
+    to: String,
+    only_in_first: Vec<SpelledNote>,
+    only_in_second: Vec<SpelledNote>,
+    common: Vec<SpelledNote>,
+    transposition: Option<(TranspositionDirection, Interval)>,
+}
+
+impl ScaleDiff {
+    /// Returns the notes of the first scale missing from the second one, spelled as in the first scale
+    pub fn only_in_first(&self) -> &[SpelledNote] {
+        &self.only_in_first
+    }
+
+    /// Returns the notes of the second scale missing from the first one, spelled as in the second scale
+    pub fn only_in_second(&self) -> &[SpelledNote] {
+        &self.only_in_second
+    }
+
+    /// Returns the notes of both scales, spelled as in the first scale
+    pub fn common(&self) -> &[SpelledNote] {
+        &self.common
+    }
+
+    /// Returns how far the second scale is transposed from the first one
+    ///
+    /// # Returns
+    /// The direction and the interval between the tonics, or `None` if the
+    /// second scale is not the first one transposed
+    pub fn transposition(&self) -> Option<(TranspositionDirection, Interval)> {
+        self.transposition
+    }
+
+    /// Returns the notes which stay on their letter with another accidental, and the others
+    ///
+    /// # Returns
+    /// The pairs of altered notes, then the other removed and added notes
+    fn alterations(
+        &self,
+    ) -> (
+        Vec<(SpelledNote, SpelledNote)>,
+        Vec<SpelledNote>,
+        Vec<SpelledNote>,
+    ) {
+        let mut added = self.only_in_second.clone();
+        let mut altered = Vec::new();
+        let mut removed = Vec::new();
+
+        for &old in &self.only_in_first {
+            match added.iter().position(|new| new.letter() == old.letter()) {
+                Some(index) => altered.push((old, added.remove(index))),
+                None => removed.push(old),
+            }
+        }
+
+        (altered, removed, added)
+    }
+}
+
+impl fmt::Display for ScaleDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} → {}: ", self.from, self.to)?;
+
+        let (altered, removed, added) = self.alterations();
+        let changes: Vec<_> = altered
+            .iter()
+            .map(|(old, new)| format!("{old} becomes {new}"))
+            .chain(removed.iter().map(|old| format!("{old} removed")))
+            .chain(added.iter().map(|new| format!("{new} added")))
+            .collect();
+        if !changes.is_empty() {
+            write!(f, "{}; ", changes.join(", "))?;
+        }
+
+        write!(f, "{} common tones; ", self.common.len())?;
+        match self.transposition {
+            Some((direction, interval)) => {
+                let direction = match direction {
+                    TranspositionDirection::Up => "up",
+                    TranspositionDirection::Down => "down",
+                };
+                match interval.name() {
+                    Some(name) => write!(f, "transposition: {direction} {name}"),
+                    None => write!(
+                        f,
+                        "transposition: {direction} {} semitones",
+                        interval.semitones()
+                    ),
+                }
+            }
+            None => write!(f, "no transposition"),
+        }
+    }
+}
+
+/// Compares two heptatonic scales
+///
+/// The second scale is a transposition of the first one when a rotation of the
+/// pitch classes of the first scale gives those of the second one and maps the
+/// tonic onto the tonic. A mode, such as A minor from C major, has the same notes
+/// but is not a transposition.
+///
+/// # Arguments
+/// * `a` - The first scale
+/// * `b` - The second scale
+///
+/// # Returns
+/// The notes only in each scale, the common notes and the transposition, if any
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let diff = scale_diff(&major_scale(C4).unwrap(), &natural_minor_scale(A3).unwrap());
+/// assert_eq!(diff.common().len(), 7);
+/// assert_eq!(diff.transposition(), None);
+/// ```
+pub fn scale_diff<Q, R>(a: &Scale<Q, 8>, b: &Scale<R, 8>) -> ScaleDiff
+where
+    Q: ScaleQuality,
+    R: ScaleQuality,
+{
+    let (a_names, b_names) = (a.note_names(), b.note_names());
+    let (a_mask, b_mask) = (a.pitch_class_mask(), b.pitch_class_mask());
+    let contains = |names: &[SpelledNote], note: &SpelledNote| {
+        names
+            .iter()
+            .any(|other| other.pitch_class() == note.pitch_class())
+    };
+
+    let tonics = i16::from(b.root().midi_number()) - i16::from(a.root().midi_number());
+    let shift = tonics.rem_euclid(i16::from(SEMITONES_IN_OCTAVE)) as u8;
+    let transposition = (a_mask.transpose(shift) == b_mask).then(|| {
+        let direction = if tonics < 0 {
+            TranspositionDirection::Down
+        } else {
+            TranspositionDirection::Up
+        };
+        (direction, Interval::new(tonics.unsigned_abs() as u8))
+    });
+
+    ScaleDiff {
+        from: a.to_string(),
+        to: b.to_string(),
+        only_in_first: a_names
+            .iter()
+            .filter(|note| !contains(&b_names, note))
+            .copied()
+            .collect(),
+        only_in_second: b_names
+            .iter()
+            .filter(|note| !contains(&a_names, note))
+            .copied()
+            .collect(),
+        common: a_names
+            .iter()
+            .filter(|note| contains(&b_names, note))
+            .copied()
+            .collect(),
+        transposition,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn names(notes: &[SpelledNote]) -> Vec<String> {
+        notes.iter().map(|note| note.to_string()).collect()
+    }
+
+    #[test]
+    fn test_relative_minor() {
+        let diff = scale_diff(&major_scale(C4).unwrap(), &natural_minor_scale(A3).unwrap());
+        assert_eq!(diff.common().len(), 7);
+        assert!(diff.only_in_first().is_empty());
+        assert!(diff.only_in_second().is_empty());
+        assert_eq!(diff.transposition(), None);
+        assert_eq!(
+            diff.to_string(),
+            "C major → A minor: 7 common tones; no transposition"
+        );
+    }
+
+    #[test]
+    fn test_transposition_up_a_major_second() {
+        let diff = scale_diff(&major_scale(C4).unwrap(), &major_scale(D4).unwrap());
+        assert_eq!(
+            diff.transposition(),
+            Some((TranspositionDirection::Up, MAJOR_SECOND))
+        );
+        assert_eq!(names(diff.only_in_first()), ["C", "F"]);
+        assert_eq!(names(diff.only_in_second()), ["F#", "C#"]);
+        assert_eq!(
+            diff.to_string(),
+            "C major → D major: C becomes C#, F becomes F#; 5 common tones; transposition: up M2"
+        );
+    }
+
+    #[test]
+    fn test_other_changes() {
+        let diff = scale_diff(&major_scale(C4).unwrap(), &major_scale(F3).unwrap());
+        assert_eq!(
+            diff.to_string(),
+            "C major → F major: B becomes Bb; 6 common tones; transposition: down P5"
+        );
+
+        let diff = scale_diff(
+            &major_scale(A3).unwrap(),
+            &harmonic_minor_scale(A3).unwrap(),
+        );
+        assert_eq!(diff.transposition(), None);
+        assert_eq!(
+            diff.to_string(),
+            "A major → A harmonic minor: C# becomes C, F# becomes F; 5 common tones; no transposition"
+        );
+
+        // The same scale is its own transposition by a unison
+        let diff = scale_diff(&major_scale(E4).unwrap(), &major_scale(E4).unwrap());
+        assert_eq!(
+            diff.to_string(),
+            "E major → E major: 7 common tones; transposition: up P1"
+        );
+    }
+}
@@ -0,0 +1,588 @@
+use crate::constants::*;
+use crate::{
+    harmonic_minor_scale, major_scale, melodic_minor_scale, natural_minor_scale, octatonic_scale,
+    pitch_range, whole_tone_scale, Error, Interval, Note, NoteSlice, PitchClassMask, Scale,
+    ScaleLike, ScaleQuality, Step,
+};
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents a scale whose quality and number of notes are only known at runtime
+///
+/// `Scale` encodes its quality and size in its type, which is ideal for the
+/// common diatonic scales. `DynScale` is used where a single function returns
+/// scales of different sizes, such as Messiaen's modes of limited transposition.
+/// As for `Scale`, the notes include the octave.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let c_major = DynScale::from(&major_scale(C4).unwrap());
+/// assert_eq!(c_major.to_string(), "C major");
+/// assert_eq!(c_major.notes().len(), 8);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DynScale {
+    name: String,
+    notes: Vec<Note>,
+    steps: Vec<Interval>,
+}
+
+impl DynScale {
+    /// Creates a new `DynScale` from its notes
+    ///
+    /// # Arguments
+    /// * `name` - The name of the scale quality
+    /// * `notes` - The notes of the scale, starting with the root and including the octave
+    ///
+    /// # Returns
+    /// A new `DynScale` instance
+    pub(crate) fn new(name: impl Into<String>, notes: Vec<Note>) -> Self {
+        let steps = notes.intervals();
+        Self {
+            name: name.into(),
+            notes,
+            steps,
+        }
+    }
+
+    /// Creates a new `DynScale` from a root note and a step pattern
+    ///
+    /// # Arguments
+    /// * `name` - The name of the scale quality
+    /// * `root` - The root note of the scale
+    /// * `steps` - The steps between consecutive notes, up to the octave
+    ///
+    /// # Returns
+    /// A new `DynScale` instance, or `Error::NoteOutOfRange` with the root if its
+    /// last note would be above MIDI 127
+    pub(crate) fn from_steps(
+        name: impl Into<String>,
+        root: Note,
+        steps: &[Step],
+    ) -> Result<Self, Error> {
+        let span: i16 = steps.iter().map(|step| i16::from(step.semitones())).sum();
+        root.checked_add_semitones(span)
+            .ok_or(Error::NoteOutOfRange(root))?;
+        let steps = steps.iter().map(|step| Step::new(step.semitones()));
+        Ok(Self::new(name, root.into_notes_from_steps(steps).collect()))
+    }
+
+    /// Returns the name of the scale quality
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the name of the scale quality, the same as `name`
+    pub fn quality(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the root note of the scale
+    pub fn root(&self) -> Note {
+        self.notes[0]
+    }
+
+    /// Returns the notes of the scale, including the octave
+    pub fn notes(&self) -> &[Note] {
+        &self.notes
+    }
+
+    /// Returns the intervals between consecutive notes of the scale
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let whole_tone = DynScale::from(whole_tone_scale(C4).unwrap());
+    /// assert_eq!(whole_tone.steps(), &[MAJOR_SECOND; 6]);
+    /// ```
+    pub fn steps(&self) -> &[Interval] {
+        &self.steps
+    }
+
+    /// Returns the scale transposed by a number of semitones
+    ///
+    /// The quality and the steps of the scale are preserved; every note is moved
+    /// by the same amount.
+    ///
+    /// # Arguments
+    /// * `semitones` - The number of semitones to move (negative values move down)
+    ///
+    /// # Returns
+    /// The transposed scale, or `None` if a note would leave the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let c_major = DynScale::from(major_scale(C4).unwrap());
+    /// assert_eq!(c_major.transpose(7), Some(DynScale::from(major_scale(G4).unwrap())));
+    /// assert_eq!(c_major.transpose(100), None);
+    /// ```
+    pub fn transpose(&self, semitones: i8) -> Option<Self> {
+        let notes = self
+            .notes
+            .iter()
+            .map(|note| note.checked_add_semitones(i16::from(semitones)))
+            .collect::<Option<_>>()?;
+        Some(Self::new(self.name.clone(), notes))
+    }
+
+    /// Converts the scale back into a scale whose quality and size are known at compile time
+    ///
+    /// This gives access to the methods that only exist on a given `Scale`, such
+    /// as the diatonic chords of the major and minor scales.
+    ///
+    /// # Returns
+    /// The fixed-size scale, or an error if the quality or the number of notes don't match
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let scale = DynScale::from(major_scale(G4).unwrap());
+    /// let g_major = scale.try_into_fixed::<MajorScaleQuality, 8>().unwrap();
+    /// assert_eq!(g_major.v_major_chord().unwrap().notes(), &[D5, FSHARP5, A5]);
+    ///
+    /// assert_eq!(
+    ///     scale.try_into_fixed::<MajorScaleQuality, 7>(),
+    ///     Err(Error::ScaleSizeMismatch { expected: 7, found: 8 })
+    /// );
+    /// ```
+    pub fn try_into_fixed<Q, const N: usize>(&self) -> Result<Scale<Q, N>, Error>
+    where
+        Q: ScaleQuality,
+    {
+        if Q::name() != self.name {
+            return Err(Error::ScaleQualityMismatch {
+                expected: Q::name(),
+                found: self.name.clone(),
+            });
+        }
+        if self.notes.len() != N {
+            return Err(Error::ScaleSizeMismatch {
+                expected: N,
+                found: self.notes.len(),
+            });
+        }
+
+        Ok(Scale::new(self.notes.iter().copied()))
+    }
+}
+
+impl ScaleLike for DynScale {
+    #[inline]
+    fn pitches(&self) -> &[Note] {
+        &self.notes
+    }
+}
+
+impl<Q, const N: usize> From<&Scale<Q, N>> for DynScale
+where
+    Q: ScaleQuality,
+{
+    fn from(scale: &Scale<Q, N>) -> Self {
+        Self::new(Q::name(), scale.notes().to_vec())
+    }
+}
+
+impl<Q, const N: usize> From<Scale<Q, N>> for DynScale
+where
+    Q: ScaleQuality,
+{
+    fn from(scale: Scale<Q, N>) -> Self {
+        Self::from(&scale)
+    }
+}
+
+/// The serialized form of a `DynScale`, its steps being recomputed from the notes
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct DynScaleRecord {
+    name: String,
+    notes: Vec<Note>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DynScale {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let record = DynScaleRecord {
+            name: self.name.clone(),
+            notes: self.notes.clone(),
+        };
+        record.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DynScale {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let record = DynScaleRecord::deserialize(deserializer)?;
+        if record.notes.len() < 2 {
+            let error = Error::NotEnoughNotes {
+                required: 2,
+                found: record.notes.len(),
+            };
+            return Err(serde::de::Error::custom(error));
+        }
+
+        Ok(Self::new(record.name, record.notes))
+    }
+}
+
+impl fmt::Display for DynScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{:X} {}", self.root(), self.name)
+    }
+}
+
+/// Parses a scale from its root note and its quality, such as `"C4 major"`
+///
+/// The quality is one of `major`, `minor`, `harmonic` and `melodic`; the full
+/// names `harmonic minor` and `melodic minor` are accepted as well.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let scale: DynScale = "A4 harmonic".parse().unwrap();
+/// assert_eq!(scale, DynScale::from(harmonic_minor_scale(A4).unwrap()));
+/// assert_eq!(
+///     "C4 bogus".parse::<DynScale>(),
+///     Err(Error::UnknownScaleQuality("bogus".to_string()))
+/// );
+/// ```
+impl FromStr for DynScale {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let (root, quality) = name
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| Error::InvalidScaleName(name.to_string()))?;
+        let root: Note = root.parse()?;
+
+        let scale = match quality.trim() {
+            "major" => major_scale(root)?.into(),
+            "minor" => natural_minor_scale(root)?.into(),
+            "harmonic" | "harmonic minor" => harmonic_minor_scale(root)?.into(),
+            "melodic" | "melodic minor" => melodic_minor_scale(root)?.into(),
+            quality => return Err(Error::UnknownScaleQuality(quality.to_string())),
+        };
+        Ok(scale)
+    }
+}
+
+/// Creates one of Messiaen's seven modes of limited transposition
+///
+/// Mode 1 is the whole-tone scale and mode 2 the octatonic scale; modes 3 to 7
+/// are the larger symmetric collections described in Messiaen's treatise.
+///
+/// # Arguments
+/// * `mode` - The number of the mode, from 1 to 7
+/// * `root` - The root note from which to build the scale
+///
+/// # Returns
+/// The scale, or `None` if the mode number is not between 1 and 7 or the scale
+/// would go above MIDI 127
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let mode_3 = messiaen_mode(3, C4).unwrap();
+/// assert_eq!(mode_3.to_string(), "C messiaen mode 3");
+/// assert_eq!(mode_3.notes(), &[C4, D4, DSHARP4, E4, FSHARP4, G4, GSHARP4, ASHARP4, B4, C5]);
+/// assert!(messiaen_mode(8, C4).is_none());
+/// assert!(messiaen_mode(3, G9).is_none());
+/// ```
+pub fn messiaen_mode(mode: u8, root: Note) -> Option<DynScale> {
+    let (name, steps): (_, &[Step]) = match mode {
+        1 => ("messiaen mode 1", &WHOLE_TONE_SCALE_STEPS),
+        2 => ("messiaen mode 2", &OCTATONIC_SCALE_STEPS),
+        3 => ("messiaen mode 3", &MESSIAEN_MODE_3_STEPS),
+        4 => ("messiaen mode 4", &MESSIAEN_MODE_4_STEPS),
+        5 => ("messiaen mode 5", &MESSIAEN_MODE_5_STEPS),
+        6 => ("messiaen mode 6", &MESSIAEN_MODE_6_STEPS),
+        7 => ("messiaen mode 7", &MESSIAEN_MODE_7_STEPS),
+        _ => return None,
+    };
+
+    DynScale::from_steps(name, root, steps).ok()
+}
+
+/// Returns every whole-tone or octatonic scale made of exactly the pitch classes of some notes
+///
+/// A symmetric scale has the same pitch classes when it is built on several of
+/// its own notes, so a symmetric collection has several valid roots: the six
+/// notes of a whole-tone scale, or four of the eight notes of an octatonic scale.
+/// Every such root is reported, rather than an arbitrary one.
+///
+/// # Arguments
+/// * `notes` - The notes to match, in any order and octave
+///
+/// # Returns
+/// The whole-tone scales then the octatonic scales with the same pitch classes
+/// as the notes, each built on a root in the fourth octave, by root; empty if
+/// the notes form neither collection
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let scales = detect_symmetric(whole_tone_scale(D3).unwrap().notes());
+/// let roots: Vec<_> = scales.iter().map(|scale| scale.root()).collect();
+/// assert_eq!(roots, vec![C4, D4, E4, FSHARP4, GSHARP4, ASHARP4]);
+/// assert!(detect_symmetric(major_scale(C4).unwrap().notes()).is_empty());
+/// ```
+pub fn detect_symmetric(notes: &[Note]) -> Vec<DynScale> {
+    let mask = PitchClassMask::from_notes(notes);
+    let roots = || pitch_range(C4, B4);
+
+    let in_range = "the scales on the fourth octave are in the MIDI range";
+    let whole_tone = roots().map(|root| DynScale::from(whole_tone_scale(root).expect(in_range)));
+    let octatonic = roots().map(|root| DynScale::from(octatonic_scale(root).expect(in_range)));
+    whole_tone
+        .chain(octatonic)
+        .filter(|scale| scale.pitch_class_mask() == mask)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn semitones(intervals: Vec<Interval>) -> Vec<u8> {
+        intervals.iter().map(Interval::semitones).collect()
+    }
+
+    #[test]
+    fn test_detect_symmetric() {
+        let collection = [E2, GSHARP3, C4, D5, FSHARP5, ASHARP6];
+        let scales = detect_symmetric(&collection);
+        assert_eq!(scales.len(), 6);
+        for scale in &scales {
+            assert_eq!(scale.quality(), "whole tone");
+            assert_eq!(
+                scale.pitch_class_mask(),
+                PitchClassMask::from_notes(&collection)
+            );
+        }
+
+        let octatonic = detect_symmetric(octatonic_scale(C4).unwrap().notes());
+        let roots: Vec<_> = octatonic.iter().map(|scale| scale.root()).collect();
+        assert_eq!(roots, vec![C4, DSHARP4, FSHARP4, A4]);
+        assert!(octatonic.iter().all(|scale| scale.quality() == "octatonic"));
+
+        // A subset or a superset of a symmetric collection doesn't match
+        assert!(detect_symmetric(&collection[1..]).is_empty());
+        assert!(detect_symmetric(&[C4, D4, E4, F4, FSHARP4, GSHARP4, ASHARP4]).is_empty());
+        assert!(detect_symmetric(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_messiaen_mode_3() {
+        let mode = messiaen_mode(3, C4).unwrap();
+        let pitch_classes = PitchClassMask::from_notes(mode.notes());
+
+        assert_eq!(pitch_classes.len(), 9);
+        for note in [C4, D4, DSHARP4, E4, FSHARP4, G4, GSHARP4, ASHARP4, B4] {
+            assert!(pitch_classes.contains(note), "{note:?}");
+        }
+        assert_eq!(semitones(mode.transpositional_symmetry()), vec![4, 8]);
+    }
+
+    #[test]
+    fn test_every_mode_is_limited() {
+        let sizes = [6, 8, 9, 8, 6, 8, 10];
+        let symmetries = [
+            vec![2, 4, 6, 8, 10],
+            vec![3, 6, 9],
+            vec![4, 8],
+            vec![6],
+            vec![6],
+            vec![6],
+            vec![6],
+        ];
+
+        for mode in 1..=7 {
+            let scale = messiaen_mode(mode, FSHARP3).unwrap();
+            let index = mode as usize - 1;
+
+            assert_eq!(scale.notes().len(), sizes[index] + 1);
+            assert_eq!(scale.notes().last(), Some(&FSHARP4));
+            assert!(scale.is_mode_of_limited_transposition());
+            assert_eq!(
+                semitones(scale.transpositional_symmetry()),
+                symmetries[index]
+            );
+        }
+
+        assert!(messiaen_mode(0, C4).is_none());
+    }
+
+    #[test]
+    fn test_from_scale() {
+        let whole_tone = DynScale::from(&whole_tone_scale(C4).unwrap());
+        assert_eq!(
+            whole_tone,
+            DynScale::from_steps("whole tone", C4, &WHOLE_TONE_SCALE_STEPS).unwrap()
+        );
+        assert_eq!(whole_tone.notes(), messiaen_mode(1, C4).unwrap().notes());
+        assert!(!DynScale::from(&major_scale(C4).unwrap()).is_mode_of_limited_transposition());
+    }
+
+    #[test]
+    fn test_round_trip_fixed_sizes() {
+        fn round_trip<Q: ScaleQuality, const N: usize>(scale: Scale<Q, N>) {
+            let dynamic = DynScale::from(&scale);
+            assert_eq!(dynamic.pitches(), scale.notes());
+            assert_eq!(dynamic.tonic(), scale.root());
+            assert_eq!(dynamic.quality(), Q::name());
+            assert_eq!(dynamic.steps().len(), N - 1);
+            assert_eq!(dynamic.try_into_fixed::<Q, N>(), Ok(scale));
+        }
+
+        round_trip(whole_tone_scale(C4).unwrap());
+        round_trip(major_scale(D4).unwrap());
+        round_trip(natural_minor_scale(A3).unwrap());
+        round_trip(harmonic_minor_scale(E4).unwrap());
+        round_trip(melodic_minor_scale(G4).unwrap());
+        round_trip(octatonic_scale(B3).unwrap());
+        round_trip(major_scale(C4).unwrap().with_flattened(7).unwrap());
+    }
+
+    #[test]
+    fn test_try_into_fixed_mismatches() {
+        let octatonic = DynScale::from(octatonic_scale(C4).unwrap());
+        assert_eq!(
+            octatonic.try_into_fixed::<MajorScaleQuality, 9>(),
+            Err(Error::ScaleQualityMismatch {
+                expected: "major",
+                found: "octatonic".to_string()
+            })
+        );
+        assert_eq!(
+            octatonic.try_into_fixed::<OctatonicScaleQuality, 8>(),
+            Err(Error::ScaleSizeMismatch {
+                expected: 8,
+                found: 9
+            })
+        );
+
+        // A Messiaen mode has no matching fixed-size quality
+        let mode = messiaen_mode(1, C4).unwrap();
+        assert!(mode.try_into_fixed::<WholeToneScaleQuality, 7>().is_err());
+    }
+
+    #[test]
+    fn test_matches_major_scale() {
+        let fixed = major_scale(C4).unwrap();
+        let dynamic = DynScale::from(&fixed);
+
+        assert_eq!(dynamic.tonic(), fixed.tonic());
+        assert_eq!(dynamic.pitches(), fixed.pitches());
+        let steps: Vec<_> = dynamic.steps().iter().map(Interval::semitones).collect();
+        let fixed_steps: Vec<_> = fixed.steps().iter().map(Step::semitones).collect();
+        assert_eq!(steps, fixed_steps);
+        assert_eq!(dynamic.pitch_class_mask(), fixed.pitch_class_mask());
+        for midi in 0..=127 {
+            let note = Note::new(midi);
+            assert_eq!(dynamic.contains(note), fixed.contains(note), "{note:?}");
+        }
+
+        let transposed = dynamic.transpose(-3).unwrap();
+        assert_eq!(transposed, DynScale::from(major_scale(A3).unwrap()));
+        assert_eq!(transposed.steps(), dynamic.steps());
+        assert!(dynamic.transpose(-61).is_none());
+    }
+
+    #[test]
+    fn test_steps() {
+        let c_major = DynScale::from(major_scale(C4).unwrap());
+        let semitones: Vec<_> = c_major.steps().iter().map(Interval::semitones).collect();
+        assert_eq!(semitones, vec![2, 2, 1, 2, 2, 2, 1]);
+    }
+
+    #[test]
+    fn test_analysis_through_scale_like() {
+        fn analyze(scale: &impl ScaleLike) -> ([u8; 6], Option<Note>, Option<Note>) {
+            (
+                scale.interval_class_vector(),
+                scale.parent_major(),
+                scale.upper_neighbor(FSHARP4),
+            )
+        }
+
+        let fixed = natural_minor_scale(E4).unwrap();
+        let dynamic = DynScale::from(&fixed);
+        assert_eq!(analyze(&fixed), analyze(&dynamic));
+        assert_eq!(analyze(&dynamic), ([2, 5, 4, 3, 6, 1], Some(G3), Some(G4)));
+        assert!(dynamic.same_pitch_classes(&major_scale(G4).unwrap()));
+        assert!(major_scale(G4).unwrap().same_pitch_classes(&dynamic));
+    }
+
+    #[test]
+    fn test_subset_and_superset() {
+        let pentatonic = DynScale::from_steps(
+            "major pentatonic",
+            C4,
+            &[WHOLE, WHOLE, WHOLE_AND_HALF, WHOLE, WHOLE_AND_HALF],
+        )
+        .unwrap();
+        let c_major = major_scale(C4).unwrap();
+
+        assert!(pentatonic.is_subset_of(&c_major));
+        assert!(c_major.is_superset_of(&pentatonic));
+        assert!(!c_major.is_subset_of(&pentatonic));
+        assert!(!pentatonic.is_superset_of(&c_major));
+
+        // The parent major of the pentatonic, in another octave and mode
+        assert!(pentatonic.is_subset_of(&natural_minor_scale(A2).unwrap()));
+        assert!(!pentatonic.is_subset_of(&major_scale(D4).unwrap()));
+        assert!(c_major.is_subset_of(&c_major) && c_major.is_superset_of(&c_major));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "C4 major".parse::<DynScale>(),
+            Ok(DynScale::from(major_scale(C4).unwrap()))
+        );
+        assert_eq!(
+            "A3 minor".parse::<DynScale>(),
+            Ok(DynScale::from(natural_minor_scale(A3).unwrap()))
+        );
+        assert_eq!(
+            "F#4 melodic minor".parse::<DynScale>(),
+            Ok(DynScale::from(melodic_minor_scale(FSHARP4).unwrap()))
+        );
+
+        assert_eq!(
+            "C4 bogus".parse::<DynScale>(),
+            Err(Error::UnknownScaleQuality("bogus".to_string()))
+        );
+        assert_eq!(
+            "C4".parse::<DynScale>(),
+            Err(Error::InvalidScaleName("C4".to_string()))
+        );
+        assert_eq!(
+            "H4 major".parse::<DynScale>(),
+            Err(Error::InvalidNoteName("H4".to_string()))
+        );
+        assert_eq!(
+            "G9 major".parse::<DynScale>(),
+            Err(Error::NoteOutOfRange(G9))
+        );
+    }
+}
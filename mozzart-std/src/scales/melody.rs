@@ -0,0 +1,174 @@
+use crate::{Note, PitchClassMask, Scale, ScaleQuality};
+use rand::{Rng, RngExt};
+use std::ops::RangeInclusive;
+
+impl<Q, const N: usize> Scale<Q, N>
+where
+    Q: ScaleQuality,
+{
+    /// Generates a random melody from the notes of the scale
+    ///
+    /// The notes are drawn between the root and the octave of the scale. See
+    /// `random_melody_in` for how each note is picked.
+    ///
+    /// # Arguments
+    /// * `len` - The number of notes in the melody
+    /// * `rng` - The source of randomness
+    ///
+    /// # Returns
+    /// The notes of the melody, in the order they are played
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// let c_major = major_scale(C4).unwrap();
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let melody = c_major.random_melody(16, &mut rng);
+    /// assert_eq!(melody.len(), 16);
+    /// assert!(melody.iter().all(|note| c_major.notes().contains(note)));
+    /// ```
+    pub fn random_melody<R>(&self, len: usize, rng: &mut R) -> Vec<Note>
+    where
+        R: Rng + ?Sized,
+    {
+        self.random_melody_in(len, self.root()..=self.notes()[N - 1], rng)
+            .expect("the root of the scale is in its own range")
+    }
+
+    /// Generates a random melody from the notes of the scale within a range
+    ///
+    /// Every note in the range whose pitch class belongs to the scale can be
+    /// picked. The first note is picked uniformly; each following note is
+    /// weighted by the inverse of its distance, in scale steps, from the previous
+    /// note, so the melody favors stepwise motion over leaps.
+    ///
+    /// # Arguments
+    /// * `len` - The number of notes in the melody
+    /// * `range` - The lowest and highest notes that may be played
+    /// * `rng` - The source of randomness
+    ///
+    /// # Returns
+    /// The `len` notes of the melody, or `None` if no note of the scale is in the
+    /// range, including when the range is inverted
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let c_major = major_scale(C4).unwrap();
+    /// let melody = c_major.random_melody_in(8, C3..=C6, &mut rng).unwrap();
+    /// assert_eq!(melody.len(), 8);
+    /// assert!(melody.iter().all(|note| (C3..=C6).contains(note)));
+    /// assert_eq!(c_major.random_melody_in(8, CSHARP4..=CSHARP4, &mut rng), None);
+    /// ```
+    pub fn random_melody_in<R>(
+        &self,
+        len: usize,
+        range: RangeInclusive<Note>,
+        rng: &mut R,
+    ) -> Option<Vec<Note>>
+    where
+        R: Rng + ?Sized,
+    {
+        let pitch_classes = PitchClassMask::from_notes(self.notes());
+        let candidates: Vec<_> = (range.start().midi_number()..=range.end().midi_number())
+            .map(Note::new)
+            .filter(|&note| pitch_classes.contains(note))
+            .collect();
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let mut melody = Vec::with_capacity(len);
+        let mut previous = rng.random_range(0..candidates.len());
+        for _ in 0..len {
+            melody.push(candidates[previous]);
+
+            let weight = |i: usize| 1.0 / (1.0 + previous.abs_diff(i) as f32);
+            let total: f32 = (0..candidates.len()).map(weight).sum();
+            let mut pick = rng.random_range(0.0..total);
+            previous = (0..candidates.len())
+                .find(|&i| {
+                    pick -= weight(i);
+                    pick < 0.0
+                })
+                .unwrap_or(candidates.len() - 1);
+        }
+
+        Some(melody)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::*;
+    use crate::*;
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    #[test]
+    fn test_random_melody_uses_scale_notes() {
+        let a_minor = natural_minor_scale(A3).unwrap();
+        let mut rng = SmallRng::seed_from_u64(42);
+
+        for len in [0, 1, 7, 100] {
+            let melody = a_minor.random_melody(len, &mut rng);
+            assert_eq!(melody.len(), len);
+            assert!(melody.iter().all(|note| a_minor.notes().contains(note)));
+        }
+    }
+
+    #[test]
+    fn test_random_melody_is_deterministic() {
+        let d_major = major_scale(D4).unwrap();
+        let first = d_major.random_melody(32, &mut SmallRng::seed_from_u64(3));
+        let second = d_major.random_melody(32, &mut SmallRng::seed_from_u64(3));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_random_melody_in_range() {
+        let c_major = major_scale(C4).unwrap();
+        let pitch_classes = PitchClassMask::from_notes(c_major.notes());
+        let mut rng = SmallRng::seed_from_u64(42);
+
+        let melody = c_major.random_melody_in(500, C2..=C6, &mut rng).unwrap();
+        assert_eq!(melody.len(), 500);
+        assert!(melody
+            .iter()
+            .all(|&note| (C2..=C6).contains(&note) && pitch_classes.contains(note)));
+
+        // Stepwise motion is more common than leaps
+        let steps = melody
+            .windows(2)
+            .filter(|pair| pair[0].midi_number().abs_diff(pair[1].midi_number()) <= 2)
+            .count();
+        assert!(steps > melody.len() / 4, "{steps}");
+
+        assert_eq!(
+            c_major.random_melody_in(1, C6..=C6, &mut rng),
+            Some(vec![C6])
+        );
+    }
+
+    #[test]
+    fn test_random_melody_in_empty_range() {
+        let c_major = major_scale(C4).unwrap();
+        let mut rng = SmallRng::seed_from_u64(42);
+
+        assert_eq!(
+            c_major.random_melody_in(4, CSHARP4..=CSHARP4, &mut rng),
+            None
+        );
+        assert_eq!(
+            c_major.random_melody_in(4, DSHARP0..=DSHARP0, &mut rng),
+            None
+        );
+        assert_eq!(c_major.random_melody_in(4, C5..=C4, &mut rng), None);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{MidiEvent, Scale, ScaleQuality};
+
+impl<Q, const N: usize> Scale<Q, N>
+where
+    Q: ScaleQuality,
+{
+    /// Returns the MIDI events that play the scale one note after the other
+    ///
+    /// Each note is switched on at its start and off when the next one starts,
+    /// so the events alternate between note-on and note-off.
+    ///
+    /// # Arguments
+    /// * `start_tick` - The tick at which the first note starts
+    /// * `ticks_per_note` - The length of each note, in ticks
+    /// * `velocity` - How hard the notes are struck, from 0 to 127
+    /// * `channel` - The MIDI channel, from 0 to 15
+    ///
+    /// # Returns
+    /// A note-on and a note-off event for every note of the scale, in time order
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let events = major_scale(C4).unwrap().to_midi_events(0, 480, 100, 0);
+    /// assert_eq!(events[0], MidiEvent::new(0, C4.note_on(0, 100)));
+    /// assert_eq!(events[1], MidiEvent::new(480, C4.note_off(0)));
+    /// assert_eq!(events[15], MidiEvent::new(3840, C5.note_off(0)));
+    /// ```
+    pub fn to_midi_events(
+        &self,
+        start_tick: u32,
+        ticks_per_note: u32,
+        velocity: u8,
+        channel: u8,
+    ) -> Vec<MidiEvent> {
+        let mut tick = start_tick;
+        self.notes()
+            .iter()
+            .flat_map(|note| {
+                let on = MidiEvent::new(tick, note.note_on(channel, velocity));
+                tick += ticks_per_note;
+                [on, MidiEvent::new(tick, note.note_off(channel))]
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::*;
+    use crate::*;
+
+    #[test]
+    fn test_to_midi_events() {
+        let events = major_scale(C4).unwrap().to_midi_events(960, 240, 90, 2);
+        assert_eq!(events.len(), 16);
+        assert!(events.windows(2).all(|pair| pair[0].tick <= pair[1].tick));
+        assert!(events
+            .chunks(2)
+            .all(|pair| pair[0].tick < pair[1].tick && pair[0].bytes[1] == pair[1].bytes[1]));
+        assert_eq!(events[0].tick, 960);
+        assert_eq!(events[0].bytes, [0x92, 60, 90]);
+        assert_eq!(events[15].tick, 960 + 8 * 240);
+    }
+}
@@ -1,3 +1,17 @@
+mod alteration;
+mod characteristic;
+mod diff;
+mod dyn_scale;
+#[cfg(feature = "rand")]
+mod melody;
+mod midi;
+mod mode;
 mod scale;
+mod scale_like;
 
+pub use alteration::*;
+pub use diff::*;
+pub use dyn_scale::*;
+pub use mode::*;
 pub use scale::*;
+pub use scale_like::*;
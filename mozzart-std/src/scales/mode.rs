@@ -0,0 +1,256 @@
+use crate::constants::*;
+use crate::{DynScale, Error, Note, ScaleLike, Step};
+use std::fmt;
+
+/// Represents one of the seven modes of the major scale
+///
+/// Each mode plays the notes of a major scale from one of its degrees: Ionian
+/// from the first, which is the major scale itself, Dorian from the second, and
+/// so on up to Locrian from the seventh.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let dorian = Mode::Dorian.scale(D4).unwrap();
+/// assert_eq!(dorian.notes(), &[D4, E4, F4, G4, A4, B4, C5, D5]);
+/// assert_eq!(Mode::of(&dorian), Some(Mode::Dorian));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mode {
+    /// The major scale itself
+    Ionian,
+    /// The major scale from its second degree
+    Dorian,
+    /// The major scale from its third degree
+    Phrygian,
+    /// The major scale from its fourth degree
+    Lydian,
+    /// The major scale from its fifth degree
+    Mixolydian,
+    /// The major scale from its sixth degree, the natural minor scale
+    Aeolian,
+    /// The major scale from its seventh degree
+    Locrian,
+}
+
+impl Mode {
+    /// The modes, in the order of the degrees of the major scale they start on
+    pub const ALL: [Mode; 7] = [
+        Mode::Ionian,
+        Mode::Dorian,
+        Mode::Phrygian,
+        Mode::Lydian,
+        Mode::Mixolydian,
+        Mode::Aeolian,
+        Mode::Locrian,
+    ];
+
+    /// Returns the degree of the major scale the mode starts on, from 1 to 7
+    pub const fn degree(&self) -> u8 {
+        match self {
+            Mode::Ionian => 1,
+            Mode::Dorian => 2,
+            Mode::Phrygian => 3,
+            Mode::Lydian => 4,
+            Mode::Mixolydian => 5,
+            Mode::Aeolian => 6,
+            Mode::Locrian => 7,
+        }
+    }
+
+    /// Returns the name of the mode, such as `lydian`
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Mode::Ionian => "ionian",
+            Mode::Dorian => "dorian",
+            Mode::Phrygian => "phrygian",
+            Mode::Lydian => "lydian",
+            Mode::Mixolydian => "mixolydian",
+            Mode::Aeolian => "aeolian",
+            Mode::Locrian => "locrian",
+        }
+    }
+
+    /// Returns the steps between the consecutive notes of the mode, up to the octave
+    fn steps(&self) -> [Step; 7] {
+        let mut steps = MAJOR_SCALE_STEPS;
+        steps.rotate_left(usize::from(self.degree() - 1));
+        steps
+    }
+
+    /// Creates the scale of the mode on a root note
+    ///
+    /// # Arguments
+    /// * `root` - The root note from which to build the scale
+    ///
+    /// # Returns
+    /// The scale of 8 notes, including the octave, or `Error::NoteOutOfRange`
+    /// if its last note would be above MIDI 127
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let lydian = Mode::Lydian.scale(C4).unwrap();
+    /// assert_eq!(lydian.to_string(), "C lydian");
+    /// assert_eq!(lydian.notes()[3], FSHARP4);
+    /// assert_eq!(Mode::Lydian.scale(G9), Err(Error::NoteOutOfRange(G9)));
+    /// ```
+    pub fn scale(&self, root: Note) -> Result<DynScale, Error> {
+        DynScale::from_steps(self.name(), root, &self.steps())
+    }
+
+    /// Returns the mode of a diatonic scale, read from its tonic
+    ///
+    /// # Arguments
+    /// * `scale` - The scale to identify, in any octave
+    ///
+    /// # Returns
+    /// The mode, or `None` if the scale doesn't have the pitch classes of a major scale
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// assert_eq!(Mode::of(&natural_minor_scale(A4).unwrap()), Some(Mode::Aeolian));
+    /// assert_eq!(Mode::of(&harmonic_minor_scale(A4).unwrap()), None);
+    /// ```
+    pub fn of<S>(scale: &S) -> Option<Mode>
+    where
+        S: ScaleLike + ?Sized,
+    {
+        let parent = scale.parent_major()?;
+        let offset = (scale.tonic().pitch_class() + SEMITONES_IN_OCTAVE - parent.pitch_class())
+            % SEMITONES_IN_OCTAVE;
+
+        let mut degree_offset = 0;
+        Mode::ALL.into_iter().find(|mode| {
+            let found = degree_offset == offset;
+            degree_offset += MAJOR_SCALE_STEPS[usize::from(mode.degree() - 1)].semitones();
+            found
+        })
+    }
+
+    /// Returns how bright the mode sounds, compared with the major scale
+    ///
+    /// Each degree of a mode is either the one of the major scale on the same
+    /// root, or a half step away from it. The brightness counts the raised degrees
+    /// and takes away the lowered ones: Lydian, with its raised fourth, is the
+    /// brightest at 1, Ionian is 0 and Locrian, with five lowered degrees, is
+    /// the darkest at -5.
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// assert_eq!(Mode::Lydian.brightness(), 1);
+    /// assert_eq!(Mode::Dorian.brightness(), -2);
+    /// assert!(Mode::Mixolydian.brightness() > Mode::Aeolian.brightness());
+    /// ```
+    pub fn brightness(&self) -> i8 {
+        let mut mode = 0;
+        let mut major = 0;
+        let mut brightness = 0;
+        for (step, major_step) in self.steps().iter().zip(MAJOR_SCALE_STEPS).take(6) {
+            mode += step.semitones() as i8;
+            major += major_step.semitones() as i8;
+            brightness += mode - major;
+        }
+        brightness
+    }
+
+    /// Returns the modes sorted from the brightest to the darkest
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    ///
+    /// let modes = Mode::all_by_brightness();
+    /// assert_eq!(modes[0], Mode::Lydian);
+    /// assert_eq!(modes[6], Mode::Locrian);
+    /// ```
+    pub fn all_by_brightness() -> [Mode; 7] {
+        let mut modes = Mode::ALL;
+        modes.sort_by_key(|mode| -mode.brightness());
+        modes
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{major_scale, natural_minor_scale, whole_tone_scale};
+
+    #[test]
+    fn test_brightness_order() {
+        let order = [
+            Mode::Lydian,
+            Mode::Ionian,
+            Mode::Mixolydian,
+            Mode::Dorian,
+            Mode::Aeolian,
+            Mode::Phrygian,
+            Mode::Locrian,
+        ];
+        assert_eq!(Mode::all_by_brightness(), order);
+        for pair in order.windows(2) {
+            assert!(pair[0].brightness() > pair[1].brightness(), "{pair:?}");
+        }
+        assert_eq!(Mode::Ionian.brightness(), 0);
+        assert_eq!(Mode::Locrian.brightness(), -5);
+    }
+
+    #[test]
+    fn test_scale_and_of() {
+        assert_eq!(
+            Mode::Ionian.scale(C4).unwrap().notes(),
+            major_scale(C4).unwrap().notes()
+        );
+        assert_eq!(
+            Mode::Aeolian.scale(A3).unwrap().notes(),
+            natural_minor_scale(A3).unwrap().notes()
+        );
+        assert_eq!(
+            Mode::Locrian.scale(B3).unwrap().notes(),
+            &[B3, C4, D4, E4, F4, G4, A4, B4]
+        );
+
+        for mode in Mode::ALL {
+            for root in [C4, FSHARP2, ASHARP5] {
+                assert_eq!(
+                    Mode::of(&mode.scale(root).unwrap()),
+                    Some(mode),
+                    "{mode} {root:?}"
+                );
+            }
+        }
+        assert_eq!(Mode::of(&whole_tone_scale(C4).unwrap()), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_presets_round_trip() {
+        let mut presets = crate::PresetStore::new();
+        for mode in Mode::ALL {
+            presets.insert_scale(mode.name(), mode.scale(D4).unwrap());
+        }
+        let loaded = crate::PresetStore::from_json(&presets.to_json()).unwrap();
+
+        for mode in Mode::ALL {
+            let scale = loaded.scale(mode.name()).unwrap();
+            assert_eq!(scale, &mode.scale(D4).unwrap());
+            assert_eq!(Mode::of(scale), Some(mode));
+        }
+    }
+}
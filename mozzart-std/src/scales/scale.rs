@@ -1,7 +1,10 @@
 use crate::{constants::*, diminished_triad, major_triad, minor_triad};
-use crate::{Chord, Interval, Note, Step};
+use crate::{
+    Chord, DynScale, Error, Interval, NamedInterval, NamedSlice, Note, NoteRow, SpelledNote, Step,
+};
 use std::fmt;
 use std::marker::PhantomData;
+use std::str::FromStr;
 
 /// Trait for converting a note into a major scale
 ///
@@ -12,8 +15,9 @@ pub trait IntoMajorScale {
     /// Converts the note into a major scale
     ///
     /// # Returns
-    /// A `Scale<MajorScaleQuality, 8>` representing the major scale starting from this note
-    fn into_major_scale(self) -> Scale<MajorScaleQuality, 8>;
+    /// A `Scale<MajorScaleQuality, 8>` representing the major scale starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
+    fn into_major_scale(self) -> Result<Scale<MajorScaleQuality, 8>, Error>;
 }
 
 /// Trait for converting a note into a natural minor scale
@@ -25,8 +29,9 @@ pub trait IntoNaturalMinorScale {
     /// Converts the note into a natural minor scale
     ///
     /// # Returns
-    /// A `Scale<MinorScaleQuality, 8>` representing the natural minor scale starting from this note
-    fn into_natural_minor_scale(self) -> Scale<MinorScaleQuality, 8>;
+    /// A `Scale<MinorScaleQuality, 8>` representing the natural minor scale starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
+    fn into_natural_minor_scale(self) -> Result<Scale<MinorScaleQuality, 8>, Error>;
 }
 
 /// Trait for converting a note into a harmonic minor scale
@@ -38,8 +43,9 @@ pub trait IntoHarmonicMinorScale {
     /// Converts the note into a harmonic minor scale
     ///
     /// # Returns
-    /// A `Scale<HarmonicMinorScaleQuality, 8>` representing the harmonic minor scale starting from this note
-    fn into_harmonic_minor_scale(self) -> Scale<HarmonicMinorScaleQuality, 8>;
+    /// A `Scale<HarmonicMinorScaleQuality, 8>` representing the harmonic minor scale starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
+    fn into_harmonic_minor_scale(self) -> Result<Scale<HarmonicMinorScaleQuality, 8>, Error>;
 }
 
 /// Trait for converting a note into a melodic minor scale
@@ -51,8 +57,9 @@ pub trait IntoMelodicMinorScale {
     /// Converts the note into a melodic minor scale
     ///
     /// # Returns
-    /// A `Scale<MelodicMinorScaleQuality, 8>` representing the melodic minor scale starting from this note
-    fn into_melodic_minor_scale(self) -> Scale<MelodicMinorScaleQuality, 8>;
+    /// A `Scale<MelodicMinorScaleQuality, 8>` representing the melodic minor scale starting from this note,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
+    fn into_melodic_minor_scale(self) -> Result<Scale<MelodicMinorScaleQuality, 8>, Error>;
 }
 
 /// Defines the musical quality of a scale, providing its name and characteristics
@@ -106,6 +113,20 @@ pub struct HarmonicMinorScaleQuality;
 /// offering a distinctive sound that is neither fully major nor minor.
 pub struct MelodicMinorScaleQuality;
 
+/// Represents the whole-tone scale quality
+///
+/// The whole-tone scale divides the octave into six whole steps: W-W-W-W-W-W.
+/// It is the first of Messiaen's modes of limited transposition, having only
+/// two distinct transpositions.
+pub struct WholeToneScaleQuality;
+
+/// Represents the octatonic scale quality (half-whole form)
+///
+/// The octatonic scale alternates half and whole steps: H-W-H-W-H-W-H-W.
+/// It is the second of Messiaen's modes of limited transposition, having only
+/// three distinct transpositions.
+pub struct OctatonicScaleQuality;
+
 impl ScaleQuality for MajorScaleQuality {
     fn name() -> &'static str {
         "major"
@@ -126,6 +147,16 @@ impl ScaleQuality for MelodicMinorScaleQuality {
         "melodic minor"
     }
 }
+impl ScaleQuality for WholeToneScaleQuality {
+    fn name() -> &'static str {
+        "whole tone"
+    }
+}
+impl ScaleQuality for OctatonicScaleQuality {
+    fn name() -> &'static str {
+        "octatonic"
+    }
+}
 
 /// Represents a musical scale with a specific number of notes
 ///
@@ -180,6 +211,22 @@ where
         }
     }
 
+    /// Creates the scale following a step pattern from a root note
+    ///
+    /// # Arguments
+    /// * `root` - The root note from which to build the scale
+    /// * `steps` - The steps between consecutive notes, one fewer than `N`
+    ///
+    /// # Returns
+    /// The scale, or `Error::NoteOutOfRange` with the root if its last note would be above MIDI 127
+    pub(crate) fn from_steps(root: Note, steps: &[Step]) -> Result<Self, Error> {
+        let span: i16 = steps.iter().map(|step| i16::from(step.semitones())).sum();
+        root.checked_add_semitones(span)
+            .ok_or(Error::NoteOutOfRange(root))?;
+        let steps = steps.iter().map(|step| Step::new(step.semitones()));
+        Ok(Self::new(root.into_notes_from_steps(steps)))
+    }
+
     /// Returns the root note of the scale
     ///
     /// The root note is the first note of the scale and establishes the tonal center.
@@ -192,7 +239,7 @@ where
     /// ```
     /// use mozzart_std::{constants::*, major_scale};
     ///
-    /// let d_major = major_scale(D4);
+    /// let d_major = major_scale(D4).unwrap();
     /// assert_eq!(d_major.root(), D4);
     /// ```
     #[inline]
@@ -212,7 +259,7 @@ where
     /// ```
     /// use mozzart_std::{constants::*, major_scale};
     ///
-    /// let c_major = major_scale(C4);
+    /// let c_major = major_scale(C4).unwrap();
     /// let notes = c_major.notes();
     ///
     /// // C major scale should have these notes: C, D, E, F, G, A, B, C
@@ -224,6 +271,204 @@ where
     pub const fn notes(&self) -> &[Note; N] {
         &self.notes
     }
+
+    /// Returns how many notes of the scale are played on black piano keys
+    ///
+    /// Every note of the scale is counted, including the octave.
+    ///
+    /// # Returns
+    /// The number of black-key notes in the scale
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale};
+    ///
+    /// assert_eq!(major_scale(C4).unwrap().black_key_count(), 0);
+    /// assert_eq!(major_scale(FSHARP4).unwrap().black_key_count(), 6);
+    /// ```
+    pub fn black_key_count(&self) -> usize {
+        self.notes.iter().filter(|note| note.is_black_key()).count()
+    }
+
+    /// Returns how many notes of the scale are played on white piano keys
+    ///
+    /// Every note of the scale is counted, including the octave.
+    ///
+    /// # Returns
+    /// The number of white-key notes in the scale
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale};
+    ///
+    /// assert_eq!(major_scale(C4).unwrap().white_key_count(), 8);
+    /// assert_eq!(major_scale(FSHARP4).unwrap().white_key_count(), 2);
+    /// ```
+    pub fn white_key_count(&self) -> usize {
+        self.notes.iter().filter(|note| note.is_white_key()).count()
+    }
+
+    /// Returns the notes of the scale in reverse order
+    ///
+    /// # Returns
+    /// The notes of the scale, from the last one to the root
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale};
+    ///
+    /// let c_major = major_scale(C4).unwrap();
+    /// assert_eq!(c_major.retrograde(), vec![C5, B4, A4, G4, F4, E4, D4, C4]);
+    /// ```
+    pub fn retrograde(&self) -> Vec<Note> {
+        self.notes.iter().rev().copied().collect()
+    }
+
+    /// Returns the formula of the scale, the interval of each degree above the tonic
+    ///
+    /// # Returns
+    /// The intervals, starting with the unison of the tonic and without the octave
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale};
+    ///
+    /// let names: Vec<_> = major_scale(C4).unwrap().formula().iter().filter_map(|interval| interval.name()).collect();
+    /// assert_eq!(names, vec!["P1", "M2", "M3", "P4", "P5", "M6", "M7"]);
+    /// ```
+    pub fn formula(&self) -> Vec<Interval> {
+        let degrees = N.saturating_sub(1);
+        self.notes[..degrees]
+            .iter()
+            .map(|&note| Interval::from_pitches(self.root(), note))
+            .collect()
+    }
+
+    /// Returns the note of the scale at an index, counting degrees across octaves
+    ///
+    /// The scale is repeated in every octave, like a ladder: index 0 is the
+    /// tonic, the number of degrees is the tonic an octave up, and negative
+    /// indices go below the tonic.
+    ///
+    /// # Arguments
+    /// * `index` - The number of degrees above the tonic, negative to go below
+    ///
+    /// # Returns
+    /// The note, clamped to the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale};
+    ///
+    /// let c_major = major_scale(C4).unwrap();
+    /// assert_eq!(c_major.note_at(2), E4);
+    /// assert_eq!(c_major.note_at(-3), G3);
+    /// assert_eq!(c_major.note_at(100).midi_number(), 127);
+    /// ```
+    pub fn note_at(&self, index: i32) -> Note {
+        let degrees = N.saturating_sub(1).max(1) as i64;
+        let index = i64::from(index);
+        let note = self.notes[index.rem_euclid(degrees) as usize];
+        let octaves = index.div_euclid(degrees);
+        let midi = i64::from(note.midi_number()) + octaves * i64::from(SEMITONES_IN_OCTAVE);
+        Note::new(midi.clamp(0, 127) as u8)
+    }
+
+    /// Returns the chromatic approach note from below to a target
+    ///
+    /// The approach note is a semitone below the target whether or not it
+    /// belongs to the scale, as in bebop lines leading into a chord tone. See
+    /// `lower_neighbor` for the closest scale member instead.
+    ///
+    /// # Arguments
+    /// * `target` - The note approached, usually a scale tone
+    ///
+    /// # Returns
+    /// The note a semitone below, or `None` if the target is MIDI 0
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale};
+    ///
+    /// assert_eq!(major_scale(C4).unwrap().chromatic_approach_below(G4), Some(FSHARP4));
+    /// assert_eq!(major_scale(C4).unwrap().chromatic_approach_below(C4), Some(B3));
+    /// ```
+    pub fn chromatic_approach_below(&self, target: Note) -> Option<Note> {
+        target.checked_add_semitones(-1)
+    }
+
+    /// Returns the chromatic approach note from above to a target
+    ///
+    /// The approach note is a semitone above the target whether or not it
+    /// belongs to the scale. See `upper_neighbor` for the closest scale member instead.
+    ///
+    /// # Arguments
+    /// * `target` - The note approached, usually a scale tone
+    ///
+    /// # Returns
+    /// The note a semitone above, or `None` if the target is MIDI 127
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale};
+    ///
+    /// assert_eq!(major_scale(C4).unwrap().chromatic_approach_above(G4), Some(GSHARP4));
+    /// assert_eq!(major_scale(C4).unwrap().chromatic_approach_above(E4), Some(F4));
+    /// ```
+    pub fn chromatic_approach_above(&self, target: Note) -> Option<Note> {
+        target.checked_add_semitones(1)
+    }
+}
+
+/// Scales are equal when they have the same quality and the same notes, octaves included
+///
+/// Use `same_pitch_classes` to compare scales regardless of their octave.
+impl<Q, const N: usize> PartialEq for Scale<Q, N>
+where
+    Q: ScaleQuality,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.notes == other.notes
+    }
+}
+
+impl<Q, const N: usize> Eq for Scale<Q, N> where Q: ScaleQuality {}
+
+/// A scale equals a sequence of notes when it has exactly these notes, in the same order
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// assert_eq!(major_scale(C4).unwrap(), [C4, D4, E4, F4, G4, A4, B4, C5]);
+/// assert_ne!(major_scale(C4).unwrap(), vec![C4, D4, E4]);
+/// ```
+impl<Q, const N: usize> PartialEq<[Note]> for Scale<Q, N>
+where
+    Q: ScaleQuality,
+{
+    fn eq(&self, other: &[Note]) -> bool {
+        self.notes[..] == *other
+    }
+}
+
+impl<Q, const N: usize, const M: usize> PartialEq<[Note; M]> for Scale<Q, N>
+where
+    Q: ScaleQuality,
+{
+    fn eq(&self, other: &[Note; M]) -> bool {
+        self.notes[..] == other[..]
+    }
+}
+
+impl<Q, const N: usize> PartialEq<Vec<Note>> for Scale<Q, N>
+where
+    Q: ScaleQuality,
+{
+    fn eq(&self, other: &Vec<Note>) -> bool {
+        self.notes[..] == other[..]
+    }
 }
 
 impl<Q, const N: usize> fmt::UpperHex for Scale<Q, N>
@@ -254,7 +499,13 @@ impl<Q, const N: usize> fmt::Debug for Scale<Q, N>
 where
     Q: ScaleQuality,
 {
+    /// Writes the root and the quality, or with the alternate flag (`{:#?}`) a table of the notes
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        if f.alternate() {
+            let rows: Vec<_> = self.notes().iter().copied().map(NoteRow).collect();
+            return fmt::Debug::fmt(&NamedSlice::new(self.to_string(), &rows), f);
+        }
+
         let root = self.root();
         let suffix = Q::name();
 
@@ -287,7 +538,7 @@ where
     /// ```
     /// use mozzart_std::{Note, constants::*, major_scale};
     ///
-    /// let c_major = major_scale(C4);
+    /// let c_major = major_scale(C4).unwrap();
     /// let steps = c_major.steps();
     /// assert_eq!(steps.len(), 7);
     ///
@@ -315,12 +566,12 @@ where
     /// ```
     /// use mozzart_std::{Note, constants::*, major_scale};
     ///
-    /// let c_major = major_scale(C4);
+    /// let c_major = major_scale(C4).unwrap();
     /// let intervals = c_major.intervals();
     /// assert_eq!(intervals.len(), 7);
     ///
-    /// // C major intervals: [MAJOR_SECOND, MAJOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MINOR_SIXTH, MAJOR_SEVENTH, PERFECT_OCTAVE]
-    /// assert_eq!(intervals, [MAJOR_SECOND, MAJOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MINOR_SIXTH, MAJOR_SEVENTH, PERFECT_OCTAVE]);
+    /// // C major intervals: [MAJOR_SECOND, MAJOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MAJOR_SIXTH, MAJOR_SEVENTH, PERFECT_OCTAVE]
+    /// assert_eq!(intervals, [MAJOR_SECOND, MAJOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MAJOR_SIXTH, MAJOR_SEVENTH, PERFECT_OCTAVE]);
     /// ```
     pub fn intervals(&self) -> [Interval; 7] {
         let mut intervals = [PERFECT_UNISON; 7];
@@ -331,6 +582,247 @@ where
 
         intervals
     }
+    /// Returns the triad built on a degree of the scale
+    ///
+    /// The triad stacks two thirds of the scale above the degree, wrapping into the
+    /// next octave when needed, so its notes all belong to the scale.
+    ///
+    /// # Arguments
+    /// * `degree` - The degree the triad is built on, from 1 (the tonic) to 7
+    ///
+    /// # Returns
+    /// The triad, or `None` if the degree is out of range, a note would be above
+    /// MIDI 127 or the notes form no known chord quality
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, ChordQuality};
+    ///
+    /// let g_major = major_scale(C4).unwrap().triad_on(5).unwrap();
+    /// assert_eq!(g_major.notes(), &[G4, B4, D5]);
+    /// assert_eq!(g_major.quality(), ChordQuality::MajorTriad);
+    /// ```
+    pub fn triad_on(&self, degree: usize) -> Option<Chord<3>> {
+        self.stacked_thirds(degree)
+    }
+
+    /// Returns the seventh chord built on a degree of the scale
+    ///
+    /// The chord stacks three thirds of the scale above the degree, wrapping into
+    /// the next octave when needed, so its notes all belong to the scale.
+    ///
+    /// # Arguments
+    /// * `degree` - The degree the chord is built on, from 1 (the tonic) to 7
+    ///
+    /// # Returns
+    /// The seventh chord, or `None` if the degree is out of range, a note would be
+    /// above MIDI 127 or the notes form no known chord quality
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, ChordQuality};
+    ///
+    /// let d_minor_seventh = major_scale(C4).unwrap().seventh_on(2).unwrap();
+    /// assert_eq!(d_minor_seventh.notes(), &[D4, F4, A4, C5]);
+    /// assert_eq!(d_minor_seventh.quality(), ChordQuality::MinorSeventh);
+    /// ```
+    pub fn seventh_on(&self, degree: usize) -> Option<Chord<4>> {
+        self.stacked_thirds(degree)
+    }
+
+    /// Returns the chord of `M` notes stacking thirds of the scale above a degree
+    fn stacked_thirds<const M: usize>(&self, degree: usize) -> Option<Chord<M>> {
+        if !(1..=7).contains(&degree) {
+            return None;
+        }
+
+        let root = self.notes[degree - 1];
+        let mut intervals = Vec::with_capacity(M - 1);
+        for third in 1..M {
+            let note = self.wrapped_note(degree - 1 + 2 * third)?;
+            intervals.push(Interval::from_pitches(root, note));
+        }
+
+        Chord::from_intervals(root, &intervals)
+    }
+
+    /// Returns the notes of the scale taken every other degree, going up in thirds
+    ///
+    /// The cycle wraps past the seventh degree into the next octave, so from the
+    /// tonic of C major it goes C, E, G, B, D, F, A and then C again an octave
+    /// higher than where it started.
+    ///
+    /// # Arguments
+    /// * `start_degree` - The degree the cycle starts on, from 1 (the tonic) to 7
+    /// * `count` - The number of notes of the cycle
+    ///
+    /// # Returns
+    /// The notes of the cycle, stopping early before a note above MIDI 127, or no
+    /// notes if the degree is out of range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale};
+    ///
+    /// let c_major = major_scale(C4).unwrap();
+    /// assert_eq!(c_major.thirds_cycle(1, 5), vec![C4, E4, G4, B4, D5]);
+    /// assert_eq!(c_major.thirds_cycle(5, 3), vec![G4, B4, D5]);
+    /// ```
+    pub fn thirds_cycle(&self, start_degree: usize, count: usize) -> Vec<Note> {
+        if !(1..=7).contains(&start_degree) {
+            return Vec::new();
+        }
+
+        (0..count)
+            .map_while(|third| self.wrapped_note(start_degree - 1 + 2 * third))
+            .collect()
+    }
+
+    /// Returns the two tetrachords of the scale
+    ///
+    /// The lower tetrachord has the degrees 1 to 4 and the upper one the degrees
+    /// 5 to 8, so both span four notes and three steps. In a major scale, both
+    /// tetrachords are whole-whole-half, a fifth apart.
+    ///
+    /// # Returns
+    /// The lower and the upper tetrachords
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale};
+    ///
+    /// let (lower, upper) = major_scale(C4).unwrap().tetrachords();
+    /// assert_eq!(lower.notes(), &[C4, D4, E4, F4]);
+    /// assert_eq!(upper.notes(), &[G4, A4, B4, C5]);
+    /// assert_eq!(lower.to_string(), "C lower tetrachord");
+    /// ```
+    pub fn tetrachords(&self) -> (DynScale, DynScale) {
+        let (lower, upper) = self.notes.split_at(4);
+        (
+            DynScale::new("lower tetrachord", lower.to_vec()),
+            DynScale::new("upper tetrachord", upper.to_vec()),
+        )
+    }
+
+    /// Returns the subdominant, the fourth degree of the scale
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale};
+    ///
+    /// assert_eq!(major_scale(C4).unwrap().subdominant(), F4);
+    /// ```
+    pub const fn subdominant(&self) -> Note {
+        self.notes[3]
+    }
+
+    /// Returns the dominant, the fifth degree of the scale
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale};
+    ///
+    /// assert_eq!(major_scale(C4).unwrap().dominant(), G4);
+    /// ```
+    pub const fn dominant(&self) -> Note {
+        self.notes[4]
+    }
+
+    /// Returns the leading tone, the seventh degree when it is a semitone below the octave
+    ///
+    /// A seventh degree a whole tone below the octave, as in the natural minor
+    /// scale, is a subtonic rather than a leading tone.
+    ///
+    /// # Returns
+    /// The seventh degree, or `None` if it is not a semitone below the octave
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, harmonic_minor_scale, major_scale, natural_minor_scale};
+    ///
+    /// assert_eq!(major_scale(C4).unwrap().leading_tone(), Some(B4));
+    /// assert_eq!(harmonic_minor_scale(A4).unwrap().leading_tone(), Some(GSHARP5));
+    /// assert_eq!(natural_minor_scale(A4).unwrap().leading_tone(), None);
+    /// ```
+    pub fn leading_tone(&self) -> Option<Note> {
+        let seventh = self.notes[6];
+        (Interval::from_pitches(seventh, self.notes[7]) == MINOR_SECOND).then_some(seventh)
+    }
+
+    /// Returns the names of the notes of the scale, one per letter
+    ///
+    /// Each letter is used once, so some notes need double sharps or double
+    /// flats. Of the spellings of the tonic, the one giving the fewest accidentals
+    /// is chosen, sharps winning ties: D major rather than Ebb major, F# major
+    /// rather than Gb major. Use `note_names_from` to choose the tonic.
+    ///
+    /// # Returns
+    /// The names of the seven notes, without the octave
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, harmonic_minor_scale};
+    ///
+    /// let names: Vec<_> = major_scale(EFLAT4).unwrap().note_names().iter().map(|note| note.to_string()).collect();
+    /// assert_eq!(names, vec!["Eb", "F", "G", "Ab", "Bb", "C", "D"]);
+    ///
+    /// let names: Vec<_> = harmonic_minor_scale(GSHARP4).unwrap().note_names().iter().map(|note| note.to_string()).collect();
+    /// assert_eq!(names, vec!["G#", "A#", "B", "C#", "D#", "E", "F##"]);
+    /// ```
+    pub fn note_names(&self) -> Vec<SpelledNote> {
+        SpelledNote::simple_spellings(self.root())
+            .into_iter()
+            .filter_map(|tonic| self.note_names_from(tonic))
+            .min_by_key(|names| {
+                names
+                    .iter()
+                    .map(|note| note.alteration().unsigned_abs())
+                    .sum::<u8>()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the names of the notes of the scale, one per letter, from a spelling of the tonic
+    ///
+    /// # Arguments
+    /// * `tonic` - The name of the tonic, which must be a spelling of the root
+    ///
+    /// # Returns
+    /// The names of the seven notes, without the octave, or `None` if the tonic
+    /// is not the root or a note would need more than a double accidental
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let g_sharp_major = major_scale(GSHARP4).unwrap().note_names_from("G#".parse().unwrap()).unwrap();
+    /// assert_eq!(g_sharp_major[6].to_string(), "F##");
+    /// assert_eq!(major_scale(C4).unwrap().note_names_from("D".parse().unwrap()), None);
+    /// ```
+    pub fn note_names_from(&self, tonic: SpelledNote) -> Option<Vec<SpelledNote>> {
+        if tonic.pitch_class() != self.root().pitch_class() {
+            return None;
+        }
+
+        self.notes[..7]
+            .iter()
+            .zip(1..)
+            .map(|(note, number)| {
+                let semitones = note.midi_number() - self.root().midi_number();
+                let interval = NamedInterval::with_semitones(number, semitones)?;
+                tonic.at_interval(interval).ok()
+            })
+            .collect()
+    }
+
+    /// Returns the note at an index of the scale continued over the following octaves
+    fn wrapped_note(&self, index: usize) -> Option<Note> {
+        let octaves = i16::try_from(index / 7)
+            .ok()?
+            .checked_mul(i16::from(SEMITONES_IN_OCTAVE))?;
+        self.notes[index % 7].checked_add_semitones(octaves)
+    }
 }
 
 impl Scale<MajorScaleQuality, 8> {
@@ -340,17 +832,18 @@ impl Scale<MajorScaleQuality, 8> {
     /// It is a major triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the I major chord
+    /// A `Chord<3>` representing the I major chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, constants::*, major_scale};
     ///
-    /// let c_major = major_scale(C4);
-    /// let i_major_chord = c_major.i_major_chord();
+    /// let c_major = major_scale(C4).unwrap();
+    /// let i_major_chord = c_major.i_major_chord().unwrap();
     /// assert_eq!(i_major_chord.notes(), &[C4, E4, G4]);
     /// ```
-    pub fn i_major_chord(&self) -> Chord<3> {
+    pub fn i_major_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[0];
         major_triad(root)
     }
@@ -361,17 +854,18 @@ impl Scale<MajorScaleQuality, 8> {
     /// It is a minor triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the II minor chord
+    /// A `Chord<3>` representing the II minor chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, constants::*, major_scale};
     ///
-    /// let c_major = major_scale(C4);
-    /// let ii_minor_chord = c_major.ii_minor_chord();
+    /// let c_major = major_scale(C4).unwrap();
+    /// let ii_minor_chord = c_major.ii_minor_chord().unwrap();
     /// assert_eq!(ii_minor_chord.notes(), &[D4, F4, A4]);
     /// ```
-    pub fn ii_minor_chord(&self) -> Chord<3> {
+    pub fn ii_minor_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[1];
         minor_triad(root)
     }
@@ -382,17 +876,18 @@ impl Scale<MajorScaleQuality, 8> {
     /// It is a minor triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the III minor chord
+    /// A `Chord<3>` representing the III minor chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, constants::*, major_scale};
     ///
-    /// let c_major = major_scale(C4);
-    /// let iii_minor_chord = c_major.iii_minor_chord();
+    /// let c_major = major_scale(C4).unwrap();
+    /// let iii_minor_chord = c_major.iii_minor_chord().unwrap();
     /// assert_eq!(iii_minor_chord.notes(), &[E4, G4, B4]);
     /// ```
-    pub fn iii_minor_chord(&self) -> Chord<3> {
+    pub fn iii_minor_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[2];
         minor_triad(root)
     }
@@ -403,17 +898,18 @@ impl Scale<MajorScaleQuality, 8> {
     /// It is a major triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the IV major chord
+    /// A `Chord<3>` representing the IV major chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, constants::*, major_scale};
     ///
-    /// let c_major = major_scale(C4);
-    /// let iv_major_chord = c_major.iv_major_chord();
+    /// let c_major = major_scale(C4).unwrap();
+    /// let iv_major_chord = c_major.iv_major_chord().unwrap();
     /// assert_eq!(iv_major_chord.notes(), &[F4, A4, C5]);
     /// ```
-    pub fn iv_major_chord(&self) -> Chord<3> {
+    pub fn iv_major_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[3];
         major_triad(root)
     }
@@ -424,17 +920,18 @@ impl Scale<MajorScaleQuality, 8> {
     /// It is a major triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the V major chord
+    /// A `Chord<3>` representing the V major chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, constants::*, major_scale};
     ///
-    /// let c_major = major_scale(C4);
-    /// let v_major_chord = c_major.v_major_chord();
+    /// let c_major = major_scale(C4).unwrap();
+    /// let v_major_chord = c_major.v_major_chord().unwrap();
     /// assert_eq!(v_major_chord.notes(), &[G4, B4, D5]);
     /// ```
-    pub fn v_major_chord(&self) -> Chord<3> {
+    pub fn v_major_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[4];
         major_triad(root)
     }
@@ -445,17 +942,18 @@ impl Scale<MajorScaleQuality, 8> {
     /// It is a minor triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the VI minor chord
+    /// A `Chord<3>` representing the VI minor chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, constants::*, major_scale};
     ///
-    /// let c_major = major_scale(C4);
-    /// let vi_minor_chord = c_major.vi_minor_chord();
+    /// let c_major = major_scale(C4).unwrap();
+    /// let vi_minor_chord = c_major.vi_minor_chord().unwrap();
     /// assert_eq!(vi_minor_chord.notes(), &[A4, C5, E5]);
     /// ```
-    pub fn vi_minor_chord(&self) -> Chord<3> {
+    pub fn vi_minor_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[5];
         minor_triad(root)
     }
@@ -466,17 +964,18 @@ impl Scale<MajorScaleQuality, 8> {
     /// It is a diminished triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the VII diminished chord
+    /// A `Chord<3>` representing the VII diminished chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, constants::*, major_scale};
     ///
-    /// let c_major = major_scale(C4);
-    /// let vii_diminished_chord = c_major.vii_diminished_chord();
+    /// let c_major = major_scale(C4).unwrap();
+    /// let vii_diminished_chord = c_major.vii_diminished_chord().unwrap();
     /// assert_eq!(vii_diminished_chord.notes(), &[B4, D5, F5]);
     /// ```
-    pub fn vii_diminished_chord(&self) -> Chord<3> {
+    pub fn vii_diminished_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[6];
         diminished_triad(root)
     }
@@ -489,18 +988,19 @@ impl Scale<MinorScaleQuality, 8> {
     /// It is a minor triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the I minor chord
+    /// A `Chord<3>` representing the I minor chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, natural_minor_scale};
     /// use mozzart_std::constants::*;
     ///
-    /// let a_minor = natural_minor_scale(C4);
-    /// let i_minor_chord = a_minor.i_minor_chord();
+    /// let a_minor = natural_minor_scale(C4).unwrap();
+    /// let i_minor_chord = a_minor.i_minor_chord().unwrap();
     /// assert_eq!(i_minor_chord.notes(), &[C4, DSHARP4, G4]);
     /// ```
-    pub fn i_minor_chord(&self) -> Chord<3> {
+    pub fn i_minor_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[0];
         minor_triad(root)
     }
@@ -511,18 +1011,19 @@ impl Scale<MinorScaleQuality, 8> {
     /// It is a diminished triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the II diminished chord
+    /// A `Chord<3>` representing the II diminished chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, natural_minor_scale};
     /// use mozzart_std::constants::*;
     ///
-    /// let a_minor = natural_minor_scale(C4);
-    /// let ii_diminished_chord = a_minor.ii_diminished_chord();
+    /// let a_minor = natural_minor_scale(C4).unwrap();
+    /// let ii_diminished_chord = a_minor.ii_diminished_chord().unwrap();
     /// assert_eq!(ii_diminished_chord.notes(), &[D4, F4, GSHARP4]);
     /// ```
-    pub fn ii_diminished_chord(&self) -> Chord<3> {
+    pub fn ii_diminished_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[1];
         diminished_triad(root)
     }
@@ -534,18 +1035,19 @@ impl Scale<MinorScaleQuality, 8> {
     /// It is a major triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the III major chord
+    /// A `Chord<3>` representing the III major chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, natural_minor_scale};
     /// use mozzart_std::constants::*;
     ///
-    /// let a_minor = natural_minor_scale(C4);
-    /// let iii_major_chord = a_minor.iii_major_chord();
+    /// let a_minor = natural_minor_scale(C4).unwrap();
+    /// let iii_major_chord = a_minor.iii_major_chord().unwrap();
     /// assert_eq!(iii_major_chord.notes(), &[DSHARP4, G4, BFLAT4]);
     /// ```
-    pub fn iii_major_chord(&self) -> Chord<3> {
+    pub fn iii_major_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[2];
         major_triad(root)
     }
@@ -557,18 +1059,19 @@ impl Scale<MinorScaleQuality, 8> {
     /// It is a minor triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the IV minor chord
+    /// A `Chord<3>` representing the IV minor chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, natural_minor_scale};
     /// use mozzart_std::constants::*;
     ///
-    /// let a_minor = natural_minor_scale(C4);
-    /// let iv_minor_chord = a_minor.iv_minor_chord();
+    /// let a_minor = natural_minor_scale(C4).unwrap();
+    /// let iv_minor_chord = a_minor.iv_minor_chord().unwrap();
     /// assert_eq!(iv_minor_chord.notes(), &[F4, GSHARP4, C5]);
     /// ```
-    pub fn iv_minor_chord(&self) -> Chord<3> {
+    pub fn iv_minor_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[3];
         minor_triad(root)
     }
@@ -580,18 +1083,19 @@ impl Scale<MinorScaleQuality, 8> {
     /// It is a minor triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the V minor chord
+    /// A `Chord<3>` representing the V minor chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, natural_minor_scale};
     /// use mozzart_std::constants::*;
     ///
-    /// let a_minor = natural_minor_scale(C4);
-    /// let v_minor_chord = a_minor.v_minor_chord();
+    /// let a_minor = natural_minor_scale(C4).unwrap();
+    /// let v_minor_chord = a_minor.v_minor_chord().unwrap();
     /// assert_eq!(v_minor_chord.notes(), &[G4, BFLAT4, D5]);
     /// ```
-    pub fn v_minor_chord(&self) -> Chord<3> {
+    pub fn v_minor_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[4];
         minor_triad(root)
     }
@@ -603,18 +1107,19 @@ impl Scale<MinorScaleQuality, 8> {
     /// It is a major triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the VI major chord
+    /// A `Chord<3>` representing the VI major chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, natural_minor_scale};
     /// use mozzart_std::constants::*;
     ///
-    /// let a_minor = natural_minor_scale(C4);
-    /// let vi_major_chord = a_minor.vi_major_chord();
+    /// let a_minor = natural_minor_scale(C4).unwrap();
+    /// let vi_major_chord = a_minor.vi_major_chord().unwrap();
     /// assert_eq!(vi_major_chord.notes(), &[GSHARP4, C5, DSHARP5]);
     /// ```
-    pub fn vi_major_chord(&self) -> Chord<3> {
+    pub fn vi_major_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[5];
         major_triad(root)
     }
@@ -626,18 +1131,19 @@ impl Scale<MinorScaleQuality, 8> {
     /// It is a major triad with the root, third, and fifth notes.
     ///
     /// # Returns
-    /// A `Chord<3>` representing the VII major chord
+    /// A `Chord<3>` representing the VII major chord,
+    /// or `Error::NoteOutOfRange` if a note would be above MIDI 127
     ///
     /// # Examples
     /// ```
     /// use mozzart_std::{Note, natural_minor_scale};
     /// use mozzart_std::constants::*;
     ///
-    /// let a_minor = natural_minor_scale(C4);
-    /// let vii_major_chord = a_minor.vii_major_chord();
+    /// let a_minor = natural_minor_scale(C4).unwrap();
+    /// let vii_major_chord = a_minor.vii_major_chord().unwrap();
     /// assert_eq!(vii_major_chord.notes(), &[ASHARP4, D5, F5]);
     /// ```
-    pub fn vii_major_chord(&self) -> Chord<3> {
+    pub fn vii_major_chord(&self) -> Result<Chord<3>, Error> {
         let root = self.notes[6];
         major_triad(root)
     }
@@ -652,23 +1158,23 @@ impl Scale<MinorScaleQuality, 8> {
 /// * `root` - The root note from which to build the scale
 ///
 /// # Returns
-/// A `Scale<MajorScale, 8>` representing the major scale
+/// A `Scale<MajorScale, 8>` representing the major scale,
+/// or `Error::NoteOutOfRange` if a note would be above MIDI 127
 ///
 /// # Examples
 /// ```
 /// use mozzart_std::{Note, constants::*, major_scale};
 ///
 /// // Create a C major scale
-/// let c_major = major_scale(C4);
+/// let c_major = major_scale(C4).unwrap();
 /// let notes = c_major.notes();
 ///
 /// // C major should contain C, D, E, F, G, A, B, C
 /// assert_eq!(notes[0], C4);
 /// assert_eq!(notes[7], C5);
 /// ```
-pub fn major_scale(root: Note) -> Scale<MajorScaleQuality, 8> {
-    let notes = root.into_notes_from_steps(MAJOR_SCALE_STEPS);
-    Scale::new(notes)
+pub fn major_scale(root: Note) -> Result<Scale<MajorScaleQuality, 8>, Error> {
+    Scale::from_steps(root, &MAJOR_SCALE_STEPS)
 }
 
 /// Creates a natural minor scale starting from the specified root note
@@ -680,7 +1186,8 @@ pub fn major_scale(root: Note) -> Scale<MajorScaleQuality, 8> {
 /// * `root` - The root note from which to build the scale
 ///
 /// # Returns
-/// A `Scale<MinorScale, 8>` representing the natural minor scale
+/// A `Scale<MinorScale, 8>` representing the natural minor scale,
+/// or `Error::NoteOutOfRange` if a note would be above MIDI 127
 ///
 /// # Examples
 /// ```
@@ -688,7 +1195,7 @@ pub fn major_scale(root: Note) -> Scale<MajorScaleQuality, 8> {
 /// use mozzart_std::constants::*;
 ///
 /// // Create an A minor scale
-/// let a_minor = natural_minor_scale(A4);
+/// let a_minor = natural_minor_scale(A4).unwrap();
 /// let notes = a_minor.notes();
 ///
 /// // A minor should contain A, B, C, D, E, F, G, A
@@ -696,9 +1203,8 @@ pub fn major_scale(root: Note) -> Scale<MajorScaleQuality, 8> {
 /// assert_eq!(notes[2], C5);
 /// assert_eq!(notes[7], A5);
 /// ```
-pub fn natural_minor_scale(root: Note) -> Scale<MinorScaleQuality, 8> {
-    let notes = root.into_notes_from_steps(NATURAL_MINOR_SCALE_STEPS);
-    Scale::new(notes)
+pub fn natural_minor_scale(root: Note) -> Result<Scale<MinorScaleQuality, 8>, Error> {
+    Scale::from_steps(root, &NATURAL_MINOR_SCALE_STEPS)
 }
 
 /// Creates a harmonic minor scale starting from the specified root note
@@ -716,14 +1222,15 @@ pub fn natural_minor_scale(root: Note) -> Scale<MinorScaleQuality, 8> {
 /// * `root` - The root note from which to build the scale
 ///
 /// # Returns
-/// A `Scale<HarmonicMinorScale, 8>` representing the harmonic minor scale
+/// A `Scale<HarmonicMinorScale, 8>` representing the harmonic minor scale,
+/// or `Error::NoteOutOfRange` if a note would be above MIDI 127
 ///
 /// # Examples
 /// ```
 /// use mozzart_std::{Note, constants::*, harmonic_minor_scale};
 ///
 /// // Create an A harmonic minor scale
-/// let a_harmonic_minor = harmonic_minor_scale(A4);
+/// let a_harmonic_minor = harmonic_minor_scale(A4).unwrap();
 /// let notes = a_harmonic_minor.notes();
 ///
 /// // A harmonic minor should contain A, B, C, D, E, F, G#, A
@@ -731,9 +1238,8 @@ pub fn natural_minor_scale(root: Note) -> Scale<MinorScaleQuality, 8> {
 /// assert_eq!(notes[6], GSHARP5); // The raised 7th degree
 /// assert_eq!(notes[7], A5);
 /// ```
-pub fn harmonic_minor_scale(root: Note) -> Scale<HarmonicMinorScaleQuality, 8> {
-    let notes = root.into_notes_from_steps(HARMONIC_MINOR_SCALE_STEPS);
-    Scale::new(notes)
+pub fn harmonic_minor_scale(root: Note) -> Result<Scale<HarmonicMinorScaleQuality, 8>, Error> {
+    Scale::from_steps(root, &HARMONIC_MINOR_SCALE_STEPS)
 }
 
 /// Creates a melodic minor scale (ascending form) starting from the specified root note
@@ -751,14 +1257,15 @@ pub fn harmonic_minor_scale(root: Note) -> Scale<HarmonicMinorScaleQuality, 8> {
 /// * `root` - The root note from which to build the scale
 ///
 /// # Returns
-/// A `Scale<MelodicMinorScale, 8>` representing the melodic minor scale (ascending form)
+/// A `Scale<MelodicMinorScale, 8>` representing the melodic minor scale (ascending form),
+/// or `Error::NoteOutOfRange` if a note would be above MIDI 127
 ///
 /// # Examples
 /// ```
 /// use mozzart_std::{Note, constants::*, melodic_minor_scale};
 ///
 /// // Create an A melodic minor scale
-/// let a_melodic_minor = melodic_minor_scale(A4);
+/// let a_melodic_minor = melodic_minor_scale(A4).unwrap();
 /// let notes = a_melodic_minor.notes();
 ///
 /// // A melodic minor should contain A, B, C, D, E, F#, G#, A
@@ -767,18 +1274,89 @@ pub fn harmonic_minor_scale(root: Note) -> Scale<HarmonicMinorScaleQuality, 8> {
 /// assert_eq!(notes[6], GSHARP5); // The raised 7th degree
 /// assert_eq!(notes[7], A5);
 /// ```
-pub fn melodic_minor_scale(root: Note) -> Scale<MelodicMinorScaleQuality, 8> {
-    let notes = root.into_notes_from_steps(MELODIC_MINOR_SCALE_STEPS);
-    Scale::new(notes)
+pub fn melodic_minor_scale(root: Note) -> Result<Scale<MelodicMinorScaleQuality, 8>, Error> {
+    Scale::from_steps(root, &MELODIC_MINOR_SCALE_STEPS)
+}
+
+/// Creates a whole-tone scale starting from the specified root note
+///
+/// A whole-tone scale consists of 7 notes (including the octave), each a whole
+/// step above the previous one.
+///
+/// # Arguments
+/// * `root` - The root note from which to build the scale
+///
+/// # Returns
+/// A `Scale<WholeToneScaleQuality, 7>` representing the whole-tone scale,
+/// or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
+/// # Examples
+/// ```
+/// use mozzart_std::{constants::*, whole_tone_scale};
+///
+/// let c_whole_tone = whole_tone_scale(C4).unwrap();
+/// assert_eq!(c_whole_tone.notes(), &[C4, D4, E4, FSHARP4, GSHARP4, ASHARP4, C5]);
+/// ```
+pub fn whole_tone_scale(root: Note) -> Result<Scale<WholeToneScaleQuality, 7>, Error> {
+    Scale::from_steps(root, &WHOLE_TONE_SCALE_STEPS)
+}
+
+/// Creates an octatonic (half-whole) scale starting from the specified root note
+///
+/// An octatonic scale consists of 9 notes (including the octave), alternating
+/// half and whole steps.
+///
+/// # Arguments
+/// * `root` - The root note from which to build the scale
+///
+/// # Returns
+/// A `Scale<OctatonicScaleQuality, 9>` representing the octatonic scale,
+/// or `Error::NoteOutOfRange` if a note would be above MIDI 127
+///
+/// # Examples
+/// ```
+/// use mozzart_std::{constants::*, octatonic_scale};
+///
+/// let c_octatonic = octatonic_scale(C4).unwrap();
+/// assert_eq!(c_octatonic.notes(), &[C4, CSHARP4, DSHARP4, E4, FSHARP4, G4, A4, ASHARP4, C5]);
+/// ```
+pub fn octatonic_scale(root: Note) -> Result<Scale<OctatonicScaleQuality, 9>, Error> {
+    Scale::from_steps(root, &OCTATONIC_SCALE_STEPS)
+}
+
+/// Parses a scale from its root note and its quality, such as `"C4 major"`
+///
+/// The string is parsed as a `DynScale` and then converted, so its quality must
+/// be the quality of the scale type.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let scale: Scale<HarmonicMinorScaleQuality, 8> = "A4 harmonic".parse().unwrap();
+/// assert_eq!(scale, harmonic_minor_scale(A4).unwrap());
+/// assert!("A4 major".parse::<Scale<HarmonicMinorScaleQuality, 8>>().is_err());
+/// ```
+impl<Q, const N: usize> FromStr for Scale<Q, N>
+where
+    Q: ScaleQuality,
+{
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        name.parse::<DynScale>()?.try_into_fixed()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{ChordQuality, ScaleLike};
 
     #[test]
     fn test_major_scale() {
-        let c4_major = major_scale(C4);
+        let c4_major = major_scale(C4).unwrap();
         let notes = c4_major.notes();
 
         // Verify notes in C major scale
@@ -794,9 +1372,22 @@ mod tests {
         assert_eq!(c4_major.to_string(), "C major");
     }
 
+    #[test]
+    fn test_eq_notes() {
+        let scale = major_scale(C4).unwrap();
+        assert_eq!(scale, [C4, D4, E4, F4, G4, A4, B4, C5]);
+        assert_eq!(scale, scale.notes().to_vec());
+        assert_eq!(scale, *scale.notes().as_slice());
+        assert_ne!(scale, [C4, D4, E4, F4, G4, A4, B4]);
+        assert_ne!(
+            natural_minor_scale(C4).unwrap(),
+            [C4, D4, E4, F4, G4, A4, B4, C5]
+        );
+    }
+
     #[test]
     fn test_natural_minor_scale() {
-        let a4_minor = natural_minor_scale(A4);
+        let a4_minor = natural_minor_scale(A4).unwrap();
         let notes = a4_minor.notes();
 
         // Verify notes in A minor scale
@@ -814,7 +1405,7 @@ mod tests {
 
     #[test]
     fn test_harmonic_minor_scale() {
-        let a4_harmonic_minor = harmonic_minor_scale(A4);
+        let a4_harmonic_minor = harmonic_minor_scale(A4).unwrap();
         let notes = a4_harmonic_minor.notes();
 
         // Verify notes in A harmonic minor scale
@@ -828,16 +1419,16 @@ mod tests {
         assert_eq!(notes[7], A5); // A5 (octave)
 
         // Confirm the difference from natural minor is at the 7th degree
-        let a4_natural_minor = natural_minor_scale(A4);
+        let a4_natural_minor = natural_minor_scale(A4).unwrap();
         assert_eq!(a4_natural_minor.notes()[6], G5); // G5 in natural minor
-        assert_eq!(harmonic_minor_scale(A4).notes()[6], GSHARP5); // G#5 in harmonic minor
+        assert_eq!(harmonic_minor_scale(A4).unwrap().notes()[6], GSHARP5); // G#5 in harmonic minor
 
         assert_eq!(a4_harmonic_minor.to_string(), "A harmonic minor");
     }
 
     #[test]
     fn test_melodic_minor_scale() {
-        let a4_melodic_minor = melodic_minor_scale(A4);
+        let a4_melodic_minor = melodic_minor_scale(A4).unwrap();
         let notes = a4_melodic_minor.notes();
 
         // Verify notes in A melodic minor scale (ascending)
@@ -851,12 +1442,12 @@ mod tests {
         assert_eq!(notes[7], A5); // A5 (octave)
 
         // Confirm the difference from natural minor is at the 6th and 7th degrees
-        let a4_natural_minor = natural_minor_scale(A4);
+        let a4_natural_minor = natural_minor_scale(A4).unwrap();
         assert_eq!(a4_natural_minor.notes()[5], F5); // F5 in natural minor
         assert_eq!(a4_natural_minor.notes()[6], G5); // G5 in natural minor
 
-        assert_eq!(melodic_minor_scale(A4).notes()[5], FSHARP5); // F#5 in melodic minor
-        assert_eq!(melodic_minor_scale(A4).notes()[6], GSHARP5); // G#5 in melodic minor
+        assert_eq!(melodic_minor_scale(A4).unwrap().notes()[5], FSHARP5); // F#5 in melodic minor
+        assert_eq!(melodic_minor_scale(A4).unwrap().notes()[6], GSHARP5); // G#5 in melodic minor
 
         assert_eq!(a4_melodic_minor.to_string(), "A melodic minor");
     }
@@ -866,21 +1457,21 @@ mod tests {
         // Test with different roots to ensure scale patterns work correctly
 
         // D major scale
-        let d4_major = major_scale(D4);
+        let d4_major = major_scale(D4).unwrap();
         let notes = d4_major.notes();
         assert_eq!(notes[0], D4); // D4
         assert_eq!(notes[2], FSHARP4); // F#4 (not F4)
         assert_eq!(notes[6], CSHARP5); // C#5 (not C5)
 
         // E harmonic minor scale
-        let e4_harmonic_minor = harmonic_minor_scale(E4);
+        let e4_harmonic_minor = harmonic_minor_scale(E4).unwrap();
         let notes = e4_harmonic_minor.notes();
         assert_eq!(notes[0], E4); // E4
         assert_eq!(notes[2], G4); // G4
         assert_eq!(notes[6], DSHARP5); // D#5 (raised 7th)
 
         // G melodic minor scale
-        let g4_melodic_minor = melodic_minor_scale(G4);
+        let g4_melodic_minor = melodic_minor_scale(G4).unwrap();
         let notes = g4_melodic_minor.notes();
         assert_eq!(notes[0], G4); // G4
         assert_eq!(notes[5], E5); // E5 (raised 6th)
@@ -889,7 +1480,7 @@ mod tests {
 
     #[test]
     fn test_intervals() {
-        let c_major = major_scale(C4);
+        let c_major = major_scale(C4).unwrap();
         let intervals = c_major.intervals();
         assert_eq!(
             intervals,
@@ -898,7 +1489,7 @@ mod tests {
                 MAJOR_THIRD,
                 PERFECT_FOURTH,
                 PERFECT_FIFTH,
-                MINOR_SIXTH,
+                MAJOR_SIXTH,
                 MAJOR_SEVENTH,
                 PERFECT_OCTAVE
             ]
@@ -907,106 +1498,547 @@ mod tests {
 
     #[test]
     fn test_steps() {
-        let c_major = major_scale(C4);
+        let c_major = major_scale(C4).unwrap();
         let steps = c_major.steps();
         assert_eq!(steps, [WHOLE, WHOLE, HALF, WHOLE, WHOLE, WHOLE, HALF]);
     }
 
     #[test]
     fn test_major_scale_i_chord() {
-        let c_major = major_scale(C4);
-        let i_chord = c_major.i_major_chord();
+        let c_major = major_scale(C4).unwrap();
+        let i_chord = c_major.i_major_chord().unwrap();
         assert_eq!(i_chord.notes(), &[C4, E4, G4]);
     }
 
     #[test]
     fn test_major_scale_ii_chord() {
-        let c_major = major_scale(C4);
-        let ii_chord = c_major.ii_minor_chord();
+        let c_major = major_scale(C4).unwrap();
+        let ii_chord = c_major.ii_minor_chord().unwrap();
         assert_eq!(ii_chord.notes(), &[D4, F4, A4]);
     }
 
     #[test]
     fn test_major_scale_iii_chord() {
-        let c_major = major_scale(C4);
-        let iii_chord = c_major.iii_minor_chord();
+        let c_major = major_scale(C4).unwrap();
+        let iii_chord = c_major.iii_minor_chord().unwrap();
         assert_eq!(iii_chord.notes(), &[E4, G4, B4]);
     }
 
     #[test]
     fn test_major_scale_iv_chord() {
-        let c_major = major_scale(C4);
-        let iv_chord = c_major.iv_major_chord();
+        let c_major = major_scale(C4).unwrap();
+        let iv_chord = c_major.iv_major_chord().unwrap();
         assert_eq!(iv_chord.notes(), &[F4, A4, C5]);
     }
 
     #[test]
     fn test_major_scale_v_chord() {
-        let c_major = major_scale(C4);
-        let v_chord = c_major.v_major_chord();
+        let c_major = major_scale(C4).unwrap();
+        let v_chord = c_major.v_major_chord().unwrap();
         assert_eq!(v_chord.notes(), &[G4, B4, D5]);
     }
 
     #[test]
     fn test_major_scale_vi_chord() {
-        let c_major = major_scale(C4);
-        let vi_chord = c_major.vi_minor_chord();
+        let c_major = major_scale(C4).unwrap();
+        let vi_chord = c_major.vi_minor_chord().unwrap();
         assert_eq!(vi_chord.notes(), &[A4, C5, E5]);
     }
 
     #[test]
     fn test_major_scale_vii_chord() {
-        let c_major = major_scale(C4);
-        let vii_chord = c_major.vii_diminished_chord();
+        let c_major = major_scale(C4).unwrap();
+        let vii_chord = c_major.vii_diminished_chord().unwrap();
         assert_eq!(vii_chord.notes(), &[B4, D5, F5]);
     }
 
     #[test]
     fn test_minor_scale_i_chord() {
-        let a_minor = natural_minor_scale(A4);
-        let i_chord = a_minor.i_minor_chord();
+        let a_minor = natural_minor_scale(A4).unwrap();
+        let i_chord = a_minor.i_minor_chord().unwrap();
         assert_eq!(i_chord.notes(), &[A4, C5, E5]);
     }
 
     #[test]
     fn test_minor_scale_ii_chord() {
-        let a_minor = natural_minor_scale(A4);
-        let ii_chord = a_minor.ii_diminished_chord();
+        let a_minor = natural_minor_scale(A4).unwrap();
+        let ii_chord = a_minor.ii_diminished_chord().unwrap();
         assert_eq!(ii_chord.notes(), &[B4, D5, F5]);
     }
 
     #[test]
     fn test_minor_scale_iii_chord() {
-        let a_minor = natural_minor_scale(A4);
-        let iii_chord = a_minor.iii_major_chord();
+        let a_minor = natural_minor_scale(A4).unwrap();
+        let iii_chord = a_minor.iii_major_chord().unwrap();
         assert_eq!(iii_chord.notes(), &[C5, E5, G5]);
     }
 
     #[test]
     fn test_minor_scale_iv_chord() {
-        let a_minor = natural_minor_scale(A4);
-        let iv_chord = a_minor.iv_minor_chord();
+        let a_minor = natural_minor_scale(A4).unwrap();
+        let iv_chord = a_minor.iv_minor_chord().unwrap();
         assert_eq!(iv_chord.notes(), &[D5, F5, A5]);
     }
 
     #[test]
     fn test_minor_scale_v_chord() {
-        let a_minor = natural_minor_scale(A4);
-        let v_chord = a_minor.v_minor_chord();
+        let a_minor = natural_minor_scale(A4).unwrap();
+        let v_chord = a_minor.v_minor_chord().unwrap();
         assert_eq!(v_chord.notes(), &[E5, G5, B5]);
     }
 
     #[test]
     fn test_minor_scale_vi_chord() {
-        let a_minor = natural_minor_scale(A4);
-        let vi_chord = a_minor.vi_major_chord();
+        let a_minor = natural_minor_scale(A4).unwrap();
+        let vi_chord = a_minor.vi_major_chord().unwrap();
         assert_eq!(vi_chord.notes(), &[F5, A5, C6]);
     }
 
     #[test]
     fn test_minor_scale_vii_chord() {
-        let a_minor = natural_minor_scale(A4);
-        let vii_chord = a_minor.vii_major_chord();
+        let a_minor = natural_minor_scale(A4).unwrap();
+        let vii_chord = a_minor.vii_major_chord().unwrap();
         assert_eq!(vii_chord.notes(), &[G5, B5, D6]);
     }
+
+    #[test]
+    fn test_black_and_white_key_counts() {
+        let c_major = major_scale(C4).unwrap();
+        assert_eq!(c_major.black_key_count(), 0);
+        assert_eq!(c_major.white_key_count(), 8);
+
+        let fsharp_major = major_scale(FSHARP4).unwrap();
+        assert_eq!(fsharp_major.black_key_count(), 6);
+        assert_eq!(fsharp_major.white_key_count(), 2);
+
+        let a_minor = natural_minor_scale(A4).unwrap();
+        assert_eq!(a_minor.black_key_count(), 0);
+
+        let a_harmonic_minor = harmonic_minor_scale(A4).unwrap();
+        assert_eq!(a_harmonic_minor.black_key_count(), 1);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let c_major = major_scale(C4).unwrap();
+        assert_eq!(c_major.upper_neighbor(E4), Some(F4));
+        assert_eq!(c_major.lower_neighbor(E4), Some(D4));
+
+        // Wrap-around at the tonic and the octave
+        assert_eq!(c_major.lower_neighbor(C4), Some(B3));
+        assert_eq!(c_major.upper_neighbor(B4), Some(C5));
+        assert_eq!(c_major.upper_neighbor(C5), Some(D5));
+        assert_eq!(c_major.lower_neighbor(C2), Some(B1));
+
+        // Notes outside of the scale
+        assert_eq!(c_major.upper_neighbor(CSHARP4), Some(D4));
+        assert_eq!(c_major.lower_neighbor(CSHARP4), Some(C4));
+
+        // The edges of the MIDI range
+        assert_eq!(c_major.upper_neighbor(G9), None);
+        assert_eq!(c_major.lower_neighbor(Note::new(0)), None);
+    }
+
+    #[test]
+    fn test_quantize() {
+        let c_major = major_scale(C4).unwrap();
+        assert_eq!(c_major.quantize(G1), G1);
+        assert_eq!(c_major.quantize(CSHARP4), C4);
+
+        // The closer neighbor wins, the lower one on a tie
+        assert_eq!(harmonic_minor_scale(A3).unwrap().quantize(G4), GSHARP4);
+        assert_eq!(whole_tone_scale(C4).unwrap().quantize(CSHARP4), C4);
+        assert_eq!(
+            major_scale(D4).unwrap().quantize(Note::new(0)),
+            Note::new(1)
+        );
+
+        let notes = [C4, CSHARP4, DSHARP4, FSHARP4, GSHARP4, ASHARP4];
+        let mut quantized = vec![G9; 2];
+        c_major.quantize_all_into(&notes, &mut quantized);
+        assert_eq!(quantized, vec![C4, C4, D4, F4, G4, A4]);
+        assert_eq!(c_major.quantize_all(&notes), quantized);
+    }
+
+    #[test]
+    fn test_retrograde() {
+        let a_minor = natural_minor_scale(A4).unwrap();
+        assert_eq!(a_minor.retrograde(), vec![A5, G5, F5, E5, D5, C5, B4, A4]);
+    }
+
+    #[test]
+    fn test_formula() {
+        let names = |formula: Vec<Interval>| -> Vec<&str> {
+            formula.iter().filter_map(Interval::name).collect()
+        };
+        assert_eq!(
+            names(major_scale(C4).unwrap().formula()),
+            ["P1", "M2", "M3", "P4", "P5", "M6", "M7"]
+        );
+        assert_eq!(
+            names(natural_minor_scale(A3).unwrap().formula()),
+            ["P1", "M2", "m3", "P4", "P5", "m6", "m7"]
+        );
+        assert_eq!(
+            major_scale(C4).unwrap().formula(),
+            major_scale(FSHARP2).unwrap().formula()
+        );
+        assert_eq!(whole_tone_scale(C4).unwrap().formula().len(), 6);
+    }
+
+    #[test]
+    fn test_note_at() {
+        let c_major = major_scale(C4).unwrap();
+        assert_eq!(c_major.note_at(0), C4);
+        assert_eq!(c_major.note_at(7), C5);
+        assert_eq!(c_major.note_at(-1), B3);
+        assert_eq!(c_major.note_at(-7), C3);
+        assert_eq!(c_major.note_at(9), E5);
+
+        // The ladder is clamped to the MIDI range
+        assert_eq!(c_major.note_at(i32::MAX).midi_number(), 127);
+        assert_eq!(c_major.note_at(i32::MIN).midi_number(), 0);
+
+        let whole_tone = whole_tone_scale(C4).unwrap();
+        assert_eq!(whole_tone.note_at(6), C5);
+        assert_eq!(whole_tone.note_at(-1), ASHARP3);
+    }
+
+    #[test]
+    fn test_chromatic_approach() {
+        let c_major = major_scale(C4).unwrap();
+        assert_eq!(c_major.chromatic_approach_below(G4), Some(FSHARP4));
+        assert_eq!(c_major.chromatic_approach_above(G4), Some(GSHARP4));
+
+        // The approach doesn't depend on the scale, unlike the neighbors
+        assert_eq!(c_major.chromatic_approach_below(F4), Some(E4));
+        assert_eq!(c_major.lower_neighbor(F4), Some(E4));
+        assert_eq!(c_major.chromatic_approach_below(D4), Some(CSHARP4));
+        assert_eq!(c_major.lower_neighbor(D4), Some(C4));
+
+        assert_eq!(c_major.chromatic_approach_below(Note::new(0)), None);
+        assert_eq!(c_major.chromatic_approach_above(G9), None);
+    }
+
+    #[test]
+    fn test_interval_class_vector() {
+        assert_eq!(
+            major_scale(C4).unwrap().interval_class_vector(),
+            [2, 5, 4, 3, 6, 1]
+        );
+
+        // Transposition and the relative minor share the same vector
+        assert_eq!(
+            major_scale(FSHARP3).unwrap().interval_class_vector(),
+            [2, 5, 4, 3, 6, 1]
+        );
+        assert_eq!(
+            natural_minor_scale(A4).unwrap().interval_class_vector(),
+            [2, 5, 4, 3, 6, 1]
+        );
+        assert_eq!(
+            harmonic_minor_scale(A4).unwrap().interval_class_vector(),
+            [3, 3, 5, 4, 4, 2]
+        );
+    }
+
+    #[test]
+    fn test_same_pitch_classes() {
+        let c4_major = major_scale(C4).unwrap();
+        let c5_major = major_scale(C5).unwrap();
+
+        assert!(c4_major.same_pitch_classes(&c5_major));
+        assert!(c4_major != c5_major);
+        assert!(c4_major == major_scale(C4).unwrap());
+
+        assert!(c4_major.same_pitch_classes(&natural_minor_scale(A4).unwrap()));
+        assert!(!c4_major.same_pitch_classes(&natural_minor_scale(C4).unwrap()));
+        assert!(!c4_major.same_pitch_classes(&whole_tone_scale(C4).unwrap()));
+    }
+
+    #[test]
+    fn test_transpositional_symmetry() {
+        let semitones = |intervals: Vec<Interval>| {
+            intervals
+                .iter()
+                .map(Interval::semitones)
+                .collect::<Vec<_>>()
+        };
+
+        // The whole-tone scale maps onto itself under every whole step
+        let whole_tone = whole_tone_scale(C4).unwrap();
+        assert_eq!(
+            semitones(whole_tone.transpositional_symmetry()),
+            vec![2, 4, 6, 8, 10]
+        );
+        assert!(whole_tone.is_mode_of_limited_transposition());
+
+        // The octatonic scale maps onto itself under every minor third
+        let octatonic = octatonic_scale(D4).unwrap();
+        assert_eq!(
+            semitones(octatonic.transpositional_symmetry()),
+            vec![3, 6, 9]
+        );
+        assert!(octatonic.is_mode_of_limited_transposition());
+
+        let c_major = major_scale(C4).unwrap();
+        assert!(c_major.transpositional_symmetry().is_empty());
+        assert!(!c_major.is_mode_of_limited_transposition());
+        assert!(!harmonic_minor_scale(A4)
+            .unwrap()
+            .is_mode_of_limited_transposition());
+    }
+
+    #[test]
+    fn test_diatonic_scales() {
+        // D Dorian is D natural minor with a raised sixth
+        let d_dorian = natural_minor_scale(D4).unwrap().with_raised(6).unwrap();
+        assert_eq!(d_dorian.notes(), &[D4, E4, F4, G4, A4, B4, C5, D5]);
+        assert!(d_dorian.is_diatonic());
+        assert_eq!(d_dorian.parent_major(), Some(C4));
+
+        assert_eq!(major_scale(FSHARP3).unwrap().parent_major(), Some(FSHARP3));
+        assert_eq!(natural_minor_scale(E4).unwrap().parent_major(), Some(G3));
+
+        // The parent major is taken an octave up when it would leave the MIDI range
+        assert_eq!(
+            natural_minor_scale(Note::new(9)).unwrap().parent_major(),
+            Some(Note::new(0))
+        );
+        let low = natural_minor_scale(Note::new(4)).unwrap();
+        assert_eq!(low.parent_major(), Some(Note::new(7)));
+
+        assert!(!whole_tone_scale(C4).unwrap().is_diatonic());
+        assert_eq!(whole_tone_scale(C4).unwrap().parent_major(), None);
+        assert!(!harmonic_minor_scale(A4).unwrap().is_diatonic());
+        assert!(!melodic_minor_scale(A4).unwrap().is_diatonic());
+        assert!(!octatonic_scale(C4).unwrap().is_diatonic());
+    }
+
+    #[test]
+    fn test_triad_on() {
+        let c_major = major_scale(C4).unwrap();
+        let expected = [
+            (ChordQuality::MajorTriad, [C4, E4, G4]),
+            (ChordQuality::MinorTriad, [D4, F4, A4]),
+            (ChordQuality::MinorTriad, [E4, G4, B4]),
+            (ChordQuality::MajorTriad, [F4, A4, C5]),
+            (ChordQuality::MajorTriad, [G4, B4, D5]),
+            (ChordQuality::MinorTriad, [A4, C5, E5]),
+            (ChordQuality::DiminishedTriad, [B4, D5, F5]),
+        ];
+        for (degree, (quality, notes)) in expected.iter().enumerate() {
+            let triad = c_major.triad_on(degree + 1).unwrap();
+            assert_eq!(triad.quality(), *quality);
+            assert_eq!(triad.notes(), notes);
+        }
+
+        assert_eq!(
+            c_major.triad_on(5).unwrap(),
+            c_major.v_major_chord().unwrap()
+        );
+        assert!(c_major.triad_on(0).is_none());
+        assert!(c_major.triad_on(8).is_none());
+
+        // The third degree of harmonic minor is an augmented triad
+        let iii = harmonic_minor_scale(A4).unwrap().triad_on(3).unwrap();
+        assert_eq!(iii.quality(), ChordQuality::AugmentedTriad);
+    }
+
+    #[test]
+    fn test_seventh_on() {
+        let c_major = major_scale(C4).unwrap();
+        let ii = c_major.seventh_on(2).unwrap();
+        assert_eq!(ii.quality(), ChordQuality::MinorSeventh);
+        assert_eq!(ii.notes(), &[D4, F4, A4, C5]);
+
+        assert_eq!(
+            c_major.seventh_on(1).unwrap().quality(),
+            ChordQuality::MajorSeventh
+        );
+        assert_eq!(
+            c_major.seventh_on(5).unwrap().quality(),
+            ChordQuality::DominantSeventh
+        );
+        assert_eq!(
+            c_major.seventh_on(7).unwrap().quality(),
+            ChordQuality::HalfDiminishedSeventh
+        );
+        assert_eq!(
+            harmonic_minor_scale(A4)
+                .unwrap()
+                .seventh_on(7)
+                .unwrap()
+                .quality(),
+            ChordQuality::DiminishedSeventh
+        );
+
+        // The augmented major seventh of harmonic minor has no chord quality
+        assert!(harmonic_minor_scale(A4).unwrap().seventh_on(3).is_none());
+        // The chord would go above MIDI 127
+        assert!(major_scale(C8).unwrap().seventh_on(7).is_none());
+        // The scale itself would go above MIDI 127
+        assert_eq!(major_scale(C9), Err(Error::NoteOutOfRange(C9)));
+        assert_eq!(octatonic_scale(G9), Err(Error::NoteOutOfRange(G9)));
+        assert!(major_scale(G8).is_ok());
+    }
+
+    #[test]
+    fn test_tetrachords() {
+        let (lower, upper) = major_scale(C4).unwrap().tetrachords();
+        assert_eq!(lower.steps(), &[MAJOR_SECOND, MAJOR_SECOND, MINOR_SECOND]);
+        assert_eq!(upper.steps(), &[MAJOR_SECOND, MAJOR_SECOND, MINOR_SECOND]);
+        assert_eq!(upper.root(), G4);
+
+        // The harmonic minor has a minor lower and a harmonic upper tetrachord
+        let (lower, upper) = harmonic_minor_scale(A4).unwrap().tetrachords();
+        assert_eq!(lower.steps(), &[MAJOR_SECOND, MINOR_SECOND, MAJOR_SECOND]);
+        assert_eq!(upper.steps(), &[MINOR_SECOND, MINOR_THIRD, MINOR_SECOND]);
+    }
+
+    /// Returns the names of the notes of a scale, as text
+    fn names(names: &[SpelledNote]) -> Vec<String> {
+        names.iter().map(|note| note.to_string()).collect()
+    }
+
+    #[test]
+    fn test_note_names_of_every_root() {
+        for midi in 60..72 {
+            let root = Note::new(midi);
+            let scales = [
+                (
+                    major_scale(root).unwrap().note_names(),
+                    major_scale(root).unwrap().notes()[..7].to_vec(),
+                ),
+                (
+                    harmonic_minor_scale(root).unwrap().note_names(),
+                    harmonic_minor_scale(root).unwrap().notes()[..7].to_vec(),
+                ),
+            ];
+            for (spelled, notes) in scales {
+                // One note per letter
+                let mut letters: Vec<_> = spelled.iter().map(|note| note.letter()).collect();
+                letters.sort();
+                letters.dedup();
+                assert_eq!(letters.len(), 7, "{root:?}");
+
+                let pitch_classes: Vec<_> = notes.iter().map(Note::pitch_class).collect();
+                let spelled: Vec<_> = spelled.iter().map(SpelledNote::pitch_class).collect();
+                assert_eq!(spelled, pitch_classes, "{root:?}");
+            }
+        }
+
+        assert_eq!(
+            names(&major_scale(FSHARP4).unwrap().note_names()),
+            ["F#", "G#", "A#", "B", "C#", "D#", "E#"]
+        );
+        assert_eq!(
+            names(&major_scale(DFLAT4).unwrap().note_names()),
+            ["Db", "Eb", "F", "Gb", "Ab", "Bb", "C"]
+        );
+        assert_eq!(
+            names(&harmonic_minor_scale(C4).unwrap().note_names()),
+            ["C", "D", "Eb", "F", "G", "Ab", "B"]
+        );
+    }
+
+    #[test]
+    fn test_note_names_with_double_accidentals() {
+        let g_sharp = "G#".parse().unwrap();
+        assert_eq!(
+            names(
+                &major_scale(GSHARP4)
+                    .unwrap()
+                    .note_names_from(g_sharp)
+                    .unwrap()
+            ),
+            ["G#", "A#", "B#", "C#", "D#", "E#", "F##"]
+        );
+        assert_eq!(
+            names(
+                &harmonic_minor_scale(GSHARP4)
+                    .unwrap()
+                    .note_names_from(g_sharp)
+                    .unwrap()
+            ),
+            ["G#", "A#", "B", "C#", "D#", "E", "F##"]
+        );
+        assert_eq!(
+            names(
+                &major_scale(CSHARP4)
+                    .unwrap()
+                    .note_names_from("C#".parse().unwrap())
+                    .unwrap()
+            ),
+            ["C#", "D#", "E#", "F#", "G#", "A#", "B#"]
+        );
+        assert_eq!(
+            names(
+                &harmonic_minor_scale(C4)
+                    .unwrap()
+                    .note_names_from("B#".parse().unwrap())
+                    .unwrap()
+            ),
+            ["B#", "C##", "D#", "E#", "F##", "G#", "A##"]
+        );
+        // E## major would need a triple sharp on its seventh degree
+        assert_eq!(
+            major_scale(FSHARP4)
+                .unwrap()
+                .note_names_from("E##".parse().unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_functional_degrees() {
+        let c_major = major_scale(C4).unwrap();
+        assert_eq!(c_major.dominant(), G4);
+        assert_eq!(c_major.subdominant(), F4);
+        assert_eq!(c_major.leading_tone(), Some(B4));
+
+        let c_minor = natural_minor_scale(C4).unwrap();
+        assert_eq!(c_minor.dominant(), G4);
+        assert_eq!(c_minor.leading_tone(), None);
+        assert_eq!(harmonic_minor_scale(C4).unwrap().leading_tone(), Some(B4));
+        assert_eq!(melodic_minor_scale(C4).unwrap().leading_tone(), Some(B4));
+    }
+
+    #[test]
+    fn test_thirds_cycle() {
+        let c_major = major_scale(C4).unwrap();
+        assert_eq!(c_major.thirds_cycle(1, 5), vec![C4, E4, G4, B4, D5]);
+        // The cycle goes around the scale, an octave higher each time it passes the tonic
+        assert_eq!(c_major.thirds_cycle(6, 6), vec![A4, C5, E5, G5, B5, D6]);
+        assert_eq!(c_major.thirds_cycle(1, 8)[7], C6);
+
+        // The cycle stops before going above MIDI 127
+        assert_eq!(
+            major_scale(C8).unwrap().thirds_cycle(1, 10),
+            vec![C8, E8, G8, B8, D9, F9]
+        );
+        assert!(c_major.thirds_cycle(8, 3).is_empty());
+        assert!(c_major.thirds_cycle(1, 0).is_empty());
+    }
+
+    #[test]
+    fn test_parse() {
+        let scale: Scale<HarmonicMinorScaleQuality, 8> = "A4 harmonic".parse().unwrap();
+        assert_eq!(scale, harmonic_minor_scale(A4).unwrap());
+
+        let scale: Scale<MajorScaleQuality, 8> = "C4 major".parse().unwrap();
+        assert_eq!(scale, major_scale(C4).unwrap());
+
+        assert_eq!(
+            "C4 bogus".parse::<Scale<MajorScaleQuality, 8>>(),
+            Err(Error::UnknownScaleQuality("bogus".to_string()))
+        );
+        assert_eq!(
+            "C4 minor".parse::<Scale<MajorScaleQuality, 8>>(),
+            Err(Error::ScaleQualityMismatch {
+                expected: "major",
+                found: "minor".to_string()
+            })
+        );
+    }
 }
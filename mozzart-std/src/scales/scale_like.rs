@@ -0,0 +1,396 @@
+use crate::constants::*;
+use crate::{major_scale, Interval, Mode, Note, PitchClassMask, Scale, ScaleQuality};
+
+/// Common behavior of the scales, whether their size is known at compile time or not
+///
+/// `Scale` and `DynScale` both implement this trait, so the analysis methods
+/// work the same on a `Scale<_, 8>` built by `major_scale` and on a `DynScale`
+/// of any length. Functions that only need the notes of a scale can take an
+/// `impl ScaleLike` to accept both.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// fn is_symmetric(scale: &impl ScaleLike) -> bool {
+///     scale.is_mode_of_limited_transposition()
+/// }
+///
+/// assert!(is_symmetric(&whole_tone_scale(C4).unwrap()));
+/// assert!(is_symmetric(&messiaen_mode(3, C4).unwrap()));
+/// assert!(!is_symmetric(&major_scale(C4).unwrap()));
+/// ```
+pub trait ScaleLike {
+    /// Returns the notes of the scale, starting with the tonic and including the octave
+    fn pitches(&self) -> &[Note];
+
+    /// Returns the tonic, the first note of the scale
+    fn tonic(&self) -> Note {
+        self.pitches()[0]
+    }
+
+    /// Returns `true` if a note with the same pitch class belongs to the scale
+    ///
+    /// # Arguments
+    /// * `note` - The note to look for, in any octave
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, ScaleLike};
+    ///
+    /// assert!(major_scale(C4).unwrap().contains_pitch_class(B2));
+    /// assert!(!major_scale(C4).unwrap().contains_pitch_class(FSHARP4));
+    /// ```
+    fn contains_pitch_class(&self, note: Note) -> bool {
+        self.pitch_class_mask().contains(note)
+    }
+
+    /// Returns `true` if the note itself, in this octave, belongs to the scale
+    ///
+    /// # Arguments
+    /// * `note` - The note to look for
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, ScaleLike};
+    ///
+    /// assert!(major_scale(C4).unwrap().contains(B4));
+    /// assert!(!major_scale(C4).unwrap().contains(B3));
+    /// ```
+    fn contains(&self, note: Note) -> bool {
+        self.pitches().contains(&note)
+    }
+
+    /// Returns the set of pitch classes of the scale
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, natural_minor_scale, ScaleLike};
+    ///
+    /// let mask = major_scale(C4).unwrap().pitch_class_mask();
+    /// assert!(mask.contains(F2));
+    /// assert_eq!(mask, natural_minor_scale(A3).unwrap().pitch_class_mask());
+    /// ```
+    fn pitch_class_mask(&self) -> PitchClassMask {
+        PitchClassMask::from_notes(self.pitches())
+    }
+
+    /// Returns the closest scale member above the given note
+    ///
+    /// The note doesn't have to belong to the scale, and the search wraps across
+    /// octaves, so the upper neighbor of the seventh degree is the tonic of the next octave.
+    ///
+    /// # Arguments
+    /// * `note` - The note whose neighbor is searched
+    ///
+    /// # Returns
+    /// The next scale member above the note, or `None` if it would be above MIDI 127
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, ScaleLike};
+    ///
+    /// let c_major = major_scale(C4).unwrap();
+    /// assert_eq!(c_major.upper_neighbor(E4), Some(F4));
+    /// assert_eq!(c_major.upper_neighbor(B4), Some(C5));
+    /// ```
+    fn upper_neighbor(&self, note: Note) -> Option<Note> {
+        let mask = self.pitch_class_mask();
+        (note.midi_number() + 1..=G9.midi_number())
+            .map(Note::new)
+            .find(|&candidate| mask.contains(candidate))
+    }
+
+    /// Returns the closest scale member below the given note
+    ///
+    /// The note doesn't have to belong to the scale, and the search wraps across
+    /// octaves, so the lower neighbor of the tonic is the seventh degree of the previous octave.
+    ///
+    /// # Arguments
+    /// * `note` - The note whose neighbor is searched
+    ///
+    /// # Returns
+    /// The next scale member below the note, or `None` if it would be below MIDI 0
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, ScaleLike};
+    ///
+    /// let c_major = major_scale(C4).unwrap();
+    /// assert_eq!(c_major.lower_neighbor(E4), Some(D4));
+    /// assert_eq!(c_major.lower_neighbor(C4), Some(B3));
+    /// ```
+    fn lower_neighbor(&self, note: Note) -> Option<Note> {
+        let mask = self.pitch_class_mask();
+        (0..note.midi_number())
+            .rev()
+            .map(Note::new)
+            .find(|&candidate| mask.contains(candidate))
+    }
+
+    /// Returns the scale member closest to the given note
+    ///
+    /// A note of the scale, in any octave, is returned as it is. Otherwise the
+    /// closest neighbor is returned, the lower one when both are as close.
+    ///
+    /// # Arguments
+    /// * `note` - The note to bring into the scale
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, ScaleLike};
+    ///
+    /// let c_major = major_scale(C4).unwrap();
+    /// assert_eq!(c_major.quantize(E2), E2);
+    /// assert_eq!(c_major.quantize(FSHARP4), F4);
+    /// ```
+    fn quantize(&self, note: Note) -> Note {
+        if self.contains_pitch_class(note) {
+            return note;
+        }
+
+        let distance = |neighbor: &Note| neighbor.midi_number().abs_diff(note.midi_number());
+        match (self.lower_neighbor(note), self.upper_neighbor(note)) {
+            (Some(lower), Some(upper)) if distance(&upper) < distance(&lower) => upper,
+            (Some(lower), _) => lower,
+            (None, upper) => upper.unwrap_or(note),
+        }
+    }
+
+    /// Returns the scale member closest to each note, see `quantize`
+    ///
+    /// # Arguments
+    /// * `notes` - The notes to bring into the scale
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, ScaleLike};
+    ///
+    /// assert_eq!(major_scale(C4).unwrap().quantize_all(&[CSHARP4, G4]), vec![C4, G4]);
+    /// ```
+    fn quantize_all(&self, notes: &[Note]) -> Vec<Note> {
+        let mut quantized = Vec::with_capacity(notes.len());
+        self.quantize_all_into(notes, &mut quantized);
+        quantized
+    }
+
+    /// Writes the scale member closest to each note into a buffer, see `quantize`
+    ///
+    /// This is `quantize_all` without allocating once the buffer is large enough,
+    /// for callers that reuse the buffer.
+    ///
+    /// # Arguments
+    /// * `notes` - The notes to bring into the scale
+    /// * `out` - The buffer, cleared then filled with one note per note, in the same order
+    fn quantize_all_into(&self, notes: &[Note], out: &mut Vec<Note>) {
+        out.clear();
+        out.extend(notes.iter().map(|&note| self.quantize(note)));
+    }
+
+    /// Returns the interval-class vector of the scale
+    ///
+    /// Notes are reduced to their pitch classes, so the octave doesn't count twice.
+    /// Every pair of distinct pitch classes is then counted under its interval class,
+    /// from 1 (a semitone or major seventh) to 6 (a tritone).
+    ///
+    /// # Returns
+    /// The number of pairs for each interval class, from 1 to 6
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, ScaleLike};
+    ///
+    /// assert_eq!(major_scale(C4).unwrap().interval_class_vector(), [2, 5, 4, 3, 6, 1]);
+    /// ```
+    fn interval_class_vector(&self) -> [u8; 6] {
+        let mut pitch_classes: Vec<u8> = self.pitches().iter().map(Note::pitch_class).collect();
+        pitch_classes.sort();
+        pitch_classes.dedup();
+
+        let mut vector = [0; 6];
+        for (i, low) in pitch_classes.iter().enumerate() {
+            for high in &pitch_classes[i + 1..] {
+                let distance = high - low;
+                let class = distance.min(SEMITONES_IN_OCTAVE - distance);
+                vector[class as usize - 1] += 1;
+            }
+        }
+
+        vector
+    }
+
+    /// Returns the non-zero transpositions that map the scale onto itself
+    ///
+    /// # Returns
+    /// The intervals, from 1 to 11 semitones, under which the pitch classes of the scale are unchanged
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, octatonic_scale, Interval, ScaleLike};
+    ///
+    /// let semitones: Vec<_> = octatonic_scale(C4).unwrap()
+    ///     .transpositional_symmetry()
+    ///     .iter()
+    ///     .map(Interval::semitones)
+    ///     .collect();
+    /// assert_eq!(semitones, vec![3, 6, 9]);
+    /// assert!(major_scale(C4).unwrap().transpositional_symmetry().is_empty());
+    /// ```
+    fn transpositional_symmetry(&self) -> Vec<Interval> {
+        self.pitch_class_mask().transpositional_symmetry()
+    }
+
+    /// Returns `true` if the scale is one of Messiaen's modes of limited transposition
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, whole_tone_scale, ScaleLike};
+    ///
+    /// assert!(whole_tone_scale(C4).unwrap().is_mode_of_limited_transposition());
+    /// assert!(!major_scale(C4).unwrap().is_mode_of_limited_transposition());
+    /// ```
+    fn is_mode_of_limited_transposition(&self) -> bool {
+        self.pitch_class_mask().is_mode_of_limited_transposition()
+    }
+
+    /// Returns `true` if both scales are made of the same pitch classes
+    ///
+    /// Unlike `==`, which compares the notes literally, this ignores octaves, the
+    /// order of the notes and the quality of the scales, so a mode compares equal
+    /// to its parent scale.
+    ///
+    /// # Arguments
+    /// * `other` - The scale to compare with
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, natural_minor_scale, ScaleLike};
+    ///
+    /// assert!(major_scale(C4).unwrap().same_pitch_classes(&major_scale(C5).unwrap()));
+    /// assert!(major_scale(C4).unwrap().same_pitch_classes(&natural_minor_scale(A3).unwrap()));
+    /// assert!(!major_scale(C4).unwrap().same_pitch_classes(&major_scale(G4).unwrap()));
+    /// ```
+    fn same_pitch_classes<S>(&self, other: &S) -> bool
+    where
+        S: ScaleLike + ?Sized,
+    {
+        self.pitch_class_mask() == other.pitch_class_mask()
+    }
+
+    /// Returns `true` if every pitch class of the scale belongs to another scale
+    ///
+    /// Octaves and the order of the notes are ignored, as in `same_pitch_classes`,
+    /// so a pentatonic scale is a subset of its parent major scale, and every
+    /// scale is a subset of itself.
+    ///
+    /// # Arguments
+    /// * `other` - The scale which may contain this one
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, natural_minor_scale, whole_tone_scale, ScaleLike};
+    ///
+    /// assert!(major_scale(C4).unwrap().is_subset_of(&natural_minor_scale(A2).unwrap()));
+    /// assert!(!whole_tone_scale(C4).unwrap().is_subset_of(&major_scale(C4).unwrap()));
+    /// ```
+    fn is_subset_of<S>(&self, other: &S) -> bool
+    where
+        S: ScaleLike + ?Sized,
+    {
+        self.pitch_class_mask()
+            .is_subset_of(&other.pitch_class_mask())
+    }
+
+    /// Returns `true` if every pitch class of another scale belongs to this scale
+    ///
+    /// # Arguments
+    /// * `other` - The scale which may be contained in this one
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, messiaen_mode, whole_tone_scale, ScaleLike};
+    ///
+    /// let mode_3 = messiaen_mode(3, C4).unwrap();
+    /// assert!(mode_3.is_superset_of(&whole_tone_scale(C4).unwrap()));
+    /// assert!(!mode_3.is_superset_of(&major_scale(C4).unwrap()));
+    /// ```
+    fn is_superset_of<S>(&self, other: &S) -> bool
+    where
+        S: ScaleLike + ?Sized,
+    {
+        other.is_subset_of(self)
+    }
+
+    /// Returns `true` if the scale is made of the pitch classes of a major scale
+    ///
+    /// This is the case for the major scale itself and for all its modes, such
+    /// as the natural minor scale or the Dorian mode.
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, natural_minor_scale, whole_tone_scale, ScaleLike};
+    ///
+    /// assert!(natural_minor_scale(A4).unwrap().is_diatonic());
+    /// assert!(!whole_tone_scale(C4).unwrap().is_diatonic());
+    /// ```
+    fn is_diatonic(&self) -> bool {
+        self.parent_major().is_some()
+    }
+
+    /// Returns the tonic of the major scale this scale is a mode of
+    ///
+    /// The tonic is the closest one at or below the root of the scale, so the
+    /// root belongs to the first octave of the parent major scale. Only when that
+    /// note would be below the MIDI range is the tonic taken an octave higher.
+    ///
+    /// # Returns
+    /// The tonic of the parent major scale, or `None` if the scale is not diatonic
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, natural_minor_scale, ScaleLike};
+    ///
+    /// assert_eq!(major_scale(G4).unwrap().parent_major(), Some(G4));
+    /// assert_eq!(natural_minor_scale(A4).unwrap().parent_major(), Some(C4));
+    /// ```
+    fn parent_major(&self) -> Option<Note> {
+        let mask = self.pitch_class_mask();
+        let c_major = major_scale(C4).expect("C4 major is in the MIDI range");
+        let major = PitchClassMask::from_notes(c_major.notes());
+        let tonic = (0..SEMITONES_IN_OCTAVE).find(|&t| major.transpose(t) == mask)?;
+
+        let root = self.tonic();
+        let below = (root.pitch_class() + SEMITONES_IN_OCTAVE - tonic) % SEMITONES_IN_OCTAVE;
+        let below = i16::from(below);
+        root.checked_add_semitones(-below)
+            .or_else(|| root.checked_add_semitones(i16::from(SEMITONES_IN_OCTAVE) - below))
+    }
+
+    /// Returns the brightness of the mode of the scale, see `Mode::brightness`
+    ///
+    /// # Returns
+    /// The brightness, from 1 for Lydian to -5 for Locrian, or `None` if the
+    /// scale is not diatonic
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::{constants::*, major_scale, natural_minor_scale, ScaleLike};
+    ///
+    /// assert_eq!(major_scale(C4).unwrap().modal_brightness(), Some(0));
+    /// assert_eq!(natural_minor_scale(A4).unwrap().modal_brightness(), Some(-3));
+    /// ```
+    fn modal_brightness(&self) -> Option<i8> {
+        Mode::of(self).map(|mode| mode.brightness())
+    }
+}
+
+impl<Q, const N: usize> ScaleLike for Scale<Q, N>
+where
+    Q: ScaleQuality,
+{
+    #[inline]
+    fn pitches(&self) -> &[Note] {
+        self.notes()
+    }
+}
@@ -0,0 +1,3 @@
+mod tone_row;
+
+pub use tone_row::*;
@@ -0,0 +1,123 @@
+use crate::{Error, Note};
+
+/// Represents a tone row, an ordered series of notes used by serial techniques
+///
+/// The row provides the classic transformations of twelve-tone composition:
+/// retrograde, inversion, and retrograde-inversion.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let row = ToneRow::new(&[C4, E4, D4]);
+/// assert_eq!(row.retrograde().notes(), &[D4, E4, C4]);
+/// assert_eq!(row.invert().unwrap().notes(), &[C4, GSHARP3, ASHARP3]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ToneRow {
+    notes: Vec<Note>,
+}
+
+impl ToneRow {
+    /// Creates a new `ToneRow` from a sequence of notes
+    ///
+    /// # Arguments
+    /// * `notes` - The notes of the row, in order
+    ///
+    /// # Returns
+    /// A new `ToneRow` instance
+    pub fn new(notes: &[Note]) -> Self {
+        Self {
+            notes: notes.to_vec(),
+        }
+    }
+
+    /// Returns the notes of the row
+    pub fn notes(&self) -> &[Note] {
+        &self.notes
+    }
+
+    /// Returns the row played backwards
+    ///
+    /// # Returns
+    /// A new row with the notes in reverse order
+    pub fn retrograde(&self) -> Self {
+        Self {
+            notes: self.notes.iter().rev().copied().collect(),
+        }
+    }
+
+    /// Returns the row with every interval mirrored around the first note
+    ///
+    /// A note a major third above the first note becomes a note a major third below it.
+    ///
+    /// # Returns
+    /// The inverted row, or `Error::NoteOutOfRange` if a mirrored note falls outside of the MIDI range
+    ///
+    /// # Examples
+    /// ```
+    /// use mozzart_std::*;
+    /// use mozzart_std::constants::*;
+    ///
+    /// let row = ToneRow::new(&[C4, G4, DSHARP4]);
+    /// assert_eq!(row.invert().unwrap().notes(), &[C4, F3, A3]);
+    /// ```
+    pub fn invert(&self) -> Result<Self, Error> {
+        let Some(first) = self.notes.first() else {
+            return Ok(self.clone());
+        };
+
+        let axis = 2 * first.midi_number() as i16;
+        let notes = self
+            .notes
+            .iter()
+            .map(|note| {
+                u8::try_from(axis - note.midi_number() as i16)
+                    .ok()
+                    .and_then(|midi| Note::try_from(midi).ok())
+                    .ok_or(Error::NoteOutOfRange(*note))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { notes })
+    }
+
+    /// Returns the retrograde of the inverted row
+    ///
+    /// # Returns
+    /// The retrograde-inversion of the row, or `Error::NoteOutOfRange` if the inversion fails
+    pub fn retrograde_inversion(&self) -> Result<Self, Error> {
+        self.invert().map(|row| row.retrograde())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_invert_short_row() {
+        let row = ToneRow::new(&[C4, E4, G4, B4]);
+        assert_eq!(row.invert().unwrap().notes(), &[C4, GSHARP3, F3, CSHARP3]);
+        assert_eq!(
+            row.retrograde_inversion().unwrap().notes(),
+            &[CSHARP3, F3, GSHARP3, C4]
+        );
+    }
+
+    #[test]
+    fn test_retrograde() {
+        let row = ToneRow::new(&[C4, E4, G4, B4]);
+        assert_eq!(row.retrograde().notes(), &[B4, G4, E4, C4]);
+        assert_eq!(row.retrograde().retrograde(), row);
+    }
+
+    #[test]
+    fn test_invert_out_of_range() {
+        let row = ToneRow::new(&[C1, G9]);
+        assert_eq!(row.invert(), Err(Error::NoteOutOfRange(G9)));
+        assert_eq!(ToneRow::new(&[]).invert(), Ok(ToneRow::new(&[])));
+    }
+}
@@ -0,0 +1,405 @@
+use crate::{Duration, DynScale, Error, Interval, Melody, Note, PitchClassMask};
+use rand::{Rng, RngExt};
+use std::ops::RangeInclusive;
+
+/// The number of times the generator starts over before giving up
+const MAX_ATTEMPTS: usize = 50;
+
+/// The number of notes the generator may try during a single attempt
+const MAX_PLACEMENTS: usize = 5_000;
+
+/// The widest move, in semitones, that counts as stepwise motion
+const MAX_STEP: u8 = 2;
+
+/// The parameters of a sight-reading exercise
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+///
+/// let params = ExerciseParams {
+///     key: DynScale::from(&major_scale(C4).unwrap()),
+///     length: 8,
+///     durations: vec![Duration::Quarter, Duration::Half],
+///     max_leap: PERFECT_FIFTH,
+///     range: C4..=C5,
+///     stepwise_ratio: 0.75,
+///     allow_chromatic: false,
+/// };
+/// assert_eq!(params.key.root(), C4);
+/// ```
+#[derive(Debug)]
+pub struct ExerciseParams {
+    /// The key of the exercise; the melody starts and ends on its tonic
+    pub key: DynScale,
+    /// The number of notes in the melody
+    pub length: usize,
+    /// The durations the notes may have
+    pub durations: Vec<Duration>,
+    /// The widest leap between two consecutive notes
+    pub max_leap: Interval,
+    /// The lowest and highest notes that may be played
+    pub range: RangeInclusive<Note>,
+    /// The proportion of moves, from 0 to 1, that are steps of one or two semitones
+    pub stepwise_ratio: f32,
+    /// Whether chromatic neighbor notes, outside of the key, may be used
+    pub allow_chromatic: bool,
+}
+
+/// The kind of move between two consecutive notes
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Move {
+    Step,
+    Leap,
+}
+
+/// Generates a sight-reading exercise
+///
+/// The melody starts and ends on a tonic of the key and never leaves the range.
+/// The number of stepwise moves is the stepwise ratio of all the moves, rounded
+/// to the nearest whole move; the other moves are leaps of at least a minor third
+/// and at most the maximum leap. When chromatic notes are allowed, they appear
+/// only as neighbors, approached and left by a half step from notes of the key.
+///
+/// The notes are placed one at a time, backtracking when a choice leads to a dead
+/// end. Each attempt is bounded, and the generator gives up after a fixed number
+/// of attempts instead of searching forever.
+///
+/// # Arguments
+/// * `params` - The constraints of the exercise
+/// * `rng` - The source of randomness
+///
+/// # Returns
+/// The melody, or an error if the parameters are invalid or cannot be satisfied
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+/// use rand::{rngs::SmallRng, SeedableRng};
+///
+/// let params = ExerciseParams {
+///     key: DynScale::from(&major_scale(G3).unwrap()),
+///     length: 12,
+///     durations: vec![Duration::Quarter],
+///     max_leap: PERFECT_FOURTH,
+///     range: D3..=D4,
+///     stepwise_ratio: 0.8,
+///     allow_chromatic: false,
+/// };
+/// let mut rng = SmallRng::seed_from_u64(7);
+/// let melody = generate_exercise(&params, &mut rng).unwrap();
+///
+/// assert_eq!(melody.len(), 12);
+/// assert_eq!(melody.notes().next().unwrap().pitch_class(), G3.pitch_class());
+/// assert_eq!(melody.notes().last().unwrap().pitch_class(), G3.pitch_class());
+/// ```
+pub fn generate_exercise<R>(params: &ExerciseParams, rng: &mut R) -> Result<Melody, Error>
+where
+    R: Rng + ?Sized,
+{
+    let mut generator = Generator::new(params)?;
+
+    for _ in 0..MAX_ATTEMPTS {
+        let moves = generator.plan(rng);
+        let tonic = generator.tonics[rng.random_range(0..generator.tonics.len())];
+        let mut notes = vec![tonic];
+
+        generator.placements = 0;
+        if generator.place(&mut notes, &moves, rng) {
+            let melody = notes.into_iter().map(|note| {
+                let duration = params.durations[rng.random_range(0..params.durations.len())];
+                (note, duration)
+            });
+            return Ok(Melody::new(melody));
+        }
+    }
+
+    Err(Error::ExerciseNotFound {
+        attempts: MAX_ATTEMPTS,
+    })
+}
+
+/// The search state of the exercise generator
+struct Generator<'a> {
+    params: &'a ExerciseParams,
+    key: PitchClassMask,
+    tonic: PitchClassMask,
+    tonics: Vec<Note>,
+    steps: usize,
+    placements: usize,
+}
+
+impl<'a> Generator<'a> {
+    /// Validates the parameters and prepares the search
+    fn new(params: &'a ExerciseParams) -> Result<Self, Error> {
+        let invalid = |reason: &str| Err(Error::InvalidExerciseParams(reason.to_string()));
+
+        if params.length == 0 {
+            return invalid("the exercise must have at least one note");
+        }
+        if params.durations.is_empty() {
+            return invalid("at least one duration must be allowed");
+        }
+        if !(0.0..=1.0).contains(&params.stepwise_ratio) {
+            return invalid("the stepwise ratio must be between 0 and 1");
+        }
+
+        let moves = params.length - 1;
+        let steps = (params.stepwise_ratio * moves as f32).round() as usize;
+        if steps < moves && params.max_leap.semitones() <= MAX_STEP {
+            return invalid("the maximum leap is too small for the requested leaps");
+        }
+
+        let tonic = PitchClassMask::from_notes(&[params.key.root()]);
+        let tonics: Vec<_> = range_notes(&params.range)
+            .filter(|&note| tonic.contains(note))
+            .collect();
+        if tonics.is_empty() {
+            return invalid("the range does not contain the tonic");
+        }
+
+        Ok(Self {
+            params,
+            key: PitchClassMask::from_notes(params.key.notes()),
+            tonic,
+            tonics,
+            steps,
+            placements: 0,
+        })
+    }
+
+    /// Picks a random order for the steps and leaps of the melody
+    fn plan<R>(&self, rng: &mut R) -> Vec<Move>
+    where
+        R: Rng + ?Sized,
+    {
+        let mut moves = vec![Move::Leap; self.params.length - 1];
+        moves[..self.steps].fill(Move::Step);
+        for i in (1..moves.len()).rev() {
+            moves.swap(i, rng.random_range(0..=i));
+        }
+        moves
+    }
+
+    /// Places the remaining notes of the melody, backtracking on dead ends
+    ///
+    /// Returns `true` if the melody was completed, `false` if no completion was
+    /// found or the attempt ran out of placements.
+    fn place<R>(&mut self, notes: &mut Vec<Note>, moves: &[Move], rng: &mut R) -> bool
+    where
+        R: Rng + ?Sized,
+    {
+        let previous = *notes.last().expect("the melody starts with the tonic");
+        let Some((&next_move, remaining)) = moves.split_first() else {
+            return self.tonic.contains(previous);
+        };
+
+        let mut candidates = self.candidates(previous, next_move);
+        while !candidates.is_empty() {
+            if self.placements == MAX_PLACEMENTS {
+                return false;
+            }
+            self.placements += 1;
+
+            let candidate = candidates.swap_remove(rng.random_range(0..candidates.len()));
+            if !self.can_reach_tonic(candidate, remaining) {
+                continue;
+            }
+
+            notes.push(candidate);
+            if self.place(notes, remaining, rng) {
+                return true;
+            }
+            notes.pop();
+        }
+
+        false
+    }
+
+    /// Returns the notes that may follow the previous note with the given move
+    fn candidates(&self, previous: Note, next_move: Move) -> Vec<Note> {
+        let distance = |note: Note| previous.midi_number().abs_diff(note.midi_number());
+        let in_key = |note: Note| self.key.contains(note);
+
+        // A chromatic neighbor must resolve by a half step to a note of the key
+        if !in_key(previous) {
+            return match next_move {
+                Move::Step => range_notes(&self.params.range)
+                    .filter(|&note| distance(note) == 1 && in_key(note))
+                    .collect(),
+                Move::Leap => Vec::new(),
+            };
+        }
+
+        range_notes(&self.params.range)
+            .filter(|&note| match next_move {
+                Move::Step => match distance(note) {
+                    1 => in_key(note) || self.params.allow_chromatic,
+                    2 => in_key(note),
+                    _ => false,
+                },
+                Move::Leap => {
+                    (MAX_STEP + 1..=self.params.max_leap.semitones()).contains(&distance(note))
+                        && in_key(note)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns `true` if a tonic can still be reached from the note with the remaining moves
+    fn can_reach_tonic(&self, note: Note, remaining: &[Move]) -> bool {
+        let reach: usize = remaining
+            .iter()
+            .map(|m| match m {
+                Move::Step => usize::from(MAX_STEP),
+                Move::Leap => usize::from(self.params.max_leap.semitones()),
+            })
+            .sum();
+
+        self.tonics
+            .iter()
+            .any(|tonic| usize::from(tonic.midi_number().abs_diff(note.midi_number())) <= reach)
+    }
+}
+
+/// Returns the notes of the range, from the lowest to the highest
+fn range_notes(range: &RangeInclusive<Note>) -> impl Iterator<Item = Note> {
+    (range.start().midi_number()..=range.end().midi_number()).map(Note::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::*;
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    fn params(key: DynScale, range: RangeInclusive<Note>) -> ExerciseParams {
+        ExerciseParams {
+            key,
+            length: 16,
+            durations: vec![Duration::Quarter, Duration::Eighth, Duration::Half],
+            max_leap: PERFECT_FIFTH,
+            range,
+            stepwise_ratio: 0.7,
+            allow_chromatic: false,
+        }
+    }
+
+    fn assert_constraints(params: &ExerciseParams, melody: &Melody) {
+        let notes: Vec<_> = melody.notes().collect();
+        let key = PitchClassMask::from_notes(params.key.notes());
+        let tonic = PitchClassMask::from_notes(&[params.key.root()]);
+
+        assert_eq!(notes.len(), params.length);
+        assert!(tonic.contains(notes[0]), "{notes:?}");
+        assert!(tonic.contains(notes[notes.len() - 1]), "{notes:?}");
+        assert!(notes.iter().all(|note| params.range.contains(note)));
+        assert!(melody.durations().all(|d| params.durations.contains(&d)));
+
+        let distances: Vec<_> = notes
+            .windows(2)
+            .map(|pair| pair[0].midi_number().abs_diff(pair[1].midi_number()))
+            .collect();
+        assert!(distances
+            .iter()
+            .all(|&d| d >= 1 && d <= params.max_leap.semitones()));
+
+        // The stepwise ratio is met up to the rounding to a whole move
+        let steps = distances.iter().filter(|&&d| d <= MAX_STEP).count();
+        let ratio = steps as f32 / distances.len() as f32;
+        let tolerance = 0.5 / distances.len() as f32 + f32::EPSILON;
+        assert!(
+            (ratio - params.stepwise_ratio).abs() <= tolerance,
+            "{ratio}"
+        );
+
+        for (i, note) in notes.iter().enumerate().filter(|(_, n)| !key.contains(**n)) {
+            assert!(params.allow_chromatic, "{notes:?}");
+            assert_eq!(distances[i - 1], 1);
+            assert_eq!(distances[i], 1);
+            assert!(key.contains(notes[i + 1]), "{note:?}");
+        }
+    }
+
+    fn assert_generates(params: &ExerciseParams, seed: u64) {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        for _ in 0..100 {
+            let melody = generate_exercise(params, &mut rng).unwrap();
+            assert_constraints(params, &melody);
+        }
+    }
+
+    #[test]
+    fn test_major_key() {
+        assert_generates(
+            &params(DynScale::from(&major_scale(C4).unwrap()), C4..=C5),
+            1,
+        );
+    }
+
+    #[test]
+    fn test_minor_key_wide_range() {
+        let mut params = params(DynScale::from(&natural_minor_scale(A3).unwrap()), E3..=A4);
+        params.length = 24;
+        params.stepwise_ratio = 0.5;
+        params.max_leap = MAJOR_SIXTH;
+        assert_generates(&params, 2);
+    }
+
+    #[test]
+    fn test_chromatic_neighbors() {
+        let mut params = params(DynScale::from(&major_scale(D4).unwrap()), D4..=D5);
+        params.allow_chromatic = true;
+        params.stepwise_ratio = 0.9;
+        assert_generates(&params, 3);
+    }
+
+    #[test]
+    fn test_only_steps_and_only_leaps() {
+        // The augmented second of the harmonic minor cannot be crossed by steps
+        let mut params = params(DynScale::from(&melodic_minor_scale(E4).unwrap()), E4..=E5);
+        params.stepwise_ratio = 1.0;
+        params.max_leap = MAJOR_SECOND;
+        assert_generates(&params, 4);
+
+        params.key = DynScale::from(&harmonic_minor_scale(E4).unwrap());
+        params.stepwise_ratio = 0.0;
+        params.max_leap = PERFECT_OCTAVE;
+        assert_generates(&params, 5);
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        let mut rng = SmallRng::seed_from_u64(6);
+        let mut params = params(DynScale::from(&major_scale(C4).unwrap()), D4..=B4);
+        assert!(matches!(
+            generate_exercise(&params, &mut rng),
+            Err(Error::InvalidExerciseParams(_))
+        ));
+
+        params.range = C4..=C5;
+        params.max_leap = MAJOR_SECOND;
+        assert!(matches!(
+            generate_exercise(&params, &mut rng),
+            Err(Error::InvalidExerciseParams(_))
+        ));
+    }
+
+    #[test]
+    fn test_unsatisfiable_gives_up() {
+        // Only leaps, within a range where no leap returns to the tonic in time
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut params = params(DynScale::from(&major_scale(C4).unwrap()), C4..=E4);
+        params.length = 2;
+        params.stepwise_ratio = 0.0;
+        assert_eq!(
+            generate_exercise(&params, &mut rng),
+            Err(Error::ExerciseNotFound {
+                attempts: MAX_ATTEMPTS
+            })
+        );
+    }
+}
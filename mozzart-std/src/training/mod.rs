@@ -0,0 +1,7 @@
+mod exercise;
+mod questions;
+mod session;
+
+pub use exercise::*;
+pub use questions::*;
+pub use session::*;
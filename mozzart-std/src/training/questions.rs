@@ -0,0 +1,323 @@
+use crate::constants::*;
+use crate::{major_scale, ChordQuality, Interval, Note};
+use rand::{Rng, RngExt};
+use std::ops::RangeInclusive;
+
+/// A question asking for the interval between two notes played one after the other
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+/// use rand::{rngs::SmallRng, SeedableRng};
+///
+/// let mut rng = SmallRng::seed_from_u64(7);
+/// let question = IntervalQuestion::random(C4..=C5, &[PERFECT_FIFTH], &mut rng).unwrap();
+/// let (low, high) = question.notes();
+/// assert_eq!((high - low).semitones(), PERFECT_FIFTH.semitones());
+/// assert!(question.check(&PERFECT_FIFTH));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntervalQuestion {
+    low: Note,
+    high: Note,
+    answer: Interval,
+}
+
+impl IntervalQuestion {
+    /// Generates a random interval question
+    ///
+    /// An interval is picked among the allowed ones that fit in the range, then
+    /// the lower note is picked so that both notes stay within the range.
+    ///
+    /// # Arguments
+    /// * `range` - The lowest and highest notes that may be played
+    /// * `allowed_intervals` - The intervals that may be asked
+    /// * `rng` - The source of randomness
+    ///
+    /// # Returns
+    /// A new question, or `None` if none of the allowed intervals fits in the range
+    pub fn random<R>(
+        range: RangeInclusive<Note>,
+        allowed_intervals: &[Interval],
+        rng: &mut R,
+    ) -> Option<Self>
+    where
+        R: Rng + ?Sized,
+    {
+        let (low, high) = (range.start().midi_number(), range.end().midi_number());
+        let span = high.checked_sub(low)?;
+        let fitting: Vec<_> = allowed_intervals
+            .iter()
+            .filter(|interval| interval.semitones() <= span)
+            .collect();
+        if fitting.is_empty() {
+            return None;
+        }
+
+        let interval = fitting[rng.random_range(0..fitting.len())];
+        let start = rng.random_range(low..=high - interval.semitones());
+
+        Some(Self {
+            low: Note::new(start),
+            high: Note::new(start + interval.semitones()),
+            answer: Interval::new(interval.semitones()),
+        })
+    }
+
+    /// Returns the two notes of the question, the lower one first
+    pub fn notes(&self) -> (Note, Note) {
+        (self.low, self.high)
+    }
+
+    /// Returns the correct answer
+    pub fn answer(&self) -> &Interval {
+        &self.answer
+    }
+
+    /// Returns `true` if the given interval is the correct answer
+    pub fn check(&self, answer: &Interval) -> bool {
+        self.answer == *answer
+    }
+}
+
+/// A question asking for the quality of a chord
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+/// use rand::{rngs::SmallRng, SeedableRng};
+///
+/// let mut rng = SmallRng::seed_from_u64(7);
+/// let qualities = [ChordQuality::MajorTriad, ChordQuality::MinorTriad];
+/// let question = ChordQualityQuestion::random(C3..=C5, &qualities, &mut rng).unwrap();
+/// assert_eq!(question.notes().len(), 3);
+/// assert!(question.check(question.answer()));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChordQualityQuestion {
+    notes: Vec<Note>,
+    answer: ChordQuality,
+}
+
+impl ChordQualityQuestion {
+    /// Generates a random chord quality question
+    ///
+    /// A quality is picked among the allowed ones that fit in the range, then
+    /// the root is picked so that every note of the chord stays within the range.
+    ///
+    /// # Arguments
+    /// * `range` - The lowest and highest notes that may be played
+    /// * `allowed_qualities` - The chord qualities that may be asked
+    /// * `rng` - The source of randomness
+    ///
+    /// # Returns
+    /// A new question, or `None` if none of the allowed qualities fits in the range
+    pub fn random<R>(
+        range: RangeInclusive<Note>,
+        allowed_qualities: &[ChordQuality],
+        rng: &mut R,
+    ) -> Option<Self>
+    where
+        R: Rng + ?Sized,
+    {
+        let (low, high) = (range.start().midi_number(), range.end().midi_number());
+        let span = high.checked_sub(low)?;
+        let width = |quality: &ChordQuality| {
+            quality
+                .intervals()
+                .iter()
+                .map(Interval::semitones)
+                .max()
+                .unwrap_or_default()
+        };
+        let fitting: Vec<_> = allowed_qualities
+            .iter()
+            .filter(|quality| width(quality) <= span)
+            .collect();
+        if fitting.is_empty() {
+            return None;
+        }
+
+        let quality = *fitting[rng.random_range(0..fitting.len())];
+        let root = rng.random_range(low..=high - width(&quality));
+        let notes = std::iter::once(root)
+            .chain(quality.intervals().iter().map(|i| root + i.semitones()))
+            .map(Note::new)
+            .collect();
+
+        Some(Self {
+            notes,
+            answer: quality,
+        })
+    }
+
+    /// Returns the notes of the chord, from the root upwards
+    pub fn notes(&self) -> &[Note] {
+        &self.notes
+    }
+
+    /// Returns the correct answer
+    pub fn answer(&self) -> ChordQuality {
+        self.answer
+    }
+
+    /// Returns `true` if the given quality is the correct answer
+    pub fn check(&self, answer: ChordQuality) -> bool {
+        self.answer == answer
+    }
+}
+
+/// A question playing a major scale, then one of its notes, and asking for its degree
+///
+/// Degrees are numbered from 1 (the tonic) to 7 (the leading tone).
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+/// use mozzart_std::constants::*;
+/// use rand::{rngs::SmallRng, SeedableRng};
+///
+/// let mut rng = SmallRng::seed_from_u64(7);
+/// let question = ScaleDegreeQuestion::random(C3..=C6, &mut rng).unwrap();
+/// let degree = question.answer();
+/// assert_eq!(question.scale()[degree as usize - 1], question.note());
+/// assert!(question.check(degree));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScaleDegreeQuestion {
+    scale: Vec<Note>,
+    note: Note,
+    answer: u8,
+}
+
+impl ScaleDegreeQuestion {
+    /// Generates a random scale degree question
+    ///
+    /// # Arguments
+    /// * `range` - The lowest and highest notes that may be played
+    /// * `rng` - The source of randomness
+    ///
+    /// # Returns
+    /// A new question, or `None` if the range is narrower than an octave
+    pub fn random<R>(range: RangeInclusive<Note>, rng: &mut R) -> Option<Self>
+    where
+        R: Rng + ?Sized,
+    {
+        let (low, high) = (range.start().midi_number(), range.end().midi_number());
+        let top = high.checked_sub(SEMITONES_IN_OCTAVE)?;
+        if top < low {
+            return None;
+        }
+
+        let tonic = Note::new(rng.random_range(low..=top));
+        let scale = major_scale(tonic)
+            .expect("the tonic is at least an octave below the top of the range")
+            .notes()
+            .to_vec();
+        let answer = rng.random_range(1..=7u8);
+
+        Some(Self {
+            note: scale[answer as usize - 1],
+            scale,
+            answer,
+        })
+    }
+
+    /// Returns the notes of the scale played before the question
+    pub fn scale(&self) -> &[Note] {
+        &self.scale
+    }
+
+    /// Returns the note whose degree is asked
+    pub fn note(&self) -> Note {
+        self.note
+    }
+
+    /// Returns the correct answer
+    pub fn answer(&self) -> u8 {
+        self.answer
+    }
+
+    /// Returns `true` if the given degree is the correct answer
+    pub fn check(&self, answer: u8) -> bool {
+        self.answer == answer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    #[test]
+    fn test_interval_questions_distribution() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let allowed = [MINOR_THIRD, MAJOR_THIRD, PERFECT_FIFTH];
+        let mut seen = [0; 3];
+
+        for _ in 0..1000 {
+            let question = IntervalQuestion::random(C4..=C5, &allowed, &mut rng).unwrap();
+            let (low, high) = question.notes();
+            assert!(low >= C4 && high <= C5);
+
+            let index = allowed.iter().position(|i| i == question.answer()).unwrap();
+            seen[index] += 1;
+            assert_eq!((high - low).semitones(), allowed[index].semitones());
+            assert!(question.check(&allowed[index]));
+        }
+
+        // Every allowed interval is asked, roughly uniformly
+        assert!(seen.iter().all(|&count| count > 250), "{seen:?}");
+    }
+
+    #[test]
+    fn test_interval_question_too_narrow() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        assert_eq!(
+            IntervalQuestion::random(C4..=D4, &[PERFECT_FIFTH], &mut rng),
+            None
+        );
+        assert_eq!(IntervalQuestion::random(C4..=C5, &[], &mut rng), None);
+    }
+
+    #[test]
+    fn test_chord_quality_questions() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let allowed = [ChordQuality::MajorTriad, ChordQuality::DominantSeventh];
+
+        for _ in 0..500 {
+            let question = ChordQualityQuestion::random(C3..=C4, &allowed, &mut rng).unwrap();
+            assert!(allowed.contains(&question.answer()));
+            assert!(question.notes().iter().all(|note| (C3..=C4).contains(note)));
+            assert_eq!(
+                question.notes().len(),
+                question.answer().intervals().len() + 1
+            );
+            assert!(!question.check(ChordQuality::MinorTriad));
+        }
+    }
+
+    #[test]
+    fn test_scale_degree_questions() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut seen = [false; 7];
+
+        for _ in 0..500 {
+            let question = ScaleDegreeQuestion::random(C3..=C5, &mut rng).unwrap();
+            let degree = question.answer();
+            seen[degree as usize - 1] = true;
+
+            assert!(question.scale().iter().all(|note| (C3..=C5).contains(note)));
+            assert_eq!(question.scale()[degree as usize - 1], question.note());
+            assert!(question.check(degree));
+        }
+
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(ScaleDegreeQuestion::random(C4..=B4, &mut rng), None);
+    }
+}
@@ -0,0 +1,198 @@
+use rand::{Rng, RngExt};
+
+/// The weight of a category that has not been missed recently
+const BASE_WEIGHT: f32 = 1.0;
+
+/// The highest weight a category can reach after repeated wrong answers
+const MAX_WEIGHT: f32 = 8.0;
+
+/// The categories of questions asked by a quiz session
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuestionCategory {
+    Interval,
+    ChordQuality,
+    ScaleDegree,
+}
+
+impl QuestionCategory {
+    /// All the categories, in a stable order
+    pub const ALL: [QuestionCategory; 3] = [
+        QuestionCategory::Interval,
+        QuestionCategory::ChordQuality,
+        QuestionCategory::ScaleDegree,
+    ];
+
+    /// Returns the position of the category in `ALL`
+    fn index(&self) -> usize {
+        match self {
+            QuestionCategory::Interval => 0,
+            QuestionCategory::ChordQuality => 1,
+            QuestionCategory::ScaleDegree => 2,
+        }
+    }
+}
+
+/// Tracks the score of a quiz and adapts which categories are asked
+///
+/// Every category starts with the same weight. A wrong answer doubles the weight
+/// of its category (up to a maximum), and a right answer halves it back towards
+/// the base weight, so recently-missed categories are asked more often until the
+/// student gets them right again.
+///
+/// # Examples
+/// ```
+/// use mozzart_std::*;
+///
+/// let mut session = QuizSession::new();
+/// session.record(QuestionCategory::Interval, false);
+/// session.record(QuestionCategory::ScaleDegree, true);
+///
+/// assert_eq!(session.score(), (1, 2));
+/// assert!(session.weight(QuestionCategory::Interval) > session.weight(QuestionCategory::ScaleDegree));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuizSession {
+    weights: [f32; 3],
+    correct: u32,
+    total: u32,
+}
+
+impl QuizSession {
+    /// Creates a new session, with an empty score and equal weights
+    pub fn new() -> Self {
+        Self {
+            weights: [BASE_WEIGHT; 3],
+            correct: 0,
+            total: 0,
+        }
+    }
+
+    /// Records the answer to a question
+    ///
+    /// # Arguments
+    /// * `category` - The category of the question
+    /// * `correct` - Whether the answer was right
+    pub fn record(&mut self, category: QuestionCategory, correct: bool) {
+        self.total += 1;
+
+        let weight = &mut self.weights[category.index()];
+        if correct {
+            self.correct += 1;
+            *weight = (*weight / 2.0).max(BASE_WEIGHT);
+        } else {
+            *weight = (*weight * 2.0).min(MAX_WEIGHT);
+        }
+    }
+
+    /// Returns the number of correct answers and the number of questions asked
+    pub fn score(&self) -> (u32, u32) {
+        (self.correct, self.total)
+    }
+
+    /// Returns the current weight of a category
+    pub fn weight(&self, category: QuestionCategory) -> f32 {
+        self.weights[category.index()]
+    }
+
+    /// Picks the category of the next question, favoring recently-missed ones
+    ///
+    /// # Arguments
+    /// * `rng` - The source of randomness
+    ///
+    /// # Returns
+    /// A category, picked with a probability proportional to its weight
+    pub fn next_category<R>(&self, rng: &mut R) -> QuestionCategory
+    where
+        R: Rng + ?Sized,
+    {
+        let total: f32 = self.weights.iter().sum();
+        let mut pick = rng.random_range(0.0..total);
+        for category in QuestionCategory::ALL {
+            let weight = self.weight(category);
+            if pick < weight {
+                return category;
+            }
+            pick -= weight;
+        }
+
+        QuestionCategory::ScaleDegree
+    }
+}
+
+impl Default for QuizSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    fn distribution(session: &QuizSession, seed: u64) -> [u32; 3] {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let mut counts = [0; 3];
+        for _ in 0..3000 {
+            counts[session.next_category(&mut rng).index()] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_score() {
+        let mut session = QuizSession::new();
+        assert_eq!(session.score(), (0, 0));
+
+        session.record(QuestionCategory::Interval, true);
+        session.record(QuestionCategory::ChordQuality, false);
+        session.record(QuestionCategory::ScaleDegree, true);
+        assert_eq!(session.score(), (2, 3));
+    }
+
+    #[test]
+    fn test_uniform_without_mistakes() {
+        let counts = distribution(&QuizSession::new(), 1);
+        assert!(
+            counts.iter().all(|&c| (900..1100).contains(&c)),
+            "{counts:?}"
+        );
+    }
+
+    #[test]
+    fn test_weighting_increases_after_wrong_answers() {
+        let mut session = QuizSession::new();
+        let before = distribution(&session, 1)[QuestionCategory::ChordQuality.index()];
+
+        session.record(QuestionCategory::ChordQuality, false);
+        assert_eq!(session.weight(QuestionCategory::ChordQuality), 2.0);
+        session.record(QuestionCategory::ChordQuality, false);
+        assert_eq!(session.weight(QuestionCategory::ChordQuality), 4.0);
+
+        let after = distribution(&session, 1);
+        let missed = after[QuestionCategory::ChordQuality.index()];
+        assert!(missed > before, "{before} -> {missed}");
+        assert!(missed > after[QuestionCategory::Interval.index()] * 3);
+
+        // The weight is capped
+        for _ in 0..10 {
+            session.record(QuestionCategory::ChordQuality, false);
+        }
+        assert_eq!(session.weight(QuestionCategory::ChordQuality), MAX_WEIGHT);
+    }
+
+    #[test]
+    fn test_weighting_recovers_after_right_answers() {
+        let mut session = QuizSession::new();
+        session.record(QuestionCategory::Interval, false);
+        session.record(QuestionCategory::Interval, false);
+        session.record(QuestionCategory::Interval, true);
+        assert_eq!(session.weight(QuestionCategory::Interval), 2.0);
+
+        session.record(QuestionCategory::Interval, true);
+        session.record(QuestionCategory::Interval, true);
+        assert_eq!(session.weight(QuestionCategory::Interval), BASE_WEIGHT);
+    }
+}
@@ -2,7 +2,8 @@
 ///
 /// This module defines the `NamedSlice` struct which pairs a name with a slice reference,
 /// enabling better debugging and display of collections in musical contexts.
-use std::fmt;
+use crate::Note;
+use std::fmt::{self, Write};
 
 /// Represents a named view over a slice of items.
 ///
@@ -29,11 +30,21 @@ pub struct NamedSlice<'a, T> {
     pub name: String,
     /// The referenced slice of items
     pub items: &'a [T],
+    /// The separator placed between items when formatting, see `with_separator`
+    separator: String,
+    /// The number of items written on one line before the output wraps
+    columns: usize,
 }
 
-/// The separator used when formatting items in a `NamedSlice`
+/// The default separator used when formatting items in a `NamedSlice`
 const SEPARATOR: &str = ", ";
 
+/// The default number of items written on one line
+const DEFAULT_COLUMNS: usize = 12;
+
+/// The indentation of the lines of a `NamedSlice` written on several lines
+const INDENT: &str = "    ";
+
 impl<'a, T> NamedSlice<'a, T> {
     /// Creates a new `NamedSlice` with the specified name and items.
     ///
@@ -55,7 +66,12 @@ impl<'a, T> NamedSlice<'a, T> {
     /// let named_chord = NamedSlice::new("C Major".to_string(), &chord_notes);
     /// ```
     pub fn new(name: String, items: &'a [T]) -> Self {
-        Self { name, items }
+        Self {
+            name,
+            items,
+            separator: SEPARATOR.to_string(),
+            columns: DEFAULT_COLUMNS,
+        }
     }
 
     /// Creates a new `NamedSlice` with an unnamed name and the specified items.
@@ -80,37 +96,187 @@ impl<'a, T> NamedSlice<'a, T> {
         Self {
             name: "".to_string(),
             items,
+            separator: SEPARATOR.to_string(),
+            columns: DEFAULT_COLUMNS,
         }
     }
+
+    /// Sets the separator placed between items when formatting.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator` - The string placed between two consecutive items
+    ///
+    /// # Returns
+    ///
+    /// The `NamedSlice` using the new separator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mozzart_std::NamedSlice;
+    ///
+    /// let chord_notes = [60, 64, 67]; // C Major chord
+    /// let named_chord = NamedSlice::new("C Major".to_string(), &chord_notes).with_separator("\t");
+    /// assert_eq!(named_chord.to_string(), "C Major:[60\t64\t67]");
+    /// ```
+    pub fn with_separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Sets the number of items written on one line before the output wraps.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The number of items on a line, at least 1
+    ///
+    /// # Returns
+    ///
+    /// The `NamedSlice` using the new number of columns
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mozzart_std::NamedSlice;
+    ///
+    /// let numbers: Vec<_> = (1..=5).collect();
+    /// let slice = NamedSlice::new("Numbers".to_string(), &numbers).with_columns(2);
+    /// assert_eq!(slice.to_string(), "Numbers:[\n    1, 2,\n    3, 4,\n    5\n]");
+    /// ```
+    pub fn with_columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
 }
 
-/// Formats a slice of items into a string representation.
+/// A note written as a row of a table: its name, MIDI number and frequency at 440 Hz
 ///
-/// This helper function converts each item in the slice to a string using the provided
-/// function, then joins them with the defined separator and wraps them in square brackets.
+/// Chords and scales wrap their notes in this type to get a table from the
+/// alternate debug output of `NamedSlice`, such as `C4      60    261.63 Hz`.
+pub(crate) struct NoteRow(pub(crate) Note);
+
+impl fmt::Debug for NoteRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let NoteRow(note) = self;
+        let frequency = note.frequency_with_reference(440.0);
+        write!(
+            f,
+            "{:<4}  {:>3}  {frequency:>8.2} Hz",
+            note.name(),
+            note.midi_number()
+        )
+    }
+}
+
+/// Writes a slice of items, separated and wrapped in square brackets.
+///
+/// The items are written straight into the output, so formatting a slice
+/// doesn't allocate an intermediate string per item.
 ///
 /// # Arguments
 ///
+/// * `out` - Where the items are written
 /// * `items` - The slice of items to format
-/// * `f` - A function that converts each item to a string
+/// * `separator` - The string placed between two consecutive items
+/// * `write_item` - A function that writes one item to the output
 ///
 /// # Returns
 ///
-/// A formatted string representation of the slice
+/// A formatting result
+fn write_items<W, T, F>(out: &mut W, items: &[T], separator: &str, mut write_item: F) -> fmt::Result
+where
+    W: fmt::Write,
+    F: FnMut(&mut W, &T) -> fmt::Result,
+{
+    out.write_char('[')?;
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.write_str(separator)?;
+        }
+        write_item(out, item)?;
+    }
+    out.write_char(']')
+}
+
+/// Writes a slice of items on several lines, in aligned columns.
+///
+/// Each line holds `columns` items, each followed by the separator and padded
+/// to the widest of them, so the items of the same column start at the same place.
 ///
-/// # Examples
+/// # Arguments
 ///
-/// ```
-/// // Internal usage example
-/// // to_string(&[1, 2, 3], |n| n.to_string()) would return "[1, 2, 3]"
-/// ```
-#[inline]
-fn to_string<T, F>(items: &[T], f: F) -> String
+/// * `f` - The formatter to write to
+/// * `items` - The slice of items to format
+/// * `separator` - The string placed between two consecutive items
+/// * `columns` - The number of items on a line
+/// * `to_string` - A function that converts each item to a string
+///
+/// # Returns
+///
+/// A formatting result
+fn write_wrapped<T, F>(
+    f: &mut fmt::Formatter<'_>,
+    items: &[T],
+    separator: &str,
+    columns: usize,
+    to_string: F,
+) -> fmt::Result
 where
     F: Fn(&T) -> String,
 {
-    let items = items.iter().map(f).collect::<Vec<_>>().join(SEPARATOR);
-    format!("[{items}]")
+    let cells: Vec<_> = items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let cell = to_string(item);
+            if i + 1 < items.len() {
+                cell + separator
+            } else {
+                cell
+            }
+        })
+        .collect();
+    let width = cells
+        .iter()
+        .map(|cell| cell.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    f.write_str("[\n")?;
+    for row in cells.chunks(columns) {
+        let line: String = row.iter().map(|cell| format!("{cell:<width$}")).collect();
+        writeln!(f, "{INDENT}{}", line.trim_end())?;
+    }
+    f.write_char(']')
+}
+
+/// Writes a slice of items as a table, one item per line after its index.
+///
+/// # Arguments
+///
+/// * `f` - The formatter to write to
+/// * `name` - The name written above the table, if not empty
+/// * `items` - The slice of items to format
+///
+/// # Returns
+///
+/// A formatting result
+fn write_table<T>(f: &mut fmt::Formatter<'_>, name: &str, items: &[T]) -> fmt::Result
+where
+    T: fmt::Debug,
+{
+    if !name.is_empty() {
+        writeln!(f, "{name}:")?;
+    }
+    let width = items.len().saturating_sub(1).to_string().len();
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            f.write_char('\n')?;
+        }
+        write!(f, "{INDENT}{i:>width$}  {item:?}")?;
+    }
+    Ok(())
 }
 
 impl<T> fmt::Debug for NamedSlice<'_, T>
@@ -125,6 +291,9 @@ where
     /// If the slice contains zero or one item, the output format is:
     /// `[item]` or `[]`
     ///
+    /// The alternate flag (`{:#?}`) writes a table instead, the name on the first
+    /// line then one item per line after its index.
+    ///
     /// # Arguments
     ///
     /// * `f` - The formatter to write to
@@ -133,18 +302,65 @@ where
     ///
     /// A formatting result
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        let items = to_string(self.items, |item| format!("{:?}", item));
+        if f.alternate() {
+            return write_table(f, &self.name, self.items);
+        }
         if self.items.len() > 1 {
-            write!(f, "{}:{items}", self.name)
-        } else {
-            write!(f, "{items}")
+            write!(f, "{}:", self.name)?;
         }
+        write_items(f, self.items, &self.separator, |f, item| {
+            write!(f, "{item:?}")
+        })
+    }
+}
+
+impl<T> fmt::Display for NamedSlice<'_, T>
+where
+    T: fmt::Display,
+{
+    /// Formats the `NamedSlice` for user-facing output.
+    ///
+    /// The layout is the same as the debug output, but each item is rendered
+    /// with its `Display` implementation. Slices of more than 12 items are
+    /// wrapped to lines of 12 items in aligned columns; `with_columns` sets
+    /// another number of items per line.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to write to
+    ///
+    /// # Returns
+    ///
+    /// A formatting result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mozzart_std::NamedSlice;
+    ///
+    /// let numbers: Vec<_> = (1..=5).collect();
+    /// let slice = NamedSlice::new("Numbers".to_string(), &numbers);
+    /// assert_eq!(slice.to_string(), "Numbers:[1, 2, 3, 4, 5]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        if self.items.len() > 1 {
+            write!(f, "{}:", self.name)?;
+        }
+        if self.items.len() > self.columns {
+            return write_wrapped(f, self.items, &self.separator, self.columns, |item| {
+                item.to_string()
+            });
+        }
+        write_items(f, self.items, &self.separator, |f, item| {
+            write!(f, "{item}")
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::*;
 
     #[test]
     fn test_named_slice_new() {
@@ -157,27 +373,33 @@ mod tests {
         assert_eq!(named_slice.items, &items);
     }
 
+    /// Writes the items with `write_items` into a string
+    fn written<T>(items: &[T], separator: &str) -> String
+    where
+        T: fmt::Display,
+    {
+        let mut out = String::new();
+        write_items(&mut out, items, separator, |out, item| {
+            write!(out, "{item}")
+        })
+        .unwrap();
+        out
+    }
+
     #[test]
-    fn test_to_string_function() {
-        // Test that to_string correctly formats an empty slice
+    fn test_write_items() {
+        // Test that write_items correctly formats an empty slice
         let empty: Vec<i32> = Vec::new();
-        let result = to_string(&empty, |n| n.to_string());
-        assert_eq!(result, "[]");
+        assert_eq!(written(&empty, SEPARATOR), "[]");
 
-        // Test that to_string correctly formats a slice with one item
-        let single = vec![42];
-        let result = to_string(&single, |n| n.to_string());
-        assert_eq!(result, "[42]");
+        // Test that write_items correctly formats a slice with one item
+        assert_eq!(written(&[42], SEPARATOR), "[42]");
 
-        // Test that to_string correctly formats a slice with multiple items
-        let multiple = vec![1, 2, 3];
-        let result = to_string(&multiple, |n| n.to_string());
-        assert_eq!(result, "[1, 2, 3]");
+        // Test that write_items correctly formats a slice with multiple items
+        assert_eq!(written(&[1, 2, 3], SEPARATOR), "[1, 2, 3]");
 
-        // Test with a custom formatter function
-        let numbers = vec![1, 2, 3];
-        let result = to_string(&numbers, |n| format!("Number {}", n));
-        assert_eq!(result, "[Number 1, Number 2, Number 3]");
+        // Test with a custom separator
+        assert_eq!(written(&[1, 2, 3], "; "), "[1; 2; 3]");
     }
 
     #[test]
@@ -206,4 +428,119 @@ mod tests {
         let debug_str = format!("{:?}", named_slice);
         assert_eq!(debug_str, "[]");
     }
+
+    #[test]
+    fn test_display_with_separator() {
+        // Test display formatting of notes with custom separators
+        let notes = [C4, E4, G4];
+        let named_slice = NamedSlice::new("C Major".to_string(), &notes).with_separator("; ");
+        assert_eq!(named_slice.to_string(), "C Major:[C; E; G]");
+
+        let named_slice = NamedSlice::new("C Major".to_string(), &notes).with_separator("\t");
+        assert_eq!(named_slice.to_string(), "C Major:[C\tE\tG]");
+        assert_eq!(
+            format!("{:?}", named_slice),
+            "C Major:[C[60]\tE[64]\tG[67]]"
+        );
+    }
+
+    #[test]
+    fn test_display_default_separator() {
+        // Test that display uses ", " unless told otherwise
+        let items = vec![1, 2, 3];
+        let named_slice = NamedSlice::new("Numbers".to_string(), &items);
+        assert_eq!(named_slice.to_string(), "Numbers:[1, 2, 3]");
+    }
+
+    /// The chromatic run from C4 to C6, 25 notes
+    fn chromatic_run() -> Vec<crate::Note> {
+        (60..=84)
+            .map(|midi| crate::Note::try_from(midi).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_display_wraps_long_slices() {
+        let notes = chromatic_run();
+        let named_slice = NamedSlice::new("Chromatic".to_string(), &notes);
+        assert_eq!(
+            named_slice.to_string(),
+            "Chromatic:[
+    C,  C#, D,  D#, E,  F,  F#, G,  G#, A,  A#, B,
+    C,  C#, D,  D#, E,  F,  F#, G,  G#, A,  A#, B,
+    C
+]"
+        );
+
+        // The number of columns sets the number of items per line
+        let named_slice = named_slice.with_columns(25);
+        assert_eq!(
+            named_slice.to_string(),
+            "Chromatic:[C, C#, D, D#, E, F, F#, G, G#, A, A#, B, C, C#, D, D#, E, F, F#, G, G#, A, A#, B, C]"
+        );
+        let named_slice = named_slice.with_columns(10);
+        assert_eq!(
+            named_slice.to_string(),
+            "Chromatic:[
+    C,  C#, D,  D#, E,  F,  F#, G,  G#, A,
+    A#, B,  C,  C#, D,  D#, E,  F,  F#, G,
+    G#, A,  A#, B,  C
+]"
+        );
+
+        // Twelve items still fit on one line
+        let octave = &notes[..12];
+        assert_eq!(
+            NamedSlice::new("Octave".to_string(), octave).to_string(),
+            "Octave:[C, C#, D, D#, E, F, F#, G, G#, A, A#, B]"
+        );
+    }
+
+    #[test]
+    fn test_debug_table() {
+        let rows: Vec<_> = chromatic_run().into_iter().map(NoteRow).collect();
+        let named_slice = NamedSlice::new("Chromatic".to_string(), &rows);
+        assert_eq!(
+            format!("{named_slice:#?}"),
+            "Chromatic:
+     0  C4     60    261.63 Hz
+     1  C#4    61    277.18 Hz
+     2  D4     62    293.66 Hz
+     3  D#4    63    311.13 Hz
+     4  E4     64    329.63 Hz
+     5  F4     65    349.23 Hz
+     6  F#4    66    369.99 Hz
+     7  G4     67    392.00 Hz
+     8  G#4    68    415.30 Hz
+     9  A4     69    440.00 Hz
+    10  A#4    70    466.16 Hz
+    11  B4     71    493.88 Hz
+    12  C5     72    523.25 Hz
+    13  C#5    73    554.37 Hz
+    14  D5     74    587.33 Hz
+    15  D#5    75    622.25 Hz
+    16  E5     76    659.26 Hz
+    17  F5     77    698.46 Hz
+    18  F#5    78    739.99 Hz
+    19  G5     79    783.99 Hz
+    20  G#5    80    830.61 Hz
+    21  A5     81    880.00 Hz
+    22  A#5    82    932.33 Hz
+    23  B5     83    987.77 Hz
+    24  C6     84   1046.50 Hz"
+        );
+
+        // The compact debug output is unchanged
+        let notes = chromatic_run();
+        assert_eq!(
+            format!("{:?}", NamedSlice::new("Third".to_string(), &notes[..2])),
+            "Third:[C[60], C#[61]]"
+        );
+        assert_eq!(
+            format!("{:#?}", NamedSlice::new("Third".to_string(), &notes[..2])),
+            "Third:\n    0  C[60]\n    1  C#[61]"
+        );
+        // Notes keep their compact debug output inside other values
+        assert_eq!(format!("{:#?}", Some(C4)), "Some(\n    C[60],\n)");
+    }
 }
@@ -0,0 +1,73 @@
+//! Checks that the buffer-writing functions don't allocate once their buffers are large enough
+
+use mozzart_std::constants::*;
+use mozzart_std::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    /// The number of allocations made by the current thread
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts the allocations of each thread, then lets the system allocator do the work
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made by the current thread while running `f`
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn test_scale_and_quantize_without_allocation() {
+    let melody: Vec<Note> = pitch_range(C3, C6).collect();
+    let mut quantized = Vec::with_capacity(melody.len());
+    let mut chord = Vec::with_capacity(8);
+    let mut tones = Vec::with_capacity(128);
+
+    for i in 0..10_000 {
+        let root = Note::try_from(36 + (i % 48) as u8).unwrap();
+        let (scale, count) = allocations(|| {
+            let scale = major_scale(root).unwrap();
+            scale.quantize_all_into(&melody, &mut quantized);
+            build_chord_into(root, &DOMINANT_SEVENTH_INTERVALS, &mut chord);
+            dominant_seventh(root)
+                .unwrap()
+                .chord_tones_in_range_into(C3..=C6, &mut tones);
+            scale
+        });
+        assert_eq!(count, 0, "iteration {i}");
+
+        if i % 97 == 0 {
+            assert_eq!(quantized, scale.quantize_all(&melody));
+            assert_eq!(dominant_seventh(root).unwrap(), chord);
+            assert_eq!(
+                tones,
+                dominant_seventh(root)
+                    .unwrap()
+                    .chord_tones_in_range(C3..=C6)
+            );
+        }
+    }
+}
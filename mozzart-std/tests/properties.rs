@@ -0,0 +1,172 @@
+//! Property tests for the arithmetic invariants of notes, scales and chords
+
+use mozzart_std::constants::*;
+use mozzart_std::*;
+use proptest::prelude::*;
+
+/// The highest MIDI note number
+const MAX_MIDI: u8 = 127;
+
+/// Generates any MIDI note
+fn note() -> impl Strategy<Value = Note> {
+    (0..=MAX_MIDI).prop_map(|midi| Note::try_from(midi).unwrap())
+}
+
+/// Generates an interval of up to two octaves
+fn interval() -> impl Strategy<Value = Interval> {
+    (0..=24u8)
+        .prop_map(|semitones| Interval::from_pitches(C4, Note::try_from(60 + semitones).unwrap()))
+}
+
+/// Generates a short vector of notes, none of them below the first one
+fn notes() -> impl Strategy<Value = Vec<Note>> {
+    note().prop_flat_map(|root| {
+        let above = (root.midi_number()..=MAX_MIDI).prop_map(|midi| Note::try_from(midi).unwrap());
+        prop::collection::vec(above, 0..8)
+            .prop_map(move |rest| std::iter::once(root).chain(rest).collect::<Vec<_>>())
+    })
+}
+
+/// Checks that the notes go strictly up and span exactly an octave
+fn assert_octave_span(notes: &[Note]) {
+    assert!(notes.windows(2).all(|pair| pair[0] < pair[1]), "{notes:?}");
+    let span = notes[notes.len() - 1].midi_number() - notes[0].midi_number();
+    assert_eq!(span, 12, "{notes:?}");
+}
+
+proptest! {
+    #[test]
+    fn intervals_from_the_root_rebuild_the_notes(notes in notes()) {
+        let root = notes[0];
+        let intervals: Vec<_> = notes[1..]
+            .iter()
+            .map(|&note| Interval::from_pitches(root, note))
+            .collect();
+
+        let rebuilt: Vec<_> = root.into_notes_from_intervals(intervals).collect();
+        prop_assert_eq!(rebuilt, notes);
+    }
+
+    #[test]
+    fn adding_an_interval_keeps_its_size(note in note(), interval in interval()) {
+        if let Some(high) = note.checked_add_semitones(i16::from(interval.semitones())) {
+            prop_assert_eq!(Interval::from_pitches(note, high), interval);
+            prop_assert_eq!(Interval::from_pitches(high, note), interval);
+        }
+    }
+
+    #[test]
+    fn scales_go_up_one_octave(root in note()) {
+        let fits = root.midi_number() <= MAX_MIDI - 12;
+        let scales = [
+            major_scale(root).map(|scale| scale.notes().to_vec()),
+            natural_minor_scale(root).map(|scale| scale.notes().to_vec()),
+            harmonic_minor_scale(root).map(|scale| scale.notes().to_vec()),
+            melodic_minor_scale(root).map(|scale| scale.notes().to_vec()),
+            whole_tone_scale(root).map(|scale| scale.notes().to_vec()),
+            octatonic_scale(root).map(|scale| scale.notes().to_vec()),
+        ];
+        for scale in scales {
+            match scale {
+                Ok(notes) => assert_octave_span(&notes),
+                Err(error) => prop_assert_eq!(error, Error::NoteOutOfRange(root)),
+            }
+        }
+        for mode in 1..=7 {
+            let scale = messiaen_mode(mode, root);
+            prop_assert_eq!(scale.is_some(), fits);
+            if let Some(scale) = scale {
+                assert_octave_span(scale.notes());
+            }
+        }
+        prop_assert_eq!(major_scale(root).is_ok(), fits);
+    }
+
+    #[test]
+    fn transposing_back_restores_the_note(note in note(), semitones in -200i16..=200) {
+        prop_assert_eq!(note.wrapping_add_semitones(semitones).wrapping_add_semitones(-semitones), note);
+        if let Some(moved) = note.checked_add_semitones(semitones) {
+            prop_assert_eq!(moved.checked_add_semitones(-semitones), Some(note));
+        }
+    }
+
+    #[test]
+    fn transposing_back_restores_the_chord(root in note(), semitones in -60i8..=60) {
+        let Ok(chord) = dominant_seventh(root) else {
+            prop_assert!(root.midi_number() > MAX_MIDI - 10);
+            return Ok(());
+        };
+        let lowest = i16::from(root.midi_number()) + i16::from(semitones);
+        let fits = (0..=i16::from(MAX_MIDI) - 10).contains(&lowest);
+
+        let transposed = chord.transpose(semitones);
+        prop_assert_eq!(transposed.is_some(), fits);
+        if let Some(transposed) = transposed {
+            prop_assert_eq!(transposed.transpose(-semitones), Some(chord));
+        }
+    }
+
+    #[test]
+    fn inverting_once_per_note_restores_the_pitch_classes(root in note()) {
+        let chord = match dominant_seventh(root) {
+            Ok(chord) => chord,
+            Err(error) => {
+                prop_assert_eq!(error, Error::NoteOutOfRange(root));
+                return Ok(());
+            }
+        };
+        let mut voicing = Voicing::from(&chord);
+        for _ in 0..chord.notes().len() {
+            match voicing.invert() {
+                Ok(inverted) => voicing = inverted,
+                Err(error) => {
+                    // Only the lowest note moves, and it can't go above the top of the range
+                    prop_assert_eq!(error, Error::NoteOutOfRange(voicing.notes()[0]));
+                    return Ok(());
+                }
+            }
+        }
+
+        prop_assert_eq!(
+            PitchClassMask::from_notes(voicing.notes()),
+            PitchClassMask::from_notes(chord.notes())
+        );
+    }
+
+    #[test]
+    fn scale_neighbors_are_in_the_scale(root in note(), note in note()) {
+        let Ok(scale) = major_scale(root) else {
+            prop_assert!(root.midi_number() > MAX_MIDI - 12);
+            return Ok(());
+        };
+
+        if let Some(upper) = scale.upper_neighbor(note) {
+            prop_assert!(upper > note);
+            prop_assert!(scale.contains_pitch_class(upper));
+            prop_assert!((note.midi_number() + 1..upper.midi_number())
+                .all(|midi| !scale.contains_pitch_class(Note::try_from(midi).unwrap())));
+        }
+        if let Some(lower) = scale.lower_neighbor(note) {
+            prop_assert!(lower < note);
+            prop_assert!(scale.contains_pitch_class(lower));
+        }
+    }
+
+    #[test]
+    fn chord_symbols_parse_back_to_the_same_pitch_classes(notes in notes()) {
+        let voicing = Voicing::new(notes);
+        for spelling in [SpellingPreference::Sharps, SpellingPreference::Flats] {
+            if let Some(symbol) = voicing.symbol(spelling) {
+                let parsed: Voicing = symbol.parse().unwrap();
+                prop_assert_eq!(
+                    PitchClassMask::from_notes(parsed.notes()),
+                    PitchClassMask::from_notes(voicing.notes())
+                );
+                prop_assert_eq!(
+                    parsed.notes()[0].pitch_class(),
+                    voicing.notes()[0].pitch_class()
+                );
+            }
+        }
+    }
+}